@@ -1,24 +1,138 @@
 fn main() -> std::io::Result<()> {
     let tokens = windows_macros::generate! {
+        std_feature = true,
         Windows::{
-            Foundation::{IReference, IStringable, PropertyValue},
+            Devices::Enumeration::{
+                DeviceInformation, DeviceInformationCustomPairing, DeviceInformationPairing,
+                DevicePairingRequestedEventArgs, DevicePairingResult, DevicePicker,
+                DevicePickerFilter,
+            },
+            Foundation::{
+                Collections::{
+                    CollectionChange, IIterable, IIterator, IKeyValuePair, IMap, IMapView,
+                    IObservableVector, IVector, IVectorChangedEventArgs, IVectorView,
+                    VectorChangedEventHandler,
+                },
+                AsyncOperationCompletedHandler, AsyncOperationProgressHandler,
+                AsyncOperationWithProgressCompletedHandler, AsyncStatus, EventRegistrationToken,
+                IAsyncInfo, IAsyncOperation, IAsyncOperationWithProgress, IClosable, IMemoryBuffer,
+                IMemoryBufferReference, IReference, IStringable, Metadata::ApiInformation,
+                PropertyValue, Rect, TypedEventHandler,
+            },
+            Storage::Streams::{
+                Buffer, IBuffer, IInputStream, IOutputStream, IRandomAccessStream,
+                InputStreamOptions,
+            },
+            System::{DispatcherQueue, DispatcherQueueController, DispatcherQueueHandler},
+            UI::Xaml::Data::{PropertyChangedEventArgs, PropertyChangedEventHandler},
             Win32::{
-                Foundation::{CloseHandle, BSTR, CO_E_NOTINITIALIZED, E_NOINTERFACE, E_POINTER},
+                Devices::DeviceAndDriverInstallation::{
+                    CM_MapCrToWin32Err, CM_Register_Notification, CM_Unregister_Notification,
+                    SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInfo,
+                    SetupDiEnumDeviceInterfaces, SetupDiGetClassDevsW, SetupDiGetDeviceInstanceIdW,
+                    SetupDiGetDeviceInterfaceDetailW, SetupDiGetDeviceRegistryPropertyW,
+                    CM_NOTIFY_FILTER_FLAG_ALL_INTERFACE_CLASSES, DIGCF_ALLCLASSES,
+                    DIGCF_DEVICEINTERFACE, DIGCF_PRESENT, SPDRP_COMPATIBLEIDS, SPDRP_DEVICEDESC,
+                    SPDRP_DRIVER, SPDRP_FRIENDLYNAME, SPDRP_HARDWAREID, SPDRP_LOCATION_INFORMATION,
+                    SPDRP_MFG, SPDRP_SERVICE,
+                },
+                Devices::HumanInterfaceDevice::{
+                    HidD_FreePreparsedData, HidD_GetAttributes, HidD_GetFeature, HidD_GetHidGuid,
+                    HidD_GetInputReport, HidD_GetManufacturerString, HidD_GetPreparsedData,
+                    HidD_GetProductString, HidD_GetSerialNumberString, HidD_SetFeature,
+                    HidP_GetButtonCaps, HidP_GetCaps, HidP_GetValueCaps,
+                },
+                Foundation::{
+                    CloseHandle, BSTR, CLASS_E_CLASSNOTAVAILABLE, CLASS_E_NOAGGREGATION,
+                    CLASS_E_NOTLICENSED, CO_E_NOTINITIALIZED, DISP_E_BADPARAMCOUNT,
+                    DISP_E_BADVARTYPE, DISP_E_EXCEPTION, DISP_E_TYPEMISMATCH, E_ABORT,
+                    E_ACCESSDENIED, E_BOUNDS, E_CHANGED_STATE, E_FAIL, E_HANDLE,
+                    E_ILLEGAL_DELEGATE_ASSIGNMENT,
+                    E_ILLEGAL_METHOD_CALL, E_ILLEGAL_STATE_CHANGE, E_INVALIDARG, E_NOINTERFACE,
+                    E_NOTIMPL, E_OUTOFMEMORY, E_POINTER, E_UNEXPECTED, REGDB_E_CLASSNOTREG, RO_E_CLOSED,
+                    RPC_E_CALL_REJECTED, RPC_E_CHANGED_MODE, RPC_E_DISCONNECTED,
+                    RPC_E_SERVERCALL_RETRYLATER, RPC_E_WRONG_THREAD, S_FALSE, S_OK,
+                },
+                Storage::FileSystem::{
+                    CreateFileW, CreateHardLinkW, CreateSymbolicLinkW, FindClose,
+                    FindFirstStreamW, FindNextStreamW, GetFileAttributesExW, GetFullPathNameW,
+                    MoveFileExW, ReadFile, ReplaceFileW, SetFileAttributesW, SetFileTime,
+                    WriteFile, READ_USN_JOURNAL_DATA_V0, USN_JOURNAL_DATA_V0,
+                    WIN32_FILE_ATTRIBUTE_DATA, WIN32_FIND_STREAM_DATA,
+                },
+                Storage::StructuredStorage::{PropVariantClear, PropVariantCopy, PROPVARIANT},
                 System::{
+                    ApplicationInstallationAndServicing::{
+                        ActivateActCtx, CreateActCtxW, DeactivateActCtx, ReleaseActCtx,
+                    },
                     Com::{
-                        CLSIDFromProgID, CoCreateGuid, CoCreateInstance, CoInitializeEx,
-                        CoTaskMemAlloc, CoTaskMemFree, IAgileObject,
+                        CLSIDFromProgID, CoCreateFreeThreadedMarshaler, CoCreateGuid,
+                        CoCreateInstance, CoGetApartmentType, CoInitializeEx, CoRegisterMallocSpy,
+                        CoRevokeMallocSpy, CoTaskMemAlloc, CoTaskMemFree, CoUninitialize,
+                        CoWaitForMultipleHandles, CreateBindCtx, CreateItemMoniker,
+                        GetRunningObjectTable, IAgileObject, IBindCtx, IConnectionPoint,
+                        IConnectionPointContainer, IEnumMoniker, IMallocSpy, IMoniker,
+                        IRunningObjectTable, MkParseDisplayName, APTTYPE, APTTYPEQUALIFIER,
+                        COWAIT_FLAGS,
+                    },
+                    Diagnostics::Debug::{
+                        FormatMessageW, GetErrorMode, GetLastError, GetThreadErrorMode,
+                        RtlCaptureStackBackTrace, SetErrorMode, SetThreadErrorMode, FACILITY_CODE,
+                        THREAD_ERROR_MODE, WIN32_ERROR,
+                    },
+                    LibraryLoader::{
+                        EnumResourceNamesW, FindResourceW, FreeLibrary, GetModuleFileNameW,
+                        GetModuleHandleExW, GetProcAddress, LoadLibraryA, LoadResource,
+                        LockResource, SizeofResource, GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS,
+                        GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
                     },
-                    Diagnostics::Debug::{FormatMessageW, GetLastError},
-                    LibraryLoader::{FreeLibrary, GetProcAddress, LoadLibraryA},
                     Memory::{GetProcessHeap, HeapAlloc, HeapFree},
-                    OleAutomation::{GetErrorInfo, IErrorInfo, SetErrorInfo},
-                    Threading::{CreateEventA, SetEvent, WaitForSingleObject},
+                    OleAutomation::{
+                        GetErrorInfo, IDispatch, IErrorInfo, ITypeInfo, SafeArrayAccessData,
+                        SafeArrayCreateVector, SafeArrayDestroy, SafeArrayGetDim,
+                        SafeArrayGetLBound, SafeArrayGetUBound, SafeArrayGetVartype,
+                        SafeArrayUnaccessData, SetErrorInfo, VariantChangeType, VariantClear,
+                        VariantCopy, DISPATCH_METHOD, DISPATCH_PROPERTYGET, DISPATCH_PROPERTYPUT,
+                        DISPATCH_PROPERTYPUTREF, DISPID_PROPERTYPUT, VARENUM,
+                    },
+                    PropertiesSystem::{
+                        InitPropVariantFromStringVector, PSGetNameFromPropertyKey,
+                        PSGetPropertyKeyFromName, PropVariantToBoolean, PropVariantToDouble,
+                        PropVariantToGUID, PropVariantToInt32, PropVariantToInt64,
+                        PropVariantToStringAlloc, PropVariantToStringVectorAlloc,
+                        PropVariantToUInt32, PropVariantToUInt64, SHGetPropertyStoreForWindow,
+                        SHGetPropertyStoreFromParsingName, GETPROPERTYSTOREFLAGS, IPropertyStore,
+                        PROPERTYKEY,
+                    },
+                    Registry::{
+                        RegCloseKey, RegCreateKeyExW, RegDeleteTreeW, RegSetValueExW,
+                        HKEY_CURRENT_USER, HKEY_LOCAL_MACHINE,
+                    },
+                    SystemServices::{
+                        DeviceIoControl, COMPRESSION_FORMAT_DEFAULT, COMPRESSION_FORMAT_NONE,
+                        FSCTL_QUERY_USN_JOURNAL, FSCTL_READ_USN_JOURNAL, FSCTL_SET_COMPRESSION,
+                        FSCTL_SET_REPARSE_POINT, FSCTL_SET_SPARSE,
+                        IO_REPARSE_TAG_MOUNT_POINT, LANG_NEUTRAL, MAKELANGID, MAKELCID,
+                        ROTFLAGS_REGISTRATIONKEEPSALIVE, SORT_DEFAULT, SUBLANG_DEFAULT,
+                    },
+                    Threading::{
+                        ConvertFiberToThread, ConvertThreadToFiberEx, CreateEventA, CreateFiberEx,
+                        DeleteFiber, SetEvent, SwitchToFiber, WaitForSingleObject,
+                    },
+                    WindowsProgramming::{
+                        ACTCTX_FLAG_HMODULE_VALID, ACTCTX_FLAG_RESOURCE_NAME_VALID,
+                        FIBER_FLAG_FLOAT_SWITCH,
+                    },
                     WinRT::{
-                        ILanguageExceptionErrorInfo2, IRestrictedErrorInfo, IWeakReference,
+                        CreateDispatcherQueueController, IBufferByteAccess,
+                        ILanguageExceptionErrorInfo2, IMemoryBufferByteAccess, IRestrictedErrorInfo, IWeakReference,
                         IWeakReferenceSource,
                     },
                 },
+                UI::Shell::{
+                    GetCurrentProcessExplicitAppUserModelID, IInitializeWithWindow,
+                    SetCurrentProcessExplicitAppUserModelID,
+                },
             },
         },
     };
@@ -1,4 +1,3 @@
-#[cfg(windows)]
 fn main() {
     let mut source: ::std::path::PathBuf = ::std::env::var("CARGO_MANIFEST_DIR")
         .expect("No `CARGO_MANIFEST_DIR` env var")
@@ -7,9 +6,23 @@ fn main() {
     source.push(".windows");
     source.push("winmd");
 
-    let destination = std::env::var("PATH").expect("No `PATH` env variable set");
-    let end = destination.find(';').expect("Path not ending in `;`");
-    let mut destination: std::path::PathBuf = destination[..end].into();
+    // Cargo adds the target directory's `deps` directory to the dynamic library search path,
+    // which is held by a different variable on each host.
+    let name = if cfg!(windows) {
+        "PATH"
+    } else if cfg!(target_os = "macos") {
+        "DYLD_FALLBACK_LIBRARY_PATH"
+    } else {
+        "LD_LIBRARY_PATH"
+    };
+
+    let mut destination = match std::env::var_os(name).and_then(|paths| {
+        std::env::split_paths(&paths).find(|path| path.file_name() == Some("deps".as_ref()))
+    }) {
+        Some(destination) => destination,
+        None => return,
+    };
+
     destination.pop();
     destination.pop();
     destination.push(".windows");
@@ -31,6 +44,3 @@ fn main() {
         }
     }
 }
-
-#[cfg(not(windows))]
-fn main() {}
@@ -65,42 +65,83 @@ fn gen_async_kind(
     };
 
     let constraints = self_name.gen_constraints();
-    let name = self_name.gen_name(gen);
     let namespace = gen.namespace("Windows.Foundation");
 
-    (
+    // The methods are named as they are generated, which depends on the naming options.
+    let status = TypeReader::get()
+        .resolve_type_def("Windows.Foundation", "IAsyncInfo")
+        .gen_method_name("get_Status");
+    let set_completed = name.gen_method_name("put_Completed");
+    let get_results = name.gen_method_name("GetResults");
+    let name = self_name.gen_name(gen);
+
+    let future = if TypeReader::get().options.into_future {
+        let std_cfg = TypeReader::get().options.gen_std_cfg();
+
         quote! {
-            pub fn get(&self) -> ::windows::Result<#return_type> {
-                if self.Status()? == #namespace AsyncStatus::Started {
-                    let (waiter, signaler) = ::windows::Waiter::new();
-                    self.SetCompleted(#namespace  #handler::new(move |_sender, _args| {
-                        // Safe because the waiter will only be dropped after being signaled.
-                        unsafe { signaler.signal(); }
-                        Ok(())
-                    }))?;
+            #std_cfg
+            impl<#constraints> ::core::future::IntoFuture for #name {
+                type Output = ::windows::Result<#return_type>;
+                type IntoFuture = ::windows::AsyncFuture<Self>;
+
+                fn into_future(self) -> Self::IntoFuture {
+                    ::windows::AsyncFuture::new(self)
                 }
-                self.GetResults()
             }
-        },
+        }
+    } else {
         quote! {
-            impl<#constraints> ::std::future::Future for #name {
+            impl<#constraints> ::core::future::Future for #name {
                 type Output = ::windows::Result<#return_type>;
 
-                fn poll(self: ::std::pin::Pin<&mut Self>, context: &mut ::std::task::Context) -> ::std::task::Poll<Self::Output> {
-                    if self.Status()? == #namespace AsyncStatus::Started {
+                fn poll(self: ::core::pin::Pin<&mut Self>, context: &mut ::core::task::Context) -> ::core::task::Poll<Self::Output> {
+                    if self.#status()? == #namespace AsyncStatus::Started {
                         let waker = context.waker().clone();
 
-                        let _ = self.SetCompleted(#namespace #handler::new(move |_sender, _args| {
+                        let _ = self.#set_completed(#namespace #handler::new(move |_sender, _args| {
                             waker.wake_by_ref();
                             Ok(())
                         }));
 
-                        ::std::task::Poll::Pending
+                        ::core::task::Poll::Pending
                     } else {
-                        ::std::task::Poll::Ready(self.GetResults())
+                        ::core::task::Poll::Ready(self.#get_results())
                     }
                 }
             }
+        }
+    };
+
+    (
+        quote! {
+            pub fn get(&self) -> ::windows::Result<#return_type> {
+                if self.#status()? == #namespace AsyncStatus::Started {
+                    let (waiter, signaler) = ::windows::Waiter::new();
+                    self.#set_completed(#namespace  #handler::new(move |_sender, _args| {
+                        // Safe because the waiter will only be dropped after being signaled.
+                        unsafe { signaler.signal(); }
+                        Ok(())
+                    }))?;
+                }
+                self.#get_results()
+            }
+        },
+        quote! {
+            impl<#constraints> ::windows::AsyncOperation for #name {
+                type Output = #return_type;
+
+                fn set_completed(&self, callback: ::windows::alloc::boxed::Box<dyn ::core::ops::Fn() + ::core::marker::Send>) -> ::windows::Result<()> {
+                    self.#set_completed(#namespace #handler::new(move |_sender, _args| {
+                        callback();
+                        Ok(())
+                    }))
+                }
+
+                fn get_results(&self) -> ::windows::Result<#return_type> {
+                    self.#get_results()
+                }
+            }
+            #future
         },
     )
 }
@@ -0,0 +1,565 @@
+use super::*;
+
+/// The name of the file holding the binding configuration at the root of a crate.
+pub const CONFIG_FILE: &str = "windows.toml";
+
+/// The options of the `GenOptions` set by the `[options]` table of a `windows.toml` file and by
+/// the `build!` macro, which have the same names and values in both.
+pub const OPTIONS: &[&str] = &[
+    "traits",
+    "raw_dylib",
+    "os_features",
+    "function_features",
+    "api_stats",
+    "std_feature",
+    "into_future",
+    "contracts",
+    "contract_features",
+    "exclude_deprecated",
+    "desktop_only",
+    "experimental",
+    "codegen_version",
+    "naming",
+];
+
+/// The binding configuration read from a `windows.toml` file, so that the metadata, the types,
+/// and the options generating the bindings may be shared by `build!` and standalone tools rather
+/// than embedded in macro tokens.
+///
+/// The file is written in a subset of TOML: keys are assigned strings, booleans, integers, or
+/// arrays of strings, and the `[options]` and `[rename]` tables follow the top-level keys.
+///
+/// ```toml
+/// metadata = "10.0.19041.0"
+/// output = "src/bindings.rs"
+/// rustfmt = true
+/// types = ["Windows.Foundation.Uri", "Windows.Win32.System.Threading.*"]
+///
+/// [options]
+/// traits = true
+/// contracts = ["UniversalApiContract 8"]
+/// naming = "metadata"
+///
+/// [rename]
+/// "Windows.Foundation.IStringable.ToString" = "Describe"
+/// ```
+#[derive(Default, Debug, PartialEq)]
+pub struct Config {
+    /// The version of the Windows SDK to read the metadata of, such as `10.0.19041.0`, or the
+    /// path of a winmd file or of a directory holding them relative to the crate.
+    pub metadata: Option<String>,
+    /// An explicit path for the generated code relative to the crate.
+    pub output: Option<String>,
+    /// Whether to run rustfmt over the generated code.
+    pub rustfmt: Option<bool>,
+    /// The types to generate, such as `Windows.Foundation.Uri`, along with the namespaces to
+    /// generate every type of, such as `Windows.Foundation.*`.
+    pub types: Vec<String>,
+    /// The options of the `[options]` table, by name.
+    pub options: Vec<(String, ConfigValue)>,
+    /// The names replacing those generated for WinRT methods and struct fields, keyed by the
+    /// full name of the member.
+    pub renames: BTreeMap<String, String>,
+}
+
+/// A value assigned in a `windows.toml` file.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConfigValue {
+    String(String),
+    Bool(bool),
+    Integer(i64),
+    Array(Vec<String>),
+}
+
+impl ConfigValue {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::String(_) => "a string",
+            Self::Bool(_) => "a boolean",
+            Self::Integer(_) => "an integer",
+            Self::Array(_) => "an array",
+        }
+    }
+}
+
+impl Config {
+    /// Reads the `windows.toml` file in the given directory, or returns `None` if there is none.
+    pub fn find<P: AsRef<std::path::Path>>(dir: P) -> Result<Option<Self>, String> {
+        let path = dir.as_ref().join(CONFIG_FILE);
+
+        if path.is_file() {
+            Self::read(path).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads the configuration from the given file.
+    pub fn read<P: AsRef<std::path::Path>>(path: P) -> Result<Self, String> {
+        let path = path.as_ref();
+
+        let text = std::fs::read_to_string(path)
+            .map_err(|error| format!("Could not read `{}`: {}", path.display(), error))?;
+
+        Self::parse(&text).map_err(|error| format!("`{}`: {}", path.display(), error))
+    }
+
+    /// Parses the configuration from the text of a `windows.toml` file.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut config = Self::default();
+        let mut table = String::new();
+        let mut lines = text.lines().enumerate();
+
+        while let Some((number, line)) = lines.next() {
+            let error = |message: String| format!("line {}: {}", number + 1, message);
+            let mut line = strip_comment(line).trim().to_string();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if line.starts_with('[') {
+                if !line.ends_with(']') {
+                    return Err(error("Expected `]` to close the table name".to_string()));
+                }
+
+                table = line[1..line.len() - 1].trim().to_string();
+
+                if table != "options" && table != "rename" {
+                    return Err(error(format!(
+                        "Unknown table `{}`, expected `options` or `rename`",
+                        table
+                    )));
+                }
+
+                continue;
+            }
+
+            // Arrays may span several lines.
+            while line.contains('[') && !line.ends_with(']') {
+                match lines.next() {
+                    Some((_, next)) => {
+                        line.push(' ');
+                        line.push_str(strip_comment(next).trim());
+                    }
+                    None => return Err(error("Expected `]` to close the array".to_string())),
+                }
+            }
+
+            let (key, value) = parse_assignment(&line).map_err(error)?;
+
+            match table.as_str() {
+                "" => config.set(&key, value).map_err(error)?,
+                "options" => config.options.push((key, value)),
+                _ => match value {
+                    ConfigValue::String(name) => {
+                        config.renames.insert(key, name);
+                    }
+                    value => {
+                        return Err(error(format!(
+                            "Expected the new name of `{}` to be a string, not {}",
+                            key,
+                            value.kind()
+                        )))
+                    }
+                },
+            }
+        }
+
+        Ok(config)
+    }
+
+    fn set(&mut self, key: &str, value: ConfigValue) -> Result<(), String> {
+        match (key, value) {
+            ("metadata", ConfigValue::String(value)) => self.metadata = Some(value),
+            ("output", ConfigValue::String(value)) => self.output = Some(value),
+            ("rustfmt", ConfigValue::Bool(value)) => self.rustfmt = Some(value),
+            ("types", ConfigValue::Array(value)) => self.types = value,
+            ("metadata", value) | ("output", value) | ("rustfmt", value) | ("types", value) => {
+                return Err(format!("`{}` can't be {}", key, value.kind()))
+            }
+            _ => {
+                return Err(format!(
+                    "Unknown key `{}`, expected `metadata`, `output`, `rustfmt`, or `types`",
+                    key
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the metadata named by the `metadata` key, either as an SDK version or as a path
+    /// relative to `dir`. Has no effect if there is no `metadata` key or if the metadata has
+    /// already been read.
+    pub fn select_metadata<P: AsRef<std::path::Path>>(&self, dir: P) -> Result<(), String> {
+        let metadata = match &self.metadata {
+            Some(metadata) => metadata,
+            None => return Ok(()),
+        };
+
+        if is_sdk_version(metadata) {
+            return pin_sdk_version(metadata).map_err(|versions| {
+                format!(
+                    "Windows SDK metadata version `{}` not found; the versions present are {}",
+                    metadata,
+                    if versions.is_empty() {
+                        "none".to_string()
+                    } else {
+                        versions.join(", ")
+                    }
+                )
+            });
+        }
+
+        let path = dir.as_ref().join(metadata);
+
+        if !path.exists() {
+            return Err(format!("Metadata path `{}` not found", path.display()));
+        }
+
+        load_winmds(&[path]);
+        Ok(())
+    }
+
+    /// Applies the options and renames to the `TypeReader` and imports the types, reading the
+    /// metadata if it hasn't been read yet.
+    pub fn apply(&self) -> Result<(), String> {
+        self.apply_options()?;
+        self.import_types()
+    }
+
+    /// Applies the options and renames to the `TypeReader` without importing the types.
+    pub fn apply_options(&self) -> Result<(), String> {
+        for (name, value) in &self.options {
+            set_option(name, value)?;
+        }
+
+        let options = &mut TypeReader::get_mut().options;
+
+        for (member, name) in &self.renames {
+            options.renames.insert(member.clone(), name.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Imports the types and namespaces listed by the `types` key.
+    pub fn import_types(&self) -> Result<(), String> {
+        let reader = TypeReader::get_mut();
+
+        for path in &self.types {
+            let path = path.replace("::", ".");
+
+            let (namespace, name) = match path.rfind('.') {
+                Some(pos) => (&path[..pos], &path[pos + 1..]),
+                None => return Err(format!("`{}` must be qualified by its namespace", path)),
+            };
+
+            if name == "*" {
+                if !reader.import_namespace(namespace) {
+                    return Err(format!("`{}` not found in metadata", namespace));
+                }
+            } else if !reader.import_type(namespace, name) {
+                return Err(format!("`{}.{}` not found in metadata", namespace, name));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Sets one of the `OPTIONS` of the `TypeReader`, returning an error if the option is unknown or
+/// its value is invalid.
+pub fn set_option(name: &str, value: &ConfigValue) -> Result<(), String> {
+    let options = &mut TypeReader::get_mut().options;
+    let unexpected = || format!("`{}` can't be {}", name, value.kind());
+
+    match (name, value) {
+        ("traits", ConfigValue::Bool(value)) => options.traits = *value,
+        ("raw_dylib", ConfigValue::Bool(value)) => options.raw_dylib = *value,
+        ("os_features", ConfigValue::Bool(value)) => options.os_features = *value,
+        ("function_features", ConfigValue::Bool(value)) => options.function_features = *value,
+        ("api_stats", ConfigValue::Bool(value)) => options.api_stats = *value,
+        ("std_feature", ConfigValue::Bool(value)) => options.std_feature = *value,
+        ("into_future", ConfigValue::Bool(value)) => options.into_future = *value,
+        ("contract_features", ConfigValue::Bool(value)) => options.contract_features = *value,
+        ("exclude_deprecated", ConfigValue::Bool(value)) => options.exclude_deprecated = *value,
+        ("desktop_only", ConfigValue::Bool(value)) => options.desktop_only = *value,
+        ("experimental", ConfigValue::Bool(value)) => options.experimental = *value,
+        ("contracts", ConfigValue::Array(contracts)) => {
+            for contract in contracts {
+                let mut parts = contract.split_whitespace();
+
+                match (parts.next(), parts.next().map(str::parse::<u16>), parts.next()) {
+                    (Some(contract), Some(Ok(version)), None) => {
+                        options.contracts.push((contract.to_string(), version))
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Expected a contract name and major version, such as `\"UniversalApiContract 8\"`, not `\"{}\"`",
+                            contract
+                        ))
+                    }
+                }
+            }
+        }
+        ("codegen_version", ConfigValue::Integer(version)) => {
+            if (1..=CODEGEN_VERSION as i64).contains(version) {
+                options.codegen_version = Some(*version as u32);
+            } else {
+                return Err(format!(
+                    "Unsupported code generation version, expected a version from 1 to {}",
+                    CODEGEN_VERSION
+                ));
+            }
+        }
+        ("naming", ConfigValue::String(naming)) => {
+            options.naming = match naming.as_str() {
+                "projected" => Naming::Projected,
+                "metadata" => Naming::Metadata,
+                _ => {
+                    return Err(
+                        "Unknown naming, expected `\"projected\"` or `\"metadata\"`".to_string()
+                    )
+                }
+            }
+        }
+        _ if OPTIONS.contains(&name) => return Err(unexpected()),
+        _ => {
+            return Err(format!(
+                "Unknown option `{}`, expected {}",
+                name,
+                expected_names(OPTIONS.iter().copied())
+            ))
+        }
+    }
+
+    Ok(())
+}
+
+/// Lists names in backticks, such as "`a`, `b`, or `c`", for error messages naming the
+/// expected alternatives.
+pub fn expected_names<'a, I: IntoIterator<Item = &'a str>>(names: I) -> String {
+    let names: Vec<String> = names
+        .into_iter()
+        .map(|name| format!("`{}`", name))
+        .collect();
+
+    match names.split_last() {
+        Some((last, rest)) if !rest.is_empty() => format!("{}, or {}", rest.join(", "), last),
+        Some((last, _)) => last.clone(),
+        None => String::new(),
+    }
+}
+
+// Removes a comment from the end of a line, ignoring `#` within strings.
+fn strip_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+
+    for (pos, c) in line.char_indices() {
+        match quote {
+            Some('"') if escaped => escaped = false,
+            Some('"') if c == '\\' => escaped = true,
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' => return &line[..pos],
+            None => {}
+        }
+    }
+
+    line
+}
+
+fn parse_assignment(line: &str) -> Result<(String, ConfigValue), String> {
+    let (key, rest) = if line.starts_with('"') || line.starts_with('\'') {
+        parse_string(line)?
+    } else {
+        let end = line
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(line.len());
+
+        if end == 0 {
+            return Err(format!("Expected a key, found `{}`", line));
+        }
+
+        (line[..end].to_string(), &line[end..])
+    };
+
+    let rest = rest.trim_start();
+
+    if !rest.starts_with('=') {
+        return Err(format!("Expected `=` after `{}`", key));
+    }
+
+    let (value, rest) = parse_value(rest[1..].trim_start())?;
+
+    if !rest.trim().is_empty() {
+        return Err(format!(
+            "Unexpected `{}` after the value of `{}`",
+            rest.trim(),
+            key
+        ));
+    }
+
+    Ok((key, value))
+}
+
+fn parse_value(text: &str) -> Result<(ConfigValue, &str), String> {
+    if text.starts_with('"') || text.starts_with('\'') {
+        let (value, rest) = parse_string(text)?;
+        return Ok((ConfigValue::String(value), rest));
+    }
+
+    if let Some(mut rest) = text.strip_prefix('[') {
+        let mut values = Vec::new();
+
+        loop {
+            rest = rest.trim_start();
+
+            if let Some(rest) = rest.strip_prefix(']') {
+                return Ok((ConfigValue::Array(values), rest));
+            }
+
+            let (value, next) = parse_string(rest)?;
+            values.push(value);
+            rest = next.trim_start();
+
+            if let Some(next) = rest.strip_prefix(',') {
+                rest = next;
+            } else if !rest.starts_with(']') {
+                return Err("Expected `,` or `]` in the array".to_string());
+            }
+        }
+    }
+
+    let end = text
+        .find(|c: char| c.is_whitespace() || c == ',' || c == ']')
+        .unwrap_or(text.len());
+
+    let (token, rest) = text.split_at(end);
+
+    let value = match token {
+        "true" => ConfigValue::Bool(true),
+        "false" => ConfigValue::Bool(false),
+        _ => match token.replace('_', "").parse() {
+            Ok(value) => ConfigValue::Integer(value),
+            Err(_) => return Err(format!("Expected a value, found `{}`", token)),
+        },
+    };
+
+    Ok((value, rest))
+}
+
+// Parses a basic string in double quotes, with escapes, or a literal string in single quotes.
+fn parse_string(text: &str) -> Result<(String, &str), String> {
+    let quote = match text.chars().next() {
+        Some(quote) if quote == '"' || quote == '\'' => quote,
+        _ => return Err(format!("Expected a string, found `{}`", text)),
+    };
+
+    let mut value = String::new();
+    let mut chars = text.char_indices().skip(1);
+
+    while let Some((pos, c)) = chars.next() {
+        if c == quote {
+            return Ok((value, &text[pos + 1..]));
+        }
+
+        if c == '\\' && quote == '"' {
+            match chars.next() {
+                Some((_, '"')) => value.push('"'),
+                Some((_, '\\')) => value.push('\\'),
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 't')) => value.push('\t'),
+                _ => return Err(format!("Unsupported escape in `{}`", text)),
+            }
+        } else {
+            value.push(c);
+        }
+    }
+
+    Err(format!("Expected `{}` to close the string", quote))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let config = Config::parse(
+            r#"
+            # The bindings of the app
+            metadata = "10.0.19041.0"
+            output = 'src/bindings.rs'
+            rustfmt = false
+            types = [
+                "Windows.Foundation.Uri", # The URI class
+                "Windows.Win32.System.Threading.*",
+            ]
+
+            [options]
+            traits = true
+            codegen_version = 2
+            contracts = ["UniversalApiContract 8"]
+
+            [rename]
+            "Windows.Foundation.IStringable.ToString" = "Describe"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.metadata.as_deref(), Some("10.0.19041.0"));
+        assert_eq!(config.output.as_deref(), Some("src/bindings.rs"));
+        assert_eq!(config.rustfmt, Some(false));
+        assert_eq!(
+            config.types,
+            ["Windows.Foundation.Uri", "Windows.Win32.System.Threading.*"]
+        );
+
+        assert_eq!(
+            config.options,
+            [
+                ("traits".to_string(), ConfigValue::Bool(true)),
+                ("codegen_version".to_string(), ConfigValue::Integer(2)),
+                (
+                    "contracts".to_string(),
+                    ConfigValue::Array(vec!["UniversalApiContract 8".to_string()])
+                ),
+            ]
+        );
+
+        assert_eq!(
+            config.renames["Windows.Foundation.IStringable.ToString"],
+            "Describe"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Config::parse("rustfmt = \"no\"").unwrap_err(),
+            "line 1: `rustfmt` can't be a string"
+        );
+        assert_eq!(
+            Config::parse("\n[features]").unwrap_err(),
+            "line 2: Unknown table `features`, expected `options` or `rename`"
+        );
+        assert_eq!(
+            Config::parse("types = [\"Windows.Foundation.Uri\"").unwrap_err(),
+            "line 1: Expected `]` to close the array"
+        );
+        assert_eq!(
+            Config::parse("[options]\ncontract = [\"UniversalApiContract 8\"]")
+                .unwrap()
+                .apply_options()
+                .unwrap_err(),
+            "Unknown option `contract`, expected `traits`, `raw_dylib`, `os_features`, `function_features`, `api_stats`, `std_feature`, `into_future`, `contracts`, `contract_features`, `exclude_deprecated`, `desktop_only`, `experimental`, `codegen_version`, or `naming`"
+        );
+        assert!(Config::parse("output").is_err());
+        assert!(Config::parse("output = \"a\" b").is_err());
+    }
+}
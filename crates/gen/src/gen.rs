@@ -1,5 +1,181 @@
 use super::*;
 
+/// The latest version of the shape of the generated code, which is generated unless
+/// `GenOptions::codegen_version` pins an earlier one.
+pub const CODEGEN_VERSION: u32 = 3;
+
+/// Options controlling what is generated in addition to the bindings themselves.
+#[derive(Default, Debug, Hash)]
+pub struct GenOptions {
+    /// Whether to generate a companion trait for each WinRT interface so that the interface
+    /// may be substituted in tests.
+    pub traits: bool,
+    /// Whether to link Win32 functions with `raw-dylib` so that the import libraries of the
+    /// Windows SDK aren't needed.
+    pub raw_dylib: bool,
+    /// Whether to gate Win32 functions requiring a later version of Windows 10 behind a feature
+    /// of the crate including the generated code, such as `win10_19041`.
+    pub os_features: bool,
+    /// Whether to gate each Win32 function behind its own feature of the crate including the
+    /// generated code, named after the function and its namespace, such as
+    /// `Windows_Win32_Foundation_CloseHandle`, so that only the functions being called are
+    /// compiled.
+    pub function_features: bool,
+    /// Whether to count the calls to each Win32 function, for `windows::api_usage`, when the
+    /// crate including the generated code enables its `api_stats` feature.
+    pub api_stats: bool,
+    /// Whether to gate the conversions between the generated types and the types of `std`, such
+    /// as `SystemTime` and `Path`, behind the `std` feature of the crate including the generated
+    /// code, so that the crate may be built with `#![no_std]`. The rest of the generated code
+    /// only depends on `core` and `alloc`.
+    pub std_feature: bool,
+    /// Whether the WinRT async interfaces implement `IntoFuture` through `AsyncFuture`, which
+    /// may be polled by different tasks, rather than implementing `Future` themselves.
+    pub into_future: bool,
+    /// The API contracts targeted by the generated code along with their major versions, such as
+    /// `UniversalApiContract` and 8. Methods introduced by later versions of these contracts are
+    /// omitted, or gated when `contract_features` is set.
+    pub contracts: Vec<(String, u16)>,
+    /// Whether to gate methods introduced by later versions of the targeted contracts behind a
+    /// feature of the crate including the generated code, such as `universal_api_contract_10`,
+    /// rather than omitting them.
+    pub contract_features: bool,
+    /// Whether to omit deprecated WinRT methods rather than gating them behind the `deprecated`
+    /// feature.
+    pub exclude_deprecated: bool,
+    /// Whether to omit the methods of WinRT classes that may only be used by UWP apps, which are
+    /// those without the `DualApiPartition` attribute, since they fail when used by desktop apps.
+    pub desktop_only: bool,
+    /// Whether to include the preview types and methods marked with the `Experimental`
+    /// attribute, which are otherwise omitted so that they aren't depended upon by accident.
+    pub experimental: bool,
+    /// The version of the shape of the generated code, so that code depending on the shape
+    /// generated by an earlier release may be migrated gradually. Version 1 returns received
+    /// WinRT arrays as `Array<T>` and doesn't generate `with_*` builders or `Display` for
+    /// classes, and versions before 3 name default overloads by their `Overload` attribute and
+    /// only generate `Display` for classes implementing `IStringable`.
+    /// Defaults to `CODEGEN_VERSION`.
+    pub codegen_version: Option<u32>,
+    /// How the names of WinRT methods are derived from the metadata. Overloaded methods other
+    /// than the default overload are named by their `Overload` attribute either way, and Win32
+    /// names are always those of the metadata.
+    pub naming: Naming,
+    /// Names replacing those generated for WinRT methods and struct fields, keyed by the full
+    /// name of the interface or struct followed by the name that would otherwise be generated,
+    /// such as `Windows.Foundation.IStringable.ToString`.
+    pub renames: BTreeMap<String, String>,
+}
+
+/// How the names of WinRT methods are derived from the metadata.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Naming {
+    /// Property and event accessors are named after the property or event, such as `Text`,
+    /// `SetText`, `Click`, and `RemoveClick`.
+    Projected,
+    /// Property and event accessors keep the names of the metadata and the ABI documentation,
+    /// such as `get_Text`, `put_Text`, `add_Click`, and `remove_Click`.
+    Metadata,
+}
+
+// Deriving `Default` for an enum requires a later compiler than the crate supports.
+#[allow(clippy::derivable_impls)]
+impl Default for Naming {
+    fn default() -> Self {
+        Self::Projected
+    }
+}
+
+/// Whether a type is available to the API contracts targeted by the generated code.
+#[derive(PartialEq, Debug)]
+pub enum Availability {
+    Available,
+    /// The type was introduced by a later version of a targeted contract and is gated behind
+    /// the named feature.
+    Feature(String),
+    /// The type was introduced by a later version of a targeted contract.
+    Unavailable {
+        contract: String,
+        version: u16,
+    },
+}
+
+impl GenOptions {
+    /// Returns whether the generated code has the shape introduced by the given version.
+    pub fn codegen_version(&self, version: u32) -> bool {
+        self.codegen_version.unwrap_or(CODEGEN_VERSION) >= version
+    }
+
+    /// Returns the attribute gating code that depends on `std`, which is empty unless
+    /// `std_feature` is set.
+    pub fn gen_std_cfg(&self) -> TokenStream {
+        if self.std_feature {
+            quote! { #[cfg(feature = "std")] }
+        } else {
+            TokenStream::new()
+        }
+    }
+
+    /// Returns the name of a method or field of the type, replacing the name it would otherwise
+    /// be generated with if it has been renamed.
+    pub fn rename(&self, def: &tables::TypeDef, name: String) -> String {
+        if self.renames.is_empty() {
+            return name;
+        }
+
+        let key = format!("{}.{}.{}", def.namespace(), trim_tick(def.name()), name);
+        self.renames.get(&key).cloned().unwrap_or(name)
+    }
+
+    /// Returns whether the method is omitted from the generated code.
+    pub fn excludes_method(&self, method: &tables::MethodDef) -> bool {
+        self.exclude_deprecated && method.is_deprecated()
+            || !self.experimental && method.is_experimental()
+    }
+
+    /// Returns whether the type, function, or constant is omitted when importing its namespace.
+    pub fn excludes_type(&self, row: &TypeRow) -> bool {
+        !self.experimental && row.is_experimental()
+    }
+
+    /// Returns whether the class is only generated in its minimal form, as a type that may be
+    /// passed to and returned from methods but that has no methods of its own.
+    pub fn excludes_class(&self, def: &tables::TypeDef) -> bool {
+        self.desktop_only && !def.has_attribute("DualApiPartitionAttribute")
+            || !self.experimental && def.is_experimental()
+    }
+
+    /// Returns whether the type is available to the targeted API contracts. Types belonging to
+    /// contracts that aren't targeted are always available.
+    pub fn availability(&self, def: &tables::TypeDef) -> Availability {
+        if self.contracts.is_empty() {
+            return Availability::Available;
+        }
+
+        let (contract, version) = match def.contract() {
+            Some(contract) => contract,
+            None => return Availability::Available,
+        };
+
+        // Contracts may be targeted by their full name or by their name alone.
+        let target = self
+            .contracts
+            .iter()
+            .find(|(target, _)| contract == *target || contract.ends_with(&format!(".{}", target)));
+
+        match target {
+            Some((_, target)) if version > *target => {
+                if self.contract_features {
+                    let name = contract.rsplit('.').next().unwrap_or(&contract);
+                    Availability::Feature(format!("{}_{}", to_snake_case(name), version))
+                } else {
+                    Availability::Unavailable { contract, version }
+                }
+            }
+            _ => Availability::Available,
+        }
+    }
+}
+
 pub enum Gen {
     Absolute,
     Relative(&'static str),
@@ -24,27 +200,33 @@ impl Gen {
                     return TokenStream::new();
                 }
 
-                let mut relative = relative.split('.').peekable();
-                let mut namespace = namespace.split('.').peekable();
+                let path = intern_path(relative, namespace, || {
+                    let mut relative = relative.split('.').peekable();
+                    let mut namespace = namespace.split('.').peekable();
 
-                while relative.peek() == namespace.peek() {
-                    if relative.next().is_none() {
-                        break;
+                    while relative.peek() == namespace.peek() {
+                        if relative.next().is_none() {
+                            break;
+                        }
+                        namespace.next();
                     }
-                    namespace.next();
-                }
 
-                let mut tokens = TokenStream::new();
+                    let mut path = String::new();
 
-                for _ in 0..relative.count() {
-                    tokens.push_str("super::");
-                }
+                    for _ in 0..relative.count() {
+                        path.push_str("super::");
+                    }
 
-                for namespace in namespace {
-                    tokens.push_str(namespace);
-                    tokens.push_str("::");
-                }
+                    for namespace in namespace {
+                        path.push_str(namespace);
+                        path.push_str("::");
+                    }
 
+                    path
+                });
+
+                let mut tokens = TokenStream::new();
+                tokens.push_str(path);
                 tokens
             }
         }
@@ -81,4 +263,128 @@ mod tests {
             "super:: IStringable"
         );
     }
+
+    #[test]
+    fn test_availability() {
+        let reader = TypeReader::get();
+        let def = reader.resolve_type_def("Windows.UI.Xaml.Hosting", "DesktopWindowXamlSource");
+
+        assert_eq!(
+            def.contract(),
+            Some(("Windows.UI.Xaml.Hosting.HostingContract".to_string(), 3))
+        );
+
+        let mut options = GenOptions::default();
+        assert_eq!(options.availability(&def), Availability::Available);
+
+        options.contracts.push(("HostingContract".to_string(), 3));
+        assert_eq!(options.availability(&def), Availability::Available);
+
+        options.contracts[0].1 = 2;
+        assert_eq!(
+            options.availability(&def),
+            Availability::Unavailable {
+                contract: "Windows.UI.Xaml.Hosting.HostingContract".to_string(),
+                version: 3
+            }
+        );
+
+        options.contract_features = true;
+        assert_eq!(
+            options.availability(&def),
+            Availability::Feature("hosting_contract_3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_exclusions() {
+        let reader = TypeReader::get();
+        let uri = reader.resolve_type_def("Windows.Foundation", "Uri");
+        let window = reader.resolve_type_def("Windows.UI.Core", "CoreWindow");
+
+        let mut options = GenOptions::default();
+        assert!(!options.excludes_class(&uri));
+        assert!(!options.excludes_class(&window));
+
+        options.desktop_only = true;
+        assert!(!options.excludes_class(&uri));
+        assert!(options.excludes_class(&window));
+
+        let row = TypeRow::TypeDef(uri.clone());
+        assert!(!row.is_experimental());
+        assert!(!options.excludes_type(&row));
+        assert!(uri
+            .methods()
+            .all(|method| !options.excludes_method(&method)));
+    }
+
+    #[test]
+    fn test_naming() {
+        let reader = TypeReader::get();
+        let def = reader.resolve_type_def("Windows.Foundation", "IUriRuntimeClass");
+        let method = |name| def.methods().find(|method| method.name() == name).unwrap();
+
+        assert_eq!(
+            method("get_RawUri").projected_name(Naming::Projected),
+            "RawUri"
+        );
+        assert_eq!(
+            method("get_RawUri").projected_name(Naming::Metadata),
+            "get_RawUri"
+        );
+        assert_eq!(method("Equals").projected_name(Naming::Metadata), "Equals");
+
+        let mut options = GenOptions::default();
+        assert_eq!(options.rename(&def, "RawUri".to_string()), "RawUri");
+
+        options.renames.insert(
+            "Windows.Foundation.IUriRuntimeClass.RawUri".to_string(),
+            "raw_uri".to_string(),
+        );
+        assert_eq!(options.rename(&def, "RawUri".to_string()), "raw_uri");
+        assert_eq!(options.rename(&def, "Host".to_string()), "Host");
+
+        let vector = reader.resolve_type_def("Windows.Foundation.Collections", "IVector`1");
+        options.renames.insert(
+            "Windows.Foundation.Collections.IVector.GetAt".to_string(),
+            "at".to_string(),
+        );
+        assert_eq!(options.rename(&vector, "GetAt".to_string()), "at");
+    }
+
+    #[test]
+    fn test_default_overload() {
+        let reader = TypeReader::get();
+        let def =
+            reader.resolve_type_def("Windows.Globalization.NumberFormatting", "INumberFormatter");
+        let names: Vec<String> = def.methods().map(|method| method.rust_name()).collect();
+        assert_eq!(names, ["FormatInt", "FormatUInt", "Format"]);
+
+        // Another overload is already named `CreateWatcher` by its `Overload` attribute.
+        let def =
+            reader.resolve_type_def("Windows.Devices.Enumeration", "IDeviceInformationStatics");
+        assert!(def.methods().any(|method| method.is_default_overload()
+            && method.rust_name() == "CreateWatcherDeviceClass"));
+
+        // `IPackageManager` of the same class already has an `AddPackageAsync` method.
+        let def = reader.resolve_type_def("Windows.Management.Deployment", "IPackageManager5");
+        assert!(def
+            .methods()
+            .any(|method| method.rust_name() == "AddPackageToVolumeAndOptionalPackagesAsync"));
+    }
+
+    #[test]
+    fn test_codegen_version() {
+        let mut options = GenOptions::default();
+        assert!(options.codegen_version(1));
+        assert!(options.codegen_version(CODEGEN_VERSION));
+
+        options.codegen_version = Some(1);
+        assert!(options.codegen_version(1));
+        assert!(!options.codegen_version(2));
+
+        options.codegen_version = Some(2);
+        assert!(options.codegen_version(2));
+        assert!(!options.codegen_version(3));
+    }
 }
@@ -11,27 +11,79 @@ impl Guid {
     ) -> Option<Self> {
         for attribute in attributes {
             if attribute.name() == "GuidAttribute" {
-                let args = attribute.args();
+                return Some(Self::from_args(&attribute.args()));
+            }
+        }
+
+        None
+    }
 
-                return Some(Self(
-                    args[0].1.unwrap_u32(),
-                    args[1].1.unwrap_u16(),
-                    args[2].1.unwrap_u16(),
-                    args[3].1.unwrap_u8(),
-                    args[4].1.unwrap_u8(),
-                    args[5].1.unwrap_u8(),
-                    args[6].1.unwrap_u8(),
-                    args[7].1.unwrap_u8(),
-                    args[8].1.unwrap_u8(),
-                    args[9].1.unwrap_u8(),
-                    args[10].1.unwrap_u8(),
-                ));
+    /// Returns the property key described by a `PropertyKeyAttribute`, which is the GUID of its
+    /// property set and the identifier of the property within the set.
+    pub fn property_key<I: IntoIterator<Item = tables::Attribute>>(
+        attributes: I,
+    ) -> Option<(Self, u32)> {
+        for attribute in attributes {
+            if attribute.name() == "PropertyKeyAttribute" {
+                let args = attribute.args();
+                return Some((Self::from_args(&args), args[11].1.unwrap_u32()));
             }
         }
 
         None
     }
 
+    fn from_args(args: &[(String, ConstantValue)]) -> Self {
+        Self(
+            args[0].1.unwrap_u32(),
+            args[1].1.unwrap_u16(),
+            args[2].1.unwrap_u16(),
+            args[3].1.unwrap_u8(),
+            args[4].1.unwrap_u8(),
+            args[5].1.unwrap_u8(),
+            args[6].1.unwrap_u8(),
+            args[7].1.unwrap_u8(),
+            args[8].1.unwrap_u8(),
+            args[9].1.unwrap_u8(),
+            args[10].1.unwrap_u8(),
+        )
+    }
+
+    /// Parses a GUID in its registry format, such as `3E68D4BD-7135-4D10-8018-9FB6D9F33FA1`, with or
+    /// without enclosing braces.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim_start_matches('{').trim_end_matches('}');
+        let parts: Vec<&str> = value.split('-').collect();
+
+        if parts.len() != 5
+            || parts
+                .iter()
+                .map(|part| part.len())
+                .ne([8, 4, 4, 4, 12].iter().cloned())
+            || !value.chars().all(|c| c == '-' || c.is_ascii_hexdigit())
+        {
+            return None;
+        }
+
+        let byte =
+            |part: &str, index: usize| u8::from_str_radix(&part[index * 2..index * 2 + 2], 16);
+        let tail = format!("{}{}", parts[3], parts[4]);
+
+        Some(Self(
+            u32::from_str_radix(parts[0], 16).ok()?,
+            u16::from_str_radix(parts[1], 16).ok()?,
+            u16::from_str_radix(parts[2], 16).ok()?,
+            byte(&tail, 0).ok()?,
+            byte(&tail, 1).ok()?,
+            byte(&tail, 2).ok()?,
+            byte(&tail, 3).ok()?,
+            byte(&tail, 4).ok()?,
+            byte(&tail, 5).ok()?,
+            byte(&tail, 6).ok()?,
+            byte(&tail, 7).ok()?,
+        ))
+    }
+
     pub fn gen(&self) -> TokenStream {
         let a = Literal::u32_unsuffixed(self.0);
         let b = Literal::u16_unsuffixed(self.1);
@@ -60,3 +112,19 @@ impl std::fmt::Debug for Guid {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let guid = Guid::parse("{3E68D4BD-7135-4D10-8018-9FB6D9F33FA1}").unwrap();
+        assert_eq!(
+            format!("{:?}", guid),
+            "3e68d4bd-7135-4d10-8018-9fb6d9f33fa1"
+        );
+        assert!(Guid::parse("3E68D4BD-7135-4D10-8018").is_none());
+        assert!(Guid::parse("3E68D4BD-7135-4D10-8018-9FB6D9F33FAZ").is_none());
+    }
+}
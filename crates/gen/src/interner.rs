@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Identifies a string stored in the process-wide string table. Identifiers and namespace paths
+/// are emitted many thousands of times for large imports so they are interned once rather than
+/// allocated every time they are written out.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct StringId(u32);
+
+impl StringId {
+    pub fn as_str(self) -> &'static str {
+        strings().read().unwrap().values[self.0 as usize]
+    }
+}
+
+#[derive(Default)]
+struct Strings {
+    ids: HashMap<&'static str, StringId>,
+    values: Vec<&'static str>,
+    // Namespace paths, keyed by the relative and target namespace, as rendered by `Gen::namespace`.
+    paths: HashMap<(StringId, StringId), StringId>,
+}
+
+fn strings() -> &'static RwLock<Strings> {
+    use std::{
+        mem::MaybeUninit,
+        ptr::{addr_of, addr_of_mut},
+        sync::Once,
+    };
+    static ONCE: Once = Once::new();
+    static mut VALUE: MaybeUninit<RwLock<Strings>> = MaybeUninit::uninit();
+
+    ONCE.call_once(|| {
+        // This is safe because `Once` provides thread-safe one-time initialization
+        unsafe { addr_of_mut!(VALUE).write(MaybeUninit::new(RwLock::new(Strings::default()))) }
+    });
+
+    // This is safe because `call_once` has already been called. The value is only accessed
+    // through raw pointers so that no reference to the mutable static is created.
+    unsafe { &*addr_of!(VALUE).cast::<RwLock<Strings>>() }
+}
+
+/// Returns the identifier for `value`, adding it to the string table if needed.
+pub fn intern(value: &str) -> StringId {
+    if let Some(id) = strings().read().unwrap().ids.get(value) {
+        return *id;
+    }
+
+    let mut strings = strings().write().unwrap();
+
+    if let Some(id) = strings.ids.get(value) {
+        return *id;
+    }
+
+    // Interned strings live for the remainder of the process, which is no longer than the
+    // build script or proc macro expansion that needs them.
+    let value: &'static str = Box::leak(value.to_string().into_boxed_str());
+    let id = StringId(strings.values.len() as u32);
+    strings.values.push(value);
+    strings.ids.insert(value, id);
+    id
+}
+
+/// Returns the interned namespace path from `relative` to `namespace`, computing it with `path`
+/// the first time the pair is seen.
+pub fn intern_path<F: FnOnce() -> String>(
+    relative: &str,
+    namespace: &str,
+    path: F,
+) -> &'static str {
+    let key = (intern(relative), intern(namespace));
+
+    let existing = strings().read().unwrap().paths.get(&key).copied();
+
+    if let Some(id) = existing {
+        return id.as_str();
+    }
+
+    let id = intern(&path());
+    strings().write().unwrap().paths.insert(key, id);
+    id.as_str()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_intern() {
+        let a = intern("Windows.Foundation");
+        let b = intern(&String::from("Windows.Foundation"));
+        assert_eq!(a, b);
+        assert_eq!(a.as_str(), "Windows.Foundation");
+        assert_ne!(a, intern("Windows.Foundation.Collections"));
+    }
+}
@@ -7,18 +7,26 @@ use super::*;
 // vcall per iteration wheras IIterator<T> requires two.
 pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &Gen) -> TokenStream {
     let name = def.full_name();
+    let reader = TypeReader::get();
+
+    // The methods are named as they are generated, which depends on the naming options.
+    let iterable_def = reader.resolve_type_def("Windows.Foundation.Collections", "IIterable`1");
+    let first = iterable_def.gen_method_name("First");
 
     // If the type is IIterator<T> then simply implement the Iterator trait over top.
     if name == ("Windows.Foundation.Collections", "IIterator`1") {
+        let current = def.gen_method_name("get_Current");
+        let move_next = def.gen_method_name("MoveNext");
+
         return quote! {
-            impl<T: ::windows::RuntimeType> ::std::iter::Iterator for IIterator<T> {
+            impl<T: ::windows::RuntimeType> ::core::iter::Iterator for IIterator<T> {
                 type Item = T;
 
-                fn next(&mut self) -> ::std::option::Option<Self::Item> {
-                    let result = self.Current().ok();
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
+                    let result = self.#current().ok();
 
                     if result.is_some() {
-                        self.MoveNext().ok()?;
+                        self.#move_next().ok()?;
                     }
 
                     result
@@ -31,20 +39,20 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
     // IIterator<T> returned by first() to implement the Iterator trait.
     if name == ("Windows.Foundation.Collections", "IIterable`1") {
         return quote! {
-            impl<T: ::windows::RuntimeType> ::std::iter::IntoIterator for IIterable<T> {
+            impl<T: ::windows::RuntimeType> ::core::iter::IntoIterator for IIterable<T> {
                 type Item = T;
                 type IntoIter = IIterator<Self::Item>;
 
                 fn into_iter(self) -> Self::IntoIter {
-                    self.First().unwrap()
+                    self.#first().unwrap()
                 }
             }
-            impl<'a, T: ::windows::RuntimeType> ::std::iter::IntoIterator for &'a IIterable<T> {
+            impl<'a, T: ::windows::RuntimeType> ::core::iter::IntoIterator for &'a IIterable<T> {
                 type Item = T;
                 type IntoIter = IIterator<Self::Item>;
 
                 fn into_iter(self) -> Self::IntoIter {
-                    self.First().unwrap()
+                    self.#first().unwrap()
                 }
             }
         };
@@ -52,6 +60,9 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
 
     // If the type is IVectorView<T> then provide the VectorViewIterator fast iterator.
     if name == ("Windows.Foundation.Collections", "IVectorView`1") {
+        let size = def.gen_method_name("get_Size");
+        let get_at = def.gen_method_name("GetAt");
+
         return quote! {
             pub struct VectorViewIterator<T: ::windows::RuntimeType + 'static> {
                 vector: IVectorView<T>,
@@ -61,26 +72,26 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
 
             impl<T: ::windows::RuntimeType> VectorViewIterator<T> {
                 pub fn new(vector: IVectorView<T>) -> Self {
-                    let size = vector.Size().unwrap();
+                    let size = vector.#size().unwrap();
                     Self { vector, current: 0, size }
                 }
             }
 
-            impl<T: ::windows::RuntimeType> ::std::iter::Iterator for VectorViewIterator<T> {
+            impl<T: ::windows::RuntimeType> ::core::iter::Iterator for VectorViewIterator<T> {
                 type Item = T;
 
-                fn next(&mut self) -> ::std::option::Option<Self::Item> {
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
                     if self.current >= self.size {
                         return None;
                     }
 
-                    let result = self.vector.GetAt(self.current);
+                    let result = self.vector.#get_at(self.current);
                     self.current += 1;
                     result.ok()
                 }
             }
 
-            impl<T: ::windows::RuntimeType> ::std::iter::IntoIterator for IVectorView<T> {
+            impl<T: ::windows::RuntimeType> ::core::iter::IntoIterator for IVectorView<T> {
                 type Item = T;
                 type IntoIter = VectorViewIterator<Self::Item>;
 
@@ -88,12 +99,12 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
                     VectorViewIterator::new(self)
                 }
             }
-            impl<'a, T: ::windows::RuntimeType> ::std::iter::IntoIterator for &'a IVectorView<T> {
+            impl<'a, T: ::windows::RuntimeType> ::core::iter::IntoIterator for &'a IVectorView<T> {
                 type Item = T;
                 type IntoIter = VectorViewIterator<Self::Item>;
 
                 fn into_iter(self) -> Self::IntoIter {
-                    VectorViewIterator::new(::std::clone::Clone::clone(self))
+                    VectorViewIterator::new(::core::clone::Clone::clone(self))
                 }
             }
         };
@@ -101,6 +112,9 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
 
     // If the type is IVector<T> then provide the VectorIterator fast iterator.
     if name == ("Windows.Foundation.Collections", "IVector`1") {
+        let size = def.gen_method_name("get_Size");
+        let get_at = def.gen_method_name("GetAt");
+
         return quote! {
             pub struct VectorIterator<T: ::windows::RuntimeType + 'static> {
                 vector: IVector<T>,
@@ -110,26 +124,26 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
 
             impl<T: ::windows::RuntimeType> VectorIterator<T> {
                 pub fn new(vector: IVector<T>) -> Self {
-                    let size = vector.Size().unwrap();
+                    let size = vector.#size().unwrap();
                     Self { vector, current: 0, size }
                 }
             }
 
-            impl<T: ::windows::RuntimeType> ::std::iter::Iterator for VectorIterator<T> {
+            impl<T: ::windows::RuntimeType> ::core::iter::Iterator for VectorIterator<T> {
                 type Item = T;
 
-                fn next(&mut self) -> ::std::option::Option<Self::Item> {
+                fn next(&mut self) -> ::core::option::Option<Self::Item> {
                     if self.current >= self.size {
                         return None;
                     }
 
-                    let result = self.vector.GetAt(self.current);
+                    let result = self.vector.#get_at(self.current);
                     self.current += 1;
                     result.ok()
                 }
             }
 
-            impl<T: ::windows::RuntimeType> ::std::iter::IntoIterator for IVector<T> {
+            impl<T: ::windows::RuntimeType> ::core::iter::IntoIterator for IVector<T> {
                 type Item = T;
                 type IntoIter = VectorIterator<Self::Item>;
 
@@ -137,12 +151,12 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
                     VectorIterator::new(self)
                 }
             }
-            impl<'a, T: ::windows::RuntimeType> ::std::iter::IntoIterator for &'a IVector<T> {
+            impl<'a, T: ::windows::RuntimeType> ::core::iter::IntoIterator for &'a IVector<T> {
                 type Item = T;
                 type IntoIter = VectorIterator<Self::Item>;
 
                 fn into_iter(self) -> Self::IntoIter {
-                    VectorIterator::new(::std::clone::Clone::clone(self))
+                    VectorIterator::new(::core::clone::Clone::clone(self))
                 }
             }
         };
@@ -162,7 +176,7 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
             let name = def.gen_name(gen);
 
             return quote! {
-                impl<#constraints> ::std::iter::IntoIterator for #name {
+                impl<#constraints> ::core::iter::IntoIterator for #name {
                     type Item = #item;
                     type IntoIter = #wfc VectorViewIterator<Self::Item>;
 
@@ -170,7 +184,7 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
                         #wfc VectorViewIterator::new(self.into())
                     }
                 }
-                impl<'a, #constraints> ::std::iter::IntoIterator for &'a #name {
+                impl<'a, #constraints> ::core::iter::IntoIterator for &'a #name {
                     type Item = #item;
                     type IntoIter = #wfc VectorViewIterator<Self::Item>;
 
@@ -187,7 +201,7 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
             let name = def.gen_name(gen);
 
             return quote! {
-                impl<#constraints> ::std::iter::IntoIterator for #name {
+                impl<#constraints> ::core::iter::IntoIterator for #name {
                     type Item = #item;
                     type IntoIter = #wfc VectorIterator<Self::Item>;
 
@@ -195,7 +209,7 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
                         #wfc VectorIterator::new(self.into())
                     }
                 }
-                impl<'a, #constraints> ::std::iter::IntoIterator for &'a #name {
+                impl<'a, #constraints> ::core::iter::IntoIterator for &'a #name {
                     type Item = #item;
                     type IntoIter = #wfc VectorIterator<Self::Item>;
 
@@ -219,20 +233,20 @@ pub fn gen_iterator(def: &tables::TypeDef, interfaces: &[InterfaceInfo], gen: &G
             let name = def.gen_name(gen);
 
             quote! {
-               impl<#constraints> ::std::iter::IntoIterator for #name {
+               impl<#constraints> ::core::iter::IntoIterator for #name {
                     type Item = #item;
                     type IntoIter = #wfc IIterator<Self::Item>;
 
                     fn into_iter(self) -> Self::IntoIter {
-                        self.First().unwrap()
+                        self.#first().unwrap()
                     }
                 }
-                impl<'a, #constraints> ::std::iter::IntoIterator for &'a #name {
+                impl<'a, #constraints> ::core::iter::IntoIterator for &'a #name {
                     type Item = #item;
                     type IntoIter = #wfc IIterator<Self::Item>;
 
                     fn into_iter(self) -> Self::IntoIter {
-                        self.First().unwrap()
+                        self.#first().unwrap()
                     }
                 }
             }
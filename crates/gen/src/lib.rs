@@ -2,9 +2,11 @@ pub use std::collections::{BTreeMap, BTreeSet};
 pub use std::iter::FromIterator;
 
 mod r#async;
+mod config;
 mod gen;
 mod guid;
 mod import_limit;
+mod interner;
 mod iterator;
 mod object;
 mod parser;
@@ -13,10 +15,13 @@ pub mod tables;
 mod to_ident;
 pub mod types;
 mod workspace;
+mod writer;
 
+pub use config::*;
 pub use gen::*;
 pub use guid::*;
 pub use import_limit::*;
+pub use interner::*;
 pub use iterator::*;
 pub use object::*;
 pub use parser::*;
@@ -24,6 +29,7 @@ pub use r#async::*;
 pub use squote::*;
 pub use to_ident::*;
 pub use workspace::*;
+pub use writer::*;
 
 // Ideally this would be defined (and used) by the nested macros crate, but this isn't yet supported by Rust.
 #[macro_export]
@@ -2,26 +2,50 @@ use super::*;
 
 pub fn gen_object(name: &TokenStream, constraints: &TokenStream) -> TokenStream {
     quote! {
-        impl<#constraints> ::std::convert::From<#name> for ::windows::IInspectable {
+        impl<#constraints> ::core::convert::From<#name> for ::windows::IInspectable {
             fn from(value: #name) -> Self {
                 value.0
             }
         }
-        impl<#constraints> ::std::convert::From<&#name> for ::windows::IInspectable {
+        impl<#constraints> ::core::convert::From<&#name> for ::windows::IInspectable {
             fn from(value: &#name) -> Self {
                 value.0.clone()
             }
         }
 
         impl<'a, #constraints> ::windows::IntoParam<'a, ::windows::IInspectable> for #name {
+            type Param = ::windows::Param<'a, ::windows::IInspectable>;
             fn into_param(self) -> ::windows::Param<'a, ::windows::IInspectable> {
                 ::windows::Param::Owned(self.0)
             }
         }
         impl<'a, #constraints> ::windows::IntoParam<'a, ::windows::IInspectable> for &'a #name {
+            type Param = ::windows::Param<'a, ::windows::IInspectable>;
             fn into_param(self) -> ::windows::Param<'a, ::windows::IInspectable> {
                 ::windows::Param::Borrowed(&self.0)
             }
         }
     }
 }
+
+// Lets a borrowed optional object be passed wherever one of the crate's interfaces is expected,
+// passing null for `None`. Only the borrowed form is converted so that a bare `None` still has a
+// single candidate type, the parameter's own. `IInspectable` and `IUnknown` are left out since
+// they belong to the `windows` crate.
+pub fn gen_optional_param(
+    from: &TokenStream,
+    into: &TokenStream,
+    constraints: &TokenStream,
+) -> TokenStream {
+    quote! {
+        impl<'a, #constraints> ::windows::IntoParam<'a, #into> for &'a ::core::option::Option<#from> {
+            type Param = ::windows::Param<'a, #into>;
+            fn into_param(self) -> ::windows::Param<'a, #into> {
+                match self {
+                    ::core::option::Option::Some(value) => ::windows::IntoParam::<'a, #into>::into_param(value),
+                    ::core::option::Option::None => ::windows::Param::None,
+                }
+            }
+        }
+    }
+}
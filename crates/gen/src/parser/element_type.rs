@@ -96,7 +96,7 @@ impl ElementType {
 
     pub fn gen_name(&self, gen: &Gen) -> TokenStream {
         match self {
-            Self::Void => quote! { ::std::ffi::c_void },
+            Self::Void => quote! { ::core::ffi::c_void },
             Self::Bool => quote! { bool },
             Self::Char => quote! { u16 },
             Self::I8 => quote! { i8 },
@@ -145,7 +145,7 @@ impl ElementType {
 
     pub fn gen_abi_type(&self, gen: &Gen) -> TokenStream {
         match self {
-            Self::Void => quote! { ::std::ffi::c_void },
+            Self::Void => quote! { ::core::ffi::c_void },
             Self::Bool => quote! { bool },
             Self::Char => quote! { u16 },
             Self::I8 => quote! { i8 },
@@ -213,7 +213,7 @@ impl ElementType {
                 let len = Literal::u32_unsuffixed(*len);
                 quote! { [#default; #len] }
             }
-            _ => quote! { ::std::default::Default::default() },
+            _ => quote! { ::core::default::Default::default() },
         }
     }
 
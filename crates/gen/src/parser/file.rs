@@ -319,14 +319,22 @@ impl File {
         let version_length = file.bytes.copy_as::<u32>(cli_offset + 12);
         let mut view = cli_offset + version_length + 20;
         let mut tables_data: (u32, u32) = (0, 0);
+        let mut strings_size = 0;
+        let mut blobs_size = 0;
 
         for _ in 0..file.bytes.copy_as::<u16>(cli_offset + version_length + 18) {
             let stream_offset = file.bytes.copy_as::<u32>(view);
             let stream_size = file.bytes.copy_as::<u32>(view + 4);
             let stream_name = file.bytes.view_as_str(view + 8);
             match stream_name {
-                b"#Strings" => file.strings = cli_offset + stream_offset,
-                b"#Blob" => file.blobs = cli_offset + stream_offset,
+                b"#Strings" => {
+                    file.strings = cli_offset + stream_offset;
+                    strings_size = stream_size;
+                }
+                b"#Blob" => {
+                    file.blobs = cli_offset + stream_offset;
+                    blobs_size = stream_size;
+                }
                 b"#~" => tables_data = (cli_offset + stream_offset, stream_size),
                 b"#GUID" => {}
                 b"#US" => {}
@@ -339,6 +347,10 @@ impl File {
             view += (8 + stream_name.len() + padding) as u32;
         }
 
+        file.validate_stream("#~", tables_data.0, tables_data.1);
+        file.validate_stream("#Strings", file.strings, strings_size);
+        file.validate_stream("#Blob", file.blobs, blobs_size);
+
         let heap_sizes = file.bytes.copy_as::<u8>(tables_data.0 + 6);
         let string_index_size = if (heap_sizes & 1) == 1 { 4 } else { 2 };
         let guid_index_size = if (heap_sizes >> 1 & 1) == 1 { 4 } else { 2 };
@@ -765,6 +777,7 @@ impl File {
         file.tables[TableIndex::NestedClass as usize].set_data(&mut view);
         file.tables[TableIndex::GenericParam as usize].set_data(&mut view);
 
+        file.validate(tables_data.0 + tables_data.1, strings_size, blobs_size);
         file
     }
 
@@ -38,19 +38,183 @@ impl InterfaceInfo {
 
                 let info = MethodInfo {
                     name,
+                    metadata_name: method.name(),
                     vtable_offset: vtable_offset as u32 + 6,
                     overload: *overload,
                     is_deprecated: method.is_deprecated(),
                 };
 
+                // Methods are counted before being omitted so that their names don't depend
+                // on the targeted contracts or other options.
+                if TypeReader::get().options.excludes_method(&method) {
+                    continue;
+                }
+
+                let availability = match interface.gen_availability() {
+                    Some(availability) => availability,
+                    None => continue,
+                };
+
                 let signature = method.signature(&interface.def.generics);
+                tokens.combine(&availability);
                 tokens.combine(&signature.gen_winrt_method(&info, interface, gen));
+
+                if !availability.as_str().is_empty() {
+                    tokens.combine(&interface.gen_probe(&info, &availability));
+                }
+            }
+        }
+
+        tokens
+    }
+
+    /// Generates the companion trait's method declarations and forwarding methods for the default
+    /// interface's own methods, named consistently with the methods generated by `gen_methods`.
+    pub fn gen_trait_methods(interfaces: &[Self], gen: &Gen) -> (TokenStream, TokenStream) {
+        let mut method_names = BTreeMap::<String, u32>::new();
+        let mut declarations = TokenStream::new();
+        let mut forwards = TokenStream::new();
+
+        for interface in interfaces {
+            for method in interface.def.methods() {
+                let name = method.rust_name();
+                let overload = method_names.entry(name.clone()).or_insert(0);
+                *overload += 1;
+
+                if interface.kind != InterfaceKind::Default
+                    || TypeReader::get().options.excludes_method(&method)
+                {
+                    continue;
+                }
+
+                let availability = match interface.gen_availability() {
+                    Some(availability) => availability,
+                    None => continue,
+                };
+
+                let info = MethodInfo {
+                    name,
+                    metadata_name: method.name(),
+                    vtable_offset: 0,
+                    overload: *overload,
+                    is_deprecated: method.is_deprecated(),
+                };
+
+                let signature = method.signature(&interface.def.generics);
+                let (declaration, forward) =
+                    signature.gen_winrt_trait_method(&info, interface, gen);
+                declarations.combine(&availability);
+                declarations.combine(&declaration);
+                forwards.combine(&availability);
+                forwards.combine(&forward);
+            }
+        }
+
+        (declarations, forwards)
+    }
+
+    /// Generates a `with_*` method for each property setter so that a class's properties may be
+    /// set by chaining calls on a newly created object.
+    pub fn gen_builders(interfaces: &[Self], gen: &Gen) -> TokenStream {
+        let mut method_names = BTreeMap::<String, u32>::new();
+        let mut tokens = TokenStream::new();
+
+        if !TypeReader::get().options.codegen_version(2) {
+            return tokens;
+        }
+
+        for interface in interfaces {
+            for method in interface.def.methods() {
+                let name = method.rust_name();
+                let overload = method_names.entry(name.clone()).or_insert(0);
+                *overload += 1;
+
+                if method.kind() != MethodKind::Set
+                    || TypeReader::get().options.excludes_method(&method)
+                {
+                    continue;
+                }
+
+                match interface.kind {
+                    InterfaceKind::Default
+                    | InterfaceKind::NonDefault
+                    | InterfaceKind::Overridable => {}
+                    _ => continue,
+                }
+
+                let availability = match interface.gen_availability() {
+                    Some(availability) => availability,
+                    None => continue,
+                };
+
+                let setter = if *overload > 1 {
+                    format_ident!("{}{}", name, overload)
+                } else {
+                    to_ident(&name)
+                };
+
+                // The metadata name of a property setter is the property name prefixed with `put_`.
+                let builder = format_ident!("with_{}", to_snake_case(&method.name()[4..]));
+                let signature = method.signature(&interface.def.generics);
+                let constraints = signature.gen_constraints(&signature.params);
+                let args = signature.params.iter().map(|param| param.param.gen_name());
+                let params = signature.gen_winrt_params(&signature.params, gen);
+
+                let deprecated = if method.is_deprecated() {
+                    quote! { #[cfg(feature = "deprecated")] }
+                } else {
+                    quote! {}
+                };
+
+                tokens.combine(&quote! {
+                    #availability
+                    #deprecated
+                    pub fn #builder<#constraints>(self, #params) -> ::windows::Result<Self> {
+                        self.#setter(#(#args),*)?;
+                        ::core::result::Result::Ok(self)
+                    }
+                });
             }
         }
 
         tokens
     }
 
+    /// Returns the attribute gating the interface's methods if the interface was introduced by
+    /// a later version of a targeted API contract, or `None` if the methods are omitted.
+    fn gen_availability(&self) -> Option<TokenStream> {
+        match TypeReader::get().options.availability(&self.def) {
+            Availability::Available => Some(TokenStream::new()),
+            Availability::Feature(feature) => Some(quote! { #[cfg(feature = #feature)] }),
+            Availability::Unavailable { .. } => None,
+        }
+    }
+
+    /// Generates a function checking at runtime whether a method gated by `availability` is
+    /// present on the running version of Windows, which is the case if its interface is present.
+    fn gen_probe(&self, method: &MethodInfo, availability: &TokenStream) -> TokenStream {
+        match self.kind {
+            InterfaceKind::Composable | InterfaceKind::Extend => return TokenStream::new(),
+            _ => {}
+        }
+
+        let name = if method.overload > 1 {
+            format!("{}{}", method.name, method.overload)
+        } else {
+            method.name.clone()
+        };
+
+        let probe = format_ident!("is_{}_present", to_snake_case(&name));
+        let interface_name = format!("{}.{}", self.def.namespace(), self.def.name());
+
+        quote! {
+            #availability
+            pub fn #probe() -> bool {
+                ::windows::is_type_present(#interface_name)
+            }
+        }
+    }
+
     pub fn gen_conversion(
         &self,
         from: &TokenStream,
@@ -64,54 +228,64 @@ impl InterfaceInfo {
         match self.kind {
             InterfaceKind::Default => {
                 let into = self.def.gen_name(gen);
+                let optional = gen_optional_param(from, &into, constraints);
+
                 quote! {
-                    impl<#constraints> ::std::convert::From<#from> for #into {
+                    impl<#constraints> ::core::convert::From<#from> for #into {
                         fn from(value: #from) -> Self {
-                            unsafe { ::std::mem::transmute(value) }
+                            unsafe { ::core::mem::transmute(value) }
                         }
                     }
-                    impl<#constraints> ::std::convert::From<&#from> for #into {
+                    impl<#constraints> ::core::convert::From<&#from> for #into {
                         fn from(value: &#from) -> Self {
-                            ::std::convert::From::from(::std::clone::Clone::clone(value))
+                            ::core::convert::From::from(::core::clone::Clone::clone(value))
                         }
                     }
                     impl<'a, #constraints> ::windows::IntoParam<'a, #into> for #from {
+                        type Param = ::windows::Param<'a, #into>;
                         fn into_param(self) -> ::windows::Param<'a, #into> {
-                            ::windows::Param::Owned(::std::convert::Into::<#into>::into(self))
+                            ::windows::Param::Owned(::core::convert::Into::<#into>::into(self))
                         }
                     }
                     impl<'a, #constraints> ::windows::IntoParam<'a, #into> for &'a #from {
+                        type Param = ::windows::Param<'a, #into>;
                         fn into_param(self) -> ::windows::Param<'a, #into> {
-                            // tODO: The various conversions are adding ref counting bumps unecessarily
-                            ::windows::Param::Owned(::std::convert::Into::<#into>::into(::std::clone::Clone::clone(self)))
+                            // The default interface has the same representation so it may be borrowed.
+                            ::windows::Param::Borrowed(unsafe { ::core::mem::transmute(self) })
                         }
                     }
+                    #optional
                 }
             }
             InterfaceKind::NonDefault => {
                 let into = self.def.gen_name(gen);
+                let optional = gen_optional_param(from, &into, constraints);
+
                 quote! {
-                    impl<#constraints> ::std::convert::From<#from> for #into {
+                    impl<#constraints> ::core::convert::From<#from> for #into {
                         fn from(value: #from) -> Self {
-                            ::std::convert::From::from(&value)
+                            ::core::convert::From::from(&value)
                         }
                     }
-                    impl<#constraints> ::std::convert::From<&#from> for #into {
+                    impl<#constraints> ::core::convert::From<&#from> for #into {
                         fn from(value: &#from) -> Self {
                             // TODO: why is this different to `Default` case?
                             ::windows::Interface::cast(value).unwrap()
                         }
                     }
                     impl<'a, #constraints> ::windows::IntoParam<'a, #into> for #from {
+                        type Param = ::windows::Param<'a, #into>;
                         fn into_param(self) -> ::windows::Param<'a, #into> {
-                            ::windows::Param::Owned(::std::convert::Into::<#into>::into(self))
+                            ::windows::Param::Owned(::core::convert::Into::<#into>::into(self))
                         }
                     }
                     impl<'a, #constraints> ::windows::IntoParam<'a, #into> for &'a #from {
+                        type Param = ::windows::Param<'a, #into>;
                         fn into_param(self) -> ::windows::Param<'a, #into> {
-                            ::windows::Param::Owned(::std::convert::Into::<#into>::into(::std::clone::Clone::clone(self)))
+                            ::windows::Param::Owned(::core::convert::Into::<#into>::into(::core::clone::Clone::clone(self)))
                         }
                     }
+                    #optional
                 }
             }
             _ => TokenStream::new(),
@@ -1,5 +1,7 @@
 pub struct MethodInfo {
     pub name: String,
+    /// The name of the method in the metadata, which `name` is derived from.
+    pub metadata_name: &'static str,
     pub vtable_offset: u32,
     pub overload: u32,
     pub is_deprecated: bool,
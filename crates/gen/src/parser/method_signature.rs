@@ -151,6 +151,7 @@ impl MethodSignature {
         };
 
         let name = self.gen_name(method, interface);
+        let alias = gen_doc_alias(&name, method.metadata_name);
 
         let vtable_offset = Literal::u32_unsuffixed(method.vtable_offset);
         let constraints = self.gen_constraints(params);
@@ -158,17 +159,7 @@ impl MethodSignature {
         let params = self.gen_winrt_params(params, gen);
         let interface_name = interface.def.gen_name(gen);
 
-        let return_type_tokens = if let Some(return_type) = &self.return_type {
-            let tokens = return_type.kind.gen_name(gen);
-
-            if return_type.is_array {
-                quote! { ::windows::Array<#tokens> }
-            } else {
-                tokens
-            }
-        } else {
-            quote! { () }
-        };
+        let return_type_tokens = self.gen_winrt_return_type(gen);
 
         let return_arg = if let Some(return_type) = &self.return_type {
             if return_type.is_array {
@@ -185,7 +176,7 @@ impl MethodSignature {
         // arguments to ensure the call succeeds in the non-aggregating case.
         let composable_args = match interface.kind {
             InterfaceKind::Composable => quote! {
-                ::std::ptr::null_mut(), ::windows::Abi::set_abi(&mut ::std::option::Option::<::windows::IInspectable>::None),
+                ::core::ptr::null_mut(), ::windows::Abi::set_abi(&mut ::core::option::Option::<::windows::IInspectable>::None),
             },
             InterfaceKind::Extend => quote! {
                 ::windows::Abi::abi(&derived__), ::windows::Abi::set_abi(base__),
@@ -194,15 +185,22 @@ impl MethodSignature {
         };
 
         let vcall = if let Some(return_type) = &self.return_type {
-            if return_type.is_array {
+            if return_type.is_array && !TypeReader::get().options.codegen_version(2) {
                 quote! {
-                    let mut result__: #return_type_tokens = ::std::mem::zeroed();
+                    let mut result__: #return_type_tokens = ::core::mem::zeroed();
                     (::windows::Interface::vtable(this).#vtable_offset)(::windows::Abi::abi(this), #(#args,)* #composable_args #return_arg)
                         .and_then(|| result__ )
                 }
+            } else if return_type.is_array {
+                let return_type = return_type.kind.gen_name(gen);
+                quote! {
+                    let mut result__ = ::windows::Array::<#return_type>::new();
+                    (::windows::Interface::vtable(this).#vtable_offset)(::windows::Abi::abi(this), #(#args,)* #composable_args #return_arg)
+                        .and_then(|| result__.into_vec())
+                }
             } else {
                 quote! {
-                    let mut result__: <#return_type_tokens as ::windows::Abi>::Abi = ::std::mem::zeroed();
+                    let mut result__: <#return_type_tokens as ::windows::Abi>::Abi = ::core::mem::zeroed();
                         (::windows::Interface::vtable(this).#vtable_offset)(::windows::Abi::abi(this), #(#args,)* #composable_args #return_arg)
                             .from_abi::<#return_type_tokens>(result__ )
                 }
@@ -222,6 +220,7 @@ impl MethodSignature {
         match interface.kind {
             InterfaceKind::Default => quote! {
                 #deprecated
+                #alias
                 pub fn #name<#constraints>(&self, #params) -> ::windows::Result<#return_type_tokens> {
                     let this = self;
                     unsafe {
@@ -232,6 +231,7 @@ impl MethodSignature {
             InterfaceKind::NonDefault | InterfaceKind::Overridable => {
                 quote! {
                     #deprecated
+                    #alias
                     pub fn #name<#constraints>(&self, #params) -> ::windows::Result<#return_type_tokens> {
                         let this = &::windows::Interface::cast::<#interface_name>(self).unwrap();
                         unsafe {
@@ -243,6 +243,7 @@ impl MethodSignature {
             InterfaceKind::Static | InterfaceKind::Composable => {
                 quote! {
                     #deprecated
+                    #alias
                     pub fn #name<#constraints>(#params) -> ::windows::Result<#return_type_tokens> {
                         Self::#interface_name(|this| unsafe { #vcall })
                     }
@@ -251,6 +252,7 @@ impl MethodSignature {
             InterfaceKind::Extend => {
                 let interface_name = to_ident(interface.def.name());
                 quote! {
+                    #alias
                     pub fn #name<#constraints>(self, #params) -> ::windows::Result<#return_type_tokens> {
                         unsafe {
                             let (derived__, base__) = ::windows::Compose::compose(self);
@@ -262,6 +264,61 @@ impl MethodSignature {
         }
     }
 
+    /// Generates the declaration of a method of an interface's companion trait along with the
+    /// method forwarding to the interface's own method, which has the same signature.
+    pub fn gen_winrt_trait_method(
+        &self,
+        method: &MethodInfo,
+        interface: &InterfaceInfo,
+        gen: &Gen,
+    ) -> (TokenStream, TokenStream) {
+        let name = self.gen_name(method, interface);
+        let alias = gen_doc_alias(&name, method.metadata_name);
+        let constraints = self.gen_constraints(&self.params);
+        let args = self.params.iter().map(|param| param.param.gen_name());
+        let params = self.gen_winrt_params(&self.params, gen);
+        let return_type_tokens = self.gen_winrt_return_type(gen);
+
+        let deprecated = if method.is_deprecated {
+            quote! { #[cfg(feature = "deprecated")] }
+        } else {
+            quote! {}
+        };
+
+        let declaration = quote! {
+            #deprecated
+            #alias
+            fn #name<#constraints>(&self, #params) -> ::windows::Result<#return_type_tokens>;
+        };
+
+        let forward = quote! {
+            #deprecated
+            fn #name<#constraints>(&self, #params) -> ::windows::Result<#return_type_tokens> {
+                Self::#name(self, #(#args),*)
+            }
+        };
+
+        (declaration, forward)
+    }
+
+    // Returned arrays are moved into a `Vec` so that callers don't need to deal with the
+    // `CoTaskMemAlloc` allocation the callee returns them in.
+    fn gen_winrt_return_type(&self, gen: &Gen) -> TokenStream {
+        if let Some(return_type) = &self.return_type {
+            let tokens = return_type.kind.gen_name(gen);
+
+            if return_type.is_array && !TypeReader::get().options.codegen_version(2) {
+                quote! { ::windows::Array<#tokens> }
+            } else if return_type.is_array {
+                quote! { ::windows::alloc::vec::Vec<<#tokens as ::windows::RuntimeType>::DefaultType> }
+            } else {
+                tokens
+            }
+        } else {
+            quote! { () }
+        }
+    }
+
     fn gen_name(&self, method: &MethodInfo, interface: &InterfaceInfo) -> Ident {
         if (interface.kind == InterfaceKind::Composable || interface.kind == InterfaceKind::Extend)
             && self.params.len() == 2
@@ -316,7 +373,7 @@ impl MethodSignature {
                         quote! { #name: #signature, }
                     }
                 } else if param.signature.kind.is_nullable() {
-                    quote! { #name: &mut ::std::option::Option<#tokens>, }
+                    quote! { #name: &mut ::core::option::Option<#tokens>, }
                 } else if let ElementType::GenericParam(_) = param.signature.kind {
                     quote! { &mut <#tokens as ::windows::RuntimeType>::DefaultType, }
                 } else if param.signature.pointers > 0 {
@@ -329,38 +386,109 @@ impl MethodSignature {
             .collect()
     }
 
+    /// Generates the arguments passing the parameters of an ABI function generated with
+    /// `gen_winrt_abi` on to the same method of another object, as they were received.
+    pub fn gen_winrt_abi_forward_args(&self) -> TokenStream {
+        let params = self.params.iter().map(|param| {
+            let name = param.param.gen_name();
+
+            if param.signature.is_array {
+                let abi_size_name = param.param.gen_abi_size_name();
+                quote! { #abi_size_name, #name }
+            } else {
+                quote! { #name }
+            }
+        });
+
+        let result = self.return_type.iter().map(|signature| {
+            if signature.is_array {
+                quote! { result_size__, result__ }
+            } else {
+                quote! { result__ }
+            }
+        });
+
+        let args = params.chain(result);
+        quote! { #(#args),* }
+    }
+
     pub fn gen_winrt_upcall(&self, inner: TokenStream, gen: &Gen) -> TokenStream {
         let invoke_args = self
             .params
             .iter()
             .map(|param| param.gen_winrt_invoke_arg(gen));
 
+        // Receive arrays are filled in by the implementation as an `Array` that is only handed
+        // over to the caller if the call succeeds.
+        let receive_arrays: Vec<&MethodParam> = self
+            .params
+            .iter()
+            .filter(|param| {
+                param.signature.is_array && param.signature.by_ref && !param.param.is_input()
+            })
+            .collect();
+
+        let receive_locals = receive_arrays.iter().map(|param| {
+            let array_name = param.param.gen_receive_array_name();
+            let kind = param.signature.kind.gen_name(gen);
+            quote! { let mut #array_name = ::windows::Array::<#kind>::new(); }
+        });
+
+        let receive_results = receive_arrays.iter().map(|param| {
+            let name = param.param.gen_name();
+            let abi_size_name = param.param.gen_abi_size_name();
+            let array_name = param.param.gen_receive_array_name();
+
+            quote! {
+                let (#array_name, array_len__) = #array_name.into_abi();
+                *#name = #array_name;
+                *#abi_size_name = array_len__;
+            }
+        });
+
+        let receive_locals = quote! { #(#receive_locals)* };
+        let receive_results = quote! { #(#receive_results)* };
+
         match &self.return_type {
             Some(return_type) if return_type.is_array => {
                 quote! {
+                    #receive_locals
                     match #inner(#(#invoke_args,)*) {
-                        ::std::result::Result::Ok(ok__) => {
+                        ::core::result::Result::Ok(ok__) => {
+                            #receive_results
                             let (ok_data__, ok_data_len__) = ok__.into_abi();
                             *result__ = ok_data__;
                             *result_size__ = ok_data_len__;
                             ::windows::HRESULT(0)
                         }
-                        ::std::result::Result::Err(err) => err.into()
+                        ::core::result::Result::Err(err) => err.into()
                     }
                 }
             }
             Some(_) => {
                 quote! {
+                    #receive_locals
                     match #inner(#(#invoke_args,)*) {
-                        ::std::result::Result::Ok(ok__) => {
-                            *result__ = ::std::mem::transmute_copy(&ok__);
-                            ::std::mem::forget(ok__);
+                        ::core::result::Result::Ok(ok__) => {
+                            #receive_results
+                            *result__ = ::core::mem::transmute_copy(&ok__);
+                            ::core::mem::forget(ok__);
                             ::windows::HRESULT(0)
                         }
-                        ::std::result::Result::Err(err) => err.into()
+                        ::core::result::Result::Err(err) => err.into()
                     }
                 }
             }
+            None if !receive_arrays.is_empty() => quote! {
+                #receive_locals
+                match #inner(#(#invoke_args,)*) {
+                    ::core::result::Result::Ok(()) => {
+                        #receive_results
+                        ::windows::HRESULT(0)
+                    }
+                    ::core::result::Result::Err(err) => err.into()
+                }
+            },
             None => quote! {
                 #inner(#(#invoke_args,)*).into()
             },
@@ -376,6 +504,12 @@ impl MethodSignature {
                 if param.is_convertible() {
                     let into = param.signature.kind.gen_name(gen);
                     quote! { #name: impl ::windows::IntoParam<'a, #into>, }
+                } else if param.is_safe_array() {
+                    if param.param.is_input() {
+                        quote! { #name: &::windows::SafeArray<impl ::windows::SafeArrayElement>, }
+                    } else {
+                        quote! { #name: &mut ::windows::SafeArray<impl ::windows::SafeArrayElement>, }
+                    }
                 } else {
                     let tokens = param.gen_win32(gen);
                     quote! { #name: #tokens, }
@@ -397,15 +531,46 @@ impl MethodParam {
         self.signature.is_const || self.param.is_const()
     }
 
+    // Input `SAFEARRAY*` and output `SAFEARRAY**` parameters are projected as `SafeArray<T>`.
+    // The element type isn't part of the metadata so it is left to the caller.
+    fn is_safe_array(&self) -> bool {
+        let flags = self.param.flags();
+
+        let pointers = if flags.input() && !flags.output() {
+            1
+        } else if flags.output() && !flags.input() && !flags.optional() {
+            2
+        } else {
+            return false;
+        };
+
+        if self.signature.pointers != pointers || self.signature.is_array {
+            return false;
+        }
+
+        match &self.signature.kind {
+            ElementType::TypeDef(def) => {
+                def.full_name() == ("Windows.Win32.System.OleAutomation", "SAFEARRAY")
+            }
+            _ => false,
+        }
+    }
+
     fn gen_winrt_invoke_arg(&self, gen: &Gen) -> TokenStream {
         let name = self.param.gen_name();
         let kind = self.signature.kind.gen_name(gen);
 
-        // TODO: This compiles but doesn't property handle delegates with array parameters.
-        // https://github.com/microsoft/windows-rs/issues/212
-
         if self.signature.is_array {
-            quote! { ::std::mem::transmute_copy(&#name) }
+            let abi_size_name = self.param.gen_abi_size_name();
+
+            if self.param.is_input() {
+                quote! { ::windows::Array::<#kind>::from_abi_slice(#name, #abi_size_name) }
+            } else if self.signature.by_ref {
+                let array_name = self.param.gen_receive_array_name();
+                quote! { &mut #array_name }
+            } else {
+                quote! { ::windows::Array::<#kind>::from_abi_slice_mut(#name, #abi_size_name) }
+            }
         } else if self.param.is_input() {
             if self.signature.kind.is_primitive() {
                 quote! { #name }
@@ -415,7 +580,7 @@ impl MethodParam {
                 quote! { &*(&#name as *const <#kind as ::windows::Abi>::Abi as *const <#kind as ::windows::RuntimeType>::DefaultType) }
             }
         } else {
-            quote! { ::std::mem::transmute_copy(&#name) }
+            quote! { ::core::mem::transmute_copy(&#name) }
         }
     }
 
@@ -424,25 +589,25 @@ impl MethodParam {
 
         if self.signature.is_array {
             if self.param.is_input() {
-                quote! { #name.len() as u32, ::std::mem::transmute(#name.as_ptr()) }
+                quote! { #name.len() as u32, ::core::mem::transmute(#name.as_ptr()) }
             } else if self.signature.by_ref {
                 quote! { #name.set_abi_len(), #name.set_abi() }
             } else {
-                quote! { #name.len() as u32, ::std::mem::transmute_copy(&#name) }
+                quote! { #name.len() as u32, ::core::mem::transmute(#name.as_mut_ptr()) }
             }
         } else if self.param.is_input() {
             if self.is_convertible() {
                 if self.is_const() {
-                    quote! { &#name.into_param().abi() }
+                    quote! { &::windows::ParamAbi::abi(&mut #name.into_param()) }
                 } else {
-                    quote! { #name.into_param().abi() }
+                    quote! { ::windows::ParamAbi::abi(&mut #name.into_param()) }
                 }
             } else if self.signature.kind.is_blittable() {
                 quote! { #name }
             } else if self.signature.pointers == 0 {
                 quote! { ::windows::Abi::abi(#name) }
             } else {
-                quote! { ::std::mem::transmute(#name) }
+                quote! { ::core::mem::transmute(#name) }
             }
         } else if self.signature.kind.is_blittable()
             || (self.signature.pointers > 0 && !self.signature.kind.is_nullable())
@@ -469,7 +634,7 @@ impl MethodParam {
 
         if self.signature.kind.is_nullable() {
             tokens.combine(&quote! {
-                ::std::option::Option<#kind>
+                ::core::option::Option<#kind>
             });
         } else {
             tokens.combine(&kind)
@@ -513,9 +678,15 @@ impl MethodParam {
         let name = self.param.gen_name();
 
         if self.is_convertible() {
-            quote! { #name.into_param().abi() }
+            quote! { ::windows::ParamAbi::abi(&mut #name.into_param()) }
+        } else if self.is_safe_array() {
+            if self.param.is_input() {
+                quote! { ::core::mem::transmute(::windows::Abi::abi(#name)) }
+            } else {
+                quote! { ::core::mem::transmute(::windows::Abi::set_abi(#name)) }
+            }
         } else {
-            quote! { ::std::mem::transmute(#name) }
+            quote! { ::core::mem::transmute(#name) }
         }
     }
 
@@ -524,11 +695,11 @@ impl MethodParam {
 
         if self.signature.is_array {
             if self.param.is_input() {
-                quote! { &[#tokens] }
+                quote! { &[<#tokens as ::windows::RuntimeType>::DefaultType] }
             } else if self.signature.by_ref {
                 quote! { &mut ::windows::Array<#tokens> }
             } else {
-                quote! { &mut [#tokens] }
+                quote! { &mut [<#tokens as ::windows::RuntimeType>::DefaultType] }
             }
         } else if self.param.is_input() {
             if let ElementType::GenericParam(_) = self.signature.kind {
@@ -536,7 +707,7 @@ impl MethodParam {
             } else if self.signature.kind.is_primitive() {
                 quote! { #tokens }
             } else if self.signature.kind.is_nullable() {
-                quote! { &::std::option::Option<#tokens> }
+                quote! { &::core::option::Option<#tokens> }
             } else {
                 quote! { &#tokens }
             }
@@ -13,6 +13,7 @@ mod method_info;
 mod method_kind;
 mod method_signature;
 mod param_flags;
+mod pinvoke_flags;
 mod row;
 mod signature;
 mod traits;
@@ -20,6 +21,7 @@ mod type_flags;
 mod type_kind;
 mod type_reader;
 mod type_tree;
+mod validate;
 
 pub use blob::*;
 pub use codes::*;
@@ -34,6 +36,7 @@ pub use method_info::*;
 pub use method_kind::*;
 pub use method_signature::*;
 pub use param_flags::*;
+pub use pinvoke_flags::*;
 pub use row::*;
 pub use signature::*;
 pub use traits::*;
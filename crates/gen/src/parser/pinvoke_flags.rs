@@ -0,0 +1,10 @@
+pub struct PInvokeFlags(pub u32);
+
+impl PInvokeFlags {
+    pub fn cdecl(&self) -> bool {
+        self.0 & 0x0700 == 0x0200
+    }
+    pub fn fastcall(&self) -> bool {
+        self.0 & 0x0700 == 0x0500
+    }
+}
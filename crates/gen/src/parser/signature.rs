@@ -58,7 +58,7 @@ impl Signature {
 
         if self.kind.is_nullable() {
             tokens.combine(&quote! {
-                ::std::option::Option<#kind>
+                ::core::option::Option<#kind>
             });
         } else {
             tokens.combine(&kind)
@@ -83,7 +83,7 @@ impl Signature {
 
         if self.kind.is_nullable() {
             tokens.combine(&quote! {
-                ::std::option::Option<#kind>
+                ::core::option::Option<#kind>
             });
         } else {
             tokens.combine(&kind)
@@ -126,7 +126,7 @@ impl Signature {
 
     pub fn gen_win32_default(&self) -> TokenStream {
         if self.pointers > 0 {
-            quote! { ::std::ptr::null_mut() }
+            quote! { ::core::ptr::null_mut() }
         } else {
             self.kind.gen_default()
         }
@@ -134,7 +134,7 @@ impl Signature {
 
     pub fn gen_winrt_default(&self) -> TokenStream {
         if self.pointers > 0 {
-            quote! { ::std::ptr::null_mut() }
+            quote! { ::core::ptr::null_mut() }
         } else {
             self.kind.gen_default()
         }
@@ -6,7 +6,14 @@ pub struct TypeReader {
     // the derived nested type names.
     nested: HashMap<Row, BTreeMap<&'static str, tables::TypeDef>>,
 
+    // Types defined once for each of the architectures they differ on, such as structs packed
+    // only on x86, hold every definition rather than only the first one in the type tree.
+    arches: HashMap<(&'static str, &'static str), Vec<tables::TypeDef>>,
+
     pub types: TypeTree,
+
+    /// Options controlling what is generated for the imported types.
+    pub options: GenOptions,
 }
 
 #[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
@@ -14,6 +21,7 @@ pub enum TypeRow {
     TypeDef(tables::TypeDef),
     MethodDef(tables::MethodDef),
     Field(tables::Field),
+    Macro(types::Macro),
 }
 
 impl TypeRow {
@@ -22,6 +30,7 @@ impl TypeRow {
             Self::TypeDef(def) => def.dependencies(include),
             Self::MethodDef(def) => def.dependencies(),
             Self::Field(def) => def.dependencies(include),
+            Self::Macro(def) => def.dependencies(),
         }
     }
 
@@ -30,6 +39,16 @@ impl TypeRow {
             Self::TypeDef(def) => def.name(),
             Self::MethodDef(def) => def.name(),
             Self::Field(def) => def.name(),
+            Self::Macro(def) => def.name,
+        }
+    }
+
+    pub fn is_experimental(&self) -> bool {
+        match self {
+            Self::TypeDef(def) => def.is_experimental(),
+            Self::MethodDef(def) => def.is_experimental(),
+            Self::Field(def) => def.is_experimental(),
+            Self::Macro(_) => false,
         }
     }
 
@@ -38,6 +57,7 @@ impl TypeRow {
             Self::TypeDef(def) => def.namespace(),
             Self::MethodDef(def) => def.parent().namespace(),
             Self::Field(def) => def.parent().namespace(),
+            Self::Macro(def) => def.namespace,
         }
     }
 }
@@ -48,6 +68,8 @@ impl From<&TypeRow> for ElementType {
             TypeRow::TypeDef(row) => row.clone().into(),
             TypeRow::MethodDef(row) => Self::MethodDef(row.clone()),
             TypeRow::Field(row) => Self::Field(row.clone()),
+            // Macros aren't referred to by the signatures of other types.
+            TypeRow::Macro(_) => unexpected!(),
         }
     }
 }
@@ -57,6 +79,29 @@ impl TypeReader {
         self.types.gen()
     }
 
+    /// Writes the code for every imported type to `writer` as it is generated.
+    pub fn write<W: std::io::Write>(&'static self, writer: &mut W) -> std::io::Result<()> {
+        self.types.write(writer)
+    }
+
+    /// Writes the code for every imported type to `writer`, reusing the code generated for any
+    /// namespace whose imported types, metadata, and options are unchanged since it was last
+    /// stored in the `cache` directory.
+    pub fn write_cached<W: std::io::Write>(
+        &'static self,
+        writer: &mut W,
+        cache: &std::path::Path,
+    ) -> std::io::Result<()> {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        hash_winmds(&mut hasher);
+        self.options.hash(&mut hasher);
+        env!("CARGO_PKG_VERSION").hash(&mut hasher);
+
+        self.types.write_cached(writer, cache, hasher.finish())
+    }
+
     pub fn get_mut() -> &'static mut Self {
         use std::{mem::MaybeUninit, sync::Once};
         static ONCE: Once = Once::new();
@@ -83,6 +128,7 @@ impl TypeReader {
     fn new() -> Self {
         let files = crate_winmds();
         let mut nested = HashMap::<Row, BTreeMap<&'static str, tables::TypeDef>>::new();
+        let mut arches = HashMap::<(&'static str, &'static str), Vec<tables::TypeDef>>::new();
         let mut types = TypeTree::from_namespace("");
         types.include = true;
 
@@ -108,6 +154,18 @@ impl TypeReader {
                     continue;
                 }
 
+                if let Some(architectures) = def.architectures() {
+                    let definitions = arches.entry((namespace, name)).or_default();
+
+                    // The same metadata may be read from more than one winmd file.
+                    if definitions
+                        .iter()
+                        .all(|def| def.architectures() != Some(architectures))
+                    {
+                        definitions.push(def.clone());
+                    }
+                }
+
                 let namespace = types.insert_namespace(namespace, 0);
 
                 if def.flags().windows_runtime() || extends != ("System", "Object") {
@@ -140,7 +198,24 @@ impl TypeReader {
             }
         }
 
-        Self { nested, types }
+        // Macros are only added to the namespaces of the metadata that is present. A macro of the
+        // same name as a type defined by the metadata is left to the metadata.
+        for (namespace, names) in types::Macro::ALL {
+            if types.get_namespace(namespace).is_some() {
+                let tree = types.insert_namespace(namespace, 0);
+
+                for name in names.iter() {
+                    tree.insert_type(name, TypeRow::Macro(types::Macro { namespace, name }));
+                }
+            }
+        }
+
+        Self {
+            nested,
+            arches,
+            types,
+            options: GenOptions::default(),
+        }
     }
 
     /// Get all the namespace names that the [`TypeReader`] knows about
@@ -152,8 +227,12 @@ impl TypeReader {
     pub fn import_namespace(&mut self, namespace: &str) -> bool {
         // TODO: borrow hackery going on here...
         if let Some(namespace) = Self::get().types.get_namespace(namespace) {
-            for name in namespace.types.keys() {
-                self.import_type_include(namespace.namespace, name, TypeInclude::Full);
+            for (name, entry) in &namespace.types {
+                // Types excluded by the options, such as experimental types, are only imported
+                // by name or as the dependencies of other types.
+                if !self.options.excludes_type(&entry.def) {
+                    self.import_type_include(namespace.namespace, name, TypeInclude::Full);
+                }
             }
 
             true
@@ -180,6 +259,59 @@ impl TypeReader {
         self.import_type_include(namespace, name, TypeInclude::Full)
     }
 
+    /// Returns the namespaces nearest to one that isn't found in metadata, nearest first, so that
+    /// a misspelled namespace may be reported along with the ones that were likely meant.
+    pub fn suggest_namespaces(&'static self, namespace: &str) -> Vec<&'static str> {
+        nearest(
+            namespace,
+            self.namespaces()
+                .into_iter()
+                .map(|namespace| (namespace, namespace)),
+        )
+    }
+
+    /// Returns the full names of the types nearest to one that isn't found in metadata, nearest
+    /// first. Types of the same name in other namespaces are suggested before any misspelling,
+    /// which is otherwise looked for in the namespace or, if it isn't found either, in the
+    /// namespaces nearest to it.
+    pub fn suggest_types(&'static self, namespace: &str, name: &str) -> Vec<String> {
+        let mut same_name = Vec::new();
+
+        for candidate in self.namespaces() {
+            if let Some(tree) = self.types.get_namespace(candidate) {
+                for key in tree.types.keys() {
+                    if key.eq_ignore_ascii_case(name) {
+                        same_name.push(format!("{}.{}", candidate, key));
+                    }
+                }
+            }
+        }
+
+        if !same_name.is_empty() {
+            same_name.truncate(MAX_SUGGESTIONS);
+            return same_name;
+        }
+
+        let namespaces = if self.types.get_namespace(namespace).is_some() {
+            vec![namespace]
+        } else {
+            self.suggest_namespaces(namespace)
+        };
+
+        let candidates = namespaces.into_iter().flat_map(|namespace| {
+            self.types
+                .get_namespace(namespace)
+                .into_iter()
+                .flat_map(move |tree| {
+                    tree.types
+                        .keys()
+                        .map(move |key| (*key, format!("{}.{}", tree.namespace, key)))
+                })
+        });
+
+        nearest(name, candidates)
+    }
+
     fn import_type_dependencies(&mut self, def: &TypeRow, include: TypeInclude) {
         for entry in def.dependencies(include) {
             let namespace = entry.def.namespace();
@@ -225,6 +357,17 @@ impl TypeReader {
         self.nested.get(&enclosing.row)
     }
 
+    /// Returns every definition of a type defined once for each of the architectures it differs
+    /// on, or `None` if the type has a single definition.
+    pub fn arch_definitions(
+        &'static self,
+        def: &tables::TypeDef,
+    ) -> Option<&'static [tables::TypeDef]> {
+        self.arches
+            .get(&(def.namespace(), trim_tick(def.name())))
+            .map(|definitions| definitions.as_slice())
+    }
+
     pub fn resolve_type(&'static self, namespace: &str, name: &str) -> ElementType {
         if let Some(def) = self
             .types
@@ -277,7 +420,23 @@ impl TypeReader {
                 })
                 .clone()
         } else {
-            self.resolve_type_def(type_ref.namespace(), type_ref.name())
+            let (namespace, name) = type_ref.full_name();
+
+            if let Some(TypeRow::TypeDef(def)) = self
+                .types
+                .get_namespace(namespace)
+                .and_then(|tree| tree.get_type(trim_tick(name)))
+                .map(|entry| &entry.def)
+            {
+                return def.clone();
+            }
+
+            // Metadata such as `Microsoft.UI.Xaml` refers to types defined by other winmd files,
+            // such as the `Windows.Foundation` types in the Windows SDK's metadata.
+            panic!(
+                "Could not find type `{}.{}` referred to by `{}`; the winmd file defining it must also be present in a `.windows/winmd` directory",
+                namespace, name, type_ref.0.file.name
+            );
         }
     }
 
@@ -377,7 +536,8 @@ impl TypeReader {
     }
 }
 
-fn trim_tick(name: &str) -> &str {
+/// Removes the generic parameter count from the name of a type, such as `IVector`1`.
+pub fn trim_tick(name: &str) -> &str {
     let len = name.len() - 2;
     match name.as_bytes().get(len) {
         Some(c) if *c == b'`' => &name[..len],
@@ -385,6 +545,54 @@ fn trim_tick(name: &str) -> &str {
     }
 }
 
+const MAX_SUGGESTIONS: usize = 3;
+
+// Returns the values whose keys are within a few edits of `target`, ignoring case, nearest first.
+fn nearest<'a, T>(target: &str, candidates: impl Iterator<Item = (&'a str, T)>) -> Vec<T> {
+    let target = target.to_ascii_lowercase();
+    let limit = std::cmp::max(2, target.len() / 4);
+
+    let mut nearest: Vec<(usize, T)> = candidates
+        .filter(|(key, _)| {
+            std::cmp::max(key.len(), target.len()) - std::cmp::min(key.len(), target.len()) <= limit
+        })
+        .filter_map(|(key, value)| {
+            let distance = edit_distance(&target, &key.to_ascii_lowercase());
+
+            if distance <= limit {
+                Some((distance, value))
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    // The sort is stable so that equally near candidates remain in alphabetical order.
+    nearest.sort_by_key(|(distance, _)| *distance);
+    nearest.truncate(MAX_SUGGESTIONS);
+    nearest.into_iter().map(|(_, value)| value).collect()
+}
+
+// The Levenshtein distance between two ASCII strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.as_bytes();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a) in a.bytes().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + if a == *b { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
 fn is_well_known(namespace: &'static str, name: &'static str) -> bool {
     for entry in &WELL_KNOWN_TYPES {
         if name == entry.1 && namespace == entry.0 {
@@ -427,3 +635,45 @@ const WELL_KNOWN_TYPES: [(&str, &str, ElementType); 10] = [
     ),
     ("System", "Type", ElementType::TypeName),
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("storage", "storage"), 0);
+        assert_eq!(edit_distance("strorage", "storage"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_suggest() {
+        let reader = TypeReader::get();
+
+        assert_eq!(
+            reader.suggest_namespaces("Windows.Strorage")[0],
+            "Windows.Storage"
+        );
+
+        assert_eq!(
+            reader.suggest_types("Windows.Strorage", "StorageFile"),
+            ["Windows.Storage.StorageFile"]
+        );
+
+        assert_eq!(
+            reader.suggest_types("Windows.Strorage", "StorageFiel")[0],
+            "Windows.Storage.StorageFile"
+        );
+
+        assert_eq!(
+            reader.suggest_types("Windows.Foundation", "IStorageFile")[0],
+            "Windows.Storage.IStorageFile"
+        );
+
+        assert!(reader
+            .suggest_types("Windows.Foundation", "Zzzzzzzzzzzzzzzz")
+            .is_empty());
+    }
+}
@@ -23,6 +23,7 @@ impl TypeEntry {
             TypeRow::TypeDef(def) => def.clone().with_generics().gen(gen, self.include),
             TypeRow::MethodDef(def) => def.gen(gen),
             TypeRow::Field(def) => def.gen(gen),
+            TypeRow::Macro(def) => def.gen(gen),
         }
     }
 }
@@ -80,6 +81,72 @@ impl TypeTree {
         namespaces
     }
 
+    /// Returns the full names of the types included in full rather than only minimally, such as
+    /// `Windows.Foundation.IStringable`.
+    pub fn full_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .types
+            .iter()
+            .filter(|(_, entry)| entry.include == TypeInclude::Full)
+            .map(|(name, _)| format!("{}.{}", self.namespace, name))
+            .collect();
+
+        for tree in self.namespaces.values() {
+            names.append(&mut tree.full_names());
+        }
+
+        names
+    }
+
+    /// Returns the libraries that the included functions are linked with.
+    pub fn link_libraries(&self) -> BTreeSet<types::Link> {
+        let mut libraries: BTreeSet<types::Link> = self
+            .types
+            .values()
+            .filter(|entry| entry.include != TypeInclude::None)
+            .filter_map(|entry| match &entry.def {
+                TypeRow::MethodDef(def) => Some(types::Function::link(def)),
+                _ => None,
+            })
+            .collect();
+
+        for tree in self.namespaces.values() {
+            libraries.append(&mut tree.link_libraries());
+        }
+
+        libraries
+    }
+
+    /// Returns the features, when `function_features` is set, of the included functions that the
+    /// Rust source calls, which are those whose names appear in it as identifiers.
+    pub fn function_features(&self, source: &str) -> BTreeSet<String> {
+        let identifiers: BTreeSet<&str> = source
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .collect();
+
+        self.function_features_of(&identifiers)
+    }
+
+    fn function_features_of(&self, identifiers: &BTreeSet<&str>) -> BTreeSet<String> {
+        let mut features: BTreeSet<String> = self
+            .types
+            .values()
+            .filter(|entry| entry.include != TypeInclude::None)
+            .filter_map(|entry| match &entry.def {
+                TypeRow::MethodDef(def) if identifiers.contains(def.name()) => {
+                    Some(def.function_feature())
+                }
+                _ => None,
+            })
+            .collect();
+
+        for tree in self.namespaces.values() {
+            features.append(&mut tree.function_features_of(identifiers));
+        }
+
+        features
+    }
+
     pub fn get_type(&self, name: &str) -> Option<&TypeEntry> {
         self.types.get(name)
     }
@@ -130,6 +197,104 @@ impl TypeTree {
             .map(move |t| t.1.gen(&gen))
             .chain(gen_namespaces(&self.namespaces))
     }
+
+    /// Writes the tree to `writer` one type at a time so that the complete output never has to
+    /// be held in memory.
+    pub fn write<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.write_types(writer)?;
+
+        for (name, tree) in &self.namespaces {
+            if tree.include {
+                writer.write_all(gen_namespace_header(name).as_str().as_bytes())?;
+                writer.write_all(b" { ")?;
+                tree.write(writer)?;
+                writer.write_all(b" } ")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the tree to `writer` like [`TypeTree::write`] but reuses the code generated for
+    /// each namespace by an earlier build, stored in the `cache` directory, as long as the types
+    /// included from the namespace and the `seed` identifying the metadata and options are
+    /// unchanged. Adding a type to one namespace then only generates that namespace again.
+    pub fn write_cached<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        cache: &std::path::Path,
+        seed: u64,
+    ) -> std::io::Result<()> {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        seed.hash(&mut hasher);
+        self.namespace.hash(&mut hasher);
+
+        for (name, entry) in &self.types {
+            if entry.include != TypeInclude::None {
+                name.hash(&mut hasher);
+                (entry.include == TypeInclude::Full).hash(&mut hasher);
+            }
+        }
+
+        let prefix = format!("{}-", self.namespace);
+        let path = cache.join(format!("{}{:016x}.rs", prefix, hasher.finish()));
+
+        if let Ok(bytes) = std::fs::read(&path) {
+            writer.write_all(&bytes)?;
+        } else {
+            let mut buffer = Vec::new();
+            self.write_types(&mut buffer)?;
+            writer.write_all(&buffer)?;
+
+            // The cache is best effort since the code has already been written. Code generated
+            // for earlier versions of the namespace is removed so that the cache doesn't grow.
+            if let Ok(entries) = std::fs::read_dir(cache) {
+                for entry in entries.filter_map(|entry| entry.ok()) {
+                    if entry.file_name().to_string_lossy().starts_with(&prefix) {
+                        let _ = std::fs::remove_file(entry.path());
+                    }
+                }
+            }
+
+            let _ = std::fs::write(&path, &buffer);
+        }
+
+        for (name, tree) in &self.namespaces {
+            if tree.include {
+                writer.write_all(gen_namespace_header(name).as_str().as_bytes())?;
+                writer.write_all(b" { ")?;
+                tree.write_cached(writer, cache, seed)?;
+                writer.write_all(b" } ")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Writes the types of this namespace, excluding any nested namespaces.
+    fn write_types<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let gen = Gen::Relative(self.namespace);
+
+        for entry in self.types.values() {
+            writer.write_all(entry.gen(&gen).as_str().as_bytes())?;
+            writer.write_all(b" ")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn gen_namespace_header(name: &str) -> TokenStream {
+    let name = to_ident(name);
+
+    quote! {
+        // TODO: https://github.com/microsoft/windows-rs/issues/212
+        // TODO: https://github.com/microsoft/win32metadata/issues/380
+        #[allow(unused_variables, non_upper_case_globals, non_snake_case, unused_unsafe, non_camel_case_types, dead_code, clippy::all)]
+        pub mod #name
+    }
 }
 
 fn gen_namespaces<'a>(
@@ -137,20 +302,162 @@ fn gen_namespaces<'a>(
 ) -> impl Iterator<Item = TokenStream> + 'a {
     namespaces.iter().map(move |(name, tree)| {
         if tree.include {
-            let name = to_ident(name);
-
+            let header = gen_namespace_header(name);
             let tokens = tree.gen();
 
             quote! {
-                // TODO: https://github.com/microsoft/windows-rs/issues/212
-                // TODO: https://github.com/microsoft/win32metadata/issues/380
-                #[allow(unused_variables, non_upper_case_globals, non_snake_case, unused_unsafe, non_camel_case_types, dead_code, clippy::all)]
-                pub mod #name {
+                #header {
                     #(#tokens)*
                 }
             }
-         } else {
-             TokenStream::new()
-         }
+        } else {
+            TokenStream::new()
+        }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_cached() {
+        let reader = TypeReader::get();
+        let mut tree = TypeTree::from_namespace("");
+        let namespace = tree.insert_namespace("Windows.Foundation", 0);
+        namespace.include = true;
+
+        for name in &["AsyncStatus", "Point"] {
+            let def = reader.resolve_type_def("Windows.Foundation", name);
+            namespace.types.insert(
+                def.name(),
+                TypeEntry {
+                    def: TypeRow::TypeDef(def),
+                    include: TypeInclude::Full,
+                },
+            );
+        }
+
+        tree.get_namespace_mut("Windows.Foundation");
+
+        let cache = std::env::temp_dir().join(format!("windows-gen-cache-{}", std::process::id()));
+        std::fs::create_dir_all(&cache).unwrap();
+
+        let mut expected = Vec::new();
+        tree.write(&mut expected).unwrap();
+
+        let mut first = Vec::new();
+        tree.write_cached(&mut first, &cache, 1).unwrap();
+        assert_eq!(first, expected);
+
+        let files = || {
+            std::fs::read_dir(&cache)
+                .unwrap()
+                .filter(|entry| {
+                    entry
+                        .as_ref()
+                        .unwrap()
+                        .file_name()
+                        .to_string_lossy()
+                        .starts_with("Windows.Foundation-")
+                })
+                .count()
+        };
+
+        assert_eq!(files(), 1);
+
+        let mut second = Vec::new();
+        tree.write_cached(&mut second, &cache, 1).unwrap();
+        assert_eq!(second, expected);
+
+        // A different seed replaces the namespace's cached code rather than adding to it.
+        let mut third = Vec::new();
+        tree.write_cached(&mut third, &cache, 2).unwrap();
+        assert_eq!(third, expected);
+        assert_eq!(files(), 1);
+
+        std::fs::remove_dir_all(&cache).unwrap();
+    }
+
+    #[test]
+    fn full_names() {
+        let reader = TypeReader::get();
+        let mut tree = TypeTree::from_namespace("");
+        let namespace = tree.insert_namespace("Windows.Foundation", 0);
+
+        for (name, include) in &[
+            ("AsyncStatus", TypeInclude::Full),
+            ("Point", TypeInclude::Minimal),
+            ("Rect", TypeInclude::None),
+        ] {
+            let def = reader.resolve_type_def("Windows.Foundation", name);
+            namespace.types.insert(
+                def.name(),
+                TypeEntry {
+                    def: TypeRow::TypeDef(def),
+                    include: *include,
+                },
+            );
+        }
+
+        assert_eq!(tree.full_names(), ["Windows.Foundation.AsyncStatus"]);
+    }
+
+    #[test]
+    fn link_libraries() {
+        let reader = TypeReader::get();
+        let mut tree = TypeTree::from_namespace("");
+        let namespace = tree.insert_namespace("Windows.Win32.Foundation", 0);
+
+        for (name, include) in &[
+            ("CloseHandle", TypeInclude::Full),
+            ("SysAllocString", TypeInclude::None),
+        ] {
+            namespace.types.insert(
+                name,
+                TypeEntry {
+                    def: reader.resolve_type_row("Windows.Win32.Foundation", name),
+                    include: *include,
+                },
+            );
+        }
+
+        assert_eq!(
+            tree.link_libraries().into_iter().collect::<Vec<_>>(),
+            [types::Link::Import {
+                msvc: "kernel32".to_string(),
+                gnu: "kernel32".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn function_features() {
+        let reader = TypeReader::get();
+        let mut tree = TypeTree::from_namespace("");
+        let namespace = tree.insert_namespace("Windows.Win32.Foundation", 0);
+
+        for (name, include) in &[
+            ("CloseHandle", TypeInclude::Full),
+            ("SysAllocString", TypeInclude::Full),
+            ("SysFreeString", TypeInclude::None),
+        ] {
+            namespace.types.insert(
+                name,
+                TypeEntry {
+                    def: reader.resolve_type_row("Windows.Win32.Foundation", name),
+                    include: *include,
+                },
+            );
+        }
+
+        let source = "unsafe { CloseHandle(handle); SysFreeString(text); } // SysAllocStringLen";
+
+        assert_eq!(
+            tree.function_features(source)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            ["Windows_Win32_Foundation_CloseHandle"]
+        );
+    }
+}
@@ -0,0 +1,356 @@
+use super::*;
+
+// The kind of value stored in a table column, used to check that the value refers to data that
+// is actually present in the file.
+#[derive(Copy, Clone)]
+enum Column {
+    Fixed,
+    Strings,
+    Blobs,
+    // A one-based row index into a table, or zero for none.
+    Index(TableIndex),
+    // The one-based index of the first row of a run in a table, which may be one past its end.
+    List(TableIndex),
+    // A coded index whose low bits select the table, where tables that aren't read are `None`.
+    Coded(u32, &'static [Option<TableIndex>]),
+}
+
+use Column::*;
+use TableIndex::*;
+
+const TYPE_DEF_OR_REF: Column = Coded(2, &[Some(TypeDef), Some(TypeRef), Some(TypeSpec)]);
+const HAS_CONSTANT: Column = Coded(2, &[Some(Field), Some(Param), None]);
+const TYPE_OR_METHOD_DEF: Column = Coded(1, &[Some(TypeDef), Some(MethodDef)]);
+const MEMBER_FORWARDED: Column = Coded(1, &[Some(Field), Some(MethodDef)]);
+const CUSTOM_ATTRIBUTE_TYPE: Column =
+    Coded(3, &[None, None, Some(MethodDef), Some(MemberRef), None]);
+
+const MEMBER_REF_PARENT: Column = Coded(
+    3,
+    &[
+        Some(TypeDef),
+        Some(TypeRef),
+        Some(ModuleRef),
+        Some(MethodDef),
+        Some(TypeSpec),
+    ],
+);
+
+const RESOLUTION_SCOPE: Column = Coded(
+    2,
+    &[
+        Some(Module),
+        Some(ModuleRef),
+        Some(AssemblyRef),
+        Some(TypeRef),
+    ],
+);
+
+const HAS_CUSTOM_ATTRIBUTE: Column = Coded(
+    5,
+    &[
+        Some(MethodDef),
+        Some(Field),
+        Some(TypeRef),
+        Some(TypeDef),
+        Some(Param),
+        Some(InterfaceImpl),
+        Some(MemberRef),
+        Some(Module),
+        None,
+        None,
+        None,
+        None,
+        Some(ModuleRef),
+        Some(TypeSpec),
+        None,
+        Some(AssemblyRef),
+        None,
+        None,
+        None,
+        Some(GenericParam),
+        None,
+        None,
+    ],
+);
+
+fn columns(table: TableIndex) -> &'static [Column] {
+    match table {
+        Constant => &[Fixed, HAS_CONSTANT, Blobs],
+        CustomAttribute => &[HAS_CUSTOM_ATTRIBUTE, CUSTOM_ATTRIBUTE_TYPE, Blobs],
+        Field => &[Fixed, Strings, Blobs],
+        GenericParam => &[Fixed, Fixed, TYPE_OR_METHOD_DEF, Strings],
+        InterfaceImpl => &[Index(TypeDef), TYPE_DEF_OR_REF],
+        MemberRef => &[MEMBER_REF_PARENT, Strings, Blobs],
+        MethodDef => &[Fixed, Fixed, Fixed, Strings, Blobs, List(Param)],
+        Param => &[Fixed, Fixed, Strings],
+        TypeDef => &[
+            Fixed,
+            Strings,
+            Strings,
+            TYPE_DEF_OR_REF,
+            List(Field),
+            List(MethodDef),
+        ],
+        TypeRef => &[RESOLUTION_SCOPE, Strings, Strings],
+        TypeSpec => &[Blobs],
+        ImplMap => &[Fixed, MEMBER_FORWARDED, Strings, Index(ModuleRef)],
+        ModuleRef => &[Strings],
+        NestedClass => &[Index(TypeDef), Index(TypeDef)],
+        // The GUID heap isn't read so the module's GUIDs aren't checked.
+        Module => &[Fixed, Strings, Fixed, Fixed, Fixed],
+        AssemblyRef => &[Fixed, Fixed, Blobs, Strings, Strings, Blobs],
+        ClassLayout => &[Fixed, Fixed, Index(TypeDef)],
+    }
+}
+
+const TABLES: [TableIndex; 17] = [
+    Constant,
+    CustomAttribute,
+    Field,
+    GenericParam,
+    InterfaceImpl,
+    MemberRef,
+    MethodDef,
+    Param,
+    TypeDef,
+    TypeRef,
+    TypeSpec,
+    ImplMap,
+    ModuleRef,
+    NestedClass,
+    Module,
+    AssemblyRef,
+    ClassLayout,
+];
+
+// The `TypeAttributes` of WinRT interfaces, which are required to have a `GuidAttribute`.
+const INTERFACE: u32 = 0x20;
+const WINDOWS_RUNTIME: u32 = 0x4000;
+
+impl File {
+    /// Panics with a message naming the file if a metadata stream doesn't lie within the file.
+    pub(crate) fn validate_stream(&self, name: &str, offset: u32, size: u32) {
+        if offset == 0 {
+            self.invalid(format_args!("the `{}` stream is missing", name));
+        }
+
+        if offset as u64 + size as u64 > self.bytes.len() as u64 {
+            self.invalid(format_args!(
+                "the `{}` stream extends beyond the end of the file, which may have been truncated",
+                name
+            ));
+        }
+    }
+
+    /// Checks that every table lies within the tables stream and that every row only refers to
+    /// rows, strings, and blobs that are present in the file, so that a corrupt or truncated file
+    /// is reported by name rather than causing an out-of-bounds panic while it is read.
+    pub(crate) fn validate(&self, tables_end: u32, strings_size: u32, blobs_size: u32) {
+        for &table in &TABLES {
+            let data = &self.tables[table as usize];
+
+            if data.row_count != 0
+                && data.data as u64 + data.row_count as u64 * data.row_size as u64
+                    > tables_end as u64
+            {
+                self.invalid(format_args!(
+                    "the {:?} table extends beyond the end of the tables stream",
+                    table
+                ));
+            }
+        }
+
+        for &table in &TABLES {
+            for row in 0..self.tables[table as usize].row_count {
+                for (column, kind) in columns(table).iter().enumerate() {
+                    self.validate_column(
+                        table,
+                        row,
+                        column as u32,
+                        *kind,
+                        strings_size,
+                        blobs_size,
+                    );
+                }
+            }
+        }
+
+        self.validate_guids();
+    }
+
+    fn validate_column(
+        &self,
+        table: TableIndex,
+        row: u32,
+        column: u32,
+        kind: Column,
+        strings_size: u32,
+        blobs_size: u32,
+    ) {
+        let value = self.u32(row, table, column);
+
+        let error = match kind {
+            Fixed => None,
+            Strings if value >= strings_size => {
+                Some("a string beyond the end of the `#Strings` stream".to_string())
+            }
+            Strings => None,
+            Blobs if value >= blobs_size => {
+                Some("a blob beyond the end of the `#Blob` stream".to_string())
+            }
+            Blobs => self.validate_blob(value, blobs_size),
+            Index(target) if value > self.tables[target as usize].row_count => {
+                Some(format!("an index beyond the end of the {:?} table", target))
+            }
+            Index(_) => None,
+            List(target) if value == 0 || value > self.tables[target as usize].row_count + 1 => {
+                Some(format!("a list beyond the end of the {:?} table", target))
+            }
+            List(_) => None,
+            Coded(bits, tables) => {
+                let tag = (value & ((1 << bits) - 1)) as usize;
+                let index = value >> bits;
+
+                match tables.get(tag) {
+                    None => Some(format!("an invalid coded index tag {}", tag)),
+                    Some(Some(target)) if index > self.tables[*target as usize].row_count => {
+                        Some(format!("an index beyond the end of the {:?} table", target))
+                    }
+                    Some(_) => None,
+                }
+            }
+        };
+
+        if let Some(error) = error {
+            self.invalid(format_args!(
+                "row {} of the {:?} table refers to {} in column {}",
+                row, table, error, column
+            ));
+        }
+    }
+
+    fn validate_blob(&self, value: u32, blobs_size: u32) -> Option<String> {
+        let offset = (self.blobs + value) as usize;
+        let initial_byte = self.bytes[offset];
+
+        let (size, size_bytes) = match initial_byte >> 5 {
+            0..=3 => ((initial_byte & 0x7f) as u64, 1),
+            4..=5 => ((initial_byte & 0x3f) as u64, 2),
+            6 => ((initial_byte & 0x1f) as u64, 4),
+            _ => return Some("a blob with an invalid length".to_string()),
+        };
+
+        if value as u64 + size_bytes as u64 > blobs_size as u64 {
+            return Some("a blob beyond the end of the `#Blob` stream".to_string());
+        }
+
+        let size = self.bytes[offset + 1..offset + size_bytes]
+            .iter()
+            .fold(size, |size, byte| (size << 8) + *byte as u64);
+
+        if value as u64 + size_bytes as u64 + size > blobs_size as u64 {
+            Some("a blob extending beyond the end of the `#Blob` stream".to_string())
+        } else {
+            None
+        }
+    }
+
+    // Every WinRT interface must have a `GuidAttribute` since its IID can't otherwise be derived.
+    fn validate_guids(&self) {
+        let mut has_guid = vec![false; self.tables[TypeDef as usize].row_count as usize];
+
+        for row in 0..self.tables[CustomAttribute as usize].row_count {
+            let parent = self.u32(row, CustomAttribute, 0);
+            let constructor = self.u32(row, CustomAttribute, 1);
+
+            // Only attributes on type definitions whose constructor is a member reference of a
+            // type reference can be the `GuidAttribute`.
+            if parent & 0x1f != 3 || constructor & 0x7 != 3 || constructor >> 3 == 0 {
+                continue;
+            }
+
+            let class = self.u32((constructor >> 3) - 1, MemberRef, 0);
+
+            if class & 0x7 != 1 || class >> 3 == 0 || parent >> 5 == 0 {
+                continue;
+            }
+
+            if self.raw_str(self.u32((class >> 3) - 1, TypeRef, 1)) == b"GuidAttribute" {
+                has_guid[(parent >> 5) as usize - 1] = true;
+            }
+        }
+
+        for (row, has_guid) in has_guid.iter().enumerate() {
+            let flags = self.u32(row as u32, TypeDef, 0);
+
+            if flags & (INTERFACE | WINDOWS_RUNTIME) == INTERFACE | WINDOWS_RUNTIME && !has_guid {
+                self.invalid(format_args!(
+                    "row {} of the TypeDef table is the WinRT interface `{}` without the required `GuidAttribute`",
+                    row,
+                    String::from_utf8_lossy(self.raw_str(self.u32(row as u32, TypeDef, 1)))
+                ));
+            }
+        }
+    }
+
+    fn raw_str(&self, value: u32) -> &[u8] {
+        let bytes = &self.bytes[(self.strings + value) as usize..];
+        let len = bytes
+            .iter()
+            .position(|c| *c == b'\0')
+            .unwrap_or(bytes.len());
+        &bytes[..len]
+    }
+
+    fn invalid(&self, message: std::fmt::Arguments) -> ! {
+        panic!(
+            "Invalid winmd file `{}`: {}. The file may be corrupt; try restoring or downloading it again.",
+            self.name, message
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn winmd() -> Vec<u8> {
+        let mut path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push(".windows/winmd/Windows.WinRT.winmd");
+        std::fs::read(path).unwrap()
+    }
+
+    #[test]
+    fn valid() {
+        let file = File::from_bytes("Windows.WinRT.winmd".to_string(), winmd());
+        assert!(file.type_def_table().row_count > 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid winmd file `Windows.WinRT.winmd`: the `#Blob` stream")]
+    fn truncated() {
+        let mut bytes = winmd();
+        let file = File::from_bytes("Windows.WinRT.winmd".to_string(), bytes.clone());
+        bytes.truncate(file.blobs as usize + 1);
+
+        File::from_bytes("Windows.WinRT.winmd".to_string(), bytes);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Invalid winmd file `Windows.WinRT.winmd`: row 0 of the TypeDef table refers to a string beyond the end of the `#Strings` stream in column 1"
+    )]
+    fn corrupt_string() {
+        let mut bytes = winmd();
+        let file = File::from_bytes("Windows.WinRT.winmd".to_string(), bytes.clone());
+        let table = file.type_def_table();
+        let offset = (table.data + table.columns[1].0) as usize;
+
+        for byte in &mut bytes[offset..offset + table.columns[1].1 as usize] {
+            *byte = 0xff;
+        }
+
+        File::from_bytes("Windows.WinRT.winmd".to_string(), bytes);
+    }
+}
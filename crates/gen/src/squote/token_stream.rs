@@ -73,6 +73,12 @@ impl Default for TokenStream {
     }
 }
 
+impl From<String> for TokenStream {
+    fn from(inner: String) -> Self {
+        Self { inner }
+    }
+}
+
 impl std::iter::FromIterator<TokenStream> for TokenStream {
     fn from_iter<I: IntoIterator<Item = TokenStream>>(iter: I) -> Self {
         iter.into_iter()
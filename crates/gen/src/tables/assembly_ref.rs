@@ -3,6 +3,12 @@ use super::*;
 #[derive(Clone)]
 pub struct AssemblyRef(pub Row);
 
+impl AssemblyRef {
+    pub fn name(&self) -> &'static str {
+        self.0.str(3)
+    }
+}
+
 impl std::fmt::Debug for AssemblyRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("AssemblyRef").field("row", &self.0).finish()
@@ -67,6 +67,11 @@ impl Field {
             .map(Attribute)
     }
 
+    pub fn is_experimental(&self) -> bool {
+        self.attributes()
+            .any(|attribute| attribute.name() == "ExperimentalAttribute")
+    }
+
     pub fn signature(&self) -> Signature {
         let mut blob = self.blob();
         blob.read_unsigned();
@@ -84,6 +89,13 @@ impl Field {
         self.signature().is_blittable()
     }
 
+    /// Returns the name of the field, unless it has been renamed.
+    pub fn rust_name(&self) -> String {
+        TypeReader::get()
+            .options
+            .rename(&self.parent(), self.name().to_string())
+    }
+
     pub fn gen_name(&self) -> TokenStream {
         let name = format_ident!("{}", self.name());
         quote! { #name }
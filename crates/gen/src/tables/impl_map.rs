@@ -4,6 +4,10 @@ use super::*;
 pub struct ImplMap(pub Row);
 
 impl ImplMap {
+    pub fn flags(&self) -> PInvokeFlags {
+        PInvokeFlags(self.0.u32(0))
+    }
+
     pub fn scope(&self) -> ModuleRef {
         ModuleRef(Row::new(
             self.0.u32(3) - 1,
@@ -42,10 +42,18 @@ impl MethodDef {
     }
 
     pub fn rust_name(&self) -> String {
+        let options = &TypeReader::get().options;
+        options.rename(&self.parent(), self.projected_name(options.naming))
+    }
+
+    /// Returns the name of the method with the given naming, before any rename.
+    pub fn projected_name(&self, naming: Naming) -> String {
         let name = self.name();
 
         if self.flags().special() {
-            if name.starts_with("get") {
+            if naming == Naming::Metadata {
+                name.to_string()
+            } else if name.starts_with("get") {
                 name[4..].to_string()
             } else if name.starts_with("put") {
                 format!("Set{}", &name[4..])
@@ -58,18 +66,102 @@ impl MethodDef {
                 "Invoke".to_string()
             }
         } else {
-            for attribute in self.attributes() {
-                if attribute.name() == "OverloadAttribute" {
-                    for (_, arg) in attribute.args() {
-                        if let ConstantValue::String(name) = arg {
-                            return name;
-                        }
+            match self.overload_name() {
+                Some(overload) if !self.keeps_metadata_name() => overload,
+                _ => name.to_string(),
+            }
+        }
+    }
+
+    /// Returns the name given by the method's `Overload` attribute, which distinguishes it from
+    /// the other methods of the same name declared by its interface.
+    pub fn overload_name(&self) -> Option<String> {
+        for attribute in self.attributes() {
+            if attribute.name() == "OverloadAttribute" {
+                for (_, arg) in attribute.args() {
+                    if let ConstantValue::String(name) = arg {
+                        return Some(name);
                     }
                 }
             }
+        }
+
+        None
+    }
+
+    /// Returns whether the method is the overload marked with the `DefaultOverload` attribute,
+    /// which languages without overloading call by the shared name.
+    pub fn is_default_overload(&self) -> bool {
+        self.has_attribute("DefaultOverloadAttribute")
+    }
 
-            name.to_string()
+    // The default overload keeps the shared name, as it does in other languages, unless another
+    // method would then share it. That includes the methods of the other interfaces of the class
+    // that an exclusive interface belongs to, since the class has the methods of them all.
+    fn keeps_metadata_name(&self) -> bool {
+        if !self.is_default_overload() || !TypeReader::get().options.codegen_version(3) {
+            return false;
         }
+
+        let parent = self.parent();
+
+        let interfaces = match parent.exclusive_to() {
+            Some(class) => types::Class(class.with_generics())
+                .interfaces()
+                .into_iter()
+                .map(|interface| interface.def)
+                .collect(),
+            None => vec![parent],
+        };
+
+        let name = self.name();
+
+        !interfaces
+            .iter()
+            .flat_map(|interface| interface.methods())
+            .any(|method| {
+                method != *self
+                    && ((method.name() == name && method.is_default_overload())
+                        || method.overload_name().as_deref().unwrap_or(method.name()) == name)
+            })
+    }
+
+    /// Returns the name of the feature gating a function that requires a version of Windows 10
+    /// later than its first release, such as `win10_19041` for build 19041.
+    pub fn os_feature(&self) -> Option<String> {
+        self.attributes().find_map(|attribute| {
+            if attribute.name() == "SupportedOSPlatformAttribute" {
+                if let Some((_, ConstantValue::String(platform))) = attribute.args().first() {
+                    let build: u32 = platform.strip_prefix("windows10.0.")?.parse().ok()?;
+
+                    if build > 10240 {
+                        return Some(format!("win10_{}", build));
+                    }
+                }
+            }
+
+            None
+        })
+    }
+
+    /// Returns the name of the feature gating the function when `function_features` is set.
+    pub fn function_feature(&self) -> String {
+        format!(
+            "{}_{}",
+            self.parent().namespace().replace('.', "_"),
+            self.name()
+        )
+    }
+
+    /// Returns the IRQL requirements of a kernel-mode function, given by the `Irql` attributes of
+    /// the WDK metadata, as lines of documentation since the compiler can't check them.
+    pub fn irql_docs(&self) -> Vec<String> {
+        self.attributes()
+            .filter_map(|attribute| {
+                let level = attribute.args().first().map(|(_, value)| irql_level(value));
+                irql_doc(attribute.name(), level.as_deref())
+            })
+            .collect()
     }
 
     pub fn kind(&self) -> MethodKind {
@@ -112,6 +204,12 @@ impl MethodDef {
         self.has_attribute("DeprecatedAttribute")
     }
 
+    /// Returns whether the method, or the interface or class declaring it, is a preview API
+    /// marked with the `Experimental` attribute.
+    pub fn is_experimental(&self) -> bool {
+        self.has_attribute("ExperimentalAttribute") || self.parent().is_experimental()
+    }
+
     pub fn impl_map(&self) -> Option<ImplMap> {
         self.0
             .file
@@ -164,6 +262,43 @@ impl MethodDef {
     }
 }
 
+fn irql_doc(attribute: &str, level: Option<&str>) -> Option<String> {
+    let doc = match (attribute, level) {
+        ("IrqlRequiresMaxAttribute", Some(level)) => {
+            format!("Must be called at IRQL <= `{}`.", level)
+        }
+        ("IrqlRequiresMinAttribute", Some(level)) => {
+            format!("Must be called at IRQL >= `{}`.", level)
+        }
+        ("IrqlRequiresAttribute", Some(level)) => format!("Must be called at IRQL `{}`.", level),
+        ("IrqlRaisesAttribute", Some(level)) => format!("Raises the IRQL to `{}`.", level),
+        ("IrqlRequiresSameAttribute", _) => "Returns at the IRQL it was called at.".to_string(),
+        ("IrqlSavesAttribute", _) => "Saves the current IRQL.".to_string(),
+        ("IrqlRestoresAttribute", _) => "Restores a previously saved IRQL.".to_string(),
+        _ => return None,
+    };
+
+    Some(doc)
+}
+
+// The levels are named as they are by the WDK headers, which is how the metadata names most of them.
+fn irql_level(value: &ConstantValue) -> String {
+    let level = match value {
+        ConstantValue::String(level) => return level.clone(),
+        ConstantValue::U8(level) => *level as u32,
+        ConstantValue::U32(level) => *level,
+        ConstantValue::I32(level) => *level as u32,
+        _ => unexpected!(),
+    };
+
+    match level {
+        0 => "PASSIVE_LEVEL".to_string(),
+        1 => "APC_LEVEL".to_string(),
+        2 => "DISPATCH_LEVEL".to_string(),
+        _ => level.to_string(),
+    }
+}
+
 impl std::fmt::Debug for MethodDef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.name())
@@ -220,4 +355,39 @@ mod tests {
         assert_eq!(p.signature.is_const, false);
         assert_eq!(p.signature.is_array, false);
     }
+
+    #[test]
+    fn test_irql_docs() {
+        assert_eq!(
+            irql_doc(
+                "IrqlRequiresMaxAttribute",
+                Some(&irql_level(&ConstantValue::U8(2)))
+            )
+            .unwrap(),
+            "Must be called at IRQL <= `DISPATCH_LEVEL`."
+        );
+        assert_eq!(
+            irql_doc(
+                "IrqlRaisesAttribute",
+                Some(&irql_level(&ConstantValue::String(
+                    "HIGH_LEVEL".to_string()
+                )))
+            )
+            .unwrap(),
+            "Raises the IRQL to `HIGH_LEVEL`."
+        );
+        assert_eq!(
+            irql_doc("IrqlSavesAttribute", None).unwrap(),
+            "Saves the current IRQL."
+        );
+        assert!(irql_doc("SupportedOSPlatformAttribute", None).is_none());
+
+        // None of the Win32 functions carry IRQL requirements.
+        let function =
+            match TypeReader::get().resolve_type_row("Windows.Win32.Foundation", "CloseHandle") {
+                TypeRow::MethodDef(def) => def,
+                _ => unexpected!(),
+            };
+        assert!(function.irql_docs().is_empty());
+    }
 }
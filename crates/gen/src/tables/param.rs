@@ -46,6 +46,10 @@ impl Param {
     pub fn gen_abi_size_name(&self) -> Ident {
         to_ident(&format!("{}_array_size", self.name()))
     }
+
+    pub fn gen_receive_array_name(&self) -> Ident {
+        to_ident(&format!("{}_array__", self.name().to_lowercase()))
+    }
 }
 
 impl std::fmt::Debug for Param {
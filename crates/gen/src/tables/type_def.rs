@@ -99,6 +99,10 @@ impl TypeDef {
         self.format_name(gen, to_abi_ident, false)
     }
 
+    pub fn gen_trait_name(&self, gen: &Gen) -> TokenStream {
+        self.format_name(gen, to_trait_ident, false)
+    }
+
     pub fn gen_turbo_abi_name(&self, gen: &Gen) -> TokenStream {
         self.format_name(gen, to_abi_ident, true)
     }
@@ -200,13 +204,61 @@ impl TypeDef {
             return false;
         }
 
-        if self.class_layout().is_some() {
+        if self
+            .arch_definitions()
+            .iter()
+            .any(|def| def.packing().is_some())
+        {
             return true;
         }
 
         self.fields().any(|field| field.signature().is_packed())
     }
 
+    /// The packing of the type's fields, if the metadata records one.
+    pub fn packing(&self) -> Option<u32> {
+        self.class_layout()
+            .map(|layout| layout.packing_size())
+            .filter(|packing| *packing != 0)
+    }
+
+    /// The architectures the type is defined for, as a mask of the metadata's `Architecture`
+    /// values, if it's defined once for each of the architectures it differs on. Nested types
+    /// are defined for the architectures of their enclosing type.
+    pub fn architectures(&self) -> Option<i32> {
+        // The `Architecture` enum isn't defined by the metadata itself so the attribute's
+        // argument is read directly rather than with `Attribute::args`.
+        if let Some(attribute) = self
+            .attributes()
+            .find(|attribute| attribute.name() == "SupportedArchitectureAttribute")
+        {
+            let mut values = attribute.0.blob(2);
+            values.read_u16();
+            return Some(values.read_i32());
+        }
+
+        self.enclosing_type()
+            .and_then(|enclosing_type| enclosing_type.architectures())
+    }
+
+    /// Every definition of the type, including this one, if it's defined once for each of the
+    /// architectures it differs on. The definitions of a nested type are those nested within
+    /// the definitions of its enclosing type.
+    pub fn arch_definitions(&self) -> Vec<Self> {
+        if let Some(enclosing_type) = self.enclosing_type() {
+            return enclosing_type
+                .arch_definitions()
+                .iter()
+                .filter_map(|def| def.nested_types()?.get(self.name()).cloned())
+                .collect();
+        }
+
+        match TypeReader::get().arch_definitions(self) {
+            Some(definitions) => definitions.to_vec(),
+            None => vec![self.clone()],
+        }
+    }
+
     pub fn is_handle(&self) -> bool {
         self.has_attribute("NativeTypedefAttribute")
     }
@@ -239,7 +291,8 @@ impl TypeDef {
             }
             TypeKind::Class => {
                 let class = types::Class(self.clone());
-                if include == TypeInclude::Minimal {
+                if include == TypeInclude::Minimal || TypeReader::get().options.excludes_class(self)
+                {
                     if let Some(default_interface) = class
                         .interfaces()
                         .iter()
@@ -276,12 +329,40 @@ impl TypeDef {
                     None
                 });
 
-                generics
+                let mut dependencies: Vec<TypeEntry> = generics
                     .chain(interfaces)
                     .chain(bases)
                     .flatten()
                     .chain(factories)
-                    .collect()
+                    .collect();
+
+                // The `XamlIsland` helper generated alongside `DesktopWindowXamlSource` needs
+                // the Win32 interop interfaces and the `WindowsXamlManager`.
+                if self.full_name() == ("Windows.UI.Xaml.Hosting", "DesktopWindowXamlSource") {
+                    let reader = TypeReader::get();
+
+                    dependencies.push(TypeEntry {
+                        include: TypeInclude::Full,
+                        def: reader.resolve_type_row(
+                            "Windows.Win32.System.WinRT",
+                            "IDesktopWindowXamlSourceNative2",
+                        ),
+                    });
+                    dependencies.push(TypeEntry {
+                        include: TypeInclude::Full,
+                        def: reader
+                            .resolve_type_row("Windows.UI.Xaml.Hosting", "WindowsXamlManager"),
+                    });
+                    dependencies.push(TypeEntry {
+                        include: TypeInclude::Minimal,
+                        def: reader.resolve_type_row(
+                            "Windows.Win32.UI.WindowsAndMessaging",
+                            "SetWindowPos",
+                        ),
+                    });
+                }
+
+                dependencies
             }
             TypeKind::Enum => Vec::new(),
             TypeKind::Struct => {
@@ -306,6 +387,14 @@ impl TypeDef {
                                 .resolve_type_row("Windows.Win32.Foundation", "SysStringLen"),
                         });
                     }
+                    ("Windows.Foundation", "Rect") => {
+                        for name in &["Point", "Size"] {
+                            dependencies.push(TypeEntry {
+                                include: TypeInclude::Minimal,
+                                def: reader.resolve_type_row("Windows.Foundation", name),
+                            });
+                        }
+                    }
                     ("Windows.Foundation.Numerics", "Matrix3x2") => {
                         dependencies.push(TypeEntry {
                             include: TypeInclude::Minimal,
@@ -427,38 +516,24 @@ impl TypeDef {
             return quote! { ::windows::ConstBuffer::from_slice(#signature) };
         }
 
-        let generics = self.generics.iter().enumerate().map(|(index, g)| {
-            let g = g.gen_name(&Gen::Absolute);
-            let semi = if index != self.generics.len() - 1 {
-                Some(quote! {
-                    .push_slice(b";")
-                })
-            } else {
-                None
-            };
+        // The signatures of generic types are computed by the runtime so that hand-written
+        // interop code computes the IIDs of their specializations identically.
+        let guid = self.guid().gen();
 
-            quote! {
-                .push_other(<#g as ::windows::RuntimeType>::SIGNATURE)
-                #semi
-            }
+        let generics = self.generics.iter().map(|g| {
+            let g = g.gen_name(&Gen::Absolute);
+            quote! { <#g as ::windows::RuntimeType>::SIGNATURE }
         });
 
         quote! {
-            {
-                ::windows::ConstBuffer::new()
-                .push_slice(b"pinterface(")
-                .push_slice(#signature)
-                .push_slice(b";")
-                #(#generics)*
-                .push_slice(b")")
-            }
+            ::windows::pinterface_signature(&::windows::Guid::from_values(#guid), &[#(#generics),*])
         }
     }
 
     pub fn gen_phantoms<'a>(&'a self) -> impl Iterator<Item = TokenStream> + 'a {
         self.generics.iter().map(move |g| {
             let g = g.gen_name(&Gen::Absolute);
-            quote! { ::std::marker::PhantomData::<#g> }
+            quote! { ::core::marker::PhantomData::<#g> }
         })
     }
 
@@ -528,6 +603,17 @@ impl TypeDef {
         self.row.list(5, TableIndex::MethodDef).map(MethodDef)
     }
 
+    /// Returns the generated name of the method with the given metadata name, such as
+    /// `get_Current`, for code that calls the method and so must follow the naming options.
+    pub fn gen_method_name(&self, name: &str) -> Ident {
+        let method = self
+            .methods()
+            .find(|method| method.name() == name)
+            .expect("Method not found");
+
+        to_ident(&method.rust_name())
+    }
+
     pub fn generic_params(&self) -> impl Iterator<Item = GenericParam> {
         self.row
             .file
@@ -565,6 +651,10 @@ impl TypeDef {
         self.attributes().any(|attribute| attribute.name() == name)
     }
 
+    pub fn is_experimental(&self) -> bool {
+        self.has_attribute("ExperimentalAttribute")
+    }
+
     pub fn is_winrt(&self) -> bool {
         self.flags().windows_runtime()
     }
@@ -577,7 +667,20 @@ impl TypeDef {
         self.is_winrt() || self.has_attribute("ScopedEnumAttribute")
     }
 
+    /// Returns whether the objects of a class may be used from any thread.
+    ///
+    /// XAML objects belong to the thread that created them and fail with `RPC_E_WRONG_THREAD` when
+    /// used from another thread, even though the metadata describes most XAML classes as agile.
     pub fn is_agile(&self) -> bool {
+        let namespace = self.namespace();
+
+        if ["Windows.UI.Xaml", "Microsoft.UI.Xaml"].iter().any(|xaml| {
+            namespace == *xaml
+                || (namespace.starts_with(xaml) && namespace[xaml.len()..].starts_with('.'))
+        }) {
+            return false;
+        }
+
         self.attributes().any(|attribute| {
             if attribute.name() == "MarshalingBehaviorAttribute" {
                 if let Some((_, ConstantValue::I32(2))) = attribute.args().get(0) {
@@ -589,6 +692,42 @@ impl TypeDef {
         })
     }
 
+    /// Returns the class that an interface is exclusive to, if any.
+    pub fn exclusive_to(&self) -> Option<TypeDef> {
+        self.attributes().find_map(|attribute| {
+            if attribute.name() == "ExclusiveToAttribute" {
+                if let Some((_, ConstantValue::TypeDef(class))) = attribute.args().first() {
+                    return Some(class.clone());
+                }
+            }
+
+            None
+        })
+    }
+
+    /// Returns whether an interface is only implemented by the objects of an agile class, in which
+    /// case any reference to the interface may be used from any thread. The factory interfaces of
+    /// such a class are excluded since the class's factory need not be agile.
+    pub fn is_exclusive_to_agile(&self) -> bool {
+        match self.exclusive_to() {
+            Some(class) => {
+                class.is_agile() && class.interfaces().any(|interface| interface == *self)
+            }
+            None => false,
+        }
+    }
+
+    /// Returns whether an interface is an overridable interface of the class it is exclusive to,
+    /// in which case it is also implemented by objects deriving from the class.
+    pub fn is_overridable(&self) -> bool {
+        match self.exclusive_to() {
+            Some(class) => class.interface_impls().any(|interface| {
+                interface.is_overridable() && interface.interface().resolve() == *self
+            }),
+            None => false,
+        }
+    }
+
     pub fn is_convertible_to(&self) -> Option<TypeDef> {
         self.attributes().find_map(|attribute| {
             if attribute.name() == "AlsoUsableForAttribute" {
@@ -657,6 +796,30 @@ impl TypeDef {
         (0, 0)
     }
 
+    /// Returns the API contract that introduced the type along with the contract's major
+    /// version, such as `Windows.Foundation.UniversalApiContract` and 8.
+    pub fn contract(&self) -> Option<(String, u16)> {
+        self.attributes().find_map(|attribute| {
+            if attribute.name() != "ContractVersionAttribute" {
+                return None;
+            }
+
+            // The overload taking only a version describes the contract itself.
+            match attribute.args().as_slice() {
+                [(_, ConstantValue::TypeDef(contract)), (_, ConstantValue::U32(version))] => {
+                    Some((
+                        format!("{}.{}", contract.namespace(), contract.name()),
+                        (version >> 16) as u16,
+                    ))
+                }
+                [(_, ConstantValue::String(contract)), (_, ConstantValue::U32(version))] => {
+                    Some((contract.clone(), (version >> 16) as u16))
+                }
+                _ => None,
+            }
+        })
+    }
+
     pub fn guid(&self) -> Guid {
         Guid::from_attributes(self.attributes()).expect("TypeDef::guid")
     }
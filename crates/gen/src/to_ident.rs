@@ -11,10 +11,77 @@ pub fn to_ident(name: &str) -> Ident {
         | "try" | "async" | "await" | "dyn" => format_ident!("r#{}", name),
         "Self" | "self" => format_ident!("{}_", name),
         "_" => format_ident!("unused"),
-        _ => format_ident!("{}", name),
+        _ => Ident::new(intern(name).as_str()),
     }
 }
 
 pub fn to_abi_ident(name: &str) -> Ident {
-    format_ident!("{}_abi", name)
+    Ident::new(intern(&format!("{}_abi", name)).as_str())
+}
+
+pub fn to_trait_ident(name: &str) -> Ident {
+    Ident::new(intern(&format!("{}Trait", name)).as_str())
+}
+
+/// Generates a `#[doc(alias)]` attribute carrying the metadata name of an item generated as
+/// `ident`, so that the item may be found by the name used in the Windows documentation. Items
+/// that keep their metadata name need no alias, and rustdoc rejects one.
+pub fn gen_doc_alias(ident: &Ident, name: &str) -> TokenStream {
+    let generated = ident.as_str();
+
+    if generated.trim_start_matches("r#") == name {
+        TokenStream::new()
+    } else {
+        quote! { #[doc(alias = #name)] }
+    }
+}
+
+/// Converts a `PascalCase` name, such as a WinRT property name, to `snake_case`.
+pub fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::with_capacity(name.len() + 4);
+
+    for (index, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_is_lower = matches!(chars.get(index + 1), Some(next) if next.is_lowercase());
+
+            // Acronyms stay together, so `HttpURIValue` becomes `http_uri_value`.
+            if previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next_is_lower)
+            {
+                result.push('_');
+            }
+        }
+
+        result.extend(c.to_lowercase());
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(to_snake_case("Text"), "text");
+        assert_eq!(to_snake_case("IsEnabled"), "is_enabled");
+        assert_eq!(to_snake_case("HttpURIValue"), "http_uri_value");
+        assert_eq!(to_snake_case("URI"), "uri");
+        assert_eq!(to_snake_case("Mp3Codec"), "mp3_codec");
+    }
+
+    #[test]
+    fn test_doc_alias() {
+        assert_eq!(gen_doc_alias(&to_ident("Text"), "Text").as_str(), "");
+        assert_eq!(gen_doc_alias(&to_ident("type"), "type").as_str(), "");
+        let name = "get_Text";
+        assert_eq!(
+            gen_doc_alias(&to_ident("Text"), name).as_str(),
+            quote! { #[doc(alias = #name)] }.as_str()
+        );
+    }
 }
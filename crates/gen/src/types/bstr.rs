@@ -3,14 +3,14 @@ use super::*;
 pub fn gen_bstr() -> TokenStream {
     quote! {
         #[repr(transparent)]
-        #[derive(::std::cmp::Eq)]
+        #[derive(::core::cmp::Eq)]
         pub struct BSTR(*mut u16);
         impl BSTR {
             /// Create an empty `BSTR`.
             ///
             /// This function does not allocate memory.
             pub fn new() -> Self {
-                Self(std::ptr::null_mut())
+                Self(::core::ptr::null_mut())
             }
 
             /// Returns `true` if the string is empty.
@@ -30,7 +30,7 @@ pub fn gen_bstr() -> TokenStream {
             /// Create a `BSTR` from a slice of 16-bit characters.
             pub fn from_wide(value: &[u16]) -> Self {
                 if value.len() == 0 {
-                    return Self(::std::ptr::null_mut());
+                    return Self(::core::ptr::null_mut());
                 }
 
                 unsafe {
@@ -47,93 +47,104 @@ pub fn gen_bstr() -> TokenStream {
                     return &[];
                 }
 
-                unsafe { ::std::slice::from_raw_parts(self.0 as *const u16, self.len()) }
+                unsafe { ::core::slice::from_raw_parts(self.0 as *const u16, self.len()) }
             }
         }
-        impl ::std::clone::Clone for BSTR {
+        impl ::core::clone::Clone for BSTR {
             fn clone(&self) -> Self {
                 Self::from_wide(self.as_wide())
             }
         }
-        impl ::std::convert::From<&str> for BSTR {
+        impl ::core::convert::From<&str> for BSTR {
             fn from(value: &str) -> Self {
-                let value: ::std::vec::Vec<u16> = value.encode_utf16().collect();
+                let value: ::windows::alloc::vec::Vec<u16> = value.encode_utf16().collect();
                 Self::from_wide(&value)
             }
         }
 
-        impl ::std::convert::From<::std::string::String> for BSTR {
-            fn from(value: ::std::string::String) -> Self {
+        impl ::core::convert::From<::windows::alloc::string::String> for BSTR {
+            fn from(value: ::windows::alloc::string::String) -> Self {
                 value.as_str().into()
             }
         }
 
-        impl  ::std::convert::From<&::std::string::String> for BSTR {
-            fn from(value: &::std::string::String) -> Self {
+        impl  ::core::convert::From<&::windows::alloc::string::String> for BSTR {
+            fn from(value: &::windows::alloc::string::String) -> Self {
                 value.as_str().into()
             }
         }
-        impl<'a> ::std::convert::TryFrom<&'a BSTR> for ::std::string::String {
-            type Error = ::std::string::FromUtf16Error;
+        impl<'a> ::core::convert::TryFrom<&'a BSTR> for ::windows::alloc::string::String {
+            type Error = ::windows::alloc::string::FromUtf16Error;
 
-            fn try_from(value: &BSTR) -> ::std::result::Result<Self, Self::Error> {
-                ::std::string::String::from_utf16(value.as_wide())
+            fn try_from(value: &BSTR) -> ::core::result::Result<Self, Self::Error> {
+                ::windows::alloc::string::String::from_utf16(value.as_wide())
             }
         }
 
-        impl ::std::convert::TryFrom<BSTR> for ::std::string::String {
-            type Error = ::std::string::FromUtf16Error;
+        impl ::core::convert::TryFrom<BSTR> for ::windows::alloc::string::String {
+            type Error = ::windows::alloc::string::FromUtf16Error;
 
-            fn try_from(value: BSTR) -> ::std::result::Result<Self, Self::Error> {
-                ::std::string::String::try_from(&value)
+            fn try_from(value: BSTR) -> ::core::result::Result<Self, Self::Error> {
+                ::windows::alloc::string::String::try_from(&value)
             }
         }
-        impl ::std::default::Default for BSTR {
+        impl ::core::default::Default for BSTR {
             fn default() -> Self {
-                Self(::std::ptr::null_mut())
+                Self(::core::ptr::null_mut())
             }
         }
-        impl ::std::fmt::Display for BSTR {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                use ::std::fmt::Write;
-                for c in ::std::char::decode_utf16(self.as_wide().iter().cloned()) {
-                    f.write_char(c.map_err(|_| ::std::fmt::Error)?)?
+        impl ::core::fmt::Display for BSTR {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                use ::core::fmt::Write;
+                // Unpaired surrogates are displayed as the replacement character, as by `String::from_utf16_lossy`.
+                for c in ::core::char::decode_utf16(self.as_wide().iter().cloned()) {
+                    f.write_char(c.unwrap_or(::core::char::REPLACEMENT_CHARACTER))?
                 }
                 Ok(())
             }
         }
-        impl ::std::fmt::Debug for BSTR {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                ::std::write!(f, "{}", self)
+        impl ::core::fmt::Debug for BSTR {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, "{}", self)
             }
         }
-        impl ::std::cmp::PartialEq for BSTR {
+        impl ::core::cmp::PartialEq for BSTR {
             fn eq(&self, other: &Self) -> bool {
                 self.as_wide() == other.as_wide()
             }
         }
-        impl ::std::cmp::PartialEq<::std::string::String> for BSTR {
-            fn eq(&self, other: &::std::string::String) -> bool {
+        impl ::core::cmp::PartialEq<::windows::alloc::string::String> for BSTR {
+            fn eq(&self, other: &::windows::alloc::string::String) -> bool {
                 self == other.as_str()
             }
         }
-        impl ::std::cmp::PartialEq<str> for BSTR {
+        impl ::core::cmp::PartialEq<str> for BSTR {
             fn eq(&self, other: &str) -> bool {
-                self == other
+                self.as_wide().iter().copied().eq(other.encode_utf16())
             }
         }
-        impl ::std::cmp::PartialEq<&str> for BSTR {
+        impl ::core::cmp::PartialEq<&str> for BSTR {
             fn eq(&self, other: &&str) -> bool {
-                self.as_wide().iter().copied().eq(other.encode_utf16())
+                self == *other
+            }
+        }
+        impl ::core::cmp::PartialEq<BSTR> for str {
+            fn eq(&self, other: &BSTR) -> bool {
+                other == self
+            }
+        }
+        impl ::core::cmp::PartialEq<BSTR> for ::windows::alloc::string::String {
+            fn eq(&self, other: &BSTR) -> bool {
+                other == self
             }
         }
 
-        impl ::std::cmp::PartialEq<BSTR> for &str {
+        impl ::core::cmp::PartialEq<BSTR> for &str {
             fn eq(&self, other: &BSTR) -> bool {
                 other == self
             }
         }
-        impl ::std::ops::Drop for BSTR {
+        impl ::core::ops::Drop for BSTR {
             fn drop(&mut self) {
                 if !self.0.is_null() {
                     unsafe { SysFreeString(self as &Self) }
@@ -148,6 +159,9 @@ pub fn gen_bstr() -> TokenStream {
                 &mut self.0 as *mut _ as _
             }
         }
+        unsafe impl ::windows::SafeArrayElement for BSTR {
+            const VARTYPE: u16 = 8;
+        }
         pub type BSTR_abi = *mut u16;
     }
 }
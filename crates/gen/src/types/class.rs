@@ -97,10 +97,17 @@ impl Class {
         let name = self.0.gen_name(gen);
         let interfaces = self.interfaces();
 
-        if include == TypeInclude::Full {
+        if include == TypeInclude::Full && !TypeReader::get().options.excludes_class(&self.0) {
             let methods = InterfaceInfo::gen_methods(&interfaces, gen);
             let runtime_name = format!("{}.{}", self.0.namespace(), self.0.name());
 
+            // Light-up code may check for a class introduced by a later version of Windows.
+            let is_present = quote! {
+                pub fn is_present() -> bool {
+                    ::windows::is_type_present(#runtime_name)
+                }
+            };
+
             let factories = interfaces.iter().filter_map(|interface| {
                 match interface.kind {
                     InterfaceKind::Static | InterfaceKind::Composable => {
@@ -157,28 +164,76 @@ impl Class {
 
                 let send_sync = if self.0.is_agile() {
                     quote! {
-                        unsafe impl ::std::marker::Send for #name {}
-                        unsafe impl ::std::marker::Sync for #name {}
+                        unsafe impl ::core::marker::Send for #name {}
+                        unsafe impl ::core::marker::Sync for #name {}
                     }
                 } else {
                     TokenStream::new()
                 };
 
+                // Classes that can describe themselves as strings, such as `Uri`, may be displayed,
+                // as may other classes by their runtime class name.
+                let display = interfaces
+                    .iter()
+                    .filter(|_| TypeReader::get().options.codegen_version(2))
+                    .find(|interface| {
+                        interface.def.full_name() == ("Windows.Foundation", "IStringable")
+                    })
+                    .map(|interface| {
+                        let stringable = interface.def.gen_name(gen);
+                        let to_string = interface.def.gen_method_name("ToString");
+
+                        quote! {
+                            impl ::core::fmt::Display for #name {
+                                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                    let value = ::core::convert::Into::<#stringable>::into(self).#to_string().unwrap_or_default();
+                                    ::core::write!(f, "{}", value)
+                                }
+                            }
+                        }
+                    })
+                    .or_else(|| {
+                        if TypeReader::get().options.codegen_version(3) {
+                            Some(quote! {
+                                impl ::core::fmt::Display for #name {
+                                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                        ::core::fmt::Display::fmt(&self.0, f)
+                                    }
+                                }
+                            })
+                        } else {
+                            None
+                        }
+                    })
+                    .unwrap_or_default();
+
+                // Classes may be downgraded without importing the `Interface` trait.
+                let downgrade = quote! {
+                    pub fn downgrade(&self) -> ::windows::Result<::windows::Weak<Self>> {
+                        ::windows::Interface::downgrade(self)
+                    }
+                };
+
+                let extensions = self.gen_extensions();
+                let builders = InterfaceInfo::gen_builders(&interfaces, gen);
                 let bases = self.gen_base_conversions(&name, gen);
                 let iterator = gen_iterator(&self.0, &interfaces, gen);
 
                 quote! {
                     #[repr(transparent)]
-                    #[derive(::std::cmp::PartialEq, ::std::cmp::Eq, ::std::clone::Clone, ::std::fmt::Debug)]
+                    #[derive(::core::cmp::PartialEq, ::core::cmp::Eq, ::core::clone::Clone, ::core::fmt::Debug)]
                     pub struct #name(::windows::IInspectable);
                     impl #name {
                         #new
+                        #is_present
+                        #downgrade
                         #methods
+                        #builders
                         #async_get
                         #(#factories)*
                     }
                     unsafe impl ::windows::RuntimeType for #name {
-                        type DefaultType = ::std::option::Option<Self>;
+                        type DefaultType = ::core::option::Option<Self>;
                         const SIGNATURE: ::windows::ConstBuffer = ::windows::ConstBuffer::from_slice(#type_signature);
                     }
                     unsafe impl ::windows::Interface for #name {
@@ -194,11 +249,14 @@ impl Class {
                     #(#bases)*
                     #send_sync
                     #iterator
+                    #display
+                    #extensions
                 }
             } else {
                 quote! {
                     pub struct #name {}
                     impl #name {
+                        #is_present
                         #methods
                         #(#factories)*
                     }
@@ -208,17 +266,19 @@ impl Class {
                 }
             }
         } else {
-            let default_interface = interfaces
-                .iter()
-                .find(|i| i.kind == InterfaceKind::Default)
-                .unwrap();
+            // A static class that is excluded has no minimal form.
+            let default_interface =
+                match interfaces.iter().find(|i| i.kind == InterfaceKind::Default) {
+                    Some(default_interface) => default_interface,
+                    None => return TokenStream::new(),
+                };
 
             let guid = default_interface.def.gen_guid(gen);
             let type_signature = Literal::byte_string(self.0.type_signature().as_bytes());
 
             quote! {
                 #[repr(transparent)]
-                #[derive(::std::cmp::PartialEq, ::std::cmp::Eq, ::std::clone::Clone, ::std::fmt::Debug)]
+                #[derive(::core::cmp::PartialEq, ::core::cmp::Eq, ::core::clone::Clone, ::core::fmt::Debug)]
                 #[doc(hidden)]
                 pub struct #name(::windows::IInspectable);
                 unsafe impl ::windows::Interface for #name {
@@ -226,13 +286,20 @@ impl Class {
                     const IID: ::windows::Guid = #guid;
                 }
                 unsafe impl ::windows::RuntimeType for #name {
-                    type DefaultType = ::std::option::Option<Self>;
+                    type DefaultType = ::core::option::Option<Self>;
                     const SIGNATURE: ::windows::ConstBuffer = ::windows::ConstBuffer::from_slice(#type_signature);
                 }
             }
         }
     }
 
+    fn gen_extensions(&self) -> TokenStream {
+        match self.0.full_name() {
+            ("Windows.UI.Xaml.Hosting", "DesktopWindowXamlSource") => gen_xaml_island(),
+            _ => TokenStream::new(),
+        }
+    }
+
     fn gen_base_conversions<'a>(
         &'a self,
         from: &'a TokenStream,
@@ -240,28 +307,32 @@ impl Class {
     ) -> impl Iterator<Item = TokenStream> + 'a {
         self.0.bases().map(move |base| {
             let into = base.gen_name(gen);
+            let optional = gen_optional_param(from, &into, &TokenStream::new());
 
             quote! {
-                impl ::std::convert::From<#from> for #into {
+                impl ::core::convert::From<#from> for #into {
                     fn from(value: #from) -> Self {
-                        ::std::convert::Into::<#into>::into(&value)
+                        ::core::convert::Into::<#into>::into(&value)
                     }
                 }
-                impl ::std::convert::From<&#from> for #into {
+                impl ::core::convert::From<&#from> for #into {
                     fn from(value: &#from) -> Self {
                         ::windows::Interface::cast(value).unwrap()
                     }
                 }
                 impl<'a> ::windows::IntoParam<'a, #into> for #from {
+                    type Param = ::windows::Param<'a, #into>;
                     fn into_param(self) -> ::windows::Param<'a, #into> {
-                        ::windows::Param::Owned(::std::convert::Into::<#into>::into(self))
+                        ::windows::Param::Owned(::core::convert::Into::<#into>::into(self))
                     }
                 }
                 impl<'a> ::windows::IntoParam<'a, #into> for &'a #from {
+                    type Param = ::windows::Param<'a, #into>;
                     fn into_param(self) -> ::windows::Param<'a, #into> {
-                        ::windows::Param::Owned(::std::convert::Into::<#into>::into(::std::clone::Clone::clone(self)))
+                        ::windows::Param::Owned(::core::convert::Into::<#into>::into(::core::clone::Clone::clone(self)))
                     }
                 }
+                #optional
             }
         })
     }
@@ -96,24 +96,26 @@ impl ComInterface {
             let mut conversions = TokenStream::new();
 
             conversions.combine(&quote! {
-                    impl ::std::convert::From<#name> for ::windows::IUnknown {
+                    impl ::core::convert::From<#name> for ::windows::IUnknown {
                         fn from(value: #name) -> Self {
-                            unsafe { ::std::mem::transmute(value) }
+                            unsafe { ::core::mem::transmute(value) }
                         }
                     }
-                    impl ::std::convert::From<&#name> for ::windows::IUnknown {
+                    impl ::core::convert::From<&#name> for ::windows::IUnknown {
                         fn from(value: &#name) -> Self {
-                            ::std::convert::From::from(::std::clone::Clone::clone(value))
+                            ::core::convert::From::from(::core::clone::Clone::clone(value))
                         }
                     }
                     impl<'a> ::windows::IntoParam<'a, ::windows::IUnknown> for #name {
+                        type Param = ::windows::Param<'a, ::windows::IUnknown>;
                         fn into_param(self) -> ::windows::Param<'a, ::windows::IUnknown> {
-                            ::windows::Param::Owned(::std::convert::Into::<::windows::IUnknown>::into(self))
+                            ::windows::Param::Owned(::core::convert::Into::<::windows::IUnknown>::into(self))
                         }
                     }
                     impl<'a> ::windows::IntoParam<'a, ::windows::IUnknown> for &'a #name {
+                        type Param = ::windows::Param<'a, ::windows::IUnknown>;
                         fn into_param(self) -> ::windows::Param<'a, ::windows::IUnknown> {
-                            ::windows::Param::Owned(::std::convert::Into::<::windows::IUnknown>::into(::std::clone::Clone::clone(self)))
+                            ::windows::Param::Owned(::core::convert::Into::<::windows::IUnknown>::into(::core::clone::Clone::clone(self)))
                         }
                     }
                 });
@@ -122,27 +124,31 @@ impl ComInterface {
                 let into = base.gen_name(gen);
 
                 conversions.combine(&quote! {
-                        impl ::std::convert::From<#name> for #into {
+                        impl ::core::convert::From<#name> for #into {
                             fn from(value: #name) -> Self {
-                                unsafe { ::std::mem::transmute(value) }
+                                unsafe { ::core::mem::transmute(value) }
                             }
                         }
-                        impl ::std::convert::From<&#name> for #into {
+                        impl ::core::convert::From<&#name> for #into {
                             fn from(value: &#name) -> Self {
-                                ::std::convert::From::from(::std::clone::Clone::clone(value))
+                                ::core::convert::From::from(::core::clone::Clone::clone(value))
                             }
                         }
                         impl<'a> ::windows::IntoParam<'a, #into> for #name {
+                            type Param = ::windows::Param<'a, #into>;
                             fn into_param(self) -> ::windows::Param<'a, #into> {
-                                ::windows::Param::Owned(::std::convert::Into::<#into>::into(self))
+                                ::windows::Param::Owned(::core::convert::Into::<#into>::into(self))
                             }
                         }
                         impl<'a> ::windows::IntoParam<'a, #into> for &'a #name {
+                            type Param = ::windows::Param<'a, #into>;
                             fn into_param(self) -> ::windows::Param<'a, #into> {
-                                ::windows::Param::Owned(::std::convert::Into::<#into>::into(::std::clone::Clone::clone(self)))
+                                ::windows::Param::Owned(::core::convert::Into::<#into>::into(::core::clone::Clone::clone(self)))
                             }
                         }
                     });
+
+                conversions.combine(&gen_optional_param(&name, &into, &TokenStream::new()));
             }
 
             let send_sync = if matches!(
@@ -150,8 +156,8 @@ impl ComInterface {
                 ("Windows.Win32.System.WinRT", "IRestrictedErrorInfo")
             ) {
                 quote! {
-                    unsafe impl ::std::marker::Send for #name {}
-                    unsafe impl ::std::marker::Sync for #name {}
+                    unsafe impl ::core::marker::Send for #name {}
+                    unsafe impl ::core::marker::Sync for #name {}
                 }
             } else {
                 quote! {}
@@ -169,7 +175,7 @@ impl ComInterface {
 
             quote! {
                 #[repr(transparent)]
-                #[derive(::std::cmp::PartialEq, ::std::cmp::Eq, ::std::clone::Clone, ::std::fmt::Debug)]
+                #[derive(::core::cmp::PartialEq, ::core::cmp::Eq, ::core::clone::Clone, ::core::fmt::Debug)]
                 pub struct #name(::windows::IUnknown);
                 impl #name {
                     #(#methods)*
@@ -193,7 +199,7 @@ impl ComInterface {
         } else {
             quote! {
                 #[repr(transparent)]
-                #[derive(::std::cmp::PartialEq, ::std::cmp::Eq, ::std::clone::Clone, ::std::fmt::Debug)]
+                #[derive(::core::cmp::PartialEq, ::core::cmp::Eq, ::core::clone::Clone, ::core::fmt::Debug)]
                 #[doc(hidden)]
                 pub struct #name(::windows::IUnknown);
                 unsafe impl ::windows::Interface for #name {
@@ -225,14 +231,17 @@ fn gen_method(
         to_ident(name)
     };
 
+    let alias = gen_doc_alias(&name, method.name());
+
     if signature.has_query_interface() {
         let leading_params = &signature.params[..signature.params.len() - 2];
         let params = signature.gen_win32_params(leading_params, gen);
         let args = leading_params.iter().map(|p| p.gen_win32_abi_arg());
 
         quote! {
+            #alias
             pub unsafe fn #name<#constraints T: ::windows::Interface>(&self, #params) -> ::windows::Result<T> {
-                let mut result__ = ::std::option::Option::None;
+                let mut result__ = ::core::option::Option::None;
                 (::windows::Interface::vtable(self).#vtable_offset)(::windows::Abi::abi(self), #(#args,)* &<T as ::windows::Interface>::IID, ::windows::Abi::set_abi(&mut result__)).and_some(result__)
             }
         }
@@ -250,8 +259,9 @@ fn gen_method(
             .gen_name(gen);
 
         quote! {
+            #alias
             pub unsafe fn #name<#constraints>(&self, #params) -> ::windows::Result<#return_type_tokens> {
-                let mut result__: <#return_type_tokens as ::windows::Abi>::Abi = ::std::mem::zeroed();
+                let mut result__: <#return_type_tokens as ::windows::Abi>::Abi = ::core::mem::zeroed();
                 (::windows::Interface::vtable(self).#vtable_offset)(::windows::Abi::abi(self), #(#args,)* &mut result__)
                 .from_abi::<#return_type_tokens>(result__ )
             }
@@ -262,8 +272,9 @@ fn gen_method(
         let return_type = signature.return_type.unwrap().kind.gen_abi_type(gen);
 
         quote! {
+            #alias
             pub unsafe fn #name<#constraints>(&self, #params) -> #return_type {
-                let mut result__: #return_type = ::std::default::Default::default();
+                let mut result__: #return_type = ::core::default::Default::default();
                 (::windows::Interface::vtable(self).#vtable_offset)(::windows::Abi::abi(self), #(#args,)* &mut result__);
                 result__
             }
@@ -274,6 +285,7 @@ fn gen_method(
 
         if return_type.kind == ElementType::HRESULT {
             quote! {
+                #alias
                 pub unsafe fn #name<#constraints>(&self, #params) -> ::windows::Result<()> {
                     (::windows::Interface::vtable(self).#vtable_offset)(::windows::Abi::abi(self), #(#args,)*).ok()
                 }
@@ -282,6 +294,7 @@ fn gen_method(
             let return_type = return_type.gen_win32_abi(gen);
 
             quote! {
+                #alias
                 pub unsafe fn #name<#constraints>(&self, #params) -> #return_type {
                     (::windows::Interface::vtable(self).#vtable_offset)(::windows::Abi::abi(self), #(#args,)*)
                 }
@@ -292,6 +305,7 @@ fn gen_method(
         let args = signature.params.iter().map(|p| p.gen_win32_abi_arg());
 
         quote! {
+            #alias
             pub unsafe fn #name<#constraints>(&self, #params) {
                 (::windows::Interface::vtable(self).#vtable_offset)(::windows::Abi::abi(self), #(#args,)*)
             }
@@ -25,20 +25,35 @@ impl Constant {
                     pub const #name: #kind = #kind(#value as _);
                 }
             }
-        } else {
-            match Guid::from_attributes(def.attributes()) {
-                Some(guid) => {
-                    let guid = guid.gen();
+        } else if let Some(guid) = Guid::from_attributes(def.attributes()) {
+            let guid = guid.gen();
 
-                    quote! {
-                        pub const #name: ::windows::Guid = ::windows::Guid::from_values(#guid);
-                    }
-                }
-                None => {
-                    // TODO: add support for https://github.com/microsoft/win32metadata/issues/339
-                    quote! {}
+            quote! {
+                pub const #name: ::windows::Guid = ::windows::Guid::from_values(#guid);
+            }
+        } else if let Some((guid, pid)) = Guid::property_key(def.attributes()) {
+            // Property keys, such as `PKEY_Title`, are structs like `PROPERTYKEY` and `DEVPROPKEY`
+            // holding the GUID of the property set followed by the property's identifier.
+            let kind = def.signature().gen_win32(gen);
+            let guid = guid.gen();
+            let pid = Literal::u32_unsuffixed(pid);
+
+            let (fmtid, pid_field) = match &def.signature().kind {
+                ElementType::TypeDef(key) => {
+                    let mut fields = key.fields().map(|field| to_ident(&field.rust_name()));
+                    (fields.next().expect("Field"), fields.next().expect("Field"))
                 }
+                _ => unexpected!(),
+            };
+
+            quote! {
+                pub const #name: #kind = #kind {
+                    #fmtid: ::windows::Guid::from_values(#guid),
+                    #pid_field: #pid,
+                };
             }
+        } else {
+            quote! {}
         }
     }
 }
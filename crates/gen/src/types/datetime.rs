@@ -0,0 +1,60 @@
+use super::*;
+
+pub fn gen_datetime() -> TokenStream {
+    let std_cfg = TypeReader::get().options.gen_std_cfg();
+
+    quote! {
+        impl DateTime {
+            // The ticks between the `DateTime` epoch of 1601-01-01 and the Unix epoch of 1970-01-01.
+            const UNIX_EPOCH: i64 = 116_444_736_000_000_000;
+        }
+        #std_cfg
+        impl ::core::convert::From<::std::time::SystemTime> for DateTime {
+            fn from(value: ::std::time::SystemTime) -> Self {
+                // A `DateTime` counts 100-nanosecond ticks, saturating for times beyond its range.
+                let ticks = match value.duration_since(::std::time::UNIX_EPOCH) {
+                    Ok(since) => (since.as_nanos() / 100) as i128,
+                    Err(error) => -((error.duration().as_nanos() / 100) as i128),
+                } + Self::UNIX_EPOCH as i128;
+
+                Self {
+                    UniversalTime: ticks.clamp(i64::MIN as i128, i64::MAX as i128) as i64,
+                }
+            }
+        }
+        #std_cfg
+        impl ::core::convert::TryFrom<DateTime> for ::std::time::SystemTime {
+            type Error = ::windows::Error;
+
+            fn try_from(value: DateTime) -> ::windows::Result<Self> {
+                let ticks = value.UniversalTime as i128 - DateTime::UNIX_EPOCH as i128;
+                let abs = ticks.abs() as u128;
+
+                let since = ::core::time::Duration::new(
+                    (abs / 10_000_000) as u64,
+                    (abs % 10_000_000 * 100) as u32,
+                );
+
+                let time = if ticks < 0 {
+                    ::std::time::UNIX_EPOCH.checked_sub(since)
+                } else {
+                    ::std::time::UNIX_EPOCH.checked_add(since)
+                };
+
+                time.ok_or_else(|| {
+                    ::windows::Error::new(
+                        ::windows::HRESULT::E_BOUNDS,
+                        "The date is outside of the range of `SystemTime`",
+                    )
+                })
+            }
+        }
+        #std_cfg
+        impl<'a> ::windows::IntoParam<'a, DateTime> for ::std::time::SystemTime {
+            type Param = ::windows::Param<'a, DateTime>;
+            fn into_param(self) -> ::windows::Param<'a, DateTime> {
+                ::windows::Param::Owned(self.into())
+            }
+        }
+    }
+}
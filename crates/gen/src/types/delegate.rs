@@ -19,6 +19,7 @@ impl Delegate {
 
         let method = MethodInfo {
             name: "Invoke".to_string(),
+            metadata_name: "Invoke",
             vtable_offset: 3,
             overload: 0,
             is_deprecated: false,
@@ -60,23 +61,24 @@ impl Delegate {
 
         quote! {
             #[repr(transparent)]
-            #[derive(::std::cmp::PartialEq, ::std::cmp::Eq, ::std::clone::Clone, ::std::fmt::Debug)]
+            #[derive(::core::cmp::PartialEq, ::core::cmp::Eq, ::core::clone::Clone, ::core::fmt::Debug)]
             pub struct #name(::windows::IUnknown, #(#struct_phantoms,)*) where #constraints;
             impl<#constraints> #name {
                 pub fn new<#fn_constraint>(invoke: F) -> Self {
                     let com = #box_name {
                         vtable: &#box_name::VTABLE,
                         count: ::windows::RefCount::new(1),
+                        marshaler: ::windows::FreeThreadedMarshaler::new(),
                         invoke,
                     };
                     unsafe {
-                        std::mem::transmute(::std::boxed::Box::new(com))
+                        ::core::mem::transmute(::windows::alloc::boxed::Box::new(com))
                     }
                 }
                 #invoke
             }
             unsafe impl<#constraints> ::windows::RuntimeType for #name {
-                type DefaultType = ::std::option::Option<Self>;
+                type DefaultType = ::core::option::Option<Self>;
                 const SIGNATURE: ::windows::ConstBuffer = #type_signature;
             }
             unsafe impl<#constraints> ::windows::Interface for #name {
@@ -97,6 +99,7 @@ impl Delegate {
                 vtable: *const #abi_name,
                 invoke: F,
                 count: ::windows::RefCount,
+                marshaler: ::windows::FreeThreadedMarshaler,
             }
             impl<#constraints #fn_constraint> #box_name {
                 const VTABLE: #abi_name = #turbo_abi_name(
@@ -114,13 +117,14 @@ impl Delegate {
                         iid == &<::windows::IAgileObject as ::windows::Interface>::IID {
                             &mut (*this).vtable as *mut _ as _
                         } else {
-                            ::std::ptr::null_mut()
+                            ::core::ptr::null_mut()
                         };
 
-                    // TODO: implement IMarshal
-
                     if (*interface).is_null() {
-                        ::windows::HRESULT(0x8000_4002) // E_NOINTERFACE
+                        // Delegates are agile, so the free-threaded marshaler lets an async
+                        // completion invoke them directly rather than marshaling the call back to
+                        // an STA that may be blocked waiting for that very completion.
+                        (*this).marshaler.query(this as _, iid, interface)
                     } else {
                         (*this).count.add_ref();
                         ::windows::HRESULT(0)
@@ -135,7 +139,7 @@ impl Delegate {
                     let remaining = (*this).count.release();
 
                     if remaining == 0 {
-                        Box::from_raw(this);
+                        ::windows::alloc::boxed::Box::from_raw(this);
                     }
 
                     remaining
@@ -4,6 +4,49 @@ use super::*;
 pub struct Function(pub tables::MethodDef);
 
 impl Function {
+    /// Returns the library that the function is linked with.
+    pub fn link(def: &tables::MethodDef) -> Link {
+        // The MinGW import libraries are named in lowercase and the GNU linker is case-sensitive
+        // when cross-compiling, while the MSVC linker ignores case.
+        let module = def
+            .impl_map()
+            .expect("Function")
+            .scope()
+            .name()
+            .to_lowercase();
+
+        let static_lib = def
+            .attributes()
+            .filter_map(|attribute| match attribute.name() {
+                "StaticLibraryAttribute" => Some(attribute.args()[0].1.unwrap_string().to_string()),
+                _ => None,
+            })
+            .next();
+
+        if let Some(name) = static_lib {
+            return Link::Static(name);
+        }
+
+        // The DLL is named directly so API sets need no umbrella library.
+        if TypeReader::get().options.raw_dylib {
+            return Link::RawDylib(module);
+        }
+
+        // The WDK metadata names the kernel-mode modules along with their extensions, such as
+        // `ntoskrnl.exe` and `ndis.sys`, while their import libraries are named without them.
+        let dll = import_library_name(&module).to_string();
+
+        // TODO: workaround for https://github.com/microsoft/windows-rs/issues/463
+        let msvc = if dll.contains("-ms-win-") || dll == "d3dcompiler_47" || dll == "sspicli" {
+            "onecoreuap".to_string()
+        } else {
+            dll.clone()
+        };
+
+        let gnu = gnu_link_name(&dll).to_string();
+        Link::Import { msvc, gnu }
+    }
+
     // TODO: move to MethodDef?
     pub fn gen(def: &tables::MethodDef, gen: &Gen) -> TokenStream {
         let name = def.gen_name(gen);
@@ -27,26 +70,46 @@ impl Function {
         };
 
         let args = signature.params.iter().map(|p| p.gen_win32_abi_arg());
-        let mut link = def.impl_map().expect("Function").scope().name();
+        let link_attr = Self::link(def).gen();
+        let extern_fn = Abi::new(def).gen(
+            &link_attr,
+            quote! { fn #name(#(#abi_params),*) #abi_return_type; },
+        );
 
-        // TODO: workaround for https://github.com/microsoft/windows-rs/issues/463
-        if link.contains("-ms-win-") || link == "D3DCOMPILER_47" || link == "SspiCli" {
-            link = "onecoreuap";
-        }
+        let api_counter = if TypeReader::get().options.api_stats {
+            let api_name = format!("{}.{}", def.parent().namespace(), def.name());
+            quote! {
+                #[cfg(feature = "api_stats")]
+                {
+                    static COUNTER: ::windows::ApiCounter = ::windows::ApiCounter::new(#api_name);
+                    COUNTER.increment();
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
 
-        let static_lib = def
-            .attributes()
-            .filter_map(|attribute| match attribute.name() {
-                "StaticLibraryAttribute" => Some(attribute.args()[0].1.gen_value()),
-                _ => None,
-            })
-            .next();
-        let link_attr = match static_lib {
-            Some(link) => quote! { #[link(name = #link, kind = "static")] },
-            None => quote! { #[link(name = #link)] },
+        let os_feature = if TypeReader::get().options.os_features {
+            def.os_feature()
+                .map(|feature| quote! { #[cfg(feature = #feature)] })
+                .unwrap_or_default()
+        } else {
+            TokenStream::new()
         };
 
-        if signature.has_query_interface() {
+        let function_feature = if TypeReader::get().options.function_features {
+            let feature = def.function_feature();
+            quote! { #[cfg(feature = #feature)] }
+        } else {
+            TokenStream::new()
+        };
+
+        let docs = def
+            .irql_docs()
+            .into_iter()
+            .map(|doc| quote! { #[doc = #doc] });
+
+        let function = if signature.has_query_interface() {
             let leading_params = &signature.params[..signature.params.len() - 2];
             let args = leading_params.iter().map(|p| p.gen_win32_abi_arg());
             let params = signature.gen_win32_params(leading_params, gen);
@@ -55,11 +118,9 @@ impl Function {
                 pub unsafe fn #name<#constraints T: ::windows::Interface>(#params) -> ::windows::Result<T> {
                     #[cfg(windows)]
                     {
-                        #link_attr
-                        extern "system" {
-                            fn #name(#(#abi_params),*) #abi_return_type;
-                        }
-                        let mut result__ = ::std::option::Option::None;
+                        #api_counter
+                        #extern_fn
+                        let mut result__ = ::core::option::Option::None;
                         #name(#(#args,)* &<T as ::windows::Interface>::IID, ::windows::Abi::set_abi(&mut result__)).and_some(result__)
                     }
                     #[cfg(not(windows))]
@@ -83,11 +144,9 @@ impl Function {
                 pub unsafe fn #name<#constraints>(#params) -> ::windows::Result<#return_type_tokens> {
                     #[cfg(windows)]
                     {
-                        #link_attr
-                        extern "system" {
-                            fn #name(#(#abi_params),*) #abi_return_type;
-                        }
-                        let mut result__: <#return_type_tokens as ::windows::Abi>::Abi = ::std::mem::zeroed();
+                        #api_counter
+                        #extern_fn
+                        let mut result__: <#return_type_tokens as ::windows::Abi>::Abi = ::core::mem::zeroed();
                         #name(#(#args,)* &mut result__).from_abi::<#return_type_tokens>(result__)
                     }
                     #[cfg(not(windows))]
@@ -100,10 +159,8 @@ impl Function {
                     pub unsafe fn #name<#constraints>(#params) -> ::windows::Result<()> {
                         #[cfg(windows)]
                         {
-                            #link_attr
-                            extern "system" {
-                                fn #name(#(#abi_params),*) -> ::windows::HRESULT;
-                            }
+                            #api_counter
+                            #extern_fn
                             #name(#(#args),*).ok()
                         }
                         #[cfg(not(windows))]
@@ -117,10 +174,8 @@ impl Function {
                     pub unsafe fn #name<#constraints>(#params) -> #return_type {
                         #[cfg(windows)]
                         {
-                            #link_attr
-                            extern "system" {
-                                fn #name(#(#abi_params),*) #abi_return_type;
-                            }
+                            #api_counter
+                            #extern_fn
                             #name(#(#args),*)
                         }
                         #[cfg(not(windows))]
@@ -133,16 +188,130 @@ impl Function {
                 pub unsafe fn #name<#constraints>(#params) {
                     #[cfg(windows)]
                     {
-                        #link_attr
-                        extern "system" {
-                            fn #name(#(#abi_params),*);
-                        }
+                        #api_counter
+                        #extern_fn
                         #name(#(#args),*)
                     }
                     #[cfg(not(windows))]
                     unimplemented!("Unsupported target OS");
                 }
             }
+        };
+
+        quote! {
+            #(#docs)*
+            #os_feature
+            #function_feature
+            #function
+        }
+    }
+}
+
+/// The calling convention of a Win32 function.
+enum Abi {
+    System,
+    Cdecl,
+    Fastcall,
+}
+
+impl Abi {
+    fn new(def: &tables::MethodDef) -> Self {
+        let flags = def.impl_map().expect("Function").flags();
+
+        if flags.cdecl() {
+            Self::Cdecl
+        } else if flags.fastcall() {
+            Self::Fastcall
+        } else {
+            Self::System
+        }
+    }
+
+    fn gen(&self, link_attr: &TokenStream, decl: TokenStream) -> TokenStream {
+        match self {
+            Self::System => quote! { #link_attr extern "system" { #decl } },
+            Self::Cdecl => quote! { #link_attr extern "C" { #decl } },
+            // Kernel-mode functions such as `KfRaiseIrql` use `__fastcall`, which only x86 tells
+            // apart from the platform's default calling convention.
+            Self::Fastcall => quote! {
+                #[cfg(target_arch = "x86")]
+                #link_attr
+                extern "fastcall" { #decl }
+                #[cfg(not(target_arch = "x86"))]
+                #link_attr
+                extern "system" { #decl }
+            },
+        }
+    }
+}
+
+// MinGW doesn't provide the `onecoreuap` umbrella library so the functions it exports are instead
+// linked from the import library of the DLL that implements them.
+/// The library that a Win32 function is linked with.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Link {
+    /// A static library named by the function's `StaticLibrary` attribute.
+    Static(String),
+    /// The DLL exporting the function, linked with `kind = "raw-dylib"`.
+    RawDylib(String),
+    /// The import libraries that the MSVC and GNU toolchains link with, which differ for the API
+    /// sets that MinGW has no import library for.
+    Import { msvc: String, gnu: String },
+}
+
+impl Link {
+    fn gen(&self) -> TokenStream {
+        match self {
+            Self::Static(name) => quote! { #[link(name = #name, kind = "static")] },
+            Self::RawDylib(dll) if import_library_name(dll) == dll => {
+                quote! { #[link(name = #dll, kind = "raw-dylib")] }
+            }
+            // A module that isn't a DLL is named verbatim, since rustc otherwise appends `.dll`.
+            Self::RawDylib(module) => {
+                quote! { #[link(name = #module, kind = "raw-dylib", modifiers = "+verbatim")] }
+            }
+            Self::Import { msvc, gnu } if msvc == gnu => quote! { #[link(name = #msvc)] },
+            Self::Import { msvc, gnu } => quote! {
+                #[cfg_attr(not(target_env = "gnu"), link(name = #msvc))]
+                #[cfg_attr(target_env = "gnu", link(name = #gnu))]
+            },
         }
     }
 }
+
+fn import_library_name(module: &str) -> &str {
+    [".dll", ".exe", ".sys"]
+        .iter()
+        .find_map(|extension| module.strip_suffix(extension))
+        .unwrap_or(module)
+}
+
+fn gnu_link_name(dll: &str) -> &str {
+    if dll.starts_with("api-ms-win-core-winrt") {
+        "runtimeobject"
+    } else if dll.starts_with("api-ms-win-core-synch") {
+        "synchronization"
+    } else if dll.starts_with("api-ms-win-core-path") {
+        "pathcch"
+    } else if dll == "sspicli" {
+        "secur32"
+    } else if dll.contains("-ms-win-") {
+        // Most of the remaining API sets are implemented by kernel32.
+        "kernel32"
+    } else {
+        dll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_import_library_name() {
+        assert_eq!(import_library_name("kernel32"), "kernel32");
+        assert_eq!(import_library_name("ntoskrnl.exe"), "ntoskrnl");
+        assert_eq!(import_library_name("hal.dll"), "hal");
+        assert_eq!(import_library_name("ndis.sys"), "ndis");
+    }
+}
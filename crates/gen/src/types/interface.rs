@@ -57,7 +57,11 @@ impl Interface {
 
             let is_exclusive = self.0.is_exclusive();
 
-            let hidden = if is_exclusive {
+            // Overridable interfaces are also implemented by the objects deriving from the class,
+            // such as those created with the `implement` macro, so they keep their methods.
+            let is_hidden = is_exclusive && !self.0.is_overridable();
+
+            let hidden = if is_hidden {
                 quote! { #[doc(hidden)] }
             } else {
                 quote! {}
@@ -66,7 +70,7 @@ impl Interface {
             // The exclusive interface may be a factory interface and then we still need a type to use
             // with the factory cache. And we don't know at this stage whether the interface is for
             // the class or its factory.
-            let public_type = if is_exclusive {
+            let public_type = if is_hidden {
                 TokenStream::new()
             } else {
                 let interfaces = self.interfaces();
@@ -79,8 +83,8 @@ impl Interface {
                     quote! {}
                 } else {
                     quote! {
-                        unsafe impl<#constraints> ::std::marker::Send for #name {}
-                        unsafe impl<#constraints> ::std::marker::Sync for #name {}
+                        unsafe impl<#constraints> ::core::marker::Send for #name {}
+                        unsafe impl<#constraints> ::core::marker::Sync for #name {}
                     }
                 };
 
@@ -89,13 +93,30 @@ impl Interface {
                     .filter(|interface| interface.kind != InterfaceKind::Default)
                     .map(|interface| interface.gen_conversion(&name, &constraints, gen));
 
+                let companion = if TypeReader::get().options.traits {
+                    let trait_name = self.0.gen_trait_name(gen);
+                    let (declarations, forwards) =
+                        InterfaceInfo::gen_trait_methods(&interfaces, gen);
+
+                    quote! {
+                        pub trait #trait_name where #constraints {
+                            #declarations
+                        }
+                        impl<#constraints> #trait_name for #name {
+                            #forwards
+                        }
+                    }
+                } else {
+                    quote! {}
+                };
+
                 quote! {
                     impl<#constraints> #name {
                         #methods
                         #async_get
                     }
                     unsafe impl<#constraints> ::windows::RuntimeType for #name {
-                        type DefaultType = ::std::option::Option<Self>;
+                        type DefaultType = ::core::option::Option<Self>;
                         const SIGNATURE: ::windows::ConstBuffer = #type_signature;
                     }
                     #future
@@ -103,18 +124,30 @@ impl Interface {
                     #(#conversions)*
                     #send_sync
                     #iterator
+                    #companion
                 }
             };
 
+            // An interface implemented only by the objects of an agile class is also agile.
+            let exclusive_send_sync = if is_exclusive && self.0.is_exclusive_to_agile() {
+                quote! {
+                    unsafe impl ::core::marker::Send for #name {}
+                    unsafe impl ::core::marker::Sync for #name {}
+                }
+            } else {
+                TokenStream::new()
+            };
+
             quote! {
                 #[repr(transparent)]
-                #[derive(::std::cmp::PartialEq, ::std::cmp::Eq, ::std::clone::Clone, ::std::fmt::Debug)]
+                #[derive(::core::cmp::PartialEq, ::core::cmp::Eq, ::core::clone::Clone, ::core::fmt::Debug)]
                 #hidden
                 pub struct #name(::windows::IInspectable, #(#struct_phantoms,)*) where #constraints;
                 unsafe impl<#constraints> ::windows::Interface for #name {
                     type Vtable = #abi_name;
                     const IID: ::windows::Guid = #guid;
                 }
+                #exclusive_send_sync
                 #public_type
                 #[repr(C)]
                 #[doc(hidden)]
@@ -132,7 +165,7 @@ impl Interface {
         } else {
             quote! {
                 #[repr(transparent)]
-                #[derive(::std::cmp::PartialEq, ::std::cmp::Eq, ::std::clone::Clone, ::std::fmt::Debug)]
+                #[derive(::core::cmp::PartialEq, ::core::cmp::Eq, ::core::clone::Clone, ::core::fmt::Debug)]
                 #[doc(hidden)]
                 pub struct #name(::windows::IInspectable, #(#struct_phantoms,)*) where #constraints;
                 unsafe impl<#constraints> ::windows::Interface for #name {
@@ -140,7 +173,7 @@ impl Interface {
                     const IID: ::windows::Guid = #guid;
                 }
                 unsafe impl<#constraints> ::windows::RuntimeType for #name {
-                    type DefaultType = ::std::option::Option<Self>;
+                    type DefaultType = ::core::option::Option<Self>;
                     const SIGNATURE: ::windows::ConstBuffer = #type_signature;
                 }
             }
@@ -158,6 +191,40 @@ mod tests {
         assert_eq!(i.type_signature(), "{96369f54-8eb6-48f0-abce-c1b211e627c3}")
     }
 
+    #[test]
+    fn test_exclusive_to_agile() {
+        let reader = TypeReader::get();
+
+        let uri = reader.resolve_type_def("Windows.Foundation", "IUriRuntimeClass");
+        assert!(uri.is_exclusive_to_agile());
+
+        // The factory of an agile class need not be agile.
+        let factory = reader.resolve_type_def("Windows.Foundation", "IUriRuntimeClassFactory");
+        assert!(!factory.is_exclusive_to_agile());
+
+        // XAML objects belong to the thread that created them.
+        let text = reader.resolve_type_def("Windows.UI.Xaml.Controls", "ITextBlock");
+        assert!(!text.is_exclusive_to_agile());
+
+        let stringable = reader.resolve_type_def("Windows.Foundation", "IStringable");
+        assert!(!stringable.is_exclusive_to_agile());
+    }
+
+    #[test]
+    fn test_overridable() {
+        let reader = TypeReader::get();
+
+        let overrides = reader.resolve_type_def("Windows.UI.Xaml", "IApplicationOverrides");
+        assert!(overrides.is_exclusive());
+        assert!(overrides.is_overridable());
+
+        let application = reader.resolve_type_def("Windows.UI.Xaml", "IApplication");
+        assert!(!application.is_overridable());
+
+        let stringable = reader.resolve_type_def("Windows.Foundation", "IStringable");
+        assert!(!stringable.is_overridable());
+    }
+
     #[test]
     fn test_interfaces() {
         let i = TypeReader::get().resolve_type_def("Windows.Foundation", "IAsyncOperation`1");
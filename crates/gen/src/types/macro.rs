@@ -0,0 +1,220 @@
+use super::*;
+
+/// A Win32 function-like macro, such as `MAKEINTRESOURCEW`, that the metadata doesn't describe
+/// but that is so widely used by C and C++ code that it is generated as a `const fn` in the
+/// namespace of the header that defines it.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord)]
+pub struct Macro {
+    pub namespace: &'static str,
+    pub name: &'static str,
+}
+
+impl Macro {
+    /// The macros by namespace, inserted into the namespaces present in the metadata.
+    pub const ALL: &'static [(&'static str, &'static [&'static str])] = &[
+        (
+            "Windows.Win32.Foundation",
+            &[
+                "FAILED",
+                "HIBYTE",
+                "HIWORD",
+                "HRESULT_FROM_WIN32",
+                "LOBYTE",
+                "LOWORD",
+                "MAKELONG",
+                "MAKEWORD",
+                "SUCCEEDED",
+            ],
+        ),
+        (
+            "Windows.Win32.System.SystemServices",
+            &["MAKELANGID", "MAKELCID", "PRIMARYLANGID", "SUBLANGID"],
+        ),
+        (
+            "Windows.Win32.UI.WindowsAndMessaging",
+            &[
+                "GET_WHEEL_DELTA_WPARAM",
+                "GET_X_LPARAM",
+                "GET_Y_LPARAM",
+                "MAKEINTRESOURCEA",
+                "MAKEINTRESOURCEW",
+            ],
+        ),
+    ];
+
+    // The types that the macro's signature refers to.
+    fn types(&self) -> &'static [(&'static str, &'static str)] {
+        match self.name {
+            "GET_WHEEL_DELTA_WPARAM" => &[("Windows.Win32.Foundation", "WPARAM")],
+            "GET_X_LPARAM" | "GET_Y_LPARAM" => &[("Windows.Win32.Foundation", "LPARAM")],
+            "MAKEINTRESOURCEA" => &[("Windows.Win32.Foundation", "PSTR")],
+            "MAKEINTRESOURCEW" => &[("Windows.Win32.Foundation", "PWSTR")],
+            _ => &[],
+        }
+    }
+
+    pub fn dependencies(&self) -> Vec<TypeEntry> {
+        self.types()
+            .iter()
+            .flat_map(|(namespace, name)| {
+                TypeReader::get()
+                    .resolve_type_def(namespace, name)
+                    .definition(TypeInclude::Minimal)
+            })
+            .collect()
+    }
+
+    pub fn gen(&self, gen: &Gen) -> TokenStream {
+        let name = to_ident(self.name);
+
+        let mut types = self.types().iter().map(|(namespace, name)| {
+            TypeReader::get()
+                .resolve_type_def(namespace, name)
+                .gen_name(gen)
+        });
+
+        let (params, result, body) = match self.name {
+            "SUCCEEDED" => (
+                quote! { hr: ::windows::HRESULT },
+                quote! { bool },
+                quote! { (hr.0 as i32) >= 0 },
+            ),
+            "FAILED" => (
+                quote! { hr: ::windows::HRESULT },
+                quote! { bool },
+                quote! { (hr.0 as i32) < 0 },
+            ),
+            "HRESULT_FROM_WIN32" => (
+                quote! { x: u32 },
+                quote! { ::windows::HRESULT },
+                quote! {
+                    if x as i32 <= 0 {
+                        ::windows::HRESULT(x)
+                    } else {
+                        ::windows::HRESULT((x & 0x0000_FFFF) | (7 << 16) | 0x8000_0000)
+                    }
+                },
+            ),
+            "LOWORD" => (
+                quote! { l: usize },
+                quote! { u16 },
+                quote! { (l & 0xFFFF) as u16 },
+            ),
+            "HIWORD" => (
+                quote! { l: usize },
+                quote! { u16 },
+                quote! { ((l >> 16) & 0xFFFF) as u16 },
+            ),
+            "LOBYTE" => (
+                quote! { w: usize },
+                quote! { u8 },
+                quote! { (w & 0xFF) as u8 },
+            ),
+            "HIBYTE" => (
+                quote! { w: usize },
+                quote! { u8 },
+                quote! { ((w >> 8) & 0xFF) as u8 },
+            ),
+            "MAKEWORD" => (
+                quote! { a: u8, b: u8 },
+                quote! { u16 },
+                quote! { (a as u16) | ((b as u16) << 8) },
+            ),
+            "MAKELONG" => (
+                quote! { a: u16, b: u16 },
+                quote! { u32 },
+                quote! { (a as u32) | ((b as u32) << 16) },
+            ),
+            "MAKELANGID" => (
+                quote! { p: u32, s: u32 },
+                quote! { u16 },
+                quote! { ((s as u16) << 10) | (p as u16) },
+            ),
+            "PRIMARYLANGID" => (
+                quote! { lgid: u16 },
+                quote! { u16 },
+                quote! { lgid & 0x3FF },
+            ),
+            "SUBLANGID" => (quote! { lgid: u16 }, quote! { u16 }, quote! { lgid >> 10 }),
+            "MAKELCID" => (
+                quote! { lgid: u16, srtid: u16 },
+                quote! { u32 },
+                quote! { ((srtid as u32) << 16) | (lgid as u32) },
+            ),
+            "GET_X_LPARAM" => {
+                let lparam = types.next().expect("LPARAM");
+                (
+                    quote! { lp: #lparam },
+                    quote! { i32 },
+                    quote! { (lp.0 & 0xFFFF) as i16 as i32 },
+                )
+            }
+            "GET_Y_LPARAM" => {
+                let lparam = types.next().expect("LPARAM");
+                (
+                    quote! { lp: #lparam },
+                    quote! { i32 },
+                    quote! { ((lp.0 >> 16) & 0xFFFF) as i16 as i32 },
+                )
+            }
+            "GET_WHEEL_DELTA_WPARAM" => {
+                let wparam = types.next().expect("WPARAM");
+                (
+                    quote! { wparam: #wparam },
+                    quote! { i16 },
+                    quote! { ((wparam.0 >> 16) & 0xFFFF) as i16 },
+                )
+            }
+            "MAKEINTRESOURCEA" => {
+                let pstr = types.next().expect("PSTR");
+                (
+                    quote! { i: u16 },
+                    quote! { #pstr },
+                    quote! { #pstr(i as usize as *mut u8) },
+                )
+            }
+            "MAKEINTRESOURCEW" => {
+                let pwstr = types.next().expect("PWSTR");
+                (
+                    quote! { i: u16 },
+                    quote! { #pwstr },
+                    quote! { #pwstr(i as usize as *mut u16) },
+                )
+            }
+            _ => unexpected!(),
+        };
+
+        quote! {
+            pub const fn #name(#params) -> #result {
+                #body
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_macros() {
+        let reader = TypeReader::get();
+
+        for (namespace, names) in Macro::ALL {
+            for name in names.iter() {
+                let row = reader.resolve_type_row(namespace, name);
+                assert!(matches!(row, TypeRow::Macro(_)), "{}.{}", namespace, name);
+            }
+        }
+
+        let def = Macro {
+            namespace: "Windows.Win32.UI.WindowsAndMessaging",
+            name: "MAKEINTRESOURCEW",
+        };
+
+        assert_eq!(
+            def.gen(&Gen::Relative("Windows.Win32.UI.WindowsAndMessaging")).as_str(),
+            "pub const fn MAKEINTRESOURCEW ( i : u16 ) -> super::super::Foundation:: PWSTR { super::super::Foundation:: PWSTR ( i as usize as * mut u16 ) }"
+        );
+    }
+}
@@ -3,7 +3,7 @@ use super::*;
 pub fn gen_matrix3x2() -> TokenStream {
     quote! {
         impl Matrix3x2 {
-            pub fn identity() -> Self {
+            pub const fn identity() -> Self {
                 Self {
                     M11: 1.0,
                     M12: 0.0,
@@ -13,7 +13,7 @@ pub fn gen_matrix3x2() -> TokenStream {
                     M32: 0.0,
                 }
             }
-            pub fn translation(x: f32, y: f32) -> Self {
+            pub const fn translation(x: f32, y: f32) -> Self {
                 Self {
                     M11: 1.0,
                     M12: 0.0,
@@ -72,89 +72,143 @@ pub fn gen_matrix3x2() -> TokenStream {
             }
         }
 
-        impl ::std::ops::Add<Matrix3x2> for Matrix3x2 {
+        impl ::core::ops::Add<Matrix3x2> for Matrix3x2 {
             type Output = Matrix3x2;
             fn add(self, rhs: Matrix3x2) -> Matrix3x2 {
                 self.impl_add(&rhs)
             }
         }
-        impl ::std::ops::Add<&Matrix3x2> for Matrix3x2 {
+        impl ::core::ops::Add<&Matrix3x2> for Matrix3x2 {
             type Output = Matrix3x2;
             fn add(self, rhs: &Matrix3x2) -> Matrix3x2 {
                 self.impl_add(rhs)
             }
         }
-        impl ::std::ops::Add<Matrix3x2> for &Matrix3x2 {
+        impl ::core::ops::Add<Matrix3x2> for &Matrix3x2 {
             type Output = Matrix3x2;
             fn add(self, rhs: Matrix3x2) -> Matrix3x2 {
                 self.impl_add(&rhs)
             }
         }
-        impl ::std::ops::Add<&Matrix3x2> for &Matrix3x2 {
+        impl ::core::ops::Add<&Matrix3x2> for &Matrix3x2 {
             type Output = Matrix3x2;
             fn add(self, rhs: &Matrix3x2) -> Matrix3x2 {
                 self.impl_add(rhs)
             }
         }
-        impl ::std::ops::Sub<Matrix3x2> for Matrix3x2 {
+        impl ::core::ops::Sub<Matrix3x2> for Matrix3x2 {
             type Output = Matrix3x2;
             fn sub(self, rhs: Matrix3x2) -> Matrix3x2 {
                 self.impl_sub(&rhs)
             }
         }
-        impl ::std::ops::Sub<&Matrix3x2> for Matrix3x2 {
+        impl ::core::ops::Sub<&Matrix3x2> for Matrix3x2 {
             type Output = Matrix3x2;
             fn sub(self, rhs: &Matrix3x2) -> Matrix3x2 {
                 self.impl_sub(rhs)
             }
         }
-        impl ::std::ops::Sub<Matrix3x2> for &Matrix3x2 {
+        impl ::core::ops::Sub<Matrix3x2> for &Matrix3x2 {
             type Output = Matrix3x2;
             fn sub(self, rhs: Matrix3x2) -> Matrix3x2 {
                 self.impl_sub(&rhs)
             }
         }
-        impl ::std::ops::Sub<&Matrix3x2> for &Matrix3x2 {
+        impl ::core::ops::Sub<&Matrix3x2> for &Matrix3x2 {
             type Output = Matrix3x2;
             fn sub(self, rhs: &Matrix3x2) -> Matrix3x2 {
                 self.impl_sub(rhs)
             }
         }
-        impl ::std::ops::Mul<Matrix3x2> for Matrix3x2 {
+        impl ::core::ops::Mul<Matrix3x2> for Matrix3x2 {
             type Output = Matrix3x2;
             fn mul(self, rhs: Matrix3x2) -> Matrix3x2 {
                 self.impl_mul(&rhs)
             }
         }
-        impl ::std::ops::Mul<&Matrix3x2> for Matrix3x2 {
+        impl ::core::ops::Mul<&Matrix3x2> for Matrix3x2 {
             type Output = Matrix3x2;
             fn mul(self, rhs: &Matrix3x2) -> Matrix3x2 {
                 self.impl_mul(rhs)
             }
         }
-        impl ::std::ops::Mul<Matrix3x2> for &Matrix3x2 {
+        impl ::core::ops::Mul<Matrix3x2> for &Matrix3x2 {
             type Output = Matrix3x2;
             fn mul(self, rhs: Matrix3x2) -> Matrix3x2 {
                 self.impl_mul(&rhs)
             }
         }
-        impl ::std::ops::Mul<&Matrix3x2> for &Matrix3x2 {
+        impl ::core::ops::Mul<&Matrix3x2> for &Matrix3x2 {
             type Output = Matrix3x2;
             fn mul(self, rhs: &Matrix3x2) -> Matrix3x2 {
                 self.impl_mul(rhs)
             }
         }
-        impl ::std::ops::Mul<f32> for Matrix3x2 {
+        impl ::core::ops::Mul<f32> for Matrix3x2 {
             type Output = Matrix3x2;
             fn mul(self, rhs: f32) -> Matrix3x2 {
                 self.impl_mul_f32(rhs)
             }
         }
-        impl ::std::ops::Mul<f32> for &Matrix3x2 {
+        impl ::core::ops::Mul<f32> for &Matrix3x2 {
             type Output = Matrix3x2;
             fn mul(self, rhs: f32) -> Matrix3x2 {
                 self.impl_mul_f32(rhs)
             }
         }
+
+        // The row-major layout used with row vectors matches the column-major layout used with
+        // column vectors, so the elements are copied in order.
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<Matrix3x2> for ::glam::Affine2 {
+            fn from(value: Matrix3x2) -> Self {
+                Self::from_cols_array(&[
+                    value.M11,
+                    value.M12,
+                    value.M21,
+                    value.M22,
+                    value.M31,
+                    value.M32,
+                ])
+            }
+        }
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<::glam::Affine2> for Matrix3x2 {
+            fn from(value: ::glam::Affine2) -> Self {
+                let array = value.to_cols_array();
+                Self {
+                    M11: array[0],
+                    M12: array[1],
+                    M21: array[2],
+                    M22: array[3],
+                    M31: array[4],
+                    M32: array[5],
+                }
+            }
+        }
+        // Each row of the matrix is a row of the `mint` matrix.
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<Matrix3x2> for ::mint::RowMatrix3x2<f32> {
+            fn from(value: Matrix3x2) -> Self {
+                Self {
+                    x: ::mint::Vector2 { x: value.M11, y: value.M12 },
+                    y: ::mint::Vector2 { x: value.M21, y: value.M22 },
+                    z: ::mint::Vector2 { x: value.M31, y: value.M32 },
+                }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<::mint::RowMatrix3x2<f32>> for Matrix3x2 {
+            fn from(value: ::mint::RowMatrix3x2<f32>) -> Self {
+                Self {
+                    M11: value.x.x,
+                    M12: value.x.y,
+                    M21: value.y.x,
+                    M22: value.y.y,
+                    M31: value.z.x,
+                    M32: value.z.y,
+                }
+            }
+        }
     }
 }
@@ -85,89 +85,174 @@ pub fn gen_matrix4x4() -> TokenStream {
             }
         }
 
-        impl ::std::ops::Add<Matrix4x4> for Matrix4x4 {
+        impl ::core::ops::Add<Matrix4x4> for Matrix4x4 {
             type Output = Matrix4x4;
             fn add(self, rhs: Matrix4x4) -> Matrix4x4 {
                 self.impl_add(&rhs)
             }
         }
-        impl ::std::ops::Add<&Matrix4x4> for Matrix4x4 {
+        impl ::core::ops::Add<&Matrix4x4> for Matrix4x4 {
             type Output = Matrix4x4;
             fn add(self, rhs: &Matrix4x4) -> Matrix4x4 {
                 self.impl_add(rhs)
             }
         }
-        impl ::std::ops::Add<Matrix4x4> for &Matrix4x4 {
+        impl ::core::ops::Add<Matrix4x4> for &Matrix4x4 {
             type Output = Matrix4x4;
             fn add(self, rhs: Matrix4x4) -> Matrix4x4 {
                 self.impl_add(&rhs)
             }
         }
-        impl ::std::ops::Add<&Matrix4x4> for &Matrix4x4 {
+        impl ::core::ops::Add<&Matrix4x4> for &Matrix4x4 {
             type Output = Matrix4x4;
             fn add(self, rhs: &Matrix4x4) -> Matrix4x4 {
                 self.impl_add(rhs)
             }
         }
-        impl ::std::ops::Sub<Matrix4x4> for Matrix4x4 {
+        impl ::core::ops::Sub<Matrix4x4> for Matrix4x4 {
             type Output = Matrix4x4;
             fn sub(self, rhs: Matrix4x4) -> Matrix4x4 {
                 self.impl_sub(&rhs)
             }
         }
-        impl ::std::ops::Sub<&Matrix4x4> for Matrix4x4 {
+        impl ::core::ops::Sub<&Matrix4x4> for Matrix4x4 {
             type Output = Matrix4x4;
             fn sub(self, rhs: &Matrix4x4) -> Matrix4x4 {
                 self.impl_sub(rhs)
             }
         }
-        impl ::std::ops::Sub<Matrix4x4> for &Matrix4x4 {
+        impl ::core::ops::Sub<Matrix4x4> for &Matrix4x4 {
             type Output = Matrix4x4;
             fn sub(self, rhs: Matrix4x4) -> Matrix4x4 {
                 self.impl_sub(&rhs)
             }
         }
-        impl ::std::ops::Sub<&Matrix4x4> for &Matrix4x4 {
+        impl ::core::ops::Sub<&Matrix4x4> for &Matrix4x4 {
             type Output = Matrix4x4;
             fn sub(self, rhs: &Matrix4x4) -> Matrix4x4 {
                 self.impl_sub(rhs)
             }
         }
-        impl ::std::ops::Mul<Matrix4x4> for Matrix4x4 {
+        impl ::core::ops::Mul<Matrix4x4> for Matrix4x4 {
             type Output = Matrix4x4;
             fn mul(self, rhs: Matrix4x4) -> Matrix4x4 {
                 self.impl_mul(&rhs)
             }
         }
-        impl ::std::ops::Mul<&Matrix4x4> for Matrix4x4 {
+        impl ::core::ops::Mul<&Matrix4x4> for Matrix4x4 {
             type Output = Matrix4x4;
             fn mul(self, rhs: &Matrix4x4) -> Matrix4x4 {
                 self.impl_mul(rhs)
             }
         }
-        impl ::std::ops::Mul<Matrix4x4> for &Matrix4x4 {
+        impl ::core::ops::Mul<Matrix4x4> for &Matrix4x4 {
             type Output = Matrix4x4;
             fn mul(self, rhs: Matrix4x4) -> Matrix4x4 {
                 self.impl_mul(&rhs)
             }
         }
-        impl ::std::ops::Mul<&Matrix4x4> for &Matrix4x4 {
+        impl ::core::ops::Mul<&Matrix4x4> for &Matrix4x4 {
             type Output = Matrix4x4;
             fn mul(self, rhs: &Matrix4x4) -> Matrix4x4 {
                 self.impl_mul(rhs)
             }
         }
-        impl ::std::ops::Mul<f32> for Matrix4x4 {
+        impl ::core::ops::Mul<f32> for Matrix4x4 {
             type Output = Matrix4x4;
             fn mul(self, rhs: f32) -> Matrix4x4 {
                 self.impl_mul_f32(rhs)
             }
         }
-        impl ::std::ops::Mul<f32> for &Matrix4x4 {
+        impl ::core::ops::Mul<f32> for &Matrix4x4 {
             type Output = Matrix4x4;
             fn mul(self, rhs: f32) -> Matrix4x4 {
                 self.impl_mul_f32(rhs)
             }
         }
+
+        // The row-major layout used with row vectors matches the column-major layout used with
+        // column vectors, so the elements are copied in order.
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<Matrix4x4> for ::glam::Mat4 {
+            fn from(value: Matrix4x4) -> Self {
+                Self::from_cols_array(&[
+                    value.M11,
+                    value.M12,
+                    value.M13,
+                    value.M14,
+                    value.M21,
+                    value.M22,
+                    value.M23,
+                    value.M24,
+                    value.M31,
+                    value.M32,
+                    value.M33,
+                    value.M34,
+                    value.M41,
+                    value.M42,
+                    value.M43,
+                    value.M44,
+                ])
+            }
+        }
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<::glam::Mat4> for Matrix4x4 {
+            fn from(value: ::glam::Mat4) -> Self {
+                let array = value.to_cols_array();
+                Self {
+                    M11: array[0],
+                    M12: array[1],
+                    M13: array[2],
+                    M14: array[3],
+                    M21: array[4],
+                    M22: array[5],
+                    M23: array[6],
+                    M24: array[7],
+                    M31: array[8],
+                    M32: array[9],
+                    M33: array[10],
+                    M34: array[11],
+                    M41: array[12],
+                    M42: array[13],
+                    M43: array[14],
+                    M44: array[15],
+                }
+            }
+        }
+        // Each row of the matrix is a row of the `mint` matrix.
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<Matrix4x4> for ::mint::RowMatrix4<f32> {
+            fn from(value: Matrix4x4) -> Self {
+                Self {
+                    x: ::mint::Vector4 { x: value.M11, y: value.M12, z: value.M13, w: value.M14 },
+                    y: ::mint::Vector4 { x: value.M21, y: value.M22, z: value.M23, w: value.M24 },
+                    z: ::mint::Vector4 { x: value.M31, y: value.M32, z: value.M33, w: value.M34 },
+                    w: ::mint::Vector4 { x: value.M41, y: value.M42, z: value.M43, w: value.M44 },
+                }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<::mint::RowMatrix4<f32>> for Matrix4x4 {
+            fn from(value: ::mint::RowMatrix4<f32>) -> Self {
+                Self {
+                    M11: value.x.x,
+                    M12: value.x.y,
+                    M13: value.x.z,
+                    M14: value.x.w,
+                    M21: value.y.x,
+                    M22: value.y.y,
+                    M23: value.y.z,
+                    M24: value.y.w,
+                    M31: value.z.x,
+                    M32: value.z.y,
+                    M33: value.z.z,
+                    M34: value.z.w,
+                    M41: value.w.x,
+                    M42: value.w.y,
+                    M43: value.w.z,
+                    M44: value.w.w,
+                }
+            }
+        }
     }
 }
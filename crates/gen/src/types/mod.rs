@@ -6,20 +6,27 @@ mod callback;
 mod class;
 mod com_interface;
 mod constant;
+mod datetime;
 mod delegate;
 mod r#enum;
 mod function;
 mod handle;
 mod interface;
+mod r#macro;
 mod matrix3x2;
 mod matrix4x4;
+mod point;
 mod pstr;
 mod pwstr;
+mod quaternion;
+mod rect;
+mod size;
 mod r#struct;
 mod timespan;
 mod vector2;
 mod vector3;
 mod vector4;
+mod xaml_island;
 
 pub use bool32::*;
 pub use bstr::*;
@@ -27,17 +34,24 @@ pub use callback::*;
 pub use class::*;
 pub use com_interface::*;
 pub use constant::*;
+pub use datetime::*;
 pub use delegate::*;
 pub use function::*;
 pub use handle::*;
 pub use interface::*;
 pub use matrix3x2::*;
 pub use matrix4x4::*;
+pub use point::*;
 pub use pstr::*;
 pub use pwstr::*;
+pub use quaternion::*;
 pub use r#enum::*;
+pub use r#macro::*;
 pub use r#struct::*;
+pub use rect::*;
+pub use size::*;
 pub use timespan::*;
 pub use vector2::*;
 pub use vector3::*;
 pub use vector4::*;
+pub use xaml_island::*;
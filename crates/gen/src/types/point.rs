@@ -0,0 +1,122 @@
+use super::*;
+
+pub fn gen_point() -> TokenStream {
+    quote! {
+        impl Point {
+            pub const fn new(X: f32, Y: f32) -> Self {
+                Self { X, Y }
+            }
+
+            fn impl_add(&self, rhs: &Self) -> Self {
+                Self {
+                    X: self.X + rhs.X,
+                    Y: self.Y + rhs.Y,
+                }
+            }
+            fn impl_sub(&self, rhs: &Self) -> Self {
+                Self {
+                    X: self.X - rhs.X,
+                    Y: self.Y - rhs.Y,
+                }
+            }
+            fn impl_div_f32(&self, rhs: f32) -> Self {
+                Self {
+                    X: self.X / rhs,
+                    Y: self.Y / rhs,
+                }
+            }
+            fn impl_mul_f32(&self, rhs: f32) -> Self {
+                Self {
+                    X: self.X * rhs,
+                    Y: self.Y * rhs,
+                }
+            }
+        }
+
+        impl ::core::ops::Add<Point> for Point {
+            type Output = Point;
+            fn add(self, rhs: Point) -> Point {
+                self.impl_add(&rhs)
+            }
+        }
+        impl ::core::ops::Add<&Point> for Point {
+            type Output = Point;
+            fn add(self, rhs: &Point) -> Point {
+                self.impl_add(rhs)
+            }
+        }
+        impl ::core::ops::Add<Point> for &Point {
+            type Output = Point;
+            fn add(self, rhs: Point) -> Point {
+                self.impl_add(&rhs)
+            }
+        }
+        impl ::core::ops::Add<&Point> for &Point {
+            type Output = Point;
+            fn add(self, rhs: &Point) -> Point {
+                self.impl_add(rhs)
+            }
+        }
+        impl ::core::ops::Sub<Point> for Point {
+            type Output = Point;
+            fn sub(self, rhs: Point) -> Point {
+                self.impl_sub(&rhs)
+            }
+        }
+        impl ::core::ops::Sub<&Point> for Point {
+            type Output = Point;
+            fn sub(self, rhs: &Point) -> Point {
+                self.impl_sub(rhs)
+            }
+        }
+        impl ::core::ops::Sub<Point> for &Point {
+            type Output = Point;
+            fn sub(self, rhs: Point) -> Point {
+                self.impl_sub(&rhs)
+            }
+        }
+        impl ::core::ops::Sub<&Point> for &Point {
+            type Output = Point;
+            fn sub(self, rhs: &Point) -> Point {
+                self.impl_sub(rhs)
+            }
+        }
+        impl ::core::ops::Div<f32> for Point {
+            type Output = Point;
+            fn div(self, rhs: f32) -> Point {
+                self.impl_div_f32(rhs)
+            }
+        }
+        impl ::core::ops::Div<f32> for &Point {
+            type Output = Point;
+            fn div(self, rhs: f32) -> Point {
+                self.impl_div_f32(rhs)
+            }
+        }
+        impl ::core::ops::Mul<f32> for Point {
+            type Output = Point;
+            fn mul(self, rhs: f32) -> Point {
+                self.impl_mul_f32(rhs)
+            }
+        }
+        impl ::core::ops::Mul<f32> for &Point {
+            type Output = Point;
+            fn mul(self, rhs: f32) -> Point {
+                self.impl_mul_f32(rhs)
+            }
+        }
+
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<Point> for ::mint::Point2<f32> {
+            fn from(value: Point) -> Self {
+                Self { x: value.X, y: value.Y }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<::mint::Point2<f32>> for Point {
+            fn from(value: ::mint::Point2<f32>) -> Self {
+                Self { X: value.x, Y: value.y }
+            }
+        }
+    }
+}
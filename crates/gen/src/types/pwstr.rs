@@ -1,23 +1,25 @@
 use super::*;
 
 pub fn gen_pwstr() -> TokenStream {
+    let std_cfg = TypeReader::get().options.gen_std_cfg();
+
     quote! {
         #[repr(transparent)]
-        #[derive(::std::clone::Clone, ::std::marker::Copy, ::std::cmp::Eq, ::std::fmt::Debug)]
+        #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::cmp::Eq, ::core::fmt::Debug)]
         pub struct PWSTR(pub *mut u16);
         impl PWSTR {
-            pub const NULL: Self = Self(::std::ptr::null_mut());
+            pub const NULL: Self = Self(::core::ptr::null_mut());
             pub fn is_null(&self) -> bool {
                 self.0.is_null()
             }
         }
-        impl ::std::default::Default for PWSTR {
+        impl ::core::default::Default for PWSTR {
             fn default() -> Self {
-                Self(::std::ptr::null_mut())
+                Self(::core::ptr::null_mut())
             }
         }
         // TODO: impl Debug and Display to display value and PartialEq etc
-        impl ::std::cmp::PartialEq for PWSTR {
+        impl ::core::cmp::PartialEq for PWSTR {
             fn eq(&self, other: &Self) -> bool {
                 // TODO: do value compare
                 self.0 == other.0
@@ -29,20 +31,70 @@ pub fn gen_pwstr() -> TokenStream {
             fn drop_param(param: &mut ::windows::Param<'_, Self>) {
                 if let ::windows::Param::Boxed(value) = param {
                     if !value.0.is_null() {
-                        unsafe { ::std::boxed::Box::from_raw(value.0); }
+                        unsafe { ::windows::alloc::boxed::Box::from_raw(value.0); }
                     }
                 }
             }
         }
+        // Short strings are converted into a buffer held by the parameter so that the call doesn't allocate.
+        impl<'a> ::windows::ParamAbi<PWSTR> for ::windows::StringParam<'a, PWSTR> {
+            fn abi(&mut self) -> PWSTR {
+                match self {
+                    ::windows::StringParam::Param(param) => ::windows::ParamAbi::abi(param),
+                    ::windows::StringParam::Buffer(buffer) => PWSTR(buffer.abi() as _),
+                }
+            }
+        }
         impl<'a> ::windows::IntoParam<'a, PWSTR> for &'a str {
+            type Param = ::windows::StringParam<'a, PWSTR>;
+            fn into_param(self) -> ::windows::StringParam<'a, PWSTR> {
+                match ::windows::WideBuffer::new(self) {
+                    ::core::option::Option::Some(buffer) => ::windows::StringParam::Buffer(buffer),
+                    ::core::option::Option::None => ::windows::StringParam::Param(::windows::Param::Boxed(PWSTR(::windows::alloc::boxed::Box::<[u16]>::into_raw(self.encode_utf16().chain(::core::iter::once(0)).collect::<::windows::alloc::vec::Vec<u16>>().into_boxed_slice()) as _))),
+                }
+            }
+        }
+        impl<'a> ::windows::IntoParam<'a, PWSTR> for ::windows::WideStr {
+            type Param = ::windows::Param<'a, PWSTR>;
+            fn into_param(self) -> ::windows::Param<'a, PWSTR> {
+                ::windows::Param::Owned(PWSTR(self.as_ptr() as _))
+            }
+        }
+        impl<'a> ::windows::IntoParam<'a, PWSTR> for &'a ::windows::alloc::string::String {
+            type Param = ::windows::StringParam<'a, PWSTR>;
+            fn into_param(self) -> ::windows::StringParam<'a, PWSTR> {
+                ::windows::IntoParam::<'a, PWSTR>::into_param(self.as_str())
+            }
+        }
+        impl<'a> ::windows::IntoParam<'a, PWSTR> for ::windows::alloc::string::String {
+            type Param = ::windows::StringParam<'a, PWSTR>;
+            fn into_param(self) -> ::windows::StringParam<'a, PWSTR> {
+                match ::windows::WideBuffer::new(&self) {
+                    ::core::option::Option::Some(buffer) => ::windows::StringParam::Buffer(buffer),
+                    ::core::option::Option::None => ::windows::StringParam::Param(::windows::Param::Boxed(PWSTR(::windows::alloc::boxed::Box::<[u16]>::into_raw(self.encode_utf16().chain(::core::iter::once(0)).collect::<::windows::alloc::vec::Vec<u16>>().into_boxed_slice()) as _))),
+                }
+            }
+        }
+        // Paths are given the extended-length prefix when needed so that long paths aren't truncated.
+        #std_cfg
+        impl<'a> ::windows::IntoParam<'a, PWSTR> for &'a ::std::path::Path {
+            type Param = ::windows::Param<'a, PWSTR>;
+            fn into_param(self) -> ::windows::Param<'a, PWSTR> {
+                ::windows::Param::Boxed(PWSTR(::windows::alloc::boxed::Box::<[u16]>::into_raw(::windows::to_wide_path(self).into_boxed_slice()) as _))
+            }
+        }
+        #std_cfg
+        impl<'a> ::windows::IntoParam<'a, PWSTR> for &'a ::std::path::PathBuf {
+            type Param = ::windows::Param<'a, PWSTR>;
             fn into_param(self) -> ::windows::Param<'a, PWSTR> {
-                ::windows::Param::Boxed(PWSTR(::std::boxed::Box::<[u16]>::into_raw(self.encode_utf16().chain(::std::iter::once(0)).collect::<std::vec::Vec<u16>>().into_boxed_slice()) as _))
+                ::windows::IntoParam::<'a, PWSTR>::into_param(self.as_path())
             }
         }
-        impl<'a> ::windows::IntoParam<'a, PWSTR> for String {
+        #std_cfg
+        impl<'a> ::windows::IntoParam<'a, PWSTR> for ::std::path::PathBuf {
+            type Param = ::windows::Param<'a, PWSTR>;
             fn into_param(self) -> ::windows::Param<'a, PWSTR> {
-                // TODO: call variant above
-                ::windows::Param::Boxed(PWSTR(::std::boxed::Box::<[u16]>::into_raw(self.encode_utf16().chain(::std::iter::once(0)).collect::<std::vec::Vec<u16>>().into_boxed_slice()) as _))
+                ::windows::Param::Boxed(PWSTR(::windows::alloc::boxed::Box::<[u16]>::into_raw(::windows::to_wide_path(&self).into_boxed_slice()) as _))
             }
         }
     }
@@ -0,0 +1,199 @@
+use super::*;
+
+pub fn gen_quaternion() -> TokenStream {
+    quote! {
+        impl Quaternion {
+            pub const fn new(X: f32, Y: f32, Z: f32, W: f32) -> Self {
+                Self { X, Y, Z, W }
+            }
+            pub const fn identity() -> Self {
+                Self {
+                    X: 0f32,
+                    Y: 0f32,
+                    Z: 0f32,
+                    W: 1f32,
+                }
+            }
+            pub fn dot(&self, rhs: &Self) -> f32 {
+                self.X * rhs.X + self.Y * rhs.Y + self.Z * rhs.Z + self.W * rhs.W
+            }
+            pub fn length_squared(&self) -> f32 {
+                self.dot(self)
+            }
+            pub fn length(&self) -> f32 {
+                self.length_squared().sqrt()
+            }
+            pub fn normalize(&self) -> Self {
+                self.impl_mul_f32(1.0 / self.length())
+            }
+            pub fn conjugate(&self) -> Self {
+                Self {
+                    X: -self.X,
+                    Y: -self.Y,
+                    Z: -self.Z,
+                    W: self.W,
+                }
+            }
+            pub fn inverse(&self) -> Self {
+                self.conjugate().impl_mul_f32(1.0 / self.length_squared())
+            }
+
+            fn impl_add(&self, rhs: &Self) -> Self {
+                Self {
+                    X: self.X + rhs.X,
+                    Y: self.Y + rhs.Y,
+                    Z: self.Z + rhs.Z,
+                    W: self.W + rhs.W,
+                }
+            }
+            fn impl_sub(&self, rhs: &Self) -> Self {
+                Self {
+                    X: self.X - rhs.X,
+                    Y: self.Y - rhs.Y,
+                    Z: self.Z - rhs.Z,
+                    W: self.W - rhs.W,
+                }
+            }
+            fn impl_mul(&self, rhs: &Self) -> Self {
+                Self {
+                    X: self.W * rhs.X + self.X * rhs.W + self.Y * rhs.Z - self.Z * rhs.Y,
+                    Y: self.W * rhs.Y - self.X * rhs.Z + self.Y * rhs.W + self.Z * rhs.X,
+                    Z: self.W * rhs.Z + self.X * rhs.Y - self.Y * rhs.X + self.Z * rhs.W,
+                    W: self.W * rhs.W - self.X * rhs.X - self.Y * rhs.Y - self.Z * rhs.Z,
+                }
+            }
+            fn impl_mul_f32(&self, rhs: f32) -> Self {
+                Self {
+                    X: self.X * rhs,
+                    Y: self.Y * rhs,
+                    Z: self.Z * rhs,
+                    W: self.W * rhs,
+                }
+            }
+        }
+
+        impl ::core::ops::Add<Quaternion> for Quaternion {
+            type Output = Quaternion;
+            fn add(self, rhs: Quaternion) -> Quaternion {
+                self.impl_add(&rhs)
+            }
+        }
+        impl ::core::ops::Add<&Quaternion> for Quaternion {
+            type Output = Quaternion;
+            fn add(self, rhs: &Quaternion) -> Quaternion {
+                self.impl_add(rhs)
+            }
+        }
+        impl ::core::ops::Add<Quaternion> for &Quaternion {
+            type Output = Quaternion;
+            fn add(self, rhs: Quaternion) -> Quaternion {
+                self.impl_add(&rhs)
+            }
+        }
+        impl ::core::ops::Add<&Quaternion> for &Quaternion {
+            type Output = Quaternion;
+            fn add(self, rhs: &Quaternion) -> Quaternion {
+                self.impl_add(rhs)
+            }
+        }
+        impl ::core::ops::Sub<Quaternion> for Quaternion {
+            type Output = Quaternion;
+            fn sub(self, rhs: Quaternion) -> Quaternion {
+                self.impl_sub(&rhs)
+            }
+        }
+        impl ::core::ops::Sub<&Quaternion> for Quaternion {
+            type Output = Quaternion;
+            fn sub(self, rhs: &Quaternion) -> Quaternion {
+                self.impl_sub(rhs)
+            }
+        }
+        impl ::core::ops::Sub<Quaternion> for &Quaternion {
+            type Output = Quaternion;
+            fn sub(self, rhs: Quaternion) -> Quaternion {
+                self.impl_sub(&rhs)
+            }
+        }
+        impl ::core::ops::Sub<&Quaternion> for &Quaternion {
+            type Output = Quaternion;
+            fn sub(self, rhs: &Quaternion) -> Quaternion {
+                self.impl_sub(rhs)
+            }
+        }
+        impl ::core::ops::Mul<Quaternion> for Quaternion {
+            type Output = Quaternion;
+            fn mul(self, rhs: Quaternion) -> Quaternion {
+                self.impl_mul(&rhs)
+            }
+        }
+        impl ::core::ops::Mul<&Quaternion> for Quaternion {
+            type Output = Quaternion;
+            fn mul(self, rhs: &Quaternion) -> Quaternion {
+                self.impl_mul(rhs)
+            }
+        }
+        impl ::core::ops::Mul<Quaternion> for &Quaternion {
+            type Output = Quaternion;
+            fn mul(self, rhs: Quaternion) -> Quaternion {
+                self.impl_mul(&rhs)
+            }
+        }
+        impl ::core::ops::Mul<&Quaternion> for &Quaternion {
+            type Output = Quaternion;
+            fn mul(self, rhs: &Quaternion) -> Quaternion {
+                self.impl_mul(rhs)
+            }
+        }
+        impl ::core::ops::Mul<f32> for Quaternion {
+            type Output = Quaternion;
+            fn mul(self, rhs: f32) -> Quaternion {
+                self.impl_mul_f32(rhs)
+            }
+        }
+        impl ::core::ops::Mul<f32> for &Quaternion {
+            type Output = Quaternion;
+            fn mul(self, rhs: f32) -> Quaternion {
+                self.impl_mul_f32(rhs)
+            }
+        }
+        impl ::core::ops::Neg for Quaternion {
+            type Output = Quaternion;
+            fn neg(self) -> Quaternion {
+                self.impl_mul_f32(-1.0)
+            }
+        }
+        impl ::core::ops::Neg for &Quaternion {
+            type Output = Quaternion;
+            fn neg(self) -> Quaternion {
+                self.impl_mul_f32(-1.0)
+            }
+        }
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<Quaternion> for ::glam::Quat {
+            fn from(value: Quaternion) -> Self {
+                Self::from_xyzw(value.X, value.Y, value.Z, value.W)
+            }
+        }
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<::glam::Quat> for Quaternion {
+            fn from(value: ::glam::Quat) -> Self {
+                Self { X: value.x, Y: value.y, Z: value.z, W: value.w }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<Quaternion> for ::mint::Quaternion<f32> {
+            fn from(value: Quaternion) -> Self {
+                Self {
+                    v: ::mint::Vector3 { x: value.X, y: value.Y, z: value.Z },
+                    s: value.W,
+                }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<::mint::Quaternion<f32>> for Quaternion {
+            fn from(value: ::mint::Quaternion<f32>) -> Self {
+                Self { X: value.v.x, Y: value.v.y, Z: value.v.z, W: value.s }
+            }
+        }
+    }
+}
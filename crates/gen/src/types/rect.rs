@@ -0,0 +1,48 @@
+use super::*;
+
+pub fn gen_rect() -> TokenStream {
+    quote! {
+        impl Rect {
+            pub const fn new(X: f32, Y: f32, Width: f32, Height: f32) -> Self {
+                Self { X, Y, Width, Height }
+            }
+            pub const fn from_point_size(point: &Point, size: &Size) -> Self {
+                Self {
+                    X: point.X,
+                    Y: point.Y,
+                    Width: size.Width,
+                    Height: size.Height,
+                }
+            }
+            pub fn point(&self) -> Point {
+                Point { X: self.X, Y: self.Y }
+            }
+            pub fn size(&self) -> Size {
+                Size {
+                    Width: self.Width,
+                    Height: self.Height,
+                }
+            }
+            pub fn right(&self) -> f32 {
+                self.X + self.Width
+            }
+            pub fn bottom(&self) -> f32 {
+                self.Y + self.Height
+            }
+            pub fn is_empty(&self) -> bool {
+                self.Width <= 0.0 || self.Height <= 0.0
+            }
+            pub fn contains(&self, point: &Point) -> bool {
+                point.X >= self.X && point.X < self.right() && point.Y >= self.Y && point.Y < self.bottom()
+            }
+            pub fn offset(&self, offset: &Point) -> Self {
+                Self {
+                    X: self.X + offset.X,
+                    Y: self.Y + offset.Y,
+                    Width: self.Width,
+                    Height: self.Height,
+                }
+            }
+        }
+    }
+}
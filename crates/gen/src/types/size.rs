@@ -0,0 +1,122 @@
+use super::*;
+
+pub fn gen_size() -> TokenStream {
+    quote! {
+        impl Size {
+            pub const fn new(Width: f32, Height: f32) -> Self {
+                Self { Width, Height }
+            }
+
+            fn impl_add(&self, rhs: &Self) -> Self {
+                Self {
+                    Width: self.Width + rhs.Width,
+                    Height: self.Height + rhs.Height,
+                }
+            }
+            fn impl_sub(&self, rhs: &Self) -> Self {
+                Self {
+                    Width: self.Width - rhs.Width,
+                    Height: self.Height - rhs.Height,
+                }
+            }
+            fn impl_div_f32(&self, rhs: f32) -> Self {
+                Self {
+                    Width: self.Width / rhs,
+                    Height: self.Height / rhs,
+                }
+            }
+            fn impl_mul_f32(&self, rhs: f32) -> Self {
+                Self {
+                    Width: self.Width * rhs,
+                    Height: self.Height * rhs,
+                }
+            }
+        }
+
+        impl ::core::ops::Add<Size> for Size {
+            type Output = Size;
+            fn add(self, rhs: Size) -> Size {
+                self.impl_add(&rhs)
+            }
+        }
+        impl ::core::ops::Add<&Size> for Size {
+            type Output = Size;
+            fn add(self, rhs: &Size) -> Size {
+                self.impl_add(rhs)
+            }
+        }
+        impl ::core::ops::Add<Size> for &Size {
+            type Output = Size;
+            fn add(self, rhs: Size) -> Size {
+                self.impl_add(&rhs)
+            }
+        }
+        impl ::core::ops::Add<&Size> for &Size {
+            type Output = Size;
+            fn add(self, rhs: &Size) -> Size {
+                self.impl_add(rhs)
+            }
+        }
+        impl ::core::ops::Sub<Size> for Size {
+            type Output = Size;
+            fn sub(self, rhs: Size) -> Size {
+                self.impl_sub(&rhs)
+            }
+        }
+        impl ::core::ops::Sub<&Size> for Size {
+            type Output = Size;
+            fn sub(self, rhs: &Size) -> Size {
+                self.impl_sub(rhs)
+            }
+        }
+        impl ::core::ops::Sub<Size> for &Size {
+            type Output = Size;
+            fn sub(self, rhs: Size) -> Size {
+                self.impl_sub(&rhs)
+            }
+        }
+        impl ::core::ops::Sub<&Size> for &Size {
+            type Output = Size;
+            fn sub(self, rhs: &Size) -> Size {
+                self.impl_sub(rhs)
+            }
+        }
+        impl ::core::ops::Div<f32> for Size {
+            type Output = Size;
+            fn div(self, rhs: f32) -> Size {
+                self.impl_div_f32(rhs)
+            }
+        }
+        impl ::core::ops::Div<f32> for &Size {
+            type Output = Size;
+            fn div(self, rhs: f32) -> Size {
+                self.impl_div_f32(rhs)
+            }
+        }
+        impl ::core::ops::Mul<f32> for Size {
+            type Output = Size;
+            fn mul(self, rhs: f32) -> Size {
+                self.impl_mul_f32(rhs)
+            }
+        }
+        impl ::core::ops::Mul<f32> for &Size {
+            type Output = Size;
+            fn mul(self, rhs: f32) -> Size {
+                self.impl_mul_f32(rhs)
+            }
+        }
+
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<Size> for ::mint::Vector2<f32> {
+            fn from(value: Size) -> Self {
+                Self { x: value.Width, y: value.Height }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<::mint::Vector2<f32>> for Size {
+            fn from(value: ::mint::Vector2<f32>) -> Self {
+                Self { Width: value.x, Height: value.y }
+            }
+        }
+    }
+}
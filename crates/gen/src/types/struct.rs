@@ -34,8 +34,8 @@ impl Struct {
                     None
                 } else {
                     let signature = f.signature();
-                    let name = f.name();
-                    Some((f, signature, to_ident(name)))
+                    let name = f.rust_name();
+                    Some((f, signature, to_ident(&name)))
                 }
             })
             .collect();
@@ -43,7 +43,7 @@ impl Struct {
         if fields.is_empty() {
             return quote! {
                 #[repr(C)]
-                #[derive(::std::clone::Clone, ::std::default::Default, ::std::fmt::Debug, ::std::cmp::PartialEq, ::std::cmp::Eq, ::std::marker::Copy)]
+                #[derive(::core::clone::Clone, ::core::default::Default, ::core::fmt::Debug, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::marker::Copy)]
                 pub struct #name(pub u8);
             };
         }
@@ -54,8 +54,10 @@ impl Struct {
         let layout = self.0.class_layout();
         let is_packed = self.0.is_packed();
 
-        let repr = if let Some(layout) = &layout {
-            let packing = Literal::u32_unsuffixed(layout.packing_size());
+        let repr = if let Some(repr) = self.gen_arch_repr(gen) {
+            repr
+        } else if let Some(packing) = self.0.packing() {
+            let packing = Literal::u32_unsuffixed(packing);
             quote! { #[repr(C, packed(#packing))] }
         } else if is_handle {
             quote! { #[repr(transparent)] }
@@ -91,15 +93,16 @@ impl Struct {
             quote! {}
         };
 
-        let clone_or_copy = if self.0.is_blittable() {
+        // Union fields are always ABI types so a union can always be copied.
+        let clone_or_copy = if self.0.is_blittable() || is_union {
             quote! {
-                #[derive(::std::clone::Clone, ::std::marker::Copy)]
+                #[derive(::core::clone::Clone, ::core::marker::Copy)]
             }
-        } else if is_union || has_union || is_packed {
+        } else if has_union || is_packed {
             quote! {}
         } else {
             quote! {
-                #[derive(::std::clone::Clone)]
+                #[derive(::core::clone::Clone)]
             }
         };
 
@@ -120,7 +123,7 @@ impl Struct {
                 ( #(#fields),* );
             }
         } else {
-            let fields = fields.iter().map(|(_, signature, name)| {
+            let fields = fields.iter().map(|(field, signature, name)| {
                 let kind = if is_winrt {
                     signature.gen_winrt(gen)
                 } else if is_union {
@@ -129,7 +132,10 @@ impl Struct {
                     signature.gen_win32(gen)
                 };
 
+                let alias = gen_doc_alias(name, field.name());
+
                 quote! {
+                    #alias
                     pub #name: #kind
                 }
             });
@@ -171,7 +177,7 @@ impl Struct {
             quote! {
                 #repr
                 #[doc(hidden)]
-                #[derive(::std::clone::Clone, ::std::marker::Copy)]
+                #[derive(::core::clone::Clone, ::core::marker::Copy)]
                 pub #struct_or_union #abi_name{ #fields }
                 unsafe impl ::windows::Abi for #name {
                     type Abi = #abi_name;
@@ -222,26 +228,37 @@ impl Struct {
 
             if layout.is_some() {
                 quote! {
-                    impl ::std::cmp::PartialEq for #name {
+                    impl ::core::cmp::PartialEq for #name {
                         fn eq(&self, other: &Self) -> bool {
                             unsafe { #(#compare)&&* }
                         }
                     }
-                    impl ::std::cmp::Eq for #name {}
+                    impl ::core::cmp::Eq for #name {}
                 }
             } else {
                 quote! {
-                    impl ::std::cmp::PartialEq for #name {
+                    impl ::core::cmp::PartialEq for #name {
                         fn eq(&self, other: &Self) -> bool {
                             #(#compare)&&*
                         }
                     }
-                    impl ::std::cmp::Eq for #name {}
+                    impl ::core::cmp::Eq for #name {}
                 }
             }
         };
 
-        let default = if is_union || has_union || has_complex_array || is_packed {
+        let default = if is_union || has_union || is_packed {
+            // Unions have no obvious default field so the all-zero bit pattern, which is the default
+            // for every Win32 type, is used instead, as it is for packed structs whose layout may
+            // depend on the architecture.
+            quote! {
+                impl ::core::default::Default for #name {
+                    fn default() -> Self {
+                        unsafe { ::core::mem::zeroed() }
+                    }
+                }
+            }
+        } else if has_complex_array {
             quote! {}
         } else {
             let defaults = fields.iter().map(|(_, signature, name)| {
@@ -264,7 +281,7 @@ impl Struct {
 
             if is_handle {
                 quote! {
-                    impl ::std::default::Default for #name {
+                    impl ::core::default::Default for #name {
                         fn default() -> Self {
                             Self(#defaults)
                         }
@@ -278,7 +295,7 @@ impl Struct {
                 }
             } else {
                 quote! {
-                    impl ::std::default::Default for #name {
+                    impl ::core::default::Default for #name {
                         fn default() -> Self {
                             Self{ #defaults }
                         }
@@ -287,10 +304,21 @@ impl Struct {
             }
         };
 
-        let debug = if is_union || has_union || has_complex_array || is_packed {
+        let debug = if is_union {
+            // The active field of a union isn't known so only its name can be displayed.
+            quote! {
+                impl ::core::fmt::Debug for #name {
+                    fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        fmt.debug_struct(#struct_name).finish()
+                    }
+                }
+            }
+        } else if has_union || has_complex_array || is_packed {
             quote! {}
         } else {
-            let debug_name = self.0.name();
+            // Nested types are named after their enclosing type rather than the metadata name, which
+            // is the same placeholder (e.g. `_Anonymous_e__Struct`) for every anonymous type.
+            let debug_name = struct_name;
 
             let debug_fields =
                 fields
@@ -325,8 +353,8 @@ impl Struct {
 
             if layout.is_some() {
                 quote! {
-                    impl ::std::fmt::Debug for #name {
-                        fn fmt(&self, fmt: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    impl ::core::fmt::Debug for #name {
+                        fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                             unsafe {
                                 fmt.debug_struct(#debug_name)
                                     #(#debug_fields)*
@@ -337,8 +365,8 @@ impl Struct {
                 }
             } else {
                 quote! {
-                    impl ::std::fmt::Debug for #name {
-                        fn fmt(&self, fmt: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    impl ::core::fmt::Debug for #name {
+                        fn fmt(&self, fmt: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                             fmt.debug_struct(#debug_name)
                                 #(#debug_fields)*
                                 .finish()
@@ -356,6 +384,7 @@ impl Struct {
 
             quote! {
                 impl<'a> ::windows::IntoParam<'a, #dependency> for #name {
+                    type Param = ::windows::Param<'a, #dependency>;
                     fn into_param(self) -> ::windows::Param<'a, #dependency> {
                         ::windows::Param::Owned(#dependency(self.0))
                     }
@@ -383,6 +412,48 @@ impl Struct {
         }
     }
 
+    // Types defined once for each of the architectures they differ on are generated from the
+    // first definition, but some, such as the SetupAPI and Shell structs packed only on x86,
+    // differ in nothing but their packing, which is then applied on each of their architectures.
+    fn gen_arch_repr(&self, gen: &Gen) -> Option<TokenStream> {
+        let definitions = self.0.arch_definitions();
+
+        if definitions
+            .iter()
+            .all(|def| def.packing() == self.0.packing())
+        {
+            return None;
+        }
+
+        let fields = |def: &tables::TypeDef| -> Vec<(&str, String)> {
+            def.fields()
+                .map(|field| (field.name(), field.signature().gen_win32(gen).into_string()))
+                .collect()
+        };
+
+        let expected = fields(&self.0);
+
+        if definitions.iter().any(|def| fields(def) != expected) {
+            return None;
+        }
+
+        let mut tokens = TokenStream::new();
+        let mut other_arches = Vec::new();
+
+        for def in definitions.iter().filter(|def| **def != self.0) {
+            let arches = gen_target_arches(def.architectures()?);
+            let repr = gen_packed_repr(def.packing());
+
+            tokens.combine(&quote! { #[cfg_attr(any(#(#arches),*), #repr)] });
+            other_arches.extend(arches);
+        }
+
+        let repr = gen_packed_repr(self.0.packing());
+        tokens.combine(&quote! { #[cfg_attr(not(any(#(#other_arches),*)), #repr)] });
+
+        Some(tokens)
+    }
+
     fn gen_replacement(&self) -> Option<TokenStream> {
         match self.0.full_name() {
             ("Windows.Win32.Foundation", "BOOL") => Some(gen_bool32()),
@@ -395,18 +466,41 @@ impl Struct {
 
     fn gen_extensions(&self) -> TokenStream {
         match self.0.full_name() {
+            ("Windows.Foundation", "DateTime") => gen_datetime(),
+            ("Windows.Foundation", "Point") => gen_point(),
+            ("Windows.Foundation", "Rect") => gen_rect(),
+            ("Windows.Foundation", "Size") => gen_size(),
             ("Windows.Foundation", "TimeSpan") => gen_timespan(),
             ("Windows.Foundation.Numerics", "Vector2") => gen_vector2(),
             ("Windows.Foundation.Numerics", "Vector3") => gen_vector3(),
             ("Windows.Foundation.Numerics", "Vector4") => gen_vector4(),
             ("Windows.Foundation.Numerics", "Matrix3x2") => gen_matrix3x2(),
             ("Windows.Foundation.Numerics", "Matrix4x4") => gen_matrix4x4(),
+            ("Windows.Foundation.Numerics", "Quaternion") => gen_quaternion(),
             ("Windows.Win32.Foundation", "HANDLE") => gen_handle(),
             _ => TokenStream::new(),
         }
     }
 }
 
+fn gen_packed_repr(packing: Option<u32>) -> TokenStream {
+    if let Some(packing) = packing {
+        let packing = Literal::u32_unsuffixed(packing);
+        quote! { repr(C, packed(#packing)) }
+    } else {
+        quote! { repr(C) }
+    }
+}
+
+// The metadata's `Architecture` values are flags for x86, x64, and Arm64 in that order.
+fn gen_target_arches(architectures: i32) -> Vec<TokenStream> {
+    [(1, "x86"), (2, "x86_64"), (4, "aarch64")]
+        .iter()
+        .filter(|(flag, _)| architectures & flag != 0)
+        .map(|(_, arch)| quote! { target_arch = #arch })
+        .collect()
+}
+
 fn gen_nested_types<'a>(
     enclosing_name: &'a str,
     enclosing_type: &'a tables::TypeDef,
@@ -460,6 +554,45 @@ mod tests {
         assert_eq!(f[6].signature().kind, ElementType::U32);
     }
 
+    #[test]
+    fn test_packing() {
+        let t = TypeReader::get().resolve_type_def("Windows.Win32.UI.Shell", "SHITEMID");
+        assert_eq!(t.packing(), Some(1));
+        assert_eq!(t.architectures(), None);
+        assert!(Struct(t)
+            .gen(&Gen::Absolute)
+            .as_str()
+            .starts_with("# [ repr ( C , packed ( 1 ) ) ]"));
+    }
+
+    #[test]
+    fn test_arch_packing() {
+        // The SetupAPI structs are only packed on x86, which is defined after x64 and Arm64.
+        let t = TypeReader::get().resolve_type_def(
+            "Windows.Win32.Devices.DeviceAndDriverInstallation",
+            "CABINET_INFO_A",
+        );
+        assert_eq!(t.architectures(), Some(6));
+        assert_eq!(t.packing(), None);
+        assert!(t.is_packed());
+
+        let definitions = t.arch_definitions();
+        assert_eq!(definitions.len(), 2);
+        assert_eq!(definitions[1].architectures(), Some(1));
+        assert_eq!(definitions[1].packing(), Some(1));
+
+        assert!(Struct(t).gen(&Gen::Absolute).as_str().starts_with(
+            "# [ cfg_attr ( any ( target_arch =\"x86\" ) , repr ( C , packed ( 1 ) ) ) ] \
+             # [ cfg_attr ( not ( any ( target_arch =\"x86\" ) ) , repr ( C ) ) ]"
+        ));
+
+        // Nested types are defined for the architectures of their enclosing type.
+        let t = TypeReader::get().resolve_type_def("Windows.Win32.UI.Shell", "NOTIFYICONDATAW");
+        let nested = t.nested_types().unwrap().values().next().unwrap();
+        assert_eq!(nested.architectures(), Some(6));
+        assert_eq!(nested.arch_definitions()[1].packing(), Some(1));
+    }
+
     #[test]
     fn test_blittable() {
         assert_eq!(
@@ -2,19 +2,31 @@ use super::*;
 
 pub fn gen_timespan() -> TokenStream {
     quote! {
-        impl ::std::convert::From<::std::time::Duration> for TimeSpan {
-            fn from(value: ::std::time::Duration) -> Self {
+        impl ::core::convert::From<::core::time::Duration> for TimeSpan {
+            fn from(value: ::core::time::Duration) -> Self {
+                // A `TimeSpan` counts 100-nanosecond ticks, saturating for durations beyond its range.
+                let ticks = value.as_nanos() / 100;
+
                 Self {
-                    Duration: (value.as_nanos() / 100) as i64,
+                    Duration: if ticks > i64::MAX as u128 { i64::MAX } else { ticks as i64 },
                 }
             }
         }
-        impl ::std::convert::From<TimeSpan> for ::std::time::Duration {
+        impl ::core::convert::From<TimeSpan> for ::core::time::Duration {
             fn from(value: TimeSpan) -> Self {
-                ::std::time::Duration::from_nanos((value.Duration * 100) as u64)
+                // A `Duration` can't be negative, so negative spans become zero.
+                if value.Duration < 0 {
+                    return ::core::time::Duration::from_secs(0);
+                }
+
+                ::core::time::Duration::new(
+                    (value.Duration / 10_000_000) as u64,
+                    (value.Duration % 10_000_000 * 100) as u32,
+                )
             }
         }
-        impl<'a> ::windows::IntoParam<'a, TimeSpan> for ::std::time::Duration {
+        impl<'a> ::windows::IntoParam<'a, TimeSpan> for ::core::time::Duration {
+            type Param = ::windows::Param<'a, TimeSpan>;
             fn into_param(self) -> ::windows::Param<'a, TimeSpan> {
                 ::windows::Param::Owned(self.into())
             }
@@ -3,19 +3,19 @@ use super::*;
 pub fn gen_vector2() -> TokenStream {
     quote! {
         impl Vector2 {
-            pub fn new(X: f32, Y: f32) -> Self {
+            pub const fn new(X: f32, Y: f32) -> Self {
                 Self { X, Y }
             }
-            pub fn zero() -> Self {
+            pub const fn zero() -> Self {
                 Self { X: 0f32, Y: 0f32 }
             }
-            pub fn one() -> Self {
+            pub const fn one() -> Self {
                 Self { X: 1f32, Y: 1f32 }
             }
-            pub fn unit_x() -> Self {
+            pub const fn unit_x() -> Self {
                 Self { X: 1.0, Y: 0.0 }
             }
-            pub fn unit_y() -> Self {
+            pub const fn unit_y() -> Self {
                 Self { X: 0.0, Y: 1.0 }
             }
             pub fn dot(&self, rhs: &Self) -> f32 {
@@ -75,125 +75,150 @@ pub fn gen_vector2() -> TokenStream {
             }
         }
 
-        impl ::std::ops::Add<Vector2> for Vector2 {
+        impl ::core::ops::Add<Vector2> for Vector2 {
             type Output = Vector2;
             fn add(self, rhs: Vector2) -> Vector2 {
                 self.impl_add(&rhs)
             }
         }
-        impl ::std::ops::Add<&Vector2> for Vector2 {
+        impl ::core::ops::Add<&Vector2> for Vector2 {
             type Output = Vector2;
             fn add(self, rhs: &Vector2) -> Vector2 {
                 self.impl_add(rhs)
             }
         }
-        impl ::std::ops::Add<Vector2> for &Vector2 {
+        impl ::core::ops::Add<Vector2> for &Vector2 {
             type Output = Vector2;
             fn add(self, rhs: Vector2) -> Vector2 {
                 self.impl_add(&rhs)
             }
         }
-        impl ::std::ops::Add<&Vector2> for &Vector2 {
+        impl ::core::ops::Add<&Vector2> for &Vector2 {
             type Output = Vector2;
             fn add(self, rhs: &Vector2) -> Vector2 {
                 self.impl_add(rhs)
             }
         }
-        impl ::std::ops::Sub<Vector2> for Vector2 {
+        impl ::core::ops::Sub<Vector2> for Vector2 {
             type Output = Vector2;
             fn sub(self, rhs: Vector2) -> Vector2 {
                 self.impl_sub(&rhs)
             }
         }
-        impl ::std::ops::Sub<&Vector2> for Vector2 {
+        impl ::core::ops::Sub<&Vector2> for Vector2 {
             type Output = Vector2;
             fn sub(self, rhs: &Vector2) -> Vector2 {
                 self.impl_sub(rhs)
             }
         }
-        impl ::std::ops::Sub<Vector2> for &Vector2 {
+        impl ::core::ops::Sub<Vector2> for &Vector2 {
             type Output = Vector2;
             fn sub(self, rhs: Vector2) -> Vector2 {
                 self.impl_sub(&rhs)
             }
         }
-        impl ::std::ops::Sub<&Vector2> for &Vector2 {
+        impl ::core::ops::Sub<&Vector2> for &Vector2 {
             type Output = Vector2;
             fn sub(self, rhs: &Vector2) -> Vector2 {
                 self.impl_sub(rhs)
             }
         }
-        impl ::std::ops::Div<Vector2> for Vector2 {
+        impl ::core::ops::Div<Vector2> for Vector2 {
             type Output = Vector2;
             fn div(self, rhs: Vector2) -> Vector2 {
                 self.impl_div(&rhs)
             }
         }
-        impl ::std::ops::Div<&Vector2> for Vector2 {
+        impl ::core::ops::Div<&Vector2> for Vector2 {
             type Output = Vector2;
             fn div(self, rhs: &Vector2) -> Vector2 {
                 self.impl_div(rhs)
             }
         }
-        impl ::std::ops::Div<Vector2> for &Vector2 {
+        impl ::core::ops::Div<Vector2> for &Vector2 {
             type Output = Vector2;
             fn div(self, rhs: Vector2) -> Vector2 {
                 self.impl_div(&rhs)
             }
         }
-        impl ::std::ops::Div<&Vector2> for &Vector2 {
+        impl ::core::ops::Div<&Vector2> for &Vector2 {
             type Output = Vector2;
             fn div(self, rhs: &Vector2) -> Vector2 {
                 self.impl_div(rhs)
             }
         }
-        impl ::std::ops::Div<f32> for Vector2 {
+        impl ::core::ops::Div<f32> for Vector2 {
             type Output = Vector2;
             fn div(self, rhs: f32) -> Vector2 {
                 self.impl_div_f32(rhs)
             }
         }
-        impl ::std::ops::Div<f32> for &Vector2 {
+        impl ::core::ops::Div<f32> for &Vector2 {
             type Output = Vector2;
             fn div(self, rhs: f32) -> Vector2 {
                 self.impl_div_f32(rhs)
             }
         }
-        impl ::std::ops::Mul<Vector2> for Vector2 {
+        impl ::core::ops::Mul<Vector2> for Vector2 {
             type Output = Vector2;
             fn mul(self, rhs: Vector2) -> Vector2 {
                 self.impl_mul(&rhs)
             }
         }
-        impl ::std::ops::Mul<&Vector2> for Vector2 {
+        impl ::core::ops::Mul<&Vector2> for Vector2 {
             type Output = Vector2;
             fn mul(self, rhs: &Vector2) -> Vector2 {
                 self.impl_mul(rhs)
             }
         }
-        impl ::std::ops::Mul<Vector2> for &Vector2 {
+        impl ::core::ops::Mul<Vector2> for &Vector2 {
             type Output = Vector2;
             fn mul(self, rhs: Vector2) -> Vector2 {
                 self.impl_mul(&rhs)
             }
         }
-        impl ::std::ops::Mul<&Vector2> for &Vector2 {
+        impl ::core::ops::Mul<&Vector2> for &Vector2 {
             type Output = Vector2;
             fn mul(self, rhs: &Vector2) -> Vector2 {
                 self.impl_mul(rhs)
             }
         }
-        impl ::std::ops::Mul<f32> for Vector2 {
+        impl ::core::ops::Mul<f32> for Vector2 {
             type Output = Vector2;
             fn mul(self, rhs: f32) -> Vector2 {
                 self.impl_mul_f32(rhs)
             }
         }
-        impl ::std::ops::Mul<f32> for &Vector2 {
+        impl ::core::ops::Mul<f32> for &Vector2 {
             type Output = Vector2;
             fn mul(self, rhs: f32) -> Vector2 {
                 self.impl_mul_f32(rhs)
             }
         }
+
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<Vector2> for ::glam::Vec2 {
+            fn from(value: Vector2) -> Self {
+                Self::new(value.X, value.Y)
+            }
+        }
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<::glam::Vec2> for Vector2 {
+            fn from(value: ::glam::Vec2) -> Self {
+                Self { X: value.x, Y: value.y }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<Vector2> for ::mint::Vector2<f32> {
+            fn from(value: Vector2) -> Self {
+                Self { x: value.X, y: value.Y }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<::mint::Vector2<f32>> for Vector2 {
+            fn from(value: ::mint::Vector2<f32>) -> Self {
+                Self { X: value.x, Y: value.y }
+            }
+        }
     }
 }
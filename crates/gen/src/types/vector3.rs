@@ -3,38 +3,38 @@ use super::*;
 pub fn gen_vector3() -> TokenStream {
     quote! {
         impl Vector3 {
-            pub fn new(X: f32, Y: f32, Z: f32) -> Self {
+            pub const fn new(X: f32, Y: f32, Z: f32) -> Self {
                 Self { X, Y, Z }
             }
-            pub fn zero() -> Self {
+            pub const fn zero() -> Self {
                 Self {
                     X: 0f32,
                     Y: 0f32,
                     Z: 0f32,
                 }
             }
-            pub fn one() -> Self {
+            pub const fn one() -> Self {
                 Self {
                     X: 1f32,
                     Y: 1f32,
                     Z: 1f32,
                 }
             }
-            pub fn unit_x() -> Self {
+            pub const fn unit_x() -> Self {
                 Self {
                     X: 1.0,
                     Y: 0.0,
                     Z: 0.0,
                 }
             }
-            pub fn unit_y() -> Self {
+            pub const fn unit_y() -> Self {
                 Self {
                     X: 0.0,
                     Y: 1.0,
                     Z: 0.0,
                 }
             }
-            pub fn unit_z() -> Self {
+            pub const fn unit_z() -> Self {
                 Self {
                     X: 0.0,
                     Y: 0.0,
@@ -104,125 +104,150 @@ pub fn gen_vector3() -> TokenStream {
             }
         }
 
-        impl ::std::ops::Add<Vector3> for Vector3 {
+        impl ::core::ops::Add<Vector3> for Vector3 {
             type Output = Vector3;
             fn add(self, rhs: Vector3) -> Vector3 {
                 self.impl_add(&rhs)
             }
         }
-        impl ::std::ops::Add<&Vector3> for Vector3 {
+        impl ::core::ops::Add<&Vector3> for Vector3 {
             type Output = Vector3;
             fn add(self, rhs: &Vector3) -> Vector3 {
                 self.impl_add(rhs)
             }
         }
-        impl ::std::ops::Add<Vector3> for &Vector3 {
+        impl ::core::ops::Add<Vector3> for &Vector3 {
             type Output = Vector3;
             fn add(self, rhs: Vector3) -> Vector3 {
                 self.impl_add(&rhs)
             }
         }
-        impl ::std::ops::Add<&Vector3> for &Vector3 {
+        impl ::core::ops::Add<&Vector3> for &Vector3 {
             type Output = Vector3;
             fn add(self, rhs: &Vector3) -> Vector3 {
                 self.impl_add(rhs)
             }
         }
-        impl ::std::ops::Sub<Vector3> for Vector3 {
+        impl ::core::ops::Sub<Vector3> for Vector3 {
             type Output = Vector3;
             fn sub(self, rhs: Vector3) -> Vector3 {
                 self.impl_sub(&rhs)
             }
         }
-        impl ::std::ops::Sub<&Vector3> for Vector3 {
+        impl ::core::ops::Sub<&Vector3> for Vector3 {
             type Output = Vector3;
             fn sub(self, rhs: &Vector3) -> Vector3 {
                 self.impl_sub(rhs)
             }
         }
-        impl ::std::ops::Sub<Vector3> for &Vector3 {
+        impl ::core::ops::Sub<Vector3> for &Vector3 {
             type Output = Vector3;
             fn sub(self, rhs: Vector3) -> Vector3 {
                 self.impl_sub(&rhs)
             }
         }
-        impl ::std::ops::Sub<&Vector3> for &Vector3 {
+        impl ::core::ops::Sub<&Vector3> for &Vector3 {
             type Output = Vector3;
             fn sub(self, rhs: &Vector3) -> Vector3 {
                 self.impl_sub(rhs)
             }
         }
-        impl ::std::ops::Div<Vector3> for Vector3 {
+        impl ::core::ops::Div<Vector3> for Vector3 {
             type Output = Vector3;
             fn div(self, rhs: Vector3) -> Vector3 {
                 self.impl_div(&rhs)
             }
         }
-        impl ::std::ops::Div<&Vector3> for Vector3 {
+        impl ::core::ops::Div<&Vector3> for Vector3 {
             type Output = Vector3;
             fn div(self, rhs: &Vector3) -> Vector3 {
                 self.impl_div(rhs)
             }
         }
-        impl ::std::ops::Div<Vector3> for &Vector3 {
+        impl ::core::ops::Div<Vector3> for &Vector3 {
             type Output = Vector3;
             fn div(self, rhs: Vector3) -> Vector3 {
                 self.impl_div(&rhs)
             }
         }
-        impl ::std::ops::Div<&Vector3> for &Vector3 {
+        impl ::core::ops::Div<&Vector3> for &Vector3 {
             type Output = Vector3;
             fn div(self, rhs: &Vector3) -> Vector3 {
                 self.impl_div(rhs)
             }
         }
-        impl ::std::ops::Div<f32> for Vector3 {
+        impl ::core::ops::Div<f32> for Vector3 {
             type Output = Vector3;
             fn div(self, rhs: f32) -> Vector3 {
                 self.impl_div_f32(rhs)
             }
         }
-        impl ::std::ops::Div<f32> for &Vector3 {
+        impl ::core::ops::Div<f32> for &Vector3 {
             type Output = Vector3;
             fn div(self, rhs: f32) -> Vector3 {
                 self.impl_div_f32(rhs)
             }
         }
-        impl ::std::ops::Mul<Vector3> for Vector3 {
+        impl ::core::ops::Mul<Vector3> for Vector3 {
             type Output = Vector3;
             fn mul(self, rhs: Vector3) -> Vector3 {
                 self.impl_mul(&rhs)
             }
         }
-        impl ::std::ops::Mul<&Vector3> for Vector3 {
+        impl ::core::ops::Mul<&Vector3> for Vector3 {
             type Output = Vector3;
             fn mul(self, rhs: &Vector3) -> Vector3 {
                 self.impl_mul(rhs)
             }
         }
-        impl ::std::ops::Mul<Vector3> for &Vector3 {
+        impl ::core::ops::Mul<Vector3> for &Vector3 {
             type Output = Vector3;
             fn mul(self, rhs: Vector3) -> Vector3 {
                 self.impl_mul(&rhs)
             }
         }
-        impl ::std::ops::Mul<&Vector3> for &Vector3 {
+        impl ::core::ops::Mul<&Vector3> for &Vector3 {
             type Output = Vector3;
             fn mul(self, rhs: &Vector3) -> Vector3 {
                 self.impl_mul(rhs)
             }
         }
-        impl ::std::ops::Mul<f32> for Vector3 {
+        impl ::core::ops::Mul<f32> for Vector3 {
             type Output = Vector3;
             fn mul(self, rhs: f32) -> Vector3 {
                 self.impl_mul_f32(rhs)
             }
         }
-        impl ::std::ops::Mul<f32> for &Vector3 {
+        impl ::core::ops::Mul<f32> for &Vector3 {
             type Output = Vector3;
             fn mul(self, rhs: f32) -> Vector3 {
                 self.impl_mul_f32(rhs)
             }
         }
+
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<Vector3> for ::glam::Vec3 {
+            fn from(value: Vector3) -> Self {
+                Self::new(value.X, value.Y, value.Z)
+            }
+        }
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<::glam::Vec3> for Vector3 {
+            fn from(value: ::glam::Vec3) -> Self {
+                Self { X: value.x, Y: value.y, Z: value.z }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<Vector3> for ::mint::Vector3<f32> {
+            fn from(value: Vector3) -> Self {
+                Self { x: value.X, y: value.Y, z: value.Z }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<::mint::Vector3<f32>> for Vector3 {
+            fn from(value: ::mint::Vector3<f32>) -> Self {
+                Self { X: value.x, Y: value.y, Z: value.z }
+            }
+        }
     }
 }
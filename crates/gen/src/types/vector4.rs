@@ -3,10 +3,10 @@ use super::*;
 pub fn gen_vector4() -> TokenStream {
     quote! {
         impl Vector4 {
-            pub fn new(X: f32, Y: f32, Z: f32, W: f32) -> Self {
+            pub const fn new(X: f32, Y: f32, Z: f32, W: f32) -> Self {
                 Self { X, Y, Z, W }
             }
-            pub fn zero() -> Self {
+            pub const fn zero() -> Self {
                 Self {
                     X: 0f32,
                     Y: 0f32,
@@ -14,7 +14,7 @@ pub fn gen_vector4() -> TokenStream {
                     W: 0f32,
                 }
             }
-            pub fn one() -> Self {
+            pub const fn one() -> Self {
                 Self {
                     X: 1f32,
                     Y: 1f32,
@@ -22,7 +22,7 @@ pub fn gen_vector4() -> TokenStream {
                     W: 1f32,
                 }
             }
-            pub fn unit_x() -> Self {
+            pub const fn unit_x() -> Self {
                 Self {
                     X: 1.0,
                     Y: 0.0,
@@ -30,7 +30,7 @@ pub fn gen_vector4() -> TokenStream {
                     W: 0.0,
                 }
             }
-            pub fn unit_y() -> Self {
+            pub const fn unit_y() -> Self {
                 Self {
                     X: 0.0,
                     Y: 1.0,
@@ -38,7 +38,7 @@ pub fn gen_vector4() -> TokenStream {
                     W: 0.0,
                 }
             }
-            pub fn unit_z() -> Self {
+            pub const fn unit_z() -> Self {
                 Self {
                     X: 0.0,
                     Y: 0.0,
@@ -46,7 +46,7 @@ pub fn gen_vector4() -> TokenStream {
                     W: 0.0,
                 }
             }
-            pub fn unit_w() -> Self {
+            pub const fn unit_w() -> Self {
                 Self {
                     X: 0.0,
                     Y: 0.0,
@@ -123,125 +123,150 @@ pub fn gen_vector4() -> TokenStream {
             }
         }
 
-        impl ::std::ops::Add<Vector4> for Vector4 {
+        impl ::core::ops::Add<Vector4> for Vector4 {
             type Output = Vector4;
             fn add(self, rhs: Vector4) -> Vector4 {
                 self.impl_add(&rhs)
             }
         }
-        impl ::std::ops::Add<&Vector4> for Vector4 {
+        impl ::core::ops::Add<&Vector4> for Vector4 {
             type Output = Vector4;
             fn add(self, rhs: &Vector4) -> Vector4 {
                 self.impl_add(rhs)
             }
         }
-        impl ::std::ops::Add<Vector4> for &Vector4 {
+        impl ::core::ops::Add<Vector4> for &Vector4 {
             type Output = Vector4;
             fn add(self, rhs: Vector4) -> Vector4 {
                 self.impl_add(&rhs)
             }
         }
-        impl ::std::ops::Add<&Vector4> for &Vector4 {
+        impl ::core::ops::Add<&Vector4> for &Vector4 {
             type Output = Vector4;
             fn add(self, rhs: &Vector4) -> Vector4 {
                 self.impl_add(rhs)
             }
         }
-        impl ::std::ops::Sub<Vector4> for Vector4 {
+        impl ::core::ops::Sub<Vector4> for Vector4 {
             type Output = Vector4;
             fn sub(self, rhs: Vector4) -> Vector4 {
                 self.impl_sub(&rhs)
             }
         }
-        impl ::std::ops::Sub<&Vector4> for Vector4 {
+        impl ::core::ops::Sub<&Vector4> for Vector4 {
             type Output = Vector4;
             fn sub(self, rhs: &Vector4) -> Vector4 {
                 self.impl_sub(rhs)
             }
         }
-        impl ::std::ops::Sub<Vector4> for &Vector4 {
+        impl ::core::ops::Sub<Vector4> for &Vector4 {
             type Output = Vector4;
             fn sub(self, rhs: Vector4) -> Vector4 {
                 self.impl_sub(&rhs)
             }
         }
-        impl ::std::ops::Sub<&Vector4> for &Vector4 {
+        impl ::core::ops::Sub<&Vector4> for &Vector4 {
             type Output = Vector4;
             fn sub(self, rhs: &Vector4) -> Vector4 {
                 self.impl_sub(rhs)
             }
         }
-        impl ::std::ops::Div<Vector4> for Vector4 {
+        impl ::core::ops::Div<Vector4> for Vector4 {
             type Output = Vector4;
             fn div(self, rhs: Vector4) -> Vector4 {
                 self.impl_div(&rhs)
             }
         }
-        impl ::std::ops::Div<&Vector4> for Vector4 {
+        impl ::core::ops::Div<&Vector4> for Vector4 {
             type Output = Vector4;
             fn div(self, rhs: &Vector4) -> Vector4 {
                 self.impl_div(rhs)
             }
         }
-        impl ::std::ops::Div<Vector4> for &Vector4 {
+        impl ::core::ops::Div<Vector4> for &Vector4 {
             type Output = Vector4;
             fn div(self, rhs: Vector4) -> Vector4 {
                 self.impl_div(&rhs)
             }
         }
-        impl ::std::ops::Div<&Vector4> for &Vector4 {
+        impl ::core::ops::Div<&Vector4> for &Vector4 {
             type Output = Vector4;
             fn div(self, rhs: &Vector4) -> Vector4 {
                 self.impl_div(rhs)
             }
         }
-        impl ::std::ops::Div<f32> for Vector4 {
+        impl ::core::ops::Div<f32> for Vector4 {
             type Output = Vector4;
             fn div(self, rhs: f32) -> Vector4 {
                 self.impl_div_f32(rhs)
             }
         }
-        impl ::std::ops::Div<f32> for &Vector4 {
+        impl ::core::ops::Div<f32> for &Vector4 {
             type Output = Vector4;
             fn div(self, rhs: f32) -> Vector4 {
                 self.impl_div_f32(rhs)
             }
         }
-        impl ::std::ops::Mul<Vector4> for Vector4 {
+        impl ::core::ops::Mul<Vector4> for Vector4 {
             type Output = Vector4;
             fn mul(self, rhs: Vector4) -> Vector4 {
                 self.impl_mul(&rhs)
             }
         }
-        impl ::std::ops::Mul<&Vector4> for Vector4 {
+        impl ::core::ops::Mul<&Vector4> for Vector4 {
             type Output = Vector4;
             fn mul(self, rhs: &Vector4) -> Vector4 {
                 self.impl_mul(rhs)
             }
         }
-        impl ::std::ops::Mul<Vector4> for &Vector4 {
+        impl ::core::ops::Mul<Vector4> for &Vector4 {
             type Output = Vector4;
             fn mul(self, rhs: Vector4) -> Vector4 {
                 self.impl_mul(&rhs)
             }
         }
-        impl ::std::ops::Mul<&Vector4> for &Vector4 {
+        impl ::core::ops::Mul<&Vector4> for &Vector4 {
             type Output = Vector4;
             fn mul(self, rhs: &Vector4) -> Vector4 {
                 self.impl_mul(rhs)
             }
         }
-        impl ::std::ops::Mul<f32> for Vector4 {
+        impl ::core::ops::Mul<f32> for Vector4 {
             type Output = Vector4;
             fn mul(self, rhs: f32) -> Vector4 {
                 self.impl_mul_f32(rhs)
             }
         }
-        impl ::std::ops::Mul<f32> for &Vector4 {
+        impl ::core::ops::Mul<f32> for &Vector4 {
             type Output = Vector4;
             fn mul(self, rhs: f32) -> Vector4 {
                 self.impl_mul_f32(rhs)
             }
         }
+
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<Vector4> for ::glam::Vec4 {
+            fn from(value: Vector4) -> Self {
+                Self::new(value.X, value.Y, value.Z, value.W)
+            }
+        }
+        #[cfg(feature = "glam")]
+        impl ::core::convert::From<::glam::Vec4> for Vector4 {
+            fn from(value: ::glam::Vec4) -> Self {
+                Self { X: value.x, Y: value.y, Z: value.z, W: value.w }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<Vector4> for ::mint::Vector4<f32> {
+            fn from(value: Vector4) -> Self {
+                Self { x: value.X, y: value.Y, z: value.Z, w: value.W }
+            }
+        }
+        #[cfg(feature = "mint")]
+        impl ::core::convert::From<::mint::Vector4<f32>> for Vector4 {
+            fn from(value: ::mint::Vector4<f32>) -> Self {
+                Self { X: value.x, Y: value.y, Z: value.z, W: value.w }
+            }
+        }
     }
 }
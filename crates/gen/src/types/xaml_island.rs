@@ -0,0 +1,79 @@
+use super::*;
+
+pub fn gen_xaml_island() -> TokenStream {
+    quote! {
+        /// Hosts XAML content within a Win32 window using a `DesktopWindowXamlSource`.
+        ///
+        /// XAML is initialized for the calling thread, which must be a single-threaded apartment
+        /// with a message loop. The thread's message loop should pass each message to
+        /// `pre_translate_message` before translating and dispatching it so that keyboard input,
+        /// such as tabbing between controls, reaches the XAML content.
+        pub struct XamlIsland {
+            manager: WindowsXamlManager,
+            source: DesktopWindowXamlSource,
+            window: super::super::super::Win32::Foundation::HWND,
+        }
+        impl XamlIsland {
+            /// Creates a XAML island as a child window of `parent`.
+            pub fn new<'a>(parent: impl ::windows::IntoParam<'a, super::super::super::Win32::Foundation::HWND>) -> ::windows::Result<Self> {
+                let manager = WindowsXamlManager::InitializeForCurrentThread()?;
+                let source = DesktopWindowXamlSource::new()?;
+                let native = ::windows::Interface::cast::<super::super::super::Win32::System::WinRT::IDesktopWindowXamlSourceNative>(&source)?;
+
+                let window = unsafe {
+                    native.AttachToWindow(parent)?;
+                    native.get_WindowHandle()?
+                };
+
+                Ok(Self { manager, source, window })
+            }
+            /// Returns the source of the XAML content, such as to handle focus navigation.
+            pub fn source(&self) -> &DesktopWindowXamlSource {
+                &self.source
+            }
+            /// Returns the child window hosting the XAML content.
+            pub fn window(&self) -> super::super::super::Win32::Foundation::HWND {
+                self.window
+            }
+            /// Sets the root element of the XAML content.
+            pub fn set_content<'a>(&self, content: impl ::windows::IntoParam<'a, super::UIElement>) -> ::windows::Result<()> {
+                self.source.SetContent(content)
+            }
+            /// Moves and resizes the XAML content within the parent window, in pixels, such as
+            /// when the parent window handles `WM_SIZE`.
+            pub fn set_bounds(&self, x: i32, y: i32, width: i32, height: i32) -> ::windows::Result<()> {
+                unsafe {
+                    super::super::super::Win32::UI::WindowsAndMessaging::SetWindowPos(
+                        self.window,
+                        super::super::super::Win32::Foundation::HWND::default(),
+                        x,
+                        y,
+                        width,
+                        height,
+                        super::super::super::Win32::UI::WindowsAndMessaging::SWP_NOZORDER | super::super::super::Win32::UI::WindowsAndMessaging::SWP_SHOWWINDOW,
+                    )
+                    .ok()
+                }
+            }
+            /// Gives the XAML content the chance to handle a message, returning `true` if the
+            /// message was handled and should not be translated or dispatched.
+            pub fn pre_translate_message(&self, message: &super::super::super::Win32::UI::WindowsAndMessaging::MSG) -> ::windows::Result<bool> {
+                let native = ::windows::Interface::cast::<super::super::super::Win32::System::WinRT::IDesktopWindowXamlSourceNative2>(&self.source)?;
+                let mut handled = super::super::super::Win32::Foundation::BOOL::default();
+
+                unsafe {
+                    native.PreTranslateMessage(message, &mut handled)?;
+                }
+
+                Ok(handled.as_bool())
+            }
+        }
+        impl ::core::ops::Drop for XamlIsland {
+            fn drop(&mut self) {
+                // The source must be closed before XAML is shut down for the thread.
+                let _ = self.source.Close();
+                let _ = self.manager.Close();
+            }
+        }
+    }
+}
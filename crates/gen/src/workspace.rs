@@ -1,17 +1,86 @@
 use super::*;
 
-pub fn crate_winmds() -> &'static [File] {
-    use std::{mem::MaybeUninit, sync::Once};
-    static ONCE: Once = Once::new();
-    static mut VALUE: MaybeUninit<Vec<File>> = MaybeUninit::uninit();
+use std::{mem::MaybeUninit, sync::Once};
+static WINMDS_ONCE: Once = Once::new();
+static mut WINMDS: MaybeUninit<Vec<File>> = MaybeUninit::uninit();
 
-    ONCE.call_once(|| {
+pub fn crate_winmds() -> &'static [File] {
+    WINMDS_ONCE.call_once(|| {
         // This is safe because `Once` provides thread-safe one-time initialization
-        unsafe { VALUE = MaybeUninit::new(get_crate_winmds()) }
+        unsafe { WINMDS = MaybeUninit::new(get_crate_winmds()) }
     });
 
     // This is safe because `call_once` has already been called.
-    unsafe { &*VALUE.as_ptr() }
+    unsafe { &*WINMDS.as_ptr() }
+}
+
+/// Reads the metadata from the given winmd files and the winmd files within the given
+/// directories, rather than from the `.windows/winmd` directories found through Cargo, so that
+/// tools running outside of a build script may read any metadata.
+///
+/// Returns `false` without reading the files if the metadata has already been read.
+pub fn load_winmds<P: AsRef<std::path::Path>>(paths: &[P]) -> bool {
+    let mut loaded = false;
+
+    WINMDS_ONCE.call_once(|| {
+        let mut result = vec![];
+
+        for path in paths {
+            let path = path.as_ref();
+
+            if path.is_dir() {
+                let mut paths = std::collections::BTreeMap::new();
+                find_winmds(path, path, None, &mut paths);
+                result.extend(paths.values().map(File::new));
+            } else {
+                result.push(File::new(path));
+            }
+        }
+
+        // This is safe because `Once` provides thread-safe one-time initialization
+        unsafe { WINMDS = MaybeUninit::new(result) }
+        loaded = true;
+    });
+
+    loaded
+}
+
+/// Reads the Windows SDK metadata found in the `.windows/winmd` directories from the directory
+/// named after the given SDK version, such as `10.0.19041.0`, ignoring the copies held for other
+/// versions of the SDK. Otherwise the copy held for the latest version present is read, so that
+/// machines with different versions of the SDK may generate different bindings.
+///
+/// Returns the SDK versions that are present if there is no metadata for the given version. Has
+/// no effect if the metadata has already been read.
+pub fn pin_sdk_version(version: &str) -> Result<(), Vec<String>> {
+    let dirs = crate_winmd_dirs();
+    let mut versions = std::collections::BTreeSet::new();
+
+    for dir in &dirs {
+        find_sdk_versions(dir, dir, &mut versions);
+    }
+
+    if !versions.iter().any(|(_, present)| present == version) {
+        return Err(versions.into_iter().map(|(_, version)| version).collect());
+    }
+
+    WINMDS_ONCE.call_once(|| {
+        // This is safe because `Once` provides thread-safe one-time initialization
+        unsafe { WINMDS = MaybeUninit::new(read_winmds(&dirs, Some(version))) }
+    });
+
+    Ok(())
+}
+
+/// Hashes the name and contents of every winmd file read by the generator, so that previously
+/// generated code can be reused while the metadata is unchanged.
+pub fn hash_winmds<H: std::hash::Hasher>(state: &mut H) {
+    use std::hash::Hash;
+
+    for file in crate_winmds() {
+        file.name.hash(state);
+        file.bytes.hash(state);
+    }
 }
 
 fn cargo_metadata() -> &'static str {
@@ -73,47 +142,295 @@ pub fn target_dir() -> String {
     json[beginning_index..beginning_index + ending_index].replace("\\\\", "\\")
 }
 
+/// Returns the target directory's `deps` directory, which Cargo adds to the dynamic library
+/// search path when running the compiler and build scripts.
+///
+/// The search path is held by a different variable on each host, so the host rather than the
+/// target decides which is read. This allows crates to be cross-compiled for Windows from Linux
+/// or macOS.
+pub fn deps_dir() -> Option<std::path::PathBuf> {
+    let name = if cfg!(windows) {
+        "PATH"
+    } else if cfg!(target_os = "macos") {
+        "DYLD_FALLBACK_LIBRARY_PATH"
+    } else {
+        "LD_LIBRARY_PATH"
+    };
+
+    std::env::var_os(name).and_then(|paths| {
+        std::env::split_paths(&paths).find(|path| path.file_name() == Some("deps".as_ref()))
+    })
+}
+
 fn get_crate_winmds() -> Vec<File> {
-    fn push_dir(result: &mut Vec<File>, dir: &std::path::Path) {
-        if let Ok(files) = std::fs::read_dir(&dir) {
-            for file in files.filter_map(|file| file.ok()) {
-                if let Ok(file_type) = file.file_type() {
-                    if file_type.is_file() {
-                        let path = file.path();
-                        if let Some("winmd") =
-                            path.extension().and_then(|extension| extension.to_str())
-                        {
-                            result.push(File::new(path));
-                        }
-                    }
-                }
-            }
-        }
-    }
+    read_winmds(&crate_winmd_dirs(), None)
+}
 
+fn read_winmds(dirs: &[std::path::PathBuf], sdk_version: Option<&str>) -> Vec<File> {
     let mut result = vec![];
 
+    for dir in dirs {
+        let mut paths = std::collections::BTreeMap::new();
+        find_winmds(dir, dir, sdk_version, &mut paths);
+        result.extend(paths.values().map(File::new));
+    }
+
+    result
+}
+
+// The `.windows/winmd` directories of the crate, of the workspace, and of the target directory.
+fn crate_winmd_dirs() -> Vec<std::path::PathBuf> {
+    let mut dirs = vec![];
+
     let mut dir: std::path::PathBuf = std::env::var("CARGO_MANIFEST_DIR")
         .expect("No `CARGO_MANIFEST_DIR` env variable set")
         .into();
 
     dir.push(".windows");
     dir.push("winmd");
-    push_dir(&mut result, &dir);
+    dirs.push(dir);
 
-    let dir = std::env::var("PATH").expect("No `PATH` env variable set");
-    let end = dir.find(';').expect("Path not ending in `;`");
-    let mut dir: std::path::PathBuf = dir[..end].into();
-    dir.pop();
-    dir.pop();
-    dir.push(".windows");
-    dir.push("winmd");
-    push_dir(&mut result, &dir);
+    if let Some(mut dir) = deps_dir() {
+        dir.pop();
+        dir.pop();
+        dir.push(".windows");
+        dir.push("winmd");
+        dirs.push(dir);
+    }
 
     let mut dir: std::path::PathBuf = target_dir().into();
     dir.push(".windows");
     dir.push("winmd");
-    push_dir(&mut result, &dir);
+    dirs.push(dir);
 
-    result
+    dirs
+}
+
+// Finds the winmd files within `dir` and its subdirectories, keyed by file name. Package layouts,
+// such as the Windows App SDK's NuGet packages holding the `Microsoft.UI.Xaml` metadata, may hold
+// a copy of the same file for each version of the package or of Windows that it targets, in which
+// case the copy in the most recent version's directory is used. Copies held in the directory of a
+// Windows SDK version other than `sdk_version`, if given, are ignored.
+fn find_winmds(
+    root: &std::path::Path,
+    dir: &std::path::Path,
+    sdk_version: Option<&str>,
+    paths: &mut std::collections::BTreeMap<std::ffi::OsString, std::path::PathBuf>,
+) {
+    if let Ok(files) = std::fs::read_dir(&dir) {
+        for file in files.filter_map(|file| file.ok()) {
+            if let Ok(file_type) = file.file_type() {
+                let path = file.path();
+
+                if file_type.is_dir() {
+                    let name = file.file_name();
+                    let name = name.to_string_lossy();
+
+                    // The directories of other SDK versions are skipped when a version is pinned.
+                    let skipped = match sdk_version {
+                        Some(version) => is_sdk_version(&name) && name != version,
+                        None => false,
+                    };
+
+                    if !skipped {
+                        find_winmds(root, &path, sdk_version, paths);
+                    }
+                } else if file_type.is_file()
+                    && path.extension().and_then(|extension| extension.to_str()) == Some("winmd")
+                {
+                    let existing = paths
+                        .entry(file.file_name())
+                        .or_insert_with(|| path.clone());
+
+                    if path_version(root, &path) > path_version(root, existing) {
+                        *existing = path;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Finds the directories within `dir` that are named after a Windows SDK version and hold metadata,
+// as in the `UnionMetadata` directory of an installed SDK, ordered by version.
+fn find_sdk_versions(
+    root: &std::path::Path,
+    dir: &std::path::Path,
+    versions: &mut std::collections::BTreeSet<(Vec<u32>, String)>,
+) {
+    if let Ok(files) = std::fs::read_dir(dir) {
+        for file in files.filter_map(|file| file.ok()) {
+            if file
+                .file_type()
+                .ok()
+                .filter(|file_type| file_type.is_dir())
+                .is_some()
+            {
+                let name = file.file_name().to_string_lossy().into_owned();
+
+                if is_sdk_version(&name) {
+                    let mut paths = std::collections::BTreeMap::new();
+                    find_winmds(root, &file.path(), None, &mut paths);
+
+                    if !paths.is_empty() {
+                        let numbers = name.split('.').map(|n| n.parse().unwrap_or(0)).collect();
+                        versions.insert((numbers, name));
+                    }
+                } else {
+                    find_sdk_versions(root, &file.path(), versions);
+                }
+            }
+        }
+    }
+}
+
+/// Whether `name` is a Windows SDK version, such as `10.0.19041.0`.
+pub fn is_sdk_version(name: &str) -> bool {
+    let parts: Vec<&str> = name.split('.').collect();
+
+    parts.len() == 4
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+// The numbers in the name of each directory between `root` and the file at `path`, so that
+// `uap10.0.18362/` orders after `uap10.0/` and `Microsoft.WindowsAppSDK.1.1.0/` after
+// `Microsoft.WindowsAppSDK.1.0.0/`.
+fn path_version(root: &std::path::Path, path: &std::path::Path) -> Vec<Vec<u32>> {
+    let path = path.strip_prefix(root).unwrap_or(path);
+
+    path.parent()
+        .map(|parent| {
+            parent
+                .components()
+                .map(|component| {
+                    component
+                        .as_os_str()
+                        .to_string_lossy()
+                        .split(|c: char| !c.is_ascii_digit())
+                        .filter(|number| !number.is_empty())
+                        .map(|number| number.parse().unwrap_or(u32::MAX))
+                        .collect()
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn versions() {
+        let root = Path::new("winmd");
+        let version = |path: &str| path_version(root, &root.join(path));
+
+        assert_eq!(version("Windows.winmd"), Vec::<Vec<u32>>::new());
+        assert_eq!(
+            version("lib/uap10.0.18362/Microsoft.UI.winmd"),
+            vec![vec![], vec![10, 0, 18362]]
+        );
+        assert!(
+            version("lib/uap10.0.18362/Microsoft.UI.winmd")
+                > version("lib/uap10.0/Microsoft.UI.winmd")
+        );
+        assert!(
+            version("Microsoft.WindowsAppSDK.1.1.0/lib/uap10.0/Microsoft.UI.winmd")
+                > version("Microsoft.WindowsAppSDK.1.0.0/lib/uap10.0.18362/Microsoft.UI.winmd")
+        );
+    }
+
+    #[test]
+    fn versioned_layout() {
+        let root = std::env::temp_dir().join("windows-gen-versioned-layout");
+        let _ = std::fs::remove_dir_all(&root);
+
+        for path in &[
+            "Windows.winmd",
+            "Microsoft.WindowsAppSDK/lib/uap10.0/Microsoft.UI.winmd",
+            "Microsoft.WindowsAppSDK/lib/uap10.0/Microsoft.UI.Xaml.winmd",
+            "Microsoft.WindowsAppSDK/lib/uap10.0.18362/Microsoft.UI.winmd",
+            "Microsoft.WindowsAppSDK/lib/uap10.0.18362/readme.txt",
+        ] {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, b"").unwrap();
+        }
+
+        let mut paths = std::collections::BTreeMap::new();
+        find_winmds(&root, &root, None, &mut paths);
+        let paths: Vec<_> = paths
+            .values()
+            .map(|path| path.strip_prefix(&root).unwrap().to_path_buf())
+            .collect();
+
+        assert_eq!(
+            paths,
+            [
+                Path::new("Microsoft.WindowsAppSDK/lib/uap10.0/Microsoft.UI.Xaml.winmd"),
+                Path::new("Microsoft.WindowsAppSDK/lib/uap10.0.18362/Microsoft.UI.winmd"),
+                Path::new("Windows.winmd"),
+            ]
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn sdk_versions() {
+        let root = std::env::temp_dir().join("windows-gen-sdk-versions");
+        let _ = std::fs::remove_dir_all(&root);
+
+        for path in &[
+            "Windows.Win32.winmd",
+            "sdk/10.0.18362.0/Windows.winmd",
+            "sdk/10.0.19041.0/Windows.winmd",
+            "sdk/10.0.22000.0/readme.txt",
+            "Microsoft.WindowsAppSDK/lib/uap10.0.18362/Microsoft.UI.winmd",
+        ] {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, b"").unwrap();
+        }
+
+        let mut versions = std::collections::BTreeSet::new();
+        find_sdk_versions(&root, &root, &mut versions);
+        let versions: Vec<_> = versions.into_iter().map(|(_, version)| version).collect();
+        assert_eq!(versions, ["10.0.18362.0", "10.0.19041.0"]);
+
+        let find = |sdk_version| {
+            let mut paths = std::collections::BTreeMap::new();
+            find_winmds(&root, &root, sdk_version, &mut paths);
+            paths
+                .values()
+                .map(|path| path.strip_prefix(&root).unwrap().to_path_buf())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            find(None),
+            [
+                Path::new("Microsoft.WindowsAppSDK/lib/uap10.0.18362/Microsoft.UI.winmd"),
+                Path::new("Windows.Win32.winmd"),
+                Path::new("sdk/10.0.19041.0/Windows.winmd"),
+            ]
+        );
+        assert_eq!(
+            find(Some("10.0.18362.0")),
+            [
+                Path::new("Microsoft.WindowsAppSDK/lib/uap10.0.18362/Microsoft.UI.winmd"),
+                Path::new("Windows.Win32.winmd"),
+                Path::new("sdk/10.0.18362.0/Windows.winmd"),
+            ]
+        );
+
+        assert!(is_sdk_version("10.0.19041.0"));
+        assert!(!is_sdk_version("uap10.0.18362"));
+        assert!(!is_sdk_version("10.0.19041"));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
 }
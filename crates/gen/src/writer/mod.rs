@@ -0,0 +1,627 @@
+use super::*;
+
+mod pe;
+mod stream;
+
+use stream::*;
+
+/// A WinRT class implemented in Rust, such as with `#[implement]`, that is described by a winmd
+/// file so that other languages may use it.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RuntimeClass {
+    /// The namespace of the class, such as `Contoso.Widgets`.
+    pub namespace: String,
+    /// The name of the class within its namespace, such as `Widget`.
+    pub name: String,
+    /// The interfaces implemented by the class, the first of which is its default interface.
+    pub interfaces: Vec<tables::TypeDef>,
+    /// Whether the class may be created without arguments, in which case it is marked with the
+    /// `Activatable` attribute and given a default constructor.
+    pub activatable: bool,
+}
+
+/// Returns the bytes of a winmd file describing the given runtime classes, whose assembly is
+/// named `assembly`. The interfaces that the classes implement are referred to by name rather
+/// than described again, so that the file is read along with the metadata defining them.
+pub fn write_winmd(assembly: &str, classes: &[RuntimeClass]) -> Vec<u8> {
+    let mut writer = Writer::default();
+    writer.write(assembly, classes);
+    pe::image(&writer.metadata())
+}
+
+/// Returns the namespace shared by all of the classes, which names the winmd file describing
+/// them since WinRT looks up the metadata of a type by the longest prefix of its namespace.
+pub fn common_namespace(classes: &[RuntimeClass]) -> String {
+    let mut common: Vec<&str> = match classes.first() {
+        Some(class) => class.namespace.split('.').collect(),
+        None => return String::new(),
+    };
+
+    for class in &classes[1..] {
+        let shared = common
+            .iter()
+            .zip(class.namespace.split('.'))
+            .take_while(|(a, b)| *a == b)
+            .count();
+
+        common.truncate(shared);
+    }
+
+    common.join(".")
+}
+
+// The flags of the rows written, as given by ECMA-335 II.23.1.
+const TYPE_PUBLIC_SEALED_RUNTIME: u32 = 0x4101;
+const METHOD_INTERFACE_IMPL: u32 = 0x01e6;
+const METHOD_CONSTRUCTOR: u32 = 0x1886;
+const METHOD_SPECIAL_NAME: u32 = 0x0800;
+const METHOD_IMPL_RUNTIME: u32 = 0x0003;
+const ASSEMBLY_WINDOWS_RUNTIME: u32 = 0x0200;
+
+// The version of the `Activatable` attribute, which is that of the class rather than of an
+// API contract.
+const ACTIVATABLE_VERSION: u32 = 0x0001_0000;
+
+#[derive(Default)]
+struct Writer {
+    tables: Tables,
+    strings: Vec<u8>,
+    string_indexes: BTreeMap<String, u32>,
+    blobs: Vec<u8>,
+    blob_indexes: BTreeMap<Vec<u8>, u32>,
+    guids: Vec<u8>,
+    assembly_refs: BTreeMap<String, u32>,
+    type_refs: BTreeMap<(String, String), u32>,
+    member_refs: BTreeMap<(u32, String, Vec<u8>), u32>,
+}
+
+impl Writer {
+    fn write(&mut self, assembly: &str, classes: &[RuntimeClass]) {
+        self.strings.push(0);
+        self.blobs.push(0);
+
+        let module_name = self.string(&format!("{}.winmd", assembly));
+        self.guids.extend_from_slice(&mvid(assembly, classes));
+        self.tables.push(MODULE, vec![0, module_name, 1, 0, 0]);
+
+        let assembly_name = self.string(assembly);
+        let empty = self.string("");
+        let empty_blob = self.blob(&[]);
+        self.tables.push(
+            ASSEMBLY,
+            vec![
+                0x8004,
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                ASSEMBLY_WINDOWS_RUNTIME,
+                empty_blob,
+                assembly_name,
+                empty,
+            ],
+        );
+
+        // The first type is the pseudo-type holding the module's global members, of which
+        // there are none.
+        let module_type = self.string("<Module>");
+        self.tables
+            .push(TYPE_DEF, vec![0, module_type, empty, 0, 1, 1]);
+
+        let object = self.type_ref("System", "Object", "mscorlib");
+
+        for class in classes {
+            self.write_class(class, object);
+        }
+
+        self.tables.sort(CUSTOM_ATTRIBUTE);
+    }
+
+    fn write_class(&mut self, class: &RuntimeClass, object: u32) {
+        let name = self.string(&class.name);
+        let namespace = self.string(&class.namespace);
+        let first_method = self.tables.len(METHOD_DEF) + 1;
+
+        let type_def = self.tables.push(
+            TYPE_DEF,
+            vec![
+                TYPE_PUBLIC_SEALED_RUNTIME,
+                name,
+                namespace,
+                coded(1, 2, object),
+                1,
+                first_method,
+            ],
+        );
+
+        if class.activatable {
+            let constructor = self.string(".ctor");
+            let signature = self.blob(&[0x20, 0x00, 0x01]);
+            let first_param = self.tables.len(PARAM) + 1;
+            self.tables.push(
+                METHOD_DEF,
+                vec![
+                    0,
+                    METHOD_IMPL_RUNTIME,
+                    METHOD_CONSTRUCTOR,
+                    constructor,
+                    signature,
+                    first_param,
+                ],
+            );
+
+            let mut value = vec![0x01, 0x00];
+            value.extend_from_slice(&ACTIVATABLE_VERSION.to_le_bytes());
+            value.extend_from_slice(&[0x00, 0x00]);
+            self.attribute(
+                coded(3, 5, type_def),
+                "ActivatableAttribute",
+                &[0x20, 0x01, 0x01, 0x09],
+                &value,
+            );
+        }
+
+        let mut names = BTreeSet::new();
+
+        for (index, interface) in class.interfaces.iter().enumerate() {
+            let interface_ref = self.type_def_ref(interface);
+
+            let interface_impl = self
+                .tables
+                .push(INTERFACE_IMPL, vec![type_def, coded(1, 2, interface_ref)]);
+
+            if index == 0 {
+                self.attribute(
+                    coded(5, 5, interface_impl),
+                    "DefaultAttribute",
+                    &[0x20, 0x00, 0x01],
+                    &[0x01, 0x00, 0x00, 0x00],
+                );
+            }
+
+            for method in interface.methods() {
+                self.write_method(type_def, interface, interface_ref, &method, &mut names);
+            }
+        }
+    }
+
+    // Writes the class's implementation of an interface method along with the `MethodImpl`
+    // relating the two. Methods sharing the name of an earlier method are qualified by the name
+    // of their interface, as they would be by an explicit implementation.
+    fn write_method(
+        &mut self,
+        type_def: u32,
+        interface: &tables::TypeDef,
+        interface_ref: u32,
+        method: &tables::MethodDef,
+        names: &mut BTreeSet<String>,
+    ) {
+        let signature = self.signature(method);
+        let declaration = self.member_ref(coded(1, 3, interface_ref), method.name(), &signature);
+
+        let name = if names.insert(method.name().to_string()) {
+            method.name().to_string()
+        } else {
+            format!(
+                "{}.{}.{}",
+                interface.namespace(),
+                interface.name(),
+                method.name()
+            )
+        };
+
+        let flags = if method.flags().special() {
+            METHOD_INTERFACE_IMPL | METHOD_SPECIAL_NAME
+        } else {
+            METHOD_INTERFACE_IMPL
+        };
+
+        let name = self.string(&name);
+        let signature = self.blob(&signature);
+        let first_param = self.tables.len(PARAM) + 1;
+        let body = self.tables.push(
+            METHOD_DEF,
+            vec![0, METHOD_IMPL_RUNTIME, flags, name, signature, first_param],
+        );
+
+        for param in method.params() {
+            let name = self.string(param.name());
+            self.tables
+                .push(PARAM, vec![param.flags().0, param.sequence(), name]);
+        }
+
+        self.tables.push(
+            METHOD_IMPL,
+            vec![type_def, coded(0, 1, body), coded(1, 1, declaration)],
+        );
+    }
+
+    // Adds a custom attribute from `Windows.Foundation.Metadata` to the row with the given
+    // `HasCustomAttribute` coded index.
+    fn attribute(&mut self, parent: u32, name: &str, signature: &[u8], value: &[u8]) {
+        let attribute = self.type_ref_by_name("Windows.Foundation.Metadata", name);
+        let constructor = self.member_ref(coded(1, 3, attribute), ".ctor", signature);
+        let value = self.blob(value);
+
+        self.tables.push(
+            CUSTOM_ATTRIBUTE,
+            vec![parent, coded(3, 3, constructor), value],
+        );
+    }
+
+    // Returns the method's signature with the types that it refers to rewritten to refer to the
+    // rows of the file being written.
+    fn signature(&mut self, method: &tables::MethodDef) -> Vec<u8> {
+        let blob = method.0.blob(4);
+        let bytes = &blob.file.bytes[blob.offset..blob.offset + blob.size];
+        let mut reader = SignatureReader {
+            file: blob.file,
+            bytes,
+            position: 0,
+        };
+        let mut result = Vec::new();
+
+        let calling_convention = reader.byte();
+        result.push(calling_convention);
+
+        let param_count = reader.unsigned();
+        push_unsigned(&mut result, param_count);
+
+        for _ in 0..=param_count {
+            self.copy_type(&mut reader, &mut result);
+        }
+
+        result
+    }
+
+    fn copy_type(&mut self, reader: &mut SignatureReader, result: &mut Vec<u8>) {
+        let element = reader.byte();
+        result.push(element);
+
+        match element {
+            // Custom modifiers, by-reference, pointers, and arrays precede another type.
+            0x1f | 0x20 => {
+                self.copy_type_reference(reader, result);
+                self.copy_type(reader, result);
+            }
+            0x0f | 0x10 | 0x1d => self.copy_type(reader, result),
+            // Classes and value types refer to their definition.
+            0x11 | 0x12 => self.copy_type_reference(reader, result),
+            // Generic parameters are numbered.
+            0x13 | 0x1e => {
+                let number = reader.unsigned();
+                push_unsigned(result, number);
+            }
+            0x15 => {
+                self.copy_type(reader, result);
+                let count = reader.unsigned();
+                push_unsigned(result, count);
+
+                for _ in 0..count {
+                    self.copy_type(reader, result);
+                }
+            }
+            0x01..=0x0e | 0x16 | 0x18 | 0x19 | 0x1c => {}
+            _ => unexpected!(),
+        }
+    }
+
+    fn copy_type_reference(&mut self, reader: &mut SignatureReader, result: &mut Vec<u8>) {
+        let type_ref = match TypeDefOrRef::decode(reader.file, reader.unsigned()) {
+            TypeDefOrRef::TypeDef(def) => self.type_def_ref(&def),
+            TypeDefOrRef::TypeRef(type_ref) => {
+                let scope = match type_ref.scope() {
+                    ResolutionScope::AssemblyRef(assembly) => assembly.name(),
+                    ResolutionScope::Module(_) => assembly_name(type_ref.0.file),
+                    _ => unexpected!(),
+                };
+
+                self.type_ref(type_ref.namespace(), type_ref.name(), scope)
+            }
+            TypeDefOrRef::TypeSpec(_) => unexpected!(),
+        };
+
+        push_unsigned(result, coded(1, 2, type_ref));
+    }
+
+    // Returns the `TypeRef` of a type defined by the metadata being read, whose assembly is
+    // named after its winmd file.
+    fn type_def_ref(&mut self, def: &tables::TypeDef) -> u32 {
+        self.type_ref(def.namespace(), def.name(), assembly_name(def.row.file))
+    }
+
+    fn type_ref_by_name(&mut self, namespace: &str, name: &str) -> u32 {
+        let reader = TypeReader::get();
+
+        match reader.get_type_name(namespace, name) {
+            Some((namespace, name)) => self.type_def_ref(&reader.resolve_type_def(namespace, name)),
+            None => self.type_ref(namespace, name, "Windows"),
+        }
+    }
+
+    fn type_ref(&mut self, namespace: &str, name: &str, assembly: &str) -> u32 {
+        let key = (namespace.to_string(), name.to_string());
+
+        if let Some(index) = self.type_refs.get(&key) {
+            return *index;
+        }
+
+        let scope = self.assembly_ref(assembly);
+        let name = self.string(name);
+        let namespace = self.string(namespace);
+        let index = self
+            .tables
+            .push(TYPE_REF, vec![coded(2, 2, scope), name, namespace]);
+
+        self.type_refs.insert(key, index);
+        index
+    }
+
+    fn assembly_ref(&mut self, name: &str) -> u32 {
+        if let Some(index) = self.assembly_refs.get(name) {
+            return *index;
+        }
+
+        // WinRT assemblies are versioned by contract rather than by assembly version.
+        let (flags, public_key_token) = if name == "mscorlib" {
+            (
+                0,
+                self.blob(&[0xb7, 0x7a, 0x5c, 0x56, 0x19, 0x34, 0xe0, 0x89]),
+            )
+        } else {
+            (ASSEMBLY_WINDOWS_RUNTIME, self.blob(&[]))
+        };
+
+        let empty = self.string("");
+        let empty_blob = self.blob(&[]);
+        let string = self.string(name);
+        let index = self.tables.push(
+            ASSEMBLY_REF,
+            vec![
+                0xff,
+                0xff,
+                0xff,
+                0xff,
+                flags,
+                public_key_token,
+                string,
+                empty,
+                empty_blob,
+            ],
+        );
+
+        self.assembly_refs.insert(name.to_string(), index);
+        index
+    }
+
+    fn member_ref(&mut self, parent: u32, name: &str, signature: &[u8]) -> u32 {
+        let key = (parent, name.to_string(), signature.to_vec());
+
+        if let Some(index) = self.member_refs.get(&key) {
+            return *index;
+        }
+
+        let name = self.string(name);
+        let signature = self.blob(signature);
+        let index = self.tables.push(MEMBER_REF, vec![parent, name, signature]);
+
+        self.member_refs.insert(key, index);
+        index
+    }
+
+    fn string(&mut self, value: &str) -> u32 {
+        if value.is_empty() {
+            return 0;
+        }
+
+        if let Some(index) = self.string_indexes.get(value) {
+            return *index;
+        }
+
+        let index = self.strings.len() as u32;
+        self.strings.extend_from_slice(value.as_bytes());
+        self.strings.push(0);
+        self.string_indexes.insert(value.to_string(), index);
+        index
+    }
+
+    fn blob(&mut self, value: &[u8]) -> u32 {
+        if value.is_empty() {
+            return 0;
+        }
+
+        if let Some(index) = self.blob_indexes.get(value) {
+            return *index;
+        }
+
+        let index = self.blobs.len() as u32;
+        push_unsigned(&mut self.blobs, value.len() as u32);
+        self.blobs.extend_from_slice(value);
+        self.blob_indexes.insert(value.to_vec(), index);
+        index
+    }
+
+    // Returns the metadata, starting with its root and the headers of its streams.
+    fn metadata(&mut self) -> Vec<u8> {
+        align(&mut self.strings, 4);
+        align(&mut self.blobs, 4);
+
+        let streams: [(&[u8], Vec<u8>); 4] = [
+            (b"#~\0\0", self.tables.stream()),
+            (b"#Strings\0\0\0\0", std::mem::take(&mut self.strings)),
+            (b"#Blob\0\0\0", std::mem::take(&mut self.blobs)),
+            (b"#GUID\0\0\0", std::mem::take(&mut self.guids)),
+        ];
+
+        let version = b"WindowsRuntime 1.4\0\0";
+
+        let mut metadata = Vec::new();
+        push_u32(&mut metadata, 0x424a_5342);
+        push_u16(&mut metadata, 1);
+        push_u16(&mut metadata, 1);
+        push_u32(&mut metadata, 0);
+        push_u32(&mut metadata, version.len() as u32);
+        metadata.extend_from_slice(version);
+        push_u16(&mut metadata, 0);
+        push_u16(&mut metadata, streams.len() as u16);
+
+        let headers_size: usize = streams.iter().map(|(name, _)| 8 + name.len()).sum();
+        let mut offset = (metadata.len() + headers_size) as u32;
+
+        for (name, stream) in &streams {
+            push_u32(&mut metadata, offset);
+            push_u32(&mut metadata, stream.len() as u32);
+            metadata.extend_from_slice(name);
+            offset += stream.len() as u32;
+        }
+
+        for (_, stream) in &streams {
+            metadata.extend_from_slice(stream);
+        }
+
+        metadata
+    }
+}
+
+struct SignatureReader<'a> {
+    file: &'static File,
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> SignatureReader<'a> {
+    fn byte(&mut self) -> u8 {
+        let byte = self.bytes[self.position];
+        self.position += 1;
+        byte
+    }
+
+    fn unsigned(&mut self) -> u32 {
+        let first = self.byte() as u32;
+
+        if first & 0x80 == 0 {
+            first
+        } else if first & 0xc0 == 0x80 {
+            (first & 0x3f) << 8 | self.byte() as u32
+        } else {
+            let rest = (0..3).fold(0, |value, _| value << 8 | self.byte() as u32);
+            (first & 0x1f) << 24 | rest
+        }
+    }
+}
+
+// Writes an unsigned integer in the compressed form of ECMA-335 II.23.2.
+fn push_unsigned(bytes: &mut Vec<u8>, value: u32) {
+    if value < 0x80 {
+        bytes.push(value as u8);
+    } else if value < 0x4000 {
+        bytes.extend_from_slice(&(value as u16 | 0x8000).to_be_bytes());
+    } else {
+        bytes.extend_from_slice(&(value | 0xc000_0000).to_be_bytes());
+    }
+}
+
+// The name of the assembly defined by a winmd file, which is the name of the file.
+fn assembly_name(file: &File) -> &str {
+    file.name.trim_end_matches(".winmd")
+}
+
+// The module's identifier is derived from its contents so that writing the same classes again
+// writes the same file.
+fn mvid(assembly: &str, classes: &[RuntimeClass]) -> [u8; 16] {
+    use std::hash::{Hash, Hasher};
+
+    let mut result = [0; 16];
+
+    for (seed, half) in result.chunks_mut(8).enumerate() {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        seed.hash(&mut hasher);
+        assembly.hash(&mut hasher);
+
+        for class in classes {
+            class.namespace.hash(&mut hasher);
+            class.name.hash(&mut hasher);
+            class.activatable.hash(&mut hasher);
+
+            for interface in &class.interfaces {
+                interface.full_name().hash(&mut hasher);
+            }
+        }
+
+        half.copy_from_slice(&hasher.finish().to_le_bytes());
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(bytes: Vec<u8>) -> &'static File {
+        Box::leak(Box::new(File::from_bytes(
+            "Contoso.winmd".to_string(),
+            bytes,
+        )))
+    }
+
+    #[test]
+    fn test_write_class() {
+        let reader = TypeReader::get();
+        let stringable = reader.resolve_type_def("Windows.Foundation", "IStringable");
+        let closable = reader.resolve_type_def("Windows.Foundation", "IClosable");
+
+        let classes = vec![RuntimeClass {
+            namespace: "Contoso".to_string(),
+            name: "Widget".to_string(),
+            interfaces: vec![stringable, closable],
+            activatable: true,
+        }];
+
+        let file = read(write_winmd("Contoso", &classes));
+        let class: tables::TypeDef = Row::new(1, TableIndex::TypeDef, file).into();
+
+        assert_eq!(class.full_name(), ("Contoso", "Widget"));
+        assert_eq!(class.extends(), ("System", "Object"));
+        assert!(class.kind() == TypeKind::Class);
+        assert!(class.has_attribute("ActivatableAttribute"));
+
+        let methods: Vec<&str> = class.methods().map(|method| method.name()).collect();
+        assert_eq!(methods, [".ctor", "ToString", "Close"]);
+
+        let interfaces: Vec<(&str, &str)> = class
+            .interface_impls()
+            .map(|interface_impl| match interface_impl.interface() {
+                TypeDefOrRef::TypeRef(type_ref) => type_ref.full_name(),
+                _ => unexpected!(),
+            })
+            .collect();
+
+        assert_eq!(
+            interfaces,
+            [
+                ("Windows.Foundation", "IStringable"),
+                ("Windows.Foundation", "IClosable")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_common_namespace() {
+        let class = |namespace: &str| RuntimeClass {
+            namespace: namespace.to_string(),
+            name: "Widget".to_string(),
+            interfaces: Vec::new(),
+            activatable: false,
+        };
+
+        assert_eq!(
+            common_namespace(&[class("Contoso.Widgets"), class("Contoso.Widgets.Controls")]),
+            "Contoso.Widgets"
+        );
+        assert_eq!(
+            common_namespace(&[class("Contoso.Widgets"), class("Contoso.Gadgets")]),
+            "Contoso"
+        );
+    }
+}
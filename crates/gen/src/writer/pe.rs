@@ -0,0 +1,109 @@
+use super::*;
+
+const FILE_ALIGNMENT: usize = 0x200;
+const SECTION_ALIGNMENT: u32 = 0x1000;
+const SECTION_RVA: u32 = 0x2000;
+const DOS_HEADER_SIZE: usize = 0x40;
+const CLI_HEADER_SIZE: u32 = 0x48;
+
+/// Wraps the metadata in the PE image that winmd files share with .NET assemblies: a DOS
+/// header, the PE headers, and a single section holding the CLI header and the metadata.
+pub fn image(metadata: &[u8]) -> Vec<u8> {
+    let section_size = CLI_HEADER_SIZE + metadata.len() as u32;
+    let raw_size = round_up(section_size as usize, FILE_ALIGNMENT) as u32;
+    let image_size =
+        SECTION_RVA + round_up(section_size as usize, SECTION_ALIGNMENT as usize) as u32;
+
+    let mut bytes = Vec::new();
+
+    // The DOS header only needs its signature and the offset of the PE headers.
+    push_u16(&mut bytes, 0x5a4d);
+    bytes.resize(DOS_HEADER_SIZE - 4, 0);
+    push_u32(&mut bytes, DOS_HEADER_SIZE as u32);
+
+    bytes.extend_from_slice(b"PE\0\0");
+
+    // The file header of a 32-bit DLL with one section.
+    push_u16(&mut bytes, 0x14c);
+    push_u16(&mut bytes, 1);
+    push_u32(&mut bytes, 0);
+    push_u32(&mut bytes, 0);
+    push_u32(&mut bytes, 0);
+    push_u16(&mut bytes, 0xe0);
+    push_u16(&mut bytes, 0x2102);
+
+    // The PE32 optional header.
+    push_u16(&mut bytes, 0x10b);
+    bytes.push(11);
+    bytes.push(0);
+    push_u32(&mut bytes, raw_size);
+    push_u32(&mut bytes, 0);
+    push_u32(&mut bytes, 0);
+    push_u32(&mut bytes, 0);
+    push_u32(&mut bytes, SECTION_RVA);
+    push_u32(&mut bytes, 0);
+    push_u32(&mut bytes, 0x0040_0000);
+    push_u32(&mut bytes, SECTION_ALIGNMENT);
+    push_u32(&mut bytes, FILE_ALIGNMENT as u32);
+    push_u16(&mut bytes, 6);
+    push_u16(&mut bytes, 0);
+    push_u16(&mut bytes, 0);
+    push_u16(&mut bytes, 0);
+    push_u16(&mut bytes, 6);
+    push_u16(&mut bytes, 0);
+    push_u32(&mut bytes, 0);
+    push_u32(&mut bytes, image_size);
+    push_u32(&mut bytes, FILE_ALIGNMENT as u32);
+    push_u32(&mut bytes, 0);
+    push_u16(&mut bytes, 3);
+    push_u16(&mut bytes, 0x8540);
+    push_u32(&mut bytes, 0x0010_0000);
+    push_u32(&mut bytes, 0x1000);
+    push_u32(&mut bytes, 0x0010_0000);
+    push_u32(&mut bytes, 0x1000);
+    push_u32(&mut bytes, 0);
+    push_u32(&mut bytes, 16);
+
+    // Of the data directories, only the CLI header's is present.
+    for directory in 0..16 {
+        if directory == 14 {
+            push_u32(&mut bytes, SECTION_RVA);
+            push_u32(&mut bytes, CLI_HEADER_SIZE);
+        } else {
+            push_u32(&mut bytes, 0);
+            push_u32(&mut bytes, 0);
+        }
+    }
+
+    // The section header.
+    bytes.extend_from_slice(b".text\0\0\0");
+    push_u32(&mut bytes, section_size);
+    push_u32(&mut bytes, SECTION_RVA);
+    push_u32(&mut bytes, raw_size);
+    push_u32(&mut bytes, FILE_ALIGNMENT as u32);
+    push_u32(&mut bytes, 0);
+    push_u32(&mut bytes, 0);
+    push_u16(&mut bytes, 0);
+    push_u16(&mut bytes, 0);
+    push_u32(&mut bytes, 0x6000_0020);
+
+    bytes.resize(FILE_ALIGNMENT, 0);
+
+    // The CLI header, which locates the metadata immediately following it.
+    push_u32(&mut bytes, CLI_HEADER_SIZE);
+    push_u16(&mut bytes, 2);
+    push_u16(&mut bytes, 5);
+    push_u32(&mut bytes, SECTION_RVA + CLI_HEADER_SIZE);
+    push_u32(&mut bytes, metadata.len() as u32);
+    push_u32(&mut bytes, 1);
+    bytes.resize(FILE_ALIGNMENT + CLI_HEADER_SIZE as usize, 0);
+
+    bytes.extend_from_slice(metadata);
+    bytes.resize(FILE_ALIGNMENT + raw_size as usize, 0);
+    bytes
+}
+
+// The alignments are powers of two.
+fn round_up(size: usize, alignment: usize) -> usize {
+    (size + alignment - 1) & !(alignment - 1)
+}
@@ -0,0 +1,209 @@
+use super::*;
+
+// The numbers of the metadata tables that are written, as given by ECMA-335 II.22.
+pub const MODULE: usize = 0x00;
+pub const TYPE_REF: usize = 0x01;
+pub const TYPE_DEF: usize = 0x02;
+pub const FIELD: usize = 0x04;
+pub const METHOD_DEF: usize = 0x06;
+pub const PARAM: usize = 0x08;
+pub const INTERFACE_IMPL: usize = 0x09;
+pub const MEMBER_REF: usize = 0x0a;
+pub const CUSTOM_ATTRIBUTE: usize = 0x0c;
+pub const METHOD_IMPL: usize = 0x19;
+pub const TYPE_SPEC: usize = 0x1b;
+pub const ASSEMBLY: usize = 0x20;
+pub const ASSEMBLY_REF: usize = 0x23;
+
+// The kind of value stored in a column, which determines its width. Heap indexes are always
+// four bytes wide while the width of table and coded indexes depends on the tables' row counts.
+#[derive(Copy, Clone)]
+enum Column {
+    Fixed(usize),
+    Heap,
+    Index(usize),
+    // A coded index with the given number of tag bits, listing only the tables that may be
+    // written since the others are empty and don't affect the width.
+    Coded(u32, &'static [usize]),
+}
+
+use Column::*;
+
+const TYPE_DEF_OR_REF: Column = Coded(2, &[TYPE_DEF, TYPE_REF, TYPE_SPEC]);
+const RESOLUTION_SCOPE: Column = Coded(2, &[MODULE, ASSEMBLY_REF, TYPE_REF]);
+const MEMBER_REF_PARENT: Column = Coded(3, &[TYPE_DEF, TYPE_REF, METHOD_DEF, TYPE_SPEC]);
+const METHOD_DEF_OR_REF: Column = Coded(1, &[METHOD_DEF, MEMBER_REF]);
+const CUSTOM_ATTRIBUTE_TYPE: Column = Coded(3, &[METHOD_DEF, MEMBER_REF]);
+
+const HAS_CUSTOM_ATTRIBUTE: Column = Coded(
+    5,
+    &[
+        METHOD_DEF,
+        TYPE_REF,
+        TYPE_DEF,
+        PARAM,
+        INTERFACE_IMPL,
+        MEMBER_REF,
+        MODULE,
+        TYPE_SPEC,
+        ASSEMBLY,
+        ASSEMBLY_REF,
+    ],
+);
+
+fn columns(table: usize) -> &'static [Column] {
+    match table {
+        MODULE => &[Fixed(2), Heap, Heap, Heap, Heap],
+        TYPE_REF => &[RESOLUTION_SCOPE, Heap, Heap],
+        TYPE_DEF => &[
+            Fixed(4),
+            Heap,
+            Heap,
+            TYPE_DEF_OR_REF,
+            Index(FIELD),
+            Index(METHOD_DEF),
+        ],
+        METHOD_DEF => &[Fixed(4), Fixed(2), Fixed(2), Heap, Heap, Index(PARAM)],
+        PARAM => &[Fixed(2), Fixed(2), Heap],
+        INTERFACE_IMPL => &[Index(TYPE_DEF), TYPE_DEF_OR_REF],
+        MEMBER_REF => &[MEMBER_REF_PARENT, Heap, Heap],
+        CUSTOM_ATTRIBUTE => &[HAS_CUSTOM_ATTRIBUTE, CUSTOM_ATTRIBUTE_TYPE, Heap],
+        METHOD_IMPL => &[Index(TYPE_DEF), METHOD_DEF_OR_REF, METHOD_DEF_OR_REF],
+        TYPE_SPEC => &[Heap],
+        ASSEMBLY => &[
+            Fixed(4),
+            Fixed(2),
+            Fixed(2),
+            Fixed(2),
+            Fixed(2),
+            Fixed(4),
+            Heap,
+            Heap,
+            Heap,
+        ],
+        ASSEMBLY_REF => &[
+            Fixed(2),
+            Fixed(2),
+            Fixed(2),
+            Fixed(2),
+            Fixed(4),
+            Heap,
+            Heap,
+            Heap,
+            Heap,
+        ],
+        _ => unexpected!(),
+    }
+}
+
+/// The rows of the metadata tables, each row holding the values of its columns.
+#[derive(Default)]
+pub struct Tables {
+    rows: BTreeMap<usize, Vec<Vec<u32>>>,
+}
+
+impl Tables {
+    /// Adds a row to the table and returns its one-based index.
+    pub fn push(&mut self, table: usize, row: Vec<u32>) -> u32 {
+        debug_assert_eq!(row.len(), columns(table).len());
+        let rows = self.rows.entry(table).or_default();
+        rows.push(row);
+        rows.len() as u32
+    }
+
+    pub fn len(&self, table: usize) -> u32 {
+        self.rows.get(&table).map_or(0, |rows| rows.len() as u32)
+    }
+
+    /// Sorts the rows of a table by their first column, as required of tables such as
+    /// `CustomAttribute` that are searched by their parent.
+    pub fn sort(&mut self, table: usize) {
+        if let Some(rows) = self.rows.get_mut(&table) {
+            rows.sort_by_key(|row| row[0]);
+        }
+    }
+
+    fn width(&self, column: Column) -> usize {
+        match column {
+            Fixed(width) => width,
+            Heap => 4,
+            Index(table) => {
+                if self.len(table) < 1 << 16 {
+                    2
+                } else {
+                    4
+                }
+            }
+            Coded(bits, tables) => {
+                if tables
+                    .iter()
+                    .all(|table| self.len(*table) < 1 << (16 - bits))
+                {
+                    2
+                } else {
+                    4
+                }
+            }
+        }
+    }
+
+    /// Returns the `#~` stream holding the tables.
+    pub fn stream(&self) -> Vec<u8> {
+        let mut valid = 0u64;
+
+        for table in self.rows.keys() {
+            valid |= 1 << table;
+        }
+
+        // The tables that are written in sorted order, which includes those that are written
+        // in the order that they are sorted by anyway.
+        let sorted = 1u64 << INTERFACE_IMPL | 1 << CUSTOM_ATTRIBUTE | 1 << METHOD_IMPL;
+
+        let mut stream = Vec::new();
+        push_u32(&mut stream, 0);
+        stream.push(2);
+        stream.push(0);
+        // Every heap index is four bytes wide.
+        stream.push(0b111);
+        stream.push(1);
+        stream.extend_from_slice(&valid.to_le_bytes());
+        stream.extend_from_slice(&sorted.to_le_bytes());
+
+        for rows in self.rows.values() {
+            push_u32(&mut stream, rows.len() as u32);
+        }
+
+        for (table, rows) in &self.rows {
+            let widths: Vec<usize> = columns(*table)
+                .iter()
+                .map(|column| self.width(*column))
+                .collect();
+
+            for row in rows {
+                for (value, width) in row.iter().zip(&widths) {
+                    stream.extend_from_slice(&value.to_le_bytes()[..*width]);
+                }
+            }
+        }
+
+        align(&mut stream, 4);
+        stream
+    }
+}
+
+/// Returns the value of a coded index referring to the `row` of the table with the given `tag`.
+pub fn coded(tag: u32, bits: u32, row: u32) -> u32 {
+    row << bits | tag
+}
+
+pub fn push_u16(bytes: &mut Vec<u8>, value: u16) {
+    bytes.extend_from_slice(&value.to_le_bytes());
+}
+
+pub fn push_u32(bytes: &mut Vec<u8>, value: u32) {
+    bytes.extend_from_slice(&value.to_le_bytes());
+}
+
+pub fn align(bytes: &mut Vec<u8>, alignment: usize) {
+    bytes.resize((bytes.len() + alignment - 1) & !(alignment - 1), 0);
+}
@@ -0,0 +1,147 @@
+use syn::parse::*;
+use syn::*;
+
+// A runtime class authored with `#[implement(class = "...")]`, along with the namespace-qualified
+// names of the types listed by the attribute in the order they are listed.
+#[derive(Debug, PartialEq)]
+pub struct AuthoredClass {
+    pub name: String,
+    pub types: Vec<String>,
+}
+
+// Finds the runtime classes authored by the structs of a Rust source file, including those
+// within inline modules.
+pub fn authored_classes(source: &str) -> Result<Vec<AuthoredClass>> {
+    let mut classes = Vec::new();
+    find_classes(&parse_file(source)?.items, &mut classes)?;
+    Ok(classes)
+}
+
+fn find_classes(items: &[Item], classes: &mut Vec<AuthoredClass>) -> Result<()> {
+    for item in items {
+        match item {
+            Item::Struct(item) => {
+                for attr in &item.attrs {
+                    if matches!(attr.path.segments.last(), Some(last) if last.ident == "implement")
+                    {
+                        if let Some(class) = attr.parse_args_with(parse_implement)? {
+                            classes.push(class);
+                        }
+                    }
+                }
+            }
+            Item::Mod(item) => {
+                if let Some((_, items)) = &item.content {
+                    find_classes(items, classes)?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+// Only the class name and the listed types describe the class, so the other options, along with
+// the class being extended and the methods being overridden, are skipped.
+fn parse_implement(input: ParseStream) -> Result<Option<AuthoredClass>> {
+    let mut class = None;
+    let mut types = Vec::new();
+
+    while !input.is_empty() {
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let name: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            match input.parse()? {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(value),
+                    ..
+                }) if name == "class" => class = Some(value.value()),
+                _ => {}
+            }
+        } else if input.parse::<Token![override]>().is_ok() {
+            while input.parse::<Ident>().is_ok() {}
+        } else if input.peek(Ident)
+            && input.peek2(Ident)
+            && input.fork().parse::<Ident>()? == "extend"
+        {
+            input.parse::<Ident>()?;
+            input.parse::<UseTree>()?;
+        } else {
+            walk(&input.parse()?, &mut String::new(), &mut types);
+        }
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(class.map(|name| AuthoredClass { name, types }))
+}
+
+fn walk(tree: &UseTree, namespace: &mut String, types: &mut Vec<String>) {
+    match tree {
+        UseTree::Path(input) => {
+            let len = namespace.len();
+            namespace.push_str(&input.ident.to_string());
+            namespace.push('.');
+            walk(&input.tree, namespace, types);
+            namespace.truncate(len);
+        }
+        UseTree::Name(input) => types.push(format!("{}{}", namespace, input.ident)),
+        UseTree::Group(input) => {
+            for tree in &input.items {
+                walk(tree, namespace, types);
+            }
+        }
+        // `#[implement]` rejects globs and renames.
+        UseTree::Glob(_) | UseTree::Rename(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_authored_classes() {
+        let classes = authored_classes(
+            r#"
+            #[implement(class = "Test.Widget", agile = false, Windows::Foundation::{IStringable, IClosable})]
+            struct Widget();
+
+            #[implement(Windows::Foundation::IStringable)]
+            struct Unnamed();
+
+            mod app {
+                #[windows::implement(
+                    extend Windows::UI::Xaml::Application,
+                    override OnLaunched OnActivated,
+                    class = "Test.App",
+                    Windows::Foundation::IClosable,
+                )]
+                struct App();
+            }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            classes,
+            [
+                AuthoredClass {
+                    name: "Test.Widget".to_string(),
+                    types: vec![
+                        "Windows.Foundation.IStringable".to_string(),
+                        "Windows.Foundation.IClosable".to_string()
+                    ],
+                },
+                AuthoredClass {
+                    name: "Test.App".to_string(),
+                    types: vec!["Windows.Foundation.IClosable".to_string()],
+                },
+            ]
+        );
+    }
+}
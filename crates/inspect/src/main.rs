@@ -0,0 +1,402 @@
+mod classes;
+
+use classes::*;
+use windows_gen::*;
+
+const USAGE: &str = "Usage: windows_inspect [--winmd <path>]... [--config <path>] [--code] <type>
+       windows_inspect [--winmd <path>]... --config <path> --features <source>...
+       windows_inspect [--winmd <path>]... --classes <source>...
+
+Prints the methods, required interfaces, and dependencies of a type, such as
+`Windows.Foundation.Uri` or `Windows::Win32::Foundation::CloseHandle`, as `build!` would
+generate them. The metadata is read from the given winmd files and directories, or from the
+`.windows/winmd` directory of the current directory if none are given.
+
+Options:
+    --winmd <path>      Reads the winmd file or the winmd files within the directory
+    --config <path>     Applies the metadata and options of a `windows.toml` file
+    --code              Also prints the code generated for the type
+    --features <path>   Prints the features, with `function_features`, of the functions of the
+                        configuration's types that the Rust source file calls
+    --classes <path>    Writes the winmd describing the runtime classes authored with
+                        `#[implement(class = ...)]` in the Rust source file to the current
+                        directory, named after the namespace shared by the classes";
+
+fn main() {
+    let mut paths = Vec::<std::path::PathBuf>::new();
+    let mut config = None;
+    let mut code = false;
+    let mut sources = Vec::<std::path::PathBuf>::new();
+    let mut class_sources = Vec::<std::path::PathBuf>::new();
+    let mut name = None;
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--winmd" => match args.next() {
+                Some(path) => paths.push(path.into()),
+                None => fail("`--winmd` requires a path"),
+            },
+            "--config" => match args.next() {
+                Some(path) => config = Some(std::path::PathBuf::from(path)),
+                None => fail("`--config` requires a path"),
+            },
+            "--code" => code = true,
+            "--features" => match args.next() {
+                Some(path) => sources.push(path.into()),
+                None => fail("`--features` requires a path"),
+            },
+            "--classes" => match args.next() {
+                Some(path) => class_sources.push(path.into()),
+                None => fail("`--classes` requires a path"),
+            },
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return;
+            }
+            _ if !arg.starts_with('-') && name.is_none() => name = Some(arg),
+            _ => fail(&format!("Unexpected argument `{}`", arg)),
+        }
+    }
+
+    if !sources.is_empty() && config.is_none() {
+        fail("`--features` requires the configuration listing the types");
+    }
+
+    let config = config.map(|path| {
+        let config = Config::read(&path).unwrap_or_else(|error| fail(&error));
+        (config, path)
+    });
+
+    // The metadata named by the configuration is read unless winmd files are given, with paths
+    // relative to the configuration's directory.
+    match &config {
+        Some((config, path)) if paths.is_empty() && config.metadata.is_some() => {
+            let dir = path.parent().unwrap_or_else(|| std::path::Path::new(""));
+            config
+                .select_metadata(dir)
+                .unwrap_or_else(|error| fail(&error));
+        }
+        _ => {
+            if paths.is_empty() {
+                paths.push([".windows", "winmd"].iter().collect());
+            }
+
+            if let Some(path) = paths.iter().find(|path| !path.exists()) {
+                fail(&format!("Could not find `{}`", path.display()));
+            }
+
+            load_winmds(&paths);
+        }
+    }
+
+    if crate_winmds().is_empty() {
+        fail("No winmd files were found");
+    }
+
+    if !sources.is_empty() {
+        print_features(&config.unwrap().0, &sources);
+        return;
+    }
+
+    if !class_sources.is_empty() {
+        write_classes(&class_sources);
+        return;
+    }
+
+    let name = name.unwrap_or_else(|| fail("No type was given"));
+
+    let name = name.replace("::", ".");
+
+    let (namespace, name) = match name.rfind('.') {
+        Some(pos) => (&name[..pos], &name[pos + 1..]),
+        None => fail("The type must be qualified by its namespace"),
+    };
+
+    // The options of the configuration shape the generated code as they do for `build!`, while
+    // its types aren't imported so that only the dependencies of the type are listed.
+    if let Some((config, _)) = &config {
+        config.apply_options().unwrap_or_else(|error| fail(&error));
+    }
+
+    let reader = TypeReader::get_mut();
+
+    if !reader.import_type(namespace, name) {
+        fail(&format!("Could not find `{}.{}`", namespace, name));
+    }
+
+    // The code is generated relative to the type's namespace, as it is by `build!`.
+    let tree = reader.types.get_namespace(namespace).unwrap();
+    let entry = tree.types[name].clone();
+    let generated = entry.gen(&Gen::Relative(tree.namespace)).into_string();
+
+    println!("{}.{} ({})", namespace, name, describe(&entry.def));
+    print_section("Methods", signatures(&generated));
+
+    if let TypeRow::TypeDef(def) = &entry.def {
+        print_section("Interfaces", interfaces(&def.clone().with_generics()));
+    }
+
+    print_section("Dependencies", dependencies(&reader.types, namespace, name));
+
+    if code {
+        println!("\nCode:\n{}", generated);
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}\n\n{}", message, USAGE);
+    std::process::exit(1)
+}
+
+// Prints the features that the source files need for the functions they call, one per line, so
+// that the crate including the generated code may declare and enable them.
+fn print_features(config: &Config, sources: &[std::path::PathBuf]) {
+    config.apply().unwrap_or_else(|error| fail(&error));
+    let reader = TypeReader::get();
+    let mut features = BTreeSet::new();
+
+    for path in sources {
+        let source = std::fs::read_to_string(path)
+            .unwrap_or_else(|_| fail(&format!("Could not read `{}`", path.display())));
+
+        features.append(&mut reader.types.function_features(&source));
+    }
+
+    for feature in features {
+        println!("{}", feature);
+    }
+}
+
+// Writes the winmd describing the runtime classes authored by the source files, so that other
+// languages may use the component. The interfaces that the classes implement are found in the
+// metadata that was read.
+fn write_classes(sources: &[std::path::PathBuf]) {
+    let reader = TypeReader::get();
+    let mut classes = Vec::new();
+
+    for path in sources {
+        let source = std::fs::read_to_string(path)
+            .unwrap_or_else(|_| fail(&format!("Could not read `{}`", path.display())));
+
+        let authored = authored_classes(&source).unwrap_or_else(|error| {
+            fail(&format!("Could not parse `{}`: {}", path.display(), error))
+        });
+
+        for class in authored {
+            let pos = class.name.rfind('.').unwrap_or_else(|| {
+                fail(&format!(
+                    "`{}` must be qualified by its namespace",
+                    class.name
+                ))
+            });
+
+            // Classes listed by the attribute are implemented but don't describe the class.
+            let interfaces = class
+                .types
+                .iter()
+                .filter_map(|name| {
+                    let entry = name
+                        .rfind('.')
+                        .and_then(|pos| {
+                            reader
+                                .types
+                                .get_namespace(&name[..pos])?
+                                .get_type(&name[pos + 1..])
+                        })
+                        .unwrap_or_else(|| fail(&format!("Could not find `{}`", name)));
+
+                    match &entry.def {
+                        TypeRow::TypeDef(def) if def.kind() == TypeKind::Interface => {
+                            Some(def.clone())
+                        }
+                        _ => None,
+                    }
+                })
+                .collect();
+
+            classes.push(RuntimeClass {
+                namespace: class.name[..pos].to_string(),
+                name: class.name[pos + 1..].to_string(),
+                interfaces,
+                activatable: false,
+            });
+        }
+    }
+
+    if classes.is_empty() {
+        fail("No runtime classes were found");
+    }
+
+    let assembly = common_namespace(&classes);
+
+    if assembly.is_empty() {
+        fail("The runtime classes must share a namespace, which names the winmd");
+    }
+
+    let path = format!("{}.winmd", assembly);
+
+    std::fs::write(&path, write_winmd(&assembly, &classes))
+        .unwrap_or_else(|error| fail(&format!("Could not write `{}`: {}", path, error)));
+
+    println!("{}", path);
+}
+
+fn print_section(title: &str, lines: Vec<String>) {
+    if !lines.is_empty() {
+        println!("\n{}:", title);
+
+        for line in lines {
+            println!("    {}", line);
+        }
+    }
+}
+
+fn describe(row: &TypeRow) -> &'static str {
+    match row {
+        TypeRow::TypeDef(def) => match def.kind() {
+            TypeKind::Interface => "interface",
+            TypeKind::Class => "class",
+            TypeKind::Enum => "enum",
+            TypeKind::Struct => "struct",
+            TypeKind::Delegate => "delegate",
+        },
+        TypeRow::MethodDef(_) | TypeRow::Macro(_) => "function",
+        TypeRow::Field(_) => "constant",
+    }
+}
+
+// The signatures of the public functions in the generated code.
+fn signatures(generated: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut rest = generated;
+
+    while let Some(pos) = ["pub fn ", "pub unsafe fn "]
+        .iter()
+        .filter_map(|pattern| rest.find(pattern))
+        .min()
+    {
+        rest = &rest[pos..];
+        let end = rest
+            .find(|c| c == '{' || c == ';')
+            .unwrap_or_else(|| rest.len());
+        result.push(tidy(&rest[..end]));
+        rest = &rest[end..];
+    }
+
+    result
+}
+
+// The interfaces required by an interface or implemented by a class, along with a class's
+// static and composable factory interfaces.
+fn interfaces(def: &tables::TypeDef) -> Vec<String> {
+    let gen = Gen::Absolute;
+
+    match def.kind() {
+        TypeKind::Class => types::Class(def.clone())
+            .interfaces()
+            .iter()
+            .map(|info| {
+                let name = tidy(info.def.gen_name(&gen).as_str());
+
+                match info.kind {
+                    InterfaceKind::Default => format!("{} (default)", name),
+                    InterfaceKind::Overridable => format!("{} (overridable)", name),
+                    InterfaceKind::Static => format!("{} (static)", name),
+                    InterfaceKind::Composable => format!("{} (composable)", name),
+                    _ if info.is_base => format!("{} (base)", name),
+                    _ => name,
+                }
+            })
+            .collect(),
+        TypeKind::Interface => def
+            .interface_impls()
+            .filter_map(|child| match child.generic_interface(&def.generics) {
+                ElementType::TypeDef(def) => Some(tidy(def.gen_name(&gen).as_str())),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+// Every other type that importing the type includes, along with whether the type is generated
+// in full or only minimally, as a type that may be passed to and returned from methods.
+fn dependencies(tree: &TypeTree, namespace: &str, name: &str) -> Vec<String> {
+    let mut result = Vec::new();
+
+    for (type_name, entry) in &tree.types {
+        if entry.include == TypeInclude::None || (tree.namespace == namespace && *type_name == name)
+        {
+            continue;
+        }
+
+        let path = format!("{}.{}", tree.namespace, type_name);
+
+        if entry.include == TypeInclude::Full {
+            result.push(path);
+        } else {
+            result.push(format!("{} (minimal)", path));
+        }
+    }
+
+    for tree in tree.namespaces.values() {
+        result.append(&mut dependencies(tree, namespace, name));
+    }
+
+    result
+}
+
+// Removes the spaces that separate the generated tokens where `rustfmt` wouldn't write them,
+// along with empty generic parameter lists and trailing commas.
+fn tidy(tokens: &str) -> String {
+    fn is_name(token: &str) -> bool {
+        token.ends_with(|c: char| c.is_alphanumeric() || c == '_')
+            && !matches!(token, "impl" | "dyn" | "mut" | "const")
+    }
+
+    let mut result = String::new();
+    let mut previous = "";
+
+    for token in tokens.split_whitespace() {
+        let joined = matches!(previous, "" | "(" | "<" | "::<" | "&" | "*")
+            || previous.ends_with("::")
+            || matches!(token, "," | ":" | ")" | ">" | ">>")
+            || (token.starts_with("::") || token == "(" || token == "<") && is_name(previous)
+            || token == "(" && matches!(previous, ">" | ">>");
+
+        if !joined {
+            result.push(' ');
+        }
+
+        result.push_str(token);
+        previous = token;
+    }
+
+    result
+        .replace("::<", "<")
+        .replace("<>", "")
+        .replace(",)", ")")
+        .replace(",>", ">")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tidy_signature() {
+        assert_eq!(
+            tidy("pub fn CombineUri < 'a , > ( & self , relativeuri : impl :: windows :: IntoParam < 'a , :: windows :: HSTRING > , ) -> :: windows :: Result < Uri >"),
+            "pub fn CombineUri<'a>(&self, relativeuri: impl ::windows::IntoParam<'a, ::windows::HSTRING>) -> ::windows::Result<Uri>"
+        );
+        assert_eq!(
+            tidy("pub fn First < > ( & self , ) -> :: windows :: Result < IIterator :: < IKeyValuePair :: < K , V > > >"),
+            "pub fn First(&self) -> ::windows::Result<IIterator<IKeyValuePair<K, V>>>"
+        );
+        assert_eq!(
+            tidy("pub fn downgrade ( & self ) -> :: windows :: Result < :: windows :: Weak < Self >>"),
+            "pub fn downgrade(&self) -> ::windows::Result<::windows::Weak<Self>>"
+        );
+    }
+}
@@ -1,25 +1,126 @@
 use super::*;
 
-use gen::TypeReader;
+use gen::{expected_names, set_option, Config, ConfigValue, TypeReader, CONFIG_FILE, OPTIONS};
 use syn::parse::*;
 use syn::*;
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct BuildMacro {
     // TODO: add exports
+    /// An explicit path for the generated code, used instead of `OUT_DIR`.
+    pub output: Option<String>,
+    /// Whether to run rustfmt over the generated code.
+    pub rustfmt: bool,
+}
+
+impl Default for BuildMacro {
+    fn default() -> Self {
+        Self {
+            output: None,
+            rustfmt: true,
+        }
+    }
 }
 
 impl BuildMacro {
     // TODO: doesn't need to be member of BuildMacro
-    pub fn into_tokens_string(&self) -> String {
-        let reader = TypeReader::get();
+    /// Returns the generated code as a raw string literal. Each type is written straight into
+    /// the literal as it is generated rather than first collecting the output of every namespace.
+    pub fn into_raw_string(&self) -> TokenStream {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(b"r#\"");
+
+        TypeReader::get()
+            .write(&mut buffer)
+            .expect("Writing to a `Vec` cannot fail");
 
-        let ts = reader.gen().fold(TokenStream::new(), |mut accum, n| {
-            accum.combine(&n);
-            accum
+        buffer.extend_from_slice(b"\"#");
+        String::from_utf8(buffer)
+            .expect("Generated code is not valid UTF-8")
+            .into()
+    }
+
+    /// Writes the generated code to `path`, preceded by the `header` line, as each type is
+    /// generated so that the output is never held in memory in its entirety. The code for each
+    /// namespace is cached in a directory next to `path` so that only the namespaces whose types
+    /// have changed are generated again.
+    pub fn write(&self, path: &std::path::Path, header: &str) -> std::io::Result<()> {
+        use std::io::Write;
+        let cache = path.with_extension("cache");
+        std::fs::create_dir_all(&cache)?;
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(path)?);
+        writeln!(writer, "{}", header)?;
+        TypeReader::get().write_cached(&mut writer, &cache)?;
+        writer.flush()
+    }
+}
+
+// Selects the metadata to read, which is either the version of the Windows SDK to read the metadata
+// of, such as `"10.0.19041.0"`, or the path of a winmd file or of a directory holding them.
+fn select_metadata(value: &LitStr) -> Result<()> {
+    let metadata = value.value();
+
+    if is_sdk_version(&metadata) {
+        return pin_sdk_version(&metadata).map_err(|versions| {
+            let message = if versions.is_empty() {
+                format!(
+                    "Windows SDK metadata version `{}` not found; no versions are present in the `.windows/winmd` directories",
+                    metadata
+                )
+            } else {
+                format!(
+                    "Windows SDK metadata version `{}` not found; the versions present are {}",
+                    metadata,
+                    versions
+                        .iter()
+                        .map(|version| format!("`{}`", version))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            };
+
+            Error::new_spanned(value, message)
         });
+    }
+
+    let mut path: std::path::PathBuf = std::env::var("CARGO_MANIFEST_DIR")
+        .expect("No `CARGO_MANIFEST_DIR` env variable set")
+        .into();
+
+    path.push(&metadata);
+
+    if !path.exists() {
+        return Err(Error::new_spanned(
+            value,
+            format!("Metadata path `{}` not found", path.display()),
+        ));
+    }
 
-        ts.into_string()
+    load_winmds(&[path]);
+    Ok(())
+}
+
+// Parses the value of an option as the value of the same option in a `windows.toml` file, so that
+// the options are validated by `set_option` for both.
+fn parse_value(input: ParseStream) -> Result<ConfigValue> {
+    if input.peek(token::Bracket) {
+        let content;
+        bracketed!(content in input);
+
+        let values = content.parse_terminated::<LitStr, Token![,]>(|input| input.parse())?;
+        return Ok(ConfigValue::Array(
+            values.iter().map(LitStr::value).collect(),
+        ));
+    }
+
+    match input.parse()? {
+        Lit::Str(value) => Ok(ConfigValue::String(value.value())),
+        Lit::Bool(value) => Ok(ConfigValue::Bool(value.value)),
+        Lit::Int(value) => Ok(ConfigValue::Integer(value.base10_parse()?)),
+        value => Err(Error::new_spanned(
+            value,
+            "Expected a string, a boolean, an integer, or an array of strings",
+        )),
     }
 }
 
@@ -27,6 +128,105 @@ impl Parse for BuildMacro {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut build = Self::default();
 
+        // The metadata is read the first time that the other options are applied, so the metadata
+        // option is applied ahead of them.
+        let options = input.fork();
+        let mut metadata = false;
+
+        while options.peek(syn::Ident) && options.peek2(Token![=]) {
+            let name: syn::Ident = options.parse()?;
+            options.parse::<Token![=]>()?;
+
+            if name == "metadata" {
+                let value: LitStr = options.parse()?;
+                select_metadata(&value)?;
+                metadata = true;
+            } else {
+                parse_value(&options)?;
+            }
+
+            options.parse::<Token![,]>()?;
+        }
+
+        // The crate's `windows.toml` is applied before the macro's own options and types, which
+        // add to it or override it.
+        let dir =
+            std::env::var("CARGO_MANIFEST_DIR").expect("No `CARGO_MANIFEST_DIR` env variable set");
+        let config_error = |message: String| Error::new(input.span(), message);
+
+        if let Some(config) = Config::find(&dir).map_err(config_error)? {
+            let config_error =
+                |message: String| config_error(format!("`{}`: {}", CONFIG_FILE, message));
+
+            if !metadata {
+                config.select_metadata(&dir).map_err(config_error)?;
+            }
+
+            config.apply().map_err(config_error)?;
+
+            if config.output.is_some() {
+                build.output = config.output;
+            }
+
+            if let Some(rustfmt) = config.rustfmt {
+                build.rustfmt = rustfmt;
+            }
+        }
+
+        // Options such as `rustfmt = false` precede the types to import.
+        while input.peek(syn::Ident) && input.peek2(Token![=]) {
+            let name: syn::Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            match name.to_string().as_str() {
+                "metadata" => {
+                    // Already applied ahead of the other options.
+                    input.parse::<LitStr>()?;
+                }
+                "output" => build.output = Some(input.parse::<LitStr>()?.value()),
+                "rustfmt" => build.rustfmt = input.parse::<LitBool>()?.value,
+                "rename" => {
+                    let value: LitStr = input.parse()?;
+                    let rename = value.value();
+                    let mut parts = rename.split_whitespace();
+
+                    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+                        (Some(member), Some("as"), Some(name), None) => {
+                            TypeReader::get_mut()
+                                .options
+                                .renames
+                                .insert(member.to_string(), name.to_string());
+                        }
+                        _ => {
+                            return Err(Error::new_spanned(
+                                value,
+                                "Expected a member and its new name, such as `\"Windows.Foundation.IStringable.ToString as Describe\"`",
+                            ))
+                        }
+                    }
+                }
+                option if OPTIONS.contains(&option) => {
+                    let span = input.span();
+                    let value = parse_value(input)?;
+                    set_option(option, &value).map_err(|message| Error::new(span, message))?;
+                }
+                _ => {
+                    let options = ["metadata", "output", "rustfmt"]
+                        .iter()
+                        .chain(OPTIONS)
+                        .chain(&["rename"])
+                        .copied();
+
+                    return Err(Error::new_spanned(
+                        name,
+                        format!("Unknown option, expected {}", expected_names(options)),
+                    ));
+                }
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+
         while !input.is_empty() {
             let tree: UseTree = input.parse()?;
 
@@ -56,12 +256,55 @@ impl Parse for BuildMacro {
                             return Err(Error::new_spanned(
                                 input,
                                 format!(
-                                    "`{}.{}` not found in metadata",
+                                    "`{}.{}` not found in metadata{}",
                                     render_namespace(&namespace),
-                                    name
+                                    name,
+                                    did_you_mean(
+                                        &TypeReader::get().suggest_types(&namespace, &name)
+                                    )
+                                ),
+                            ));
+                        }
+
+                        let row = TypeReader::get().resolve_type_row(&namespace, &name);
+
+                        if row.is_experimental() && !TypeReader::get().options.experimental {
+                            return Err(Error::new_spanned(
+                                input,
+                                format!(
+                                    "`{}.{}` is an experimental API, which requires `experimental = true`",
+                                    namespace, name
                                 ),
                             ));
                         }
+
+                        // A type named explicitly must be available to the targeted contracts,
+                        // unlike the types imported along with a namespace.
+                        if let gen::TypeRow::TypeDef(def) = row {
+                            if let gen::Availability::Unavailable { contract, version } =
+                                TypeReader::get().options.availability(&def)
+                            {
+                                return Err(Error::new_spanned(
+                                    input,
+                                    format!(
+                                        "`{}.{}` requires version {} of `{}`, which is later than the targeted version",
+                                        namespace, name, version, contract
+                                    ),
+                                ));
+                            }
+
+                            if def.kind() == gen::TypeKind::Class
+                                && TypeReader::get().options.excludes_class(&def)
+                            {
+                                return Err(Error::new_spanned(
+                                    input,
+                                    format!(
+                                        "`{}.{}` may only be used by UWP apps, which `desktop_only` excludes",
+                                        namespace, name
+                                    ),
+                                ));
+                            }
+                        }
                     }
                     UseTree::Glob(input) => {
                         let reader = TypeReader::get_mut();
@@ -69,7 +312,11 @@ impl Parse for BuildMacro {
                         if !reader.import_namespace(&namespace) {
                             return Err(Error::new_spanned(
                                 input,
-                                format!("`{}` not found in metadata", render_namespace(&namespace)),
+                                format!(
+                                    "`{}` not found in metadata{}",
+                                    render_namespace(&namespace),
+                                    did_you_mean(&TypeReader::get().suggest_namespaces(&namespace))
+                                ),
                             ));
                         }
                     }
@@ -0,0 +1,435 @@
+use super::*;
+use proc_macro::{Delimiter, Group, Spacing, Span, TokenTree};
+
+type ParseResult<T> = std::result::Result<T, (Span, String)>;
+
+pub fn gen(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    match ComInterface::parse(input) {
+        Ok(interface) => interface.gen().parse().unwrap(),
+        Err((span, message)) => syn::Error::new(span.into(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+struct ComInterface {
+    attributes: Vec<String>,
+    visibility: String,
+    name: String,
+    guid: Guid,
+    base: String,
+    methods: Vec<Method>,
+}
+
+struct Method {
+    attributes: Vec<String>,
+    name: String,
+    params: Vec<(String, String)>,
+    return_type: Option<String>,
+}
+
+impl ComInterface {
+    fn parse(input: proc_macro::TokenStream) -> ParseResult<Self> {
+        let mut cursor = Cursor::new(input, Span::call_site());
+        let mut attributes = Vec::new();
+        let mut guid = None;
+
+        for attribute in cursor.parse_attributes()? {
+            let mut inner = Cursor::new(attribute.stream(), attribute.span());
+
+            if inner.peek_ident("uuid") {
+                inner.next();
+                guid = Some(parse_guid(inner.expect_group(Delimiter::Parenthesis)?)?);
+            } else {
+                attributes.push(format!("#[{}]", attribute.stream()));
+            }
+        }
+
+        let mut visibility = String::new();
+
+        if cursor.peek_ident("pub") {
+            cursor.next();
+            visibility.push_str("pub");
+
+            if let Some(TokenTree::Group(group)) = cursor.peek() {
+                if group.delimiter() == Delimiter::Parenthesis {
+                    visibility.push_str(&group.to_string());
+                    cursor.next();
+                }
+            }
+        }
+
+        cursor.expect_keyword("interface")?;
+        let name_span = cursor.span();
+        let name = cursor.expect_ident()?;
+        cursor.expect_punct(':')?;
+        let base = cursor.parse_type()?;
+        let body = cursor.expect_group(Delimiter::Brace)?;
+        cursor.expect_end()?;
+
+        let guid = guid.ok_or_else(|| {
+            (
+                name_span,
+                format!("`{}` requires a `#[uuid(\"...\")]` attribute", name),
+            )
+        })?;
+
+        let mut cursor = Cursor::new(body.stream(), body.span());
+        let mut methods = Vec::new();
+
+        while !cursor.is_empty() {
+            methods.push(Method::parse(&mut cursor)?);
+        }
+
+        Ok(Self {
+            attributes,
+            visibility,
+            name,
+            guid,
+            base,
+            methods,
+        })
+    }
+
+    fn gen(&self) -> TokenStream {
+        let name = format_ident!("{}", self.name);
+        let abi_name = format_ident!("{}_abi", self.name);
+        let attributes = TokenStream::from(self.attributes.concat());
+        let visibility = TokenStream::from(self.visibility.clone());
+        let base = TokenStream::from(self.base.clone());
+        let guid = self.guid.gen();
+
+        let mut methods = TokenStream::new();
+        let mut abi_signatures = TokenStream::new();
+
+        for (index, method) in self.methods.iter().enumerate() {
+            // The first field of the vtable is the base interface's vtable.
+            let vtable_offset = Literal::usize_unsuffixed(index + 1);
+            let method_attributes = TokenStream::from(method.attributes.concat());
+            let method_name = format_ident!("{}", method.name);
+
+            let params = method.params.iter().map(|(name, param_type)| {
+                let name = format_ident!("{}", name);
+                let param_type = TokenStream::from(param_type.clone());
+                quote! { #name: #param_type }
+            });
+
+            let args = method
+                .params
+                .iter()
+                .map(|(name, _)| format_ident!("{}", name));
+
+            let return_type = if let Some(return_type) = &method.return_type {
+                let return_type = TokenStream::from(return_type.clone());
+                quote! { -> #return_type }
+            } else {
+                quote! {}
+            };
+
+            methods.combine(&quote! {
+                #method_attributes
+                pub unsafe fn #method_name(&self, #(#params),*) #return_type {
+                    (::windows::Interface::vtable(self).#vtable_offset)(::windows::Abi::abi(self), #(#args),*)
+                }
+            });
+
+            let params = method.params.iter().map(|(name, param_type)| {
+                let name = format_ident!("{}", name);
+                let param_type = TokenStream::from(param_type.clone());
+                quote! { #name: #param_type }
+            });
+
+            abi_signatures.combine(&quote! {
+                pub unsafe extern "system" fn(this: ::windows::RawPtr, #(#params),*) #return_type,
+            });
+        }
+
+        let mut bases = vec![base.clone()];
+        let base_name = self
+            .base
+            .rsplit(|c: char| c == ':' || c.is_whitespace())
+            .next();
+
+        // Every interface may be converted to `IUnknown` but it need only be mentioned once.
+        if base_name != Some("IUnknown") {
+            bases.push(quote! { ::windows::IUnknown });
+        }
+
+        let mut conversions = TokenStream::new();
+
+        for into in &bases {
+            conversions.combine(&quote! {
+                impl ::core::convert::From<#name> for #into {
+                    fn from(value: #name) -> Self {
+                        unsafe { ::core::mem::transmute(value) }
+                    }
+                }
+                impl ::core::convert::From<&#name> for #into {
+                    fn from(value: &#name) -> Self {
+                        ::core::convert::From::from(::core::clone::Clone::clone(value))
+                    }
+                }
+                impl<'a> ::windows::IntoParam<'a, #into> for #name {
+                    type Param = ::windows::Param<'a, #into>;
+                    fn into_param(self) -> ::windows::Param<'a, #into> {
+                        ::windows::Param::Owned(::core::convert::Into::<#into>::into(self))
+                    }
+                }
+                impl<'a> ::windows::IntoParam<'a, #into> for &'a #name {
+                    type Param = ::windows::Param<'a, #into>;
+                    fn into_param(self) -> ::windows::Param<'a, #into> {
+                        ::windows::Param::Owned(::core::convert::Into::<#into>::into(::core::clone::Clone::clone(self)))
+                    }
+                }
+            });
+        }
+
+        quote! {
+            #attributes
+            #[repr(transparent)]
+            #[derive(::core::cmp::PartialEq, ::core::cmp::Eq, ::core::clone::Clone, ::core::fmt::Debug)]
+            #visibility struct #name(::windows::IUnknown);
+            #[allow(non_snake_case)]
+            impl #name {
+                #methods
+            }
+            unsafe impl ::windows::Interface for #name {
+                type Vtable = #abi_name;
+                const IID: ::windows::Guid = ::windows::Guid::from_values(#guid);
+            }
+            impl ::core::ops::Deref for #name {
+                type Target = #base;
+                fn deref(&self) -> &Self::Target {
+                    // The interfaces share the same representation and the vtable begins with the
+                    // base interface's vtable so the base interface's methods may be called directly.
+                    unsafe { &*(self as *const Self as *const #base) }
+                }
+            }
+            #conversions
+            #[repr(C)]
+            #[doc(hidden)]
+            #[allow(non_camel_case_types)]
+            #visibility struct #abi_name(
+                pub <#base as ::windows::Interface>::Vtable,
+                #abi_signatures
+            );
+        }
+    }
+}
+
+impl Method {
+    fn parse(cursor: &mut Cursor) -> ParseResult<Self> {
+        let attributes = cursor
+            .parse_attributes()?
+            .iter()
+            .map(|attribute| format!("#[{}]", attribute.stream()))
+            .collect();
+
+        cursor.expect_keyword("fn")?;
+        let name = cursor.expect_ident()?;
+        let params_group = cursor.expect_group(Delimiter::Parenthesis)?;
+
+        let return_type = if cursor.peek_punct('-') {
+            cursor.next();
+            cursor.expect_punct('>')?;
+            Some(cursor.parse_type()?)
+        } else {
+            None
+        };
+
+        cursor.expect_punct(';')?;
+
+        let mut cursor = Cursor::new(params_group.stream(), params_group.span());
+        let mut params = Vec::new();
+
+        // The methods are called through an interface pointer so must take `&self`.
+        cursor.expect_punct('&')?;
+        cursor.expect_keyword("self")?;
+
+        while !cursor.is_empty() {
+            cursor.expect_punct(',')?;
+
+            if cursor.is_empty() {
+                break;
+            }
+
+            let name = cursor.expect_ident()?;
+            cursor.expect_punct(':')?;
+            params.push((name, cursor.parse_type()?));
+        }
+
+        Ok(Self {
+            attributes,
+            name,
+            params,
+            return_type,
+        })
+    }
+}
+
+fn parse_guid(group: Group) -> ParseResult<Guid> {
+    let mut cursor = Cursor::new(group.stream(), group.span());
+    let span = cursor.span();
+
+    let guid = match cursor.next() {
+        Some(TokenTree::Literal(literal)) => {
+            let value = literal.to_string();
+            Guid::parse(value.trim_matches('"'))
+        }
+        _ => None,
+    };
+
+    cursor.expect_end()?;
+    guid.ok_or_else(|| (span, "expected a GUID string".to_string()))
+}
+
+struct Cursor {
+    tokens: Vec<TokenTree>,
+    position: usize,
+    end: Span,
+}
+
+impl Cursor {
+    fn new(stream: proc_macro::TokenStream, end: Span) -> Self {
+        Self {
+            tokens: stream.into_iter().collect(),
+            position: 0,
+            end,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position == self.tokens.len()
+    }
+
+    fn peek(&self) -> Option<&TokenTree> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<TokenTree> {
+        let token = self.tokens.get(self.position).cloned();
+
+        if token.is_some() {
+            self.position += 1;
+        }
+
+        token
+    }
+
+    fn span(&self) -> Span {
+        self.peek().map_or(self.end, |token| token.span())
+    }
+
+    fn error<T>(&self, expected: &str) -> ParseResult<T> {
+        Err((self.span(), format!("expected {}", expected)))
+    }
+
+    fn peek_ident(&self, name: &str) -> bool {
+        matches!(self.peek(), Some(TokenTree::Ident(ident)) if ident.to_string() == name)
+    }
+
+    fn peek_punct(&self, c: char) -> bool {
+        matches!(self.peek(), Some(TokenTree::Punct(punct)) if punct.as_char() == c)
+    }
+
+    fn expect_ident(&mut self) -> ParseResult<String> {
+        match self.peek() {
+            Some(TokenTree::Ident(ident)) => {
+                let ident = ident.to_string();
+                self.next();
+                Ok(ident)
+            }
+            _ => self.error("an identifier"),
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> ParseResult<()> {
+        if self.peek_ident(keyword) {
+            self.next();
+            Ok(())
+        } else {
+            self.error(&format!("`{}`", keyword))
+        }
+    }
+
+    fn expect_punct(&mut self, c: char) -> ParseResult<()> {
+        if self.peek_punct(c) {
+            self.next();
+            Ok(())
+        } else {
+            self.error(&format!("`{}`", c))
+        }
+    }
+
+    fn expect_group(&mut self, delimiter: Delimiter) -> ParseResult<Group> {
+        match self.peek() {
+            Some(TokenTree::Group(group)) if group.delimiter() == delimiter => {
+                let group = group.clone();
+                self.next();
+                Ok(group)
+            }
+            _ => self.error(match delimiter {
+                Delimiter::Parenthesis => "`(`",
+                Delimiter::Brace => "`{`",
+                Delimiter::Bracket => "`[`",
+                Delimiter::None => "a group",
+            }),
+        }
+    }
+
+    fn expect_end(&self) -> ParseResult<()> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err((self.span(), "unexpected token".to_string()))
+        }
+    }
+
+    fn parse_attributes(&mut self) -> ParseResult<Vec<Group>> {
+        let mut attributes = Vec::new();
+
+        while self.peek_punct('#') {
+            self.next();
+            attributes.push(self.expect_group(Delimiter::Bracket)?);
+        }
+
+        Ok(attributes)
+    }
+
+    // Collects the tokens of a type, which ends at a `,` or `;` that is not nested within angle
+    // brackets, at a braced group, or at the end of the input.
+    fn parse_type(&mut self) -> ParseResult<String> {
+        let mut tokens = Vec::new();
+        let mut depth = 0usize;
+        let mut arrow = false;
+
+        while let Some(token) = self.peek() {
+            match token {
+                TokenTree::Punct(punct) => {
+                    match punct.as_char() {
+                        ',' | ';' if depth == 0 => break,
+                        '<' => depth += 1,
+                        '>' if !arrow => depth = depth.saturating_sub(1),
+                        _ => {}
+                    }
+
+                    arrow = punct.as_char() == '-' && punct.spacing() == Spacing::Joint;
+                }
+                TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => break,
+                _ => arrow = false,
+            }
+
+            tokens.push(token.clone());
+            self.next();
+        }
+
+        if tokens.is_empty() {
+            self.error("a type")
+        } else {
+            Ok(tokens
+                .into_iter()
+                .collect::<proc_macro::TokenStream>()
+                .to_string())
+        }
+    }
+}
@@ -8,6 +8,12 @@ pub fn gen(
 
     let implements = syn::parse_macro_input!(attribute as ImplementMacro);
     let impl_type = syn::parse_macro_input!(impl_type as syn::ItemStruct);
+    let reader = TypeReader::get();
+
+    if let Err(error) = implements.validate(reader) {
+        return error.to_compile_error().into();
+    }
+
     let impl_name = impl_type.ident.to_string();
     let impl_ident = format_ident!("{}", impl_name);
     let box_ident = format_ident!("{}_box", impl_name);
@@ -18,7 +24,7 @@ pub fn gen(
     let mut vtable_ctors = TokenStream::new();
     let mut shims = TokenStream::new();
     let mut queries = TokenStream::new();
-    let reader = TypeReader::get();
+    let mut iids = vec![];
     let gen = gen::Gen::Absolute;
 
     for (interface_count, (t, overrides)) in implements.interfaces(reader).iter().enumerate() {
@@ -66,12 +72,34 @@ pub fn gen(
 
             let signature = method.signature(&[]);
             let abi_signature = signature.gen_winrt_abi(&gen);
-            let upcall = if *overrides {
-                if implements.overrides.contains(method.name()) {
-                    signature
-                        .gen_winrt_upcall(quote! { (*this).implementation.#method_ident }, &gen)
-                } else {
-                    quote! { ::windows::HRESULT(0) }
+            let upcall = if implements.overrides.contains(method.name()) {
+                signature.gen_winrt_upcall(quote! { (*this).implementation.#method_ident }, &gen)
+            } else if *overrides {
+                // Methods that aren't overridden are forwarded to the base class so that it
+                // keeps its own behavior and fills in any results.
+                let args = signature.gen_winrt_abi_forward_args();
+                let vtable_offset = Literal::usize_unsuffixed(vtable_offset + 6);
+
+                quote! {
+                    match &(*this).base {
+                        ::core::option::Option::Some(base) => match ::windows::Interface::cast::<#interface_ident>(base) {
+                            ::core::result::Result::Ok(base) => (::windows::Interface::vtable(&base).#vtable_offset)(::windows::Abi::abi(&base), #args),
+                            ::core::result::Result::Err(error) => error.code(),
+                        },
+                        ::core::option::Option::None => ::windows::HRESULT(0),
+                    }
+                }
+            } else if let Some(forward) = &implements.forward {
+                // Methods that aren't overridden are forwarded to the object held by the field.
+                let field = format_ident!("{}", forward);
+                let args = signature.gen_winrt_abi_forward_args();
+                let vtable_offset = Literal::usize_unsuffixed(vtable_offset + 6);
+
+                quote! {
+                    match ::windows::Interface::cast::<#interface_ident>(&(*this).implementation.#field) {
+                        ::core::result::Result::Ok(inner) => (::windows::Interface::vtable(&inner).#vtable_offset)(::windows::Abi::abi(&inner), #args),
+                        ::core::result::Result::Err(error) => error.code(),
+                    }
                 }
             } else {
                 signature.gen_winrt_upcall(quote! { (*this).implementation.#method_ident }, &gen)
@@ -83,23 +111,23 @@ pub fn gen(
                         #upcall
                     }
                 });
-
-            queries.combine(&quote! {
-                &<#interface_ident as ::windows::Interface>::IID => {
-                    &mut self.vtables.#interface_literal as *mut _ as _
-                }
-            });
         }
 
+        queries.combine(&quote! {
+            &<#interface_ident as ::windows::Interface>::IID => {
+                &mut self.vtables.#interface_literal as *mut _ as _
+            }
+        });
+
+        iids.push(interface_ident.clone());
+
         if !t.is_exclusive() {
             tokens.combine(&quote! {
-                    impl ::std::convert::From<#impl_ident> for #interface_ident {
+                    impl ::core::convert::From<#impl_ident> for #interface_ident {
                         fn from(implementation: #impl_ident) -> Self {
-                            let com = #box_ident::new(implementation);
-
                             unsafe {
-                                let ptr = ::std::boxed::Box::into_raw(::std::boxed::Box::new(com));
-                                ::std::mem::transmute_copy(&::std::ptr::NonNull::new_unchecked(&mut (*ptr).vtables.#interface_literal as *mut _ as _))
+                                let ptr = #box_ident::into_raw(implementation);
+                                ::core::mem::transmute_copy(&::core::ptr::NonNull::new_unchecked(&mut (*ptr).vtables.#interface_literal as *mut _ as _))
                             }
                         }
                     }
@@ -137,32 +165,61 @@ pub fn gen(
         quote! {}
     };
 
+    // A single-threaded object can't be agile as its reference count isn't atomic.
+    let agile = if implements.agile && !implements.single_threaded {
+        quote! { | &<::windows::IAgileObject as ::windows::Interface>::IID }
+    } else {
+        quote! {}
+    };
+
+    let (marshaler_field, marshaler_new, marshaler_query) = if implements.free_threaded {
+        (
+            quote! { marshaler: ::windows::FreeThreadedMarshaler, },
+            quote! { marshaler: ::windows::FreeThreadedMarshaler::new(), },
+            quote! {
+                if (*interface).is_null() && self.marshaler.query(&mut self.identity_vtable as *mut _ as _, iid, interface).is_ok() {
+                    return ::windows::HRESULT(0);
+                }
+            },
+        )
+    } else {
+        (quote! {}, quote! {}, quote! {})
+    };
+
+    let count = if implements.single_threaded {
+        quote! { ::windows::SingleThreadedRefCount }
+    } else {
+        quote! { ::windows::WeakRefCount }
+    };
+
+    // An authored runtime class reports its name from every interface.
+    let class_name = match &implements.class {
+        Some(class) => quote! { ::windows::HSTRING::from(#class) },
+        None => quote! { ::windows::HSTRING::new() },
+    };
+
     tokens.combine(&quote! {
         impl #impl_ident {
             #constructors
         }
-        impl ::std::convert::From<#impl_ident> for ::windows::IUnknown {
+        impl ::core::convert::From<#impl_ident> for ::windows::IUnknown {
             fn from(implementation: #impl_ident) -> Self {
-                let com = #box_ident::new(implementation);
-
                 unsafe {
-                    let ptr = ::std::boxed::Box::into_raw(::std::boxed::Box::new(com));
-                    ::std::mem::transmute_copy(&::std::ptr::NonNull::new_unchecked(&mut (*ptr).identity_vtable as *mut _ as _))
+                    let ptr = #box_ident::into_raw(implementation);
+                    ::core::mem::transmute_copy(&::core::ptr::NonNull::new_unchecked(&mut (*ptr).identity_vtable as *mut _ as _))
                 }
             }
         }
-        impl ::std::convert::From<#impl_ident> for ::windows::IInspectable {
+        impl ::core::convert::From<#impl_ident> for ::windows::IInspectable {
             fn from(implementation: #impl_ident) -> Self {
-                let com = #box_ident::new(implementation);
-
                 unsafe {
-                    let ptr = ::std::boxed::Box::into_raw(::std::boxed::Box::new(com));
-                    ::std::mem::transmute_copy(&::std::ptr::NonNull::new_unchecked(&mut (*ptr).identity_vtable as *mut _ as _))
+                    let ptr = #box_ident::into_raw(implementation);
+                    ::core::mem::transmute_copy(&::core::ptr::NonNull::new_unchecked(&mut (*ptr).identity_vtable as *mut _ as _))
                 }
             }
         }
         impl ::windows::Compose for #impl_ident {
-            unsafe fn compose<'a>(implementation: Self) -> (::windows::IInspectable, &'a mut std::option::Option<::windows::IInspectable>) {
+            unsafe fn compose<'a>(implementation: Self) -> (::windows::IInspectable, &'a mut ::core::option::Option<::windows::IInspectable>) {
                 let inspectable: ::windows::IInspectable = implementation.into();
                 let this = (::windows::Abi::abi(&inspectable) as *mut ::windows::RawPtr).sub(1) as *mut #box_ident;
                 (inspectable, &mut (*this).base)
@@ -170,11 +227,12 @@ pub fn gen(
         }
         #[repr(C)]
         struct #box_ident {
-            base: ::std::option::Option<::windows::IInspectable>,
+            base: ::core::option::Option<::windows::IInspectable>,
             identity_vtable: *const ::windows::IInspectable_abi,
             vtables: (#(*const #vtable_idents,)*),
             implementation: #impl_ident,
-            count: ::windows::WeakRefCount,
+            count: #count,
+            #marshaler_field
         }
         impl #box_ident {
             const VTABLES: (#(#vtable_idents,)*) = (
@@ -190,23 +248,29 @@ pub fn gen(
             );
             fn new(implementation: #impl_ident) -> Self {
                 Self {
-                    base: ::std::option::Option::None,
+                    base: ::core::option::Option::None,
                     identity_vtable: &Self::IDENTITY_VTABLE,
                     vtables: (#(&Self::VTABLES.#vtable_ordinals,)*),
                     implementation,
-                    count: ::windows::WeakRefCount::new(),
+                    count: #count::new(),
+                    #marshaler_new
                 }
             }
+            unsafe fn into_raw(implementation: #impl_ident) -> *mut Self {
+                let ptr = ::windows::alloc::boxed::Box::into_raw(::windows::alloc::boxed::Box::new(Self::new(implementation)));
+                ::windows::track_object(&mut (*ptr).identity_vtable as *mut _ as _, ::core::any::type_name::<#impl_ident>());
+                ptr
+            }
             fn QueryInterface(&mut self, iid: &::windows::Guid, interface: *mut ::windows::RawPtr) -> ::windows::HRESULT {
                 unsafe {
                     *interface = match iid {
                         #queries
                         &<::windows::IUnknown as ::windows::Interface>::IID
                         | &<::windows::IInspectable as ::windows::Interface>::IID
-                        | &<::windows::IAgileObject as ::windows::Interface>::IID => {
+                        #agile => {
                             &mut self.identity_vtable as *mut _ as _
                         }
-                        _ => ::std::ptr::null_mut(),
+                        _ => ::core::ptr::null_mut(),
                     };
 
                     if !(*interface).is_null() {
@@ -215,6 +279,7 @@ pub fn gen(
                     }
 
                     *interface = self.count.query(iid, &mut self.identity_vtable as *mut _ as _);
+                    #marshaler_query
 
                     if (*interface).is_null() {
                         if let Some(base) = &self.base {
@@ -233,8 +298,10 @@ pub fn gen(
             fn Release(&mut self) -> u32 {
                 let remaining = self.count.release();
                 if remaining == 0 {
+                    ::windows::untrack_object(&mut self.identity_vtable as *mut _ as _);
+
                     unsafe {
-                        ::std::boxed::Box::from_raw(self);
+                        ::windows::alloc::boxed::Box::from_raw(self);
                     }
                 }
                 remaining
@@ -244,12 +311,10 @@ pub fn gen(
                 count: *mut u32,
                 values: *mut *mut ::windows::Guid,
             ) -> ::windows::HRESULT {
-                // Note: even if we end up implementing this in future, it still doesn't need a this pointer
-                // since the data to be returned is type- not instance-specific so can be shared for all
-                // interfaces.
-                *count = 0;
-                *values = ::std::ptr::null_mut();
-                ::windows::HRESULT(0)
+                // The data to be returned is type- not instance-specific so doesn't need a this pointer
+                // and can be shared for all interfaces. The interfaces are reported in the order they
+                // were declared.
+                ::windows::copy_iids(&[#(<#iids as ::windows::Interface>::IID,)*], count, values)
             }
             unsafe extern "system" fn GetRuntimeClassName(
                 _: ::windows::RawPtr,
@@ -259,8 +324,8 @@ pub fn gen(
                 // Otherwise, the interface name should be returned on a per-interface basis and the identity
                 // implementation should return an empty string.
 
-                let h = ::windows::HSTRING::new();
-                *value = ::std::mem::transmute(h);
+                let h = #class_name;
+                *value = ::core::mem::transmute(h);
                 ::windows::HRESULT(0)
             }
             unsafe extern "system" fn GetTrustLevel(_: ::windows::RawPtr, value: *mut i32) -> ::windows::HRESULT {
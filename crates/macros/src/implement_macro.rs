@@ -1,15 +1,49 @@
-use gen::{tables::TypeDef, TypeKind, TypeReader};
+use super::{did_you_mean, generated_types};
+use gen::{tables::TypeDef, RuntimeClass, TypeKind, TypeReader};
 use std::collections::*;
 use syn::parse::*;
 use syn::*;
 
 custom_keyword!(extend);
+custom_keyword!(free_threaded);
+custom_keyword!(none);
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct ImplementMacro {
     pub extend: Option<(&'static str, &'static str)>,
     pub overrides: BTreeSet<&'static str>,
-    pub implement: BTreeSet<(&'static str, &'static str)>,
+    // The field of the struct holding the object that methods which aren't overridden are
+    // forwarded to.
+    pub forward: Option<String>,
+    // Interfaces are kept in the order they are declared as this determines the order of the
+    // vtables and of the interfaces reported by `GetIids`.
+    pub implement: Vec<(&'static str, &'static str)>,
+    pub agile: bool,
+    pub free_threaded: bool,
+    // Whether the object is only used by the thread that created it, so its reference count
+    // needn't be atomic.
+    pub single_threaded: bool,
+    // The namespace-qualified name of the runtime class that the struct is authored as.
+    pub class: Option<String>,
+    // The methods named after `override` are only checked once the class being extended or the
+    // interfaces being forwarded are known.
+    override_names: Vec<Ident>,
+}
+
+impl Default for ImplementMacro {
+    fn default() -> Self {
+        Self {
+            extend: None,
+            overrides: BTreeSet::new(),
+            forward: None,
+            implement: Vec::new(),
+            agile: true,
+            free_threaded: false,
+            single_threaded: false,
+            class: None,
+            override_names: Vec::new(),
+        }
+    }
 }
 
 impl ImplementMacro {
@@ -33,6 +67,87 @@ impl ImplementMacro {
         result
     }
 
+    /// Returns the runtime class that the struct is authored as, if any, which implements the
+    /// listed interfaces.
+    pub fn runtime_class(&self, reader: &'static TypeReader) -> Option<RuntimeClass> {
+        let class = self.class.as_ref()?;
+        let pos = class.rfind('.')?;
+
+        let interfaces = self
+            .implement
+            .iter()
+            .map(|(namespace, name)| reader.resolve_type_def(namespace, name))
+            .filter(|def| def.kind() == TypeKind::Interface)
+            .collect();
+
+        Some(RuntimeClass {
+            namespace: class[..pos].to_string(),
+            name: class[pos + 1..].to_string(),
+            interfaces,
+            activatable: false,
+        })
+    }
+
+    /// Checks that the bindings generated by the crate's `build!` macro include the interfaces
+    /// being implemented, which otherwise fail to compile with errors about their missing ABI
+    /// types. Crates using bindings generated by another crate aren't checked.
+    pub fn validate(&self, reader: &'static TypeReader) -> Result<()> {
+        if self.single_threaded && self.free_threaded {
+            return Err(Error::new(
+                proc_macro::Span::call_site().into(),
+                "A single-threaded object can't aggregate the free-threaded marshaler",
+            ));
+        }
+
+        if let Some(class) = self.runtime_class(reader) {
+            if class.interfaces.is_empty() {
+                return Err(Error::new(
+                    proc_macro::Span::call_site().into(),
+                    format!(
+                        "`{}.{}` must implement an interface, the first of which is its default interface",
+                        class.namespace, class.name
+                    ),
+                ));
+            }
+        }
+
+        let generated = match generated_types() {
+            Some(generated) => generated,
+            None => return Ok(()),
+        };
+
+        let mut required: Vec<TypeDef> = self
+            .interfaces(reader)
+            .into_iter()
+            .map(|(def, _)| def)
+            .collect();
+
+        if let Some((namespace, name)) = self.extend {
+            required.push(reader.resolve_type_def(namespace, name));
+        }
+
+        for def in required {
+            let (namespace, name) = def.full_name();
+
+            if !generated.contains(&format!("{}.{}", namespace, name)) {
+                let name = name.split('`').next().unwrap_or(name);
+
+                return Err(Error::new(
+                    proc_macro::Span::call_site().into(),
+                    format!(
+                        "`{}.{}` is not included in the bindings generated by the crate's `build!` macro, so it can't be implemented. Add `{}::{}` to the `build!` macro in the crate's build script.",
+                        namespace,
+                        name,
+                        namespace.replace('.', "::"),
+                        name
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
     fn parse_implement(&mut self, reader: &'static TypeReader, cursor: ParseStream) -> Result<()> {
         if let Ok(tree) = cursor.parse::<UseTree>() {
             self.walk_implement(reader, &tree, &mut String::new())?;
@@ -66,7 +181,9 @@ impl ImplementMacro {
                 if let Some((namespace, name)) = reader.get_type_name(namespace, &name) {
                     match reader.resolve_type_def(namespace, name).kind() {
                         TypeKind::Class | TypeKind::Interface => {
-                            self.implement.insert((namespace, name));
+                            if !self.implement.contains(&(namespace, name)) {
+                                self.implement.push((namespace, name));
+                            }
                         }
                         _ => {
                             return Err(Error::new_spanned(
@@ -78,7 +195,12 @@ impl ImplementMacro {
                 } else {
                     return Err(Error::new_spanned(
                         input,
-                        format!("`{}.{}` not found in metadata", namespace, name),
+                        format!(
+                            "`{}.{}` not found in metadata{}",
+                            namespace,
+                            name,
+                            did_you_mean(&reader.suggest_types(namespace, &name))
+                        ),
                     ));
                 }
             }
@@ -98,30 +220,125 @@ impl ImplementMacro {
         Ok(())
     }
 
-    fn parse_override(&mut self, reader: &'static TypeReader, cursor: ParseStream) -> Result<()> {
-        // Any number of methods may be overridden but only if a class is being overridden.
-        if let Some((namespace, name)) = self.extend {
-            if cursor.parse::<Token![override]>().is_ok() {
-                let methods = reader
-                    .resolve_type_def(namespace, name)
-                    .overridable_methods();
+    fn parse_option(&mut self, cursor: ParseStream) -> Result<()> {
+        // Options take the form `name = value` and control the behavior of the implementation
+        // as a whole rather than the interfaces it implements.
+        while cursor.peek(Ident) && cursor.peek2(Token![=]) {
+            let name: Ident = cursor.parse()?;
+            cursor.parse::<Token![=]>()?;
 
-                while let Ok(input) = cursor.parse::<Ident>() {
-                    let name = input.to_string();
+            if name == "agile" {
+                self.agile = cursor.parse::<LitBool>()?.value;
+            } else if name == "marshal" {
+                let lookahead = cursor.lookahead1();
 
-                    if let Some(name) = methods.get(name.as_str()) {
-                        self.overrides.insert(name);
-                    } else {
-                        return Err(Error::new_spanned(
-                            input,
-                            format!("`{}` not an overridable method", name),
-                        ));
-                    }
+                if lookahead.peek(free_threaded) {
+                    cursor.parse::<free_threaded>()?;
+                    self.free_threaded = true;
+                } else if lookahead.peek(none) {
+                    cursor.parse::<none>()?;
+                    self.free_threaded = false;
+                } else {
+                    return Err(lookahead.error());
                 }
+            } else if name == "forward" {
+                self.forward = Some(match cursor.parse()? {
+                    Member::Named(ident) => ident.to_string(),
+                    Member::Unnamed(index) => index.index.to_string(),
+                });
+            } else if name == "class" {
+                let class: LitStr = cursor.parse()?;
 
-                if !cursor.is_empty() {
-                    cursor.parse::<Token![,]>()?;
+                if !class.value().contains('.') {
+                    return Err(Error::new_spanned(
+                        class,
+                        "The class name must be qualified by its namespace",
+                    ));
                 }
+
+                self.class = Some(class.value());
+            } else if name == "ref_count" {
+                let model: LitStr = cursor.parse()?;
+
+                self.single_threaded = match model.value().as_str() {
+                    "single_threaded" => true,
+                    "multi_threaded" => false,
+                    _ => {
+                        return Err(Error::new_spanned(
+                            model,
+                            "Expected `\"single_threaded\"` or `\"multi_threaded\"`",
+                        ))
+                    }
+                };
+            } else {
+                return Err(Error::new_spanned(
+                    name,
+                    "Unknown option, expected `agile`, `marshal`, `forward`, `class`, or `ref_count`",
+                ));
+            }
+
+            if !cursor.is_empty() {
+                cursor.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_override(&mut self, cursor: ParseStream) -> Result<()> {
+        if cursor.parse::<Token![override]>().is_ok() {
+            while let Ok(input) = cursor.parse::<Ident>() {
+                self.override_names.push(input);
+            }
+
+            if !cursor.is_empty() {
+                cursor.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn resolve_overrides(&mut self, reader: &'static TypeReader) -> Result<()> {
+        // Any number of methods may be overridden but only if a class is being extended, in
+        // which case they are the methods of its `*Overrides` interfaces, or if methods are being
+        // forwarded, in which case they are the methods of the interfaces being implemented.
+        let mut methods = BTreeSet::new();
+
+        if let Some((namespace, name)) = self.extend {
+            methods.extend(
+                reader
+                    .resolve_type_def(namespace, name)
+                    .overridable_methods(),
+            );
+        }
+
+        if self.forward.is_some() {
+            for (namespace, name) in &self.implement {
+                methods.extend(
+                    reader
+                        .resolve_type_def(namespace, name)
+                        .methods()
+                        .map(|method| method.name()),
+                );
+            }
+        }
+
+        for input in std::mem::take(&mut self.override_names) {
+            let name = input.to_string();
+
+            if let Some(name) = methods.get(name.as_str()).copied() {
+                self.overrides.insert(name);
+            } else if self.extend.is_none() && self.forward.is_none() {
+                return Err(Error::new_spanned(
+                    input,
+                    "Only the methods of an extended class or of forwarded interfaces can be overridden",
+                ));
+            } else {
+                return Err(Error::new_spanned(
+                    input,
+                    format!("`{}` not an overridable method", name),
+                ));
             }
         }
 
@@ -174,7 +391,12 @@ impl ImplementMacro {
                 } else {
                     return Err(Error::new_spanned(
                         input,
-                        format!("`{}.{}` not found in metadata", namespace, name),
+                        format!(
+                            "`{}.{}` not found in metadata{}",
+                            namespace,
+                            name,
+                            did_you_mean(&reader.suggest_types(namespace, &name))
+                        ),
                     ));
                 }
             }
@@ -193,11 +415,14 @@ impl Parse for ImplementMacro {
         let reader = TypeReader::get();
 
         while !cursor.is_empty() {
+            input.parse_option(cursor)?;
             input.parse_extend(reader, cursor)?;
-            input.parse_override(reader, cursor)?;
+            input.parse_override(cursor)?;
             input.parse_implement(reader, cursor)?;
         }
 
+        input.resolve_overrides(reader)?;
+
         Ok(input)
     }
 }
@@ -1,6 +1,8 @@
 mod build_macro;
+mod com_interface;
 mod implement;
 mod implement_macro;
+mod main_macro;
 
 use build_macro::*;
 use gen::*;
@@ -31,6 +33,155 @@ impl ToTokens for RawString {
 /// follow the same convention as Rust `use` paths. Types know which other types they depend on so
 /// `build` will generate any other WinRT types needed for the specified type to work.
 ///
+/// # Metadata
+/// Metadata is read from the winmd files in the `.windows/winmd` directories of the crate and of
+/// the workspace, including their subdirectories. Metadata that is distributed in packages, such
+/// as the `Microsoft.UI.Xaml` metadata of the Windows App SDK, may be added by copying or linking
+/// the package's directory there. Where a package holds a copy of the same winmd file for each
+/// version of Windows that it targets, as in `lib/uap10.0` and `lib/uap10.0.18362`, the copy in
+/// the directory with the highest version is read. Types that such metadata refers to, such as
+/// those in `Windows.Foundation`, must also be present.
+///
+/// The metadata of several versions of the Windows SDK may be held in directories named after
+/// each version, as in the SDK's `UnionMetadata` directory, in which case the latest version is
+/// read unless the `metadata` option pins another.
+///
+/// Driver bindings are generated the same way from the Windows Driver Kit metadata, adding
+/// `Windows.Wdk.winmd` to the `.windows/winmd` directory alongside `Windows.Win32.winmd` and
+/// naming the `Windows::Wdk` types. Kernel-mode functions are called with their declared calling
+/// conventions and link with the import libraries of the modules exporting them, such as
+/// `ntoskrnl.lib` for `ntoskrnl.exe`, which the WDK's library directory must be passed to the
+/// linker to find. Their IRQL requirements are included in their documentation.
+///
+/// Widely used Win32 function-like macros that the metadata doesn't describe may be named like
+/// any other function and are generated as `const fn`s in the namespace of their header:
+/// `SUCCEEDED`, `FAILED`, `HRESULT_FROM_WIN32`, `LOWORD`, `HIWORD`, `LOBYTE`, `HIBYTE`,
+/// `MAKEWORD`, and `MAKELONG` in `Windows::Win32::Foundation`, `MAKELANGID`, `PRIMARYLANGID`,
+/// `SUBLANGID`, and `MAKELCID` in `Windows::Win32::System::SystemServices`, and
+/// `MAKEINTRESOURCEA`, `MAKEINTRESOURCEW`, `GET_X_LPARAM`, `GET_Y_LPARAM`, and
+/// `GET_WHEEL_DELTA_WPARAM` in `Windows::Win32::UI::WindowsAndMessaging`.
+///
+/// # Options
+/// The types may be preceded by options controlling how the generated code is written:
+///
+/// * `metadata = "10.0.19041.0"` reads the Windows SDK metadata held for the given version of
+///   the SDK, so that machines holding different versions generate identical code. Naming a
+///   version that isn't present is an error listing the versions that are. The option may
+///   instead name a winmd file or a directory holding them, relative to the crate's directory, to
+///   read in place of the `.windows/winmd` directories.
+/// * `output = "path"` writes the code to the given path instead of `windows.rs` in `OUT_DIR`.
+///   Relative paths are relative to the build script's working directory.
+/// * `rustfmt = false` skips formatting the generated code with rustfmt.
+/// * `traits = true` also generates a trait for each WinRT interface, named after the interface
+///   with a `Trait` suffix, that is implemented by the interface by forwarding to its methods.
+///   Code written against the trait may then be given a mock implementation in tests.
+/// * `raw_dylib = true` links Win32 functions with `kind = "raw-dylib"`, naming the DLL or API set
+///   that exports each function, so that the build doesn't need the Windows SDK's import
+///   libraries. This requires a compiler supporting `raw-dylib` and the crate including the
+///   generated code must enable the `raw_dylib` feature on compilers where it is unstable.
+/// * `os_features = true` gates each Win32 function that requires a version of Windows 10 later
+///   than its first release behind a feature named after the build, such as `win10_19041`. The
+///   crate including the generated code declares the features, each enabling the features of
+///   the earlier builds, so that enabling the feature of the minimum supported build makes every
+///   function available on that build.
+/// * `function_features = true` gates each Win32 function behind its own feature, named after
+///   the function and its namespace, such as `Windows_Win32_Foundation_CloseHandle`, so that
+///   importing a namespace with hundreds of functions only compiles the few that are enabled.
+///   `windows_inspect --features` lists the features that a crate's source files need.
+/// * `api_stats = true` counts the calls to each Win32 function when the crate including the
+///   generated code enables its `api_stats` feature, so that `windows::api_usage` reports the
+///   functions being called.
+/// * `std_feature = true` gates the conversions between the generated types and those of `std`,
+///   such as `SystemTime` and `Path`, behind the crate's `std` feature. The rest of the generated
+///   code only depends on `core` and `alloc`, so that a `#![no_std]` crate may include it along
+///   with the `windows` crate's `std` feature disabled.
+/// * `into_future = true` makes the WinRT async interfaces, such as `IAsyncOperation<T>`,
+///   implement `IntoFuture` instead of `Future`, so that awaiting one awaits an
+///   `windows::AsyncFuture`. Unlike the operation itself, the future may be polled by different
+///   tasks, as by `select!` and `join!`, without missing its completion, and it's `Send` so that
+///   it may be awaited by a task spawned on a multithreaded executor such as tokio's.
+/// * `contracts = ["UniversalApiContract 8"]` targets the given major versions of WinRT API
+///   contracts, such as the versions shipped with the minimum supported release of Windows.
+///   Methods of interfaces introduced by later versions of a contract are omitted so that they
+///   can't be called by accident, and naming a type introduced by a later version is an error.
+///   Contracts that aren't targeted are unrestricted.
+/// * `contract_features = true` gates the methods introduced by later versions of the targeted
+///   contracts behind a feature named after the contract and version, such as
+///   `universal_api_contract_10`, instead of omitting them. Each such method is accompanied by a
+///   function, such as `is_key_tip_target_present` for `KeyTipTarget`, that checks whether the
+///   method is present on the running version of Windows. Every class also has an `is_present`
+///   function.
+/// * `exclude_deprecated = true` omits deprecated WinRT methods instead of gating them behind the
+///   `deprecated` feature.
+/// * `desktop_only = true` omits the methods of WinRT classes that may only be used by UWP apps,
+///   such as `CoreWindow`, since they fail when called by desktop apps. Such classes may still be
+///   passed to and returned from other methods, and naming one explicitly is an error.
+/// * `experimental = true` includes the preview APIs marked with the `Experimental` attribute,
+///   which may change or be removed by later versions of Windows. They are otherwise omitted:
+///   types are skipped when importing their namespace, naming one explicitly is an error, and
+///   the methods of experimental classes and interfaces aren't generated.
+/// * `codegen_version = 1` pins the shape of the generated code to that of an earlier release,
+///   so that the crate may be upgraded before the code calling the bindings is migrated. The
+///   option defaults to the latest version, and naming a later version is an error.
+///   * Version 1 returns received WinRT arrays as `windows::Array<T>` and doesn't generate the
+///     `with_*` builders of classes or the `Display` implementation of classes implementing
+///     `IStringable`.
+///   * Version 2 returns received WinRT arrays as `Vec<T>`, generates a `with_*` builder for
+///     each property setter of a class, and implements `Display` for classes implementing
+///     `IStringable`.
+///   * Version 3 names the overload of a WinRT method marked with the `DefaultOverload`
+///     attribute by the method's own name, such as `Format` rather than `FormatDouble`, while
+///     the other overloads keep the names given by their `Overload` attribute, such as
+///     `FormatInt`. The default overload keeps its `Overload` name if another method of the
+///     interface, or of the class the interface belongs to, already has the method's own name.
+///     Earlier versions name every overload by its `Overload` attribute. Version 3 also
+///     implements `Display` for every class, displaying those that don't implement `IStringable`
+///     by their runtime class name.
+/// * `naming = "metadata"` names the accessors of WinRT properties and events as the metadata
+///   and the ABI documentation do, such as `get_Text`, `put_Text`, `add_Click`, and
+///   `remove_Click`, rather than `Text`, `SetText`, `Click`, and `RemoveClick` as with the default
+///   `naming = "projected"`. Overloaded methods other than the default overload are named by
+///   their `Overload` attribute either way, and Win32 functions, constants, and struct fields
+///   always keep the names and case of the metadata.
+/// * `rename = "Windows.Foundation.IStringable.ToString as Describe"` replaces the name generated
+///   for a WinRT method, named by its interface, or for a struct field, named by its struct. The
+///   name being replaced is the one that would otherwise be generated, and the option may be
+///   repeated. Fields used by the helpers generated for structs such as `Point` and `TimeSpan`
+///   can't be renamed. Neither option changes the names of the methods implemented with
+///   `#[implement]`.
+///
+/// # Configuration
+/// The metadata, types, and options may instead be given by a `windows.toml` file next to the
+/// crate's `Cargo.toml`, so that the configuration may be reviewed and shared with tools such as
+/// `windows_inspect` rather than embedded in the macro. The file sets the `metadata`, `output`,
+/// and `rustfmt` options and lists the types to generate, followed by the `[options]` table,
+/// whose options are named and validated as above, and the `[rename]` table mapping full member
+/// names to their new names. The macro's own options and types are
+/// applied after those of the file.
+///
+/// ```toml
+/// metadata = "10.0.19041.0"
+/// types = ["Windows.Foundation.Uri", "Windows.Win32.System.Threading.*"]
+///
+/// [options]
+/// traits = true
+/// contracts = ["UniversalApiContract 8"]
+///
+/// [rename]
+/// "Windows.Foundation.IStringable.ToString" = "Describe"
+/// ```
+///
+/// ```rust,ignore
+/// build!();
+/// ```
+///
+/// When targeting Windows, the build script also emits a `cargo:rustc-link-lib` directive for the
+/// import library of each generated Win32 function, naming the MinGW library where it differs
+/// for the GNU toolchain, so that the libraries linked with needn't be listed by hand.
+///
+/// If the code cannot be written, the build script panics with a message describing the
+/// path and the underlying error.
+///
 /// # Example
 /// The following `build!` generates all types inside of the `Microsoft::AI::MachineLearning`
 /// namespace.
@@ -40,30 +191,128 @@ impl ToTokens for RawString {
 ///     Microsoft::AI::MachineLearning::*
 /// );
 /// ```
+///
+/// The following `build!` writes the unformatted code to a path in the crate's source tree.
+///
+/// ```rust,ignore
+/// build!(
+///     output = "src/windows.rs",
+///     rustfmt = false,
+///     Windows::Win32::Foundation::CloseHandle,
+/// );
+/// ```
 #[proc_macro]
 pub fn build(stream: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = stream.to_string();
     let build = parse_macro_input!(stream as BuildMacro);
-    let tokens = RawString(build.into_tokens_string());
-    let target_dir = std::env::var("PATH").expect("No `PATH` env variable set");
-    let end = target_dir.find(';').expect("Path not ending in `;`");
-    let target_dir = RawString(target_dir[..end].to_string());
 
-    let tokens = quote! {
-        {
-            // The following must be injected into the token stream because the `OUT_DIR` and `PROFILE`
-            // environment variables are only set when the build script run and not when it is being compiled.
+    // Cargo adds the target directory to the dynamic library search path, which is needed to
+    // copy any `.windows` directory but isn't available under every build system.
+    let target_dir = deps_dir().map(|dir| dir.to_string_lossy().into_owned());
+
+    // The generated code is streamed to a file that the build script copies when it runs rather
+    // than being returned as a string literal, which would have to be held in memory by both the
+    // generator and the compiler and embedded in the build script.
+    let generated = generated_path(target_dir.as_deref());
+
+    // The header identifies the inputs that the code was generated from so that code generated
+    // from the same metadata, types, and options is neither generated nor written again.
+    let header = input_header(&input);
+
+    if !starts_with_line(&generated, &header) {
+        if let Err(error) = build.write(&generated, &header) {
+            let message = RawString(format!(
+                "Could not write generated code to `{}`: {}",
+                generated.display(),
+                error
+            ));
+
+            return quote! { ::std::compile_error!(#message); }
+                .as_str()
+                .parse()
+                .unwrap();
+        }
+    }
+
+    // The types generated in full are listed by the build script in `OUT_DIR`, where
+    // `#[implement]` finds them when the crate itself is compiled, so that it can check that the
+    // crate's bindings include the interfaces it implements.
+    let types = RawString(TypeReader::get().types.full_names().join("\n"));
+
+    let header_line = RawString(format!("{}\n", header));
+    let generated = RawString(generated.to_string_lossy().into_owned());
+
+    let target_dir = match target_dir {
+        Some(target_dir) => {
+            let target_dir = RawString(target_dir);
+            quote! { ::std::option::Option::Some(::std::path::PathBuf::from(#target_dir)) }
+        }
+        None => quote! { ::std::option::Option::None },
+    };
 
-            use ::std::io::Write;
-            let mut path = ::std::path::PathBuf::from(
-                ::std::env::var("OUT_DIR").expect("No `OUT_DIR` env variable set"),
-            );
+    let path = match &build.output {
+        Some(output) => {
+            let output = RawString(output.clone());
+            quote! {
+                let path = ::std::path::PathBuf::from(#output);
 
+                if let ::std::option::Option::Some(parent) = path.parent() {
+                    if !parent.as_os_str().is_empty() {
+                        ::std::fs::create_dir_all(parent).map_err(|error| ::std::format!("Could not create `{}`: {}", parent.display(), error))?;
+                    }
+                }
+            }
+        }
+        None => quote! {
+            let mut path = ::std::path::PathBuf::from(var("OUT_DIR")?);
             path.push("windows.rs");
-            ::std::fs::write(&path, #tokens).expect("Could not write generated code to windows.rs");
+        },
+    };
 
+    // The import libraries of the generated functions are passed to the linker by the build
+    // script as well, so that they needn't be listed by hand. Static libraries are already
+    // bundled by their `#[link]` attributes and `raw-dylib` needs no import library.
+    let links = TypeReader::get()
+        .types
+        .link_libraries()
+        .into_iter()
+        .filter_map(|link| match link {
+            types::Link::Import { msvc, gnu } => Some(quote! { (#msvc, #gnu), }),
+            _ => None,
+        })
+        .collect::<TokenStream>();
+
+    // The crate's `windows.toml` is included by the build script so that it is compiled again,
+    // and so generates the code again, when the configuration changes.
+    let config = match config_path() {
+        Some(path) => {
+            let path = RawString(path.to_string_lossy().into_owned());
+            quote! { const _: &[u8] = ::std::include_bytes!(#path); }
+        }
+        None => quote! {},
+    };
+
+    let rustfmt = if build.rustfmt {
+        quote! {
+            // Formatting is best effort since rustfmt may not be installed or may not be allowed to run.
             let mut cmd = ::std::process::Command::new("rustfmt");
             cmd.arg(&path);
             let _ = cmd.output();
+        }
+    } else {
+        quote! {}
+    };
+
+    let tokens = quote! {
+        {
+            #config
+
+            // The following must be injected into the token stream because the `OUT_DIR` and `PROFILE`
+            // environment variables are only set when the build script run and not when it is being compiled.
+
+            fn var(name: &str) -> ::std::result::Result<::std::string::String, ::std::string::String> {
+                ::std::env::var(name).map_err(|error| ::std::format!("Could not read the `{}` environment variable: {}", name, error))
+            }
 
             fn copy(source: &::std::path::Path, destination: &mut ::std::path::PathBuf) {
                 if let ::std::result::Result::Ok(entries) = ::std::fs::read_dir(source) {
@@ -105,37 +354,78 @@ pub fn build(stream: proc_macro::TokenStream) -> proc_macro::TokenStream {
                 }
             }
 
-            let mut source : ::std::path::PathBuf = ::std::env::var("CARGO_MANIFEST_DIR").expect("No `CARGO_MANIFEST_DIR` env variable set").into();
-            source.push(".windows");
+            fn build() -> ::std::result::Result<(), ::std::string::String> {
+                #path
 
-            if source.exists() {
-                println!("cargo:rerun-if-changed={}", source.to_str().expect("`CARGO_MANIFEST_DIR` not a valid path"));
+                // Rewriting unchanged code would cause the crate including it to be recompiled.
+                let current = ::std::fs::read_to_string(&path).unwrap_or_default();
 
-                // The `target_arch` cfg is not set for build scripts so we need to sniff it out from the environment variable.
-                source.push(match ::std::env::var("CARGO_CFG_TARGET_ARCH").expect("No `CARGO_CFG_TARGET_ARCH` env variable set").as_str() {
-                    "x86_64" => "x64",
-                    "x86" => "x86",
-                    "arm" => "arm",
-                    "aarch64" => "arm64",
-                    unexpected => panic!("Unexpected `{}` architecture set by `CARGO_CFG_TARGET_ARCH`", unexpected),
-                });
+                if !current.starts_with(#header_line) {
+                    ::std::fs::copy(#generated, &path).map_err(|error| ::std::format!("Could not copy generated code to `{}`: {}", path.display(), error))?;
 
-                if source.exists() {
-                    println!("cargo:rustc-link-search=native={}", source.to_str().expect("`CARGO_MANIFEST_DIR` not a valid path"));
+                    #rustfmt
                 }
 
-                let mut destination : ::std::path::PathBuf = #target_dir.into();
-                destination.pop();
-                destination.pop();
+                let types = ::std::path::Path::new(&var("OUT_DIR")?).join(#TYPES_FILE);
+                ::std::fs::write(&types, #types).map_err(|error| ::std::format!("Could not write `{}`: {}", types.display(), error))?;
+
+                if var("CARGO_CFG_TARGET_OS")? == "windows" {
+                    let gnu = ::std::env::var("CARGO_CFG_TARGET_ENV").map_or(false, |env| env == "gnu");
+                    let links: &[(&str, &str)] = &[#links];
+
+                    for (msvc, gnu_link) in links {
+                        println!("cargo:rustc-link-lib={}", if gnu { gnu_link } else { msvc });
+                    }
+                }
+
+                let mut source = ::std::path::PathBuf::from(var("CARGO_MANIFEST_DIR")?);
+                source.push(".windows");
+
+                if source.exists() {
+                    println!("cargo:rerun-if-changed={}", source.display());
+
+                    // The `target_arch` cfg is not set for build scripts so we need to sniff it out from the environment variable.
+                    source.push(match var("CARGO_CFG_TARGET_ARCH")?.as_str() {
+                        "x86_64" => "x64",
+                        "x86" => "x86",
+                        "arm" => "arm",
+                        "aarch64" => "arm64",
+                        unexpected => return ::std::result::Result::Err(::std::format!("Unexpected `{}` architecture set by `CARGO_CFG_TARGET_ARCH`", unexpected)),
+                    });
+
+                    if source.exists() {
+                        // MinGW import libraries (`lib*.a`) are kept in a `gnu` subdirectory so that they take
+                        // precedence over any MSVC import libraries when targeting the GNU environment.
+                        if ::std::env::var("CARGO_CFG_TARGET_ENV").map_or(false, |env| env == "gnu") {
+                            let gnu = source.join("gnu");
+
+                            if gnu.exists() {
+                                println!("cargo:rustc-link-search=native={}", gnu.display());
+                            }
+                        }
+
+                        println!("cargo:rustc-link-search=native={}", source.display());
+                    }
+
+                    let mut destination: ::std::path::PathBuf = #target_dir.ok_or_else(|| ::std::string::String::from("Could not determine the target directory for copying the `.windows` directory"))?;
+                    destination.pop();
+                    destination.pop();
+
+                    let profile = var("PROFILE")?;
+                    copy_to_profile(&source, &destination, &profile);
+
+                    destination.push(".windows");
+                    destination.push("winmd");
+                    source.pop();
+                    source.push("winmd");
+                    copy(&source, &mut destination);
+                }
 
-                let profile = ::std::env::var("PROFILE").expect("No `PROFILE` env variable set");
-                copy_to_profile(&source, &destination, &profile);
+                ::std::result::Result::Ok(())
+            }
 
-                destination.push(".windows");
-                destination.push("winmd");
-                source.pop();
-                source.push("winmd");
-                copy(&source, &mut destination);
+            if let ::std::result::Result::Err(message) = build() {
+                ::std::panic!("{}", message);
             }
         }
     };
@@ -143,15 +433,108 @@ pub fn build(stream: proc_macro::TokenStream) -> proc_macro::TokenStream {
     tokens.as_str().parse().unwrap()
 }
 
+// Returns the comment written at the start of the generated code, which includes a hash of the
+// metadata, the `build` macro's input, and the version of the generator.
+fn input_header(input: &str) -> String {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    hash_winmds(&mut hasher);
+    input.hash(&mut hasher);
+    config_path()
+        .and_then(|path| std::fs::read(path).ok())
+        .hash(&mut hasher);
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+
+    format!(
+        "// Generated by the windows crate from inputs with hash {:016x}.",
+        hasher.finish()
+    )
+}
+
+// Returns the path of the crate's `windows.toml`, if it has one.
+fn config_path() -> Option<std::path::PathBuf> {
+    let mut path: std::path::PathBuf = std::env::var("CARGO_MANIFEST_DIR").ok()?.into();
+    path.push(CONFIG_FILE);
+    Some(path).filter(|path| path.is_file())
+}
+
+// Returns `true` if the file at `path` starts with `line`, without reading the rest of the file.
+fn starts_with_line(path: &std::path::Path, line: &str) -> bool {
+    use std::io::BufRead;
+
+    let mut first = String::new();
+
+    std::fs::File::open(path)
+        .and_then(|file| std::io::BufReader::new(file).read_line(&mut first))
+        .map_or(false, |_| first.trim_end() == line)
+}
+
+// Returns a path for the generated code that is unique to the crate whose build script is
+// being compiled.
+fn generated_path(target_dir: Option<&str>) -> std::path::PathBuf {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    std::env::var("CARGO_MANIFEST_DIR")
+        .unwrap_or_default()
+        .hash(&mut hasher);
+
+    let name = format!(
+        "windows-{}-{:016x}.rs",
+        std::env::var("CARGO_PKG_NAME").unwrap_or_default(),
+        hasher.finish()
+    );
+
+    let mut path = match target_dir {
+        Some(target_dir) => std::path::PathBuf::from(target_dir),
+        None => std::env::temp_dir(),
+    };
+
+    path.push(name);
+    path
+}
+
+// The name of the file in `OUT_DIR` listing the types generated in full by the `build!` macro.
+const TYPES_FILE: &str = "windows.types";
+
+// Returns the names of the types generated in full by the `build!` macro of the crate being
+// compiled, or `None` if the crate doesn't generate its own bindings. Cargo only sets `OUT_DIR`
+// for crates with a build script, whose `build!` macro writes the list there before the crate is
+// compiled.
+fn generated_types() -> Option<BTreeSet<String>> {
+    generated_types_in(std::path::Path::new(&std::env::var_os("OUT_DIR")?))
+}
+
+fn generated_types_in(out_dir: &std::path::Path) -> Option<BTreeSet<String>> {
+    let names = std::fs::read_to_string(out_dir.join(TYPES_FILE)).ok()?;
+    Some(names.lines().map(str::to_string).collect())
+}
+
+// Returns the end of an error message naming the suggested alternatives, if any, to a name that
+// isn't found in metadata.
+fn did_you_mean<T: AsRef<str>>(suggestions: &[T]) -> String {
+    let suggestions: Vec<String> = suggestions
+        .iter()
+        .map(|suggestion| format!("`{}`", suggestion.as_ref()))
+        .collect();
+
+    match suggestions.len() {
+        0 => String::new(),
+        1 => format!("; did you mean {}?", suggestions[0]),
+        len => format!(
+            "; did you mean {}, or {}?",
+            suggestions[..len - 1].join(", "),
+            suggestions[len - 1]
+        ),
+    }
+}
+
 #[proc_macro]
 pub fn generate(stream: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let build = parse_macro_input!(stream as BuildMacro);
 
-    let mut tokens = String::new();
-    tokens.push_str("r#\"");
-    tokens.push_str(&build.into_tokens_string());
-    tokens.push_str("\"#");
-    tokens.parse().unwrap()
+    build.into_raw_string().parse().unwrap()
 }
 
 /// Rust structs can use the [`macro@implement`] attribute macro to implement entire WinRT
@@ -159,7 +542,100 @@ pub fn generate(stream: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///
 /// If the attribute [`proc_macro::TokenStream`] contains the name of a WinRT class then all
 /// of its interfaces are implemented. Otherwise, whatever interfaces are contained within
-/// the attribute TokenStream are implemented.
+/// the attribute TokenStream are implemented. The interfaces are reported by `GetIids` in the
+/// order they are listed.
+///
+/// If the crate generates its own bindings with [`macro@build`], the classes and interfaces
+/// must be included in full by the `build!` macro, and any that aren't are reported along with
+/// the path to add to it.
+///
+/// # Options
+/// The interfaces may be preceded by options controlling how the object behaves when queried:
+///
+/// * `agile = false` stops the object from reporting that it implements `IAgileObject`.
+/// * `marshal = free_threaded` aggregates the free-threaded marshaler so that the object
+///   implements `IMarshal` and is passed between apartments without a proxy.
+/// * `ref_count = "single_threaded"` counts references without atomic operations, for objects
+///   that are only used by the thread that created them, such as UI callbacks. The object isn't
+///   agile, can't aggregate the free-threaded marshaler, and doesn't support weak references.
+///
+/// ```ignore
+/// #[implement(marshal = free_threaded, Windows::Foundation::IStringable)]
+/// struct Stringable();
+/// ```
+///
+/// # Authoring classes
+/// `class = "Namespace.Name"` authors a runtime class of that name, whose default interface is
+/// the first interface listed. The object reports the name from `GetRuntimeClassName`, and the
+/// class is described by a winmd, named after the namespace shared by the crate's classes, that
+/// `windows_inspect --classes src/lib.rs` writes so that other languages can consume the
+/// component. The macro itself writes nothing.
+///
+/// ```ignore
+/// #[implement(class = "Contoso.Widget", Windows::Foundation::IStringable)]
+/// struct Widget();
+/// ```
+///
+/// # Forwarding
+/// `forward = field` wraps an existing object held by the struct's field, which must be an
+/// interface such as `IInspectable`. The methods named after `override` are called on the struct,
+/// while the others are forwarded to the wrapped object by querying it for the interface being
+/// called, so that only the methods being intercepted need be written. Forwarding a method to an
+/// object that doesn't implement its interface returns `E_NOINTERFACE`.
+///
+/// ```ignore
+/// #[implement(
+///     forward = inner,
+///     override ReadAsync,
+///     Windows::Storage::Streams::IInputStream,
+///     Windows::Foundation::IClosable,
+/// )]
+/// struct Logged {
+///     inner: IInputStream,
+/// }
+/// ```
+///
+/// # Extending classes
+/// `extend` followed by a composable class, such as a XAML `Application` or control, derives
+/// the object from that class, which must also be included by the `build!` macro. The class's
+/// `*Overrides` interfaces are implemented along with the listed interfaces: the methods named
+/// after `override` are called on the struct, while the others are forwarded to the base class.
+/// The class's composable constructors, such as `new`, are generated for the struct
+/// and consume it to create the object along with its base. Until then the object has no base
+/// and the methods that aren't overridden do nothing.
+///
+/// A XAML app derives from `Application` and creates its application object in the callback
+/// passed to `Application::Start`, which only returns once the app exits. The app must be
+/// packaged, as XAML apps only start in the context of a package.
+///
+/// ```ignore
+/// use Windows::ApplicationModel::Activation::LaunchActivatedEventArgs;
+/// use Windows::UI::Xaml::{Application, ApplicationInitializationCallback, Controls::TextBlock, Window};
+///
+/// #[implement(extend Windows::UI::Xaml::Application, override OnLaunched)]
+/// struct App();
+///
+/// #[allow(non_snake_case)]
+/// impl App {
+///     fn OnLaunched(&self, _: &Option<LaunchActivatedEventArgs>) -> windows::Result<()> {
+///         let text = TextBlock::new()?;
+///         text.SetText("Hello from Rust")?;
+///
+///         let window = Window::Current()?;
+///         window.SetContent(text)?;
+///         window.Activate()
+///     }
+/// }
+///
+/// fn main() -> windows::Result<()> {
+///     windows::initialize_sta()?;
+///
+///     Application::Start(ApplicationInitializationCallback::new(|_| {
+///         App().new()?;
+///         Ok(())
+///     }))
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn implement(
     attribute: proc_macro::TokenStream,
@@ -167,3 +643,80 @@ pub fn implement(
 ) -> proc_macro::TokenStream {
     implement::gen(attribute, input)
 }
+
+/// Reports an error returned by `main` the way Windows tools do, rather than with the `Debug`
+/// representation printed by the standard library.
+///
+/// When the function returns an [`Error`](../windows/struct.Error.html), its `HRESULT` and
+/// message are printed to standard error and the process exits with the `HRESULT` as its exit
+/// code, so that scripts and the tools launching the process can recover the original code.
+///
+/// # Example
+/// ```ignore
+/// #[windows::main]
+/// fn main() -> windows::Result<()> {
+///     let uri = Uri::CreateUri("https://github.com/microsoft/windows-rs")?;
+///     println!("{}", uri.Host()?);
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn main(
+    attribute: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    main_macro::gen(attribute, input)
+}
+
+/// Declares a COM interface that is not described by any metadata, such as one only available
+/// from a vendor's header or IDL file.
+///
+/// The interface's IID is given by the `uuid` attribute and its base interface follows its name.
+/// The methods are declared in vtable order using ABI types and are called without any
+/// conversion. The resulting interface may be used like any generated interface, including
+/// with `cast` and as a parameter to functions accepting its base interface, and dereferences
+/// to its base interface so that inherited methods may be called directly.
+///
+/// Interfaces declared this way cannot be implemented with [`macro@implement`] since it relies on
+/// metadata to describe the methods.
+///
+/// # Example
+/// ```ignore
+/// windows::com_interface! {
+///     #[uuid("0000010c-0000-0000-C000-000000000046")]
+///     pub interface IPersist: windows::IUnknown {
+///         fn GetClassID(&self, clsid: *mut windows::Guid) -> windows::HRESULT;
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn com_interface(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    com_interface::gen(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn types_file() {
+        let dir = std::env::temp_dir().join(format!("windows-types-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // Without a list the crate's bindings weren't generated by its own build script, so
+        // `#[implement]` doesn't check them.
+        assert_eq!(generated_types_in(&dir), None);
+
+        std::fs::write(
+            dir.join(TYPES_FILE),
+            "Windows.Foundation.IStringable\nWindows.Foundation.Uri",
+        )
+        .unwrap();
+
+        let types = generated_types_in(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(types.len(), 2);
+        assert!(types.contains("Windows.Foundation.IStringable"));
+    }
+}
@@ -0,0 +1,68 @@
+use syn::spanned::Spanned;
+
+pub fn gen(
+    attribute: proc_macro::TokenStream,
+    original_fn: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    if let Some(token) = attribute.into_iter().next() {
+        return syn::Error::new(token.span().into(), "`main` does not take any options")
+            .to_compile_error()
+            .into();
+    }
+
+    let main_fn = original_fn.clone();
+    let main_fn = syn::parse_macro_input!(main_fn as syn::ItemFn);
+
+    if let Err(error) = validate(&main_fn.sig) {
+        return error.to_compile_error().into();
+    }
+
+    // The original function is nested within the new `main` so that it shadows it, letting the
+    // body and any attributes of the function stay exactly as written.
+    let mut tokens: proc_macro::TokenStream = "fn main()".parse().unwrap();
+
+    let mut body = original_fn;
+    body.extend(
+        "if let ::std::result::Result::Err(error) = main() { ::windows::Error::exit(&error) }"
+            .parse::<proc_macro::TokenStream>()
+            .unwrap(),
+    );
+
+    tokens.extend(std::iter::once(proc_macro::TokenTree::Group(
+        proc_macro::Group::new(proc_macro::Delimiter::Brace, body),
+    )));
+
+    tokens
+}
+
+fn validate(sig: &syn::Signature) -> syn::Result<()> {
+    if sig.ident != "main" {
+        return Err(syn::Error::new(
+            sig.ident.span(),
+            "`main` may only be applied to the `main` function",
+        ));
+    }
+
+    if sig.asyncness.is_some() {
+        return Err(syn::Error::new(
+            sig.asyncness.span(),
+            "`main` may not be applied to an `async` function",
+        ));
+    }
+
+    if !sig.generics.params.is_empty() || !sig.inputs.is_empty() {
+        return Err(syn::Error::new(
+            sig.span(),
+            "`main` may not have parameters",
+        ));
+    }
+
+    if let syn::ReturnType::Default = sig.output {
+        return Err(syn::Error::new(
+            sig.span(),
+            "`main` must return `windows::Result<()>`",
+        ));
+    }
+
+    Ok(())
+}
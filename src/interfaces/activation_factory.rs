@@ -47,8 +47,8 @@ unsafe impl Interface for IActivationFactory {
     );
 }
 
-impl std::fmt::Debug for IActivationFactory {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for IActivationFactory {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.0)
     }
 }
@@ -1,5 +1,8 @@
 use super::*;
-use bindings::Windows::Foundation::{IReference, IStringable, PropertyValue};
+use bindings::Windows::{
+    Foundation::{IReference, IStringable, PropertyValue},
+    Win32::System::Com::CoTaskMemFree,
+};
 
 /// A WinRT object that may be used as a polymorphic stand-in for any WinRT class, interface, or boxed value.
 /// [`IInspectable`] represents the
@@ -13,9 +16,35 @@ impl IInspectable {
     /// Returns the canonical type name for the underlying object.
     pub fn type_name(&self) -> Result<HSTRING> {
         unsafe {
-            let mut abi = std::ptr::null_mut();
+            let mut abi = core::ptr::null_mut();
             (self.vtable().4)(self.abi(), &mut abi).ok()?;
-            Ok(std::mem::transmute(abi))
+            Ok(core::mem::transmute(abi))
+        }
+    }
+
+    /// Returns the name of the runtime class of the underlying object, such as
+    /// `Windows.Storage.StorageFile`, which is the most derived class the object was created as.
+    /// Objects implemented with `#[implement]` return an empty name unless they author a class
+    /// with its `class` option.
+    pub fn runtime_class_name(&self) -> Result<HSTRING> {
+        self.type_name()
+    }
+
+    /// Returns the interfaces implemented by the underlying object, excluding `IUnknown` and
+    /// `IInspectable`.
+    pub fn iids(&self) -> Result<Vec<Guid>> {
+        unsafe {
+            let mut count = 0;
+            let mut values = core::ptr::null_mut();
+            (self.vtable().3)(self.abi(), &mut count, &mut values).ok()?;
+
+            if values.is_null() {
+                return Ok(Vec::new());
+            }
+
+            let iids = core::slice::from_raw_parts(values, count as usize).to_vec();
+            CoTaskMemFree(values as _);
+            Ok(iids)
         }
     }
 }
@@ -48,39 +77,64 @@ unsafe impl RuntimeType for IInspectable {
         crate::ConstBuffer::from_slice(b"cinterface(IInspectable)");
 }
 
-impl std::fmt::Debug for IInspectable {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for IInspectable {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         // Attempts to retrieve the string representation of the object via the
         // IStringable interface. If that fails, it will use the canonical type
         // name to give some idea of what the object represents. This implementation
-        // is used by all of the generated `Debug` implementations for WinRT
-        // classes and interfaces.
+        // is used by the generated `Display` implementations for WinRT classes that
+        // don't implement IStringable themselves.
 
-        let name = self
+        let value = self
             .cast::<IStringable>()
             .and_then(|s| s.ToString())
             .or_else(|_| self.type_name())
             .unwrap_or_default();
 
-        write!(f, "{:?} {}", self.0, name)
+        write!(f, "{}", value)
+    }
+}
+
+impl core::fmt::Debug for IInspectable {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Writes the pointer followed by the canonical type name and, if the object
+        // implements IStringable, its quoted string representation unless that is
+        // just the type name again. This implementation is used by all of the
+        // generated `Debug` implementations for WinRT classes and interfaces.
+
+        write!(f, "{:?}", self.0)?;
+
+        let name = self.type_name().unwrap_or_default();
+
+        if !name.is_empty() {
+            write!(f, " {}", name)?;
+        }
+
+        if let Ok(value) = self.cast::<IStringable>().and_then(|s| s.ToString()) {
+            if value != name {
+                write!(f, " {:?}", value.to_string())?;
+            }
+        }
+
+        Ok(())
     }
 }
 
 macro_rules! primitive_boxed_type {
     ($(($t:ty, $m:ident)),+) => {
-        $(impl std::convert::TryFrom<$t> for IInspectable {
+        $(impl core::convert::TryFrom<$t> for IInspectable {
             type Error = Error;
             fn try_from(value: $t) -> Result<Self> {
                 PropertyValue::$m(value)
             }
         }
-        impl std::convert::TryFrom<IInspectable> for $t {
+        impl core::convert::TryFrom<IInspectable> for $t {
             type Error = Error;
             fn try_from(value: IInspectable) -> Result<Self> {
                 <IInspectable as Interface>::cast::<IReference<$t>>(&value)?.Value()
             }
         }
-        impl std::convert::TryFrom<&IInspectable> for $t {
+        impl core::convert::TryFrom<&IInspectable> for $t {
             type Error = Error;
             fn try_from(value: &IInspectable) -> Result<Self> {
                 <IInspectable as Interface>::cast::<IReference<$t>>(value)?.Value()
@@ -102,31 +156,31 @@ primitive_boxed_type! {
     (f64, CreateDouble)
 }
 
-impl std::convert::TryFrom<&str> for IInspectable {
+impl core::convert::TryFrom<&str> for IInspectable {
     type Error = Error;
     fn try_from(value: &str) -> Result<Self> {
         PropertyValue::CreateString(value)
     }
 }
-impl std::convert::TryFrom<HSTRING> for IInspectable {
+impl core::convert::TryFrom<HSTRING> for IInspectable {
     type Error = Error;
     fn try_from(value: HSTRING) -> Result<Self> {
         PropertyValue::CreateString(value)
     }
 }
-impl std::convert::TryFrom<&HSTRING> for IInspectable {
+impl core::convert::TryFrom<&HSTRING> for IInspectable {
     type Error = Error;
     fn try_from(value: &HSTRING) -> Result<Self> {
         PropertyValue::CreateString(value)
     }
 }
-impl std::convert::TryFrom<IInspectable> for HSTRING {
+impl core::convert::TryFrom<IInspectable> for HSTRING {
     type Error = Error;
     fn try_from(value: IInspectable) -> Result<Self> {
         <IInspectable as Interface>::cast::<IReference<HSTRING>>(&value)?.Value()
     }
 }
-impl std::convert::TryFrom<&IInspectable> for HSTRING {
+impl core::convert::TryFrom<&IInspectable> for HSTRING {
     type Error = Error;
     fn try_from(value: &IInspectable) -> Result<Self> {
         <IInspectable as Interface>::cast::<IReference<HSTRING>>(value)?.Value()
@@ -5,7 +5,7 @@ use crate::*;
 /// under the hood to provide reference-counted lifetime management as well as the ability
 /// to query for additional interfaces that the object may implement.
 #[repr(transparent)]
-pub struct IUnknown(std::ptr::NonNull<std::ffi::c_void>);
+pub struct IUnknown(core::ptr::NonNull<core::ffi::c_void>);
 
 #[repr(C)]
 pub struct IUnknown_abi(
@@ -37,6 +37,8 @@ impl Clone for IUnknown {
 
 impl Drop for IUnknown {
     fn drop(&mut self) {
+        check_release();
+
         unsafe {
             (self.vtable().2)(self.abi()); // Release
         }
@@ -55,8 +57,8 @@ impl PartialEq for IUnknown {
 
 impl Eq for IUnknown {}
 
-impl std::fmt::Debug for IUnknown {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for IUnknown {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.0)
     }
 }
@@ -6,8 +6,20 @@ future using code generated on the fly directly from the metadata describing the
 where you can call them as if they were just another Rust module.
 
 Learn more here: <https://github.com/microsoft/windows-rs>
+
+# `no_std`
+
+Without its default `std` feature the crate is `#![no_std]` and only depends on `core` and
+`alloc`, for use by drivers and other environments without `std`. The core of the runtime, such
+as [`HRESULT`], [`Error`], [`Guid`], [`HSTRING`], and the interfaces and traits calling and
+implementing COM and WinRT interfaces, remains available, while the helpers built on `std`, such
+as those for files, devices, threads, and collections, are omitted. Bindings are generated for
+such crates with the `std_feature` option of [`build`]. Strings are allocated by the
+[`Allocator`] installed with [`set_allocator`] rather than the global allocator.
 */
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 #[macro_use]
 mod macros;
 
@@ -26,22 +38,77 @@ pub use bindings::Windows::Win32::System::Com::IAgileObject;
 #[doc(hidden)]
 pub use interfaces::{IActivationFactory, IInspectable_abi};
 
+#[doc(hidden)]
+pub use runtime::{copy_iids, FreeThreadedMarshaler};
+
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub use runtime::ApiCounter;
+
+#[doc(hidden)]
+pub use runtime::{
+    encode_utf16, track_object, untrack_object, utf16_len, HStringReference, StringParam,
+    WideBuffer,
+};
+
+#[cfg(all(feature = "std", feature = "tokio"))]
+pub use runtime::spawn_blocking;
+
 pub use interfaces::{IInspectable, IUnknown};
 pub use result::{Error, Result, HRESULT};
+#[cfg(feature = "std")]
+pub use runtime::{
+    api_usage, app_user_model_id, bind_to_object, create_hard_link, create_junction,
+    create_symbolic_link, file_info, file_streams, from_wide_path, initialize_with_window,
+    input_stream, iterable, map, map_view, observable_vector, on_shutdown, pair_device,
+    pick_device, register_server, rename_durable, requires_window, running_object, running_objects,
+    server_manifest, set_app_user_model_id, set_compressed, set_file_attributes, set_file_times,
+    set_late_release_check, set_sparse, shutdown, spawn_on, to_wide_path, uninitialize,
+    unregister_server, vector, vector_view, write_api_usage, write_atomic,
+    write_atomic_with_backup, ActivationContext, ActivationScope, Allocation, ApartmentChecked,
+    AsyncFuture, CancellationToken, Cancelled, ControlCode, Device, DeviceControl, DeviceInfo,
+    DeviceInfoIter, DeviceInfoSet, DeviceInterfaceEvent, DeviceNotification, Dispatch, Dispatcher,
+    DispatcherThread, Event, EventArgs, EventConnection, EventSender, EventSink, EventStream,
+    Fiber, FiberContext, FileAttributes, FileInfo, FileStream, FileTimes, HidAttributes,
+    HidButtonCaps, HidCaps, HidDevice, HidInputReports, HidReportType, HidValueCaps,
+    InputStreamReader, LeakCheck, LiveObject, MemoryBufferBytes, Module, ObjectTracker,
+    OutputStreamWriter, PairingRequest, PairingResponse, ProcessErrorMode, PropVariant,
+    PropertyChangedEvent, PropertyKey, PropertyStore, PropertyValue, RandomAccessStreamIo,
+    RegistrationScope, ResourceId, RunningObject, ServerClass, ThreadErrorMode, ThreadFiber,
+    ThreadingModel, TransferMethod, UsnJournal, UsnRecord, UsnRecords, Variant, WindowAppUserModel,
+};
 pub use runtime::{
-    create_instance, factory, initialize_mta, initialize_sta, Array, FactoryCache, Guid, Param,
-    RefCount, Waiter, Weak, WeakRefCount, HSTRING,
+    create_instance, factory, initialize_mta, initialize_sta, is_api_contract_present,
+    is_type_present, pinterface_signature, set_allocator, Allocator, Array, AsyncOperation,
+    FactoryCache, Guid, Param, ProcessHeap, RefCount, SafeArray, SafeArrayElement, Signature,
+    SingleThreadedRefCount, Waiter, Weak, WeakRefCount, WideStr, HSTRING,
 };
-pub use traits::{Abi, Compose, Interface, IntoParam, RuntimeName, RuntimeType};
+pub use traits::{Abi, Compose, Interface, IntoParam, ParamAbi, RuntimeName, RuntimeType};
 
 #[cfg(feature = "macros")]
-pub use windows_macros::{build, implement};
+pub use windows_macros::{build, com_interface, implement, main};
 
 extern crate self as windows;
 
+// Generated code names the `alloc` types through this crate so that crates without `std` needn't
+// declare `extern crate alloc` themselves.
+#[doc(hidden)]
+pub extern crate alloc;
+
+// The `alloc` types that the `std` prelude would otherwise provide.
+#[allow(unused_imports)]
+use alloc::{
+    borrow::ToOwned,
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
 // TODO: rather than hiding, consider just removing
 #[doc(hidden)]
-pub type RawPtr = *mut std::ffi::c_void;
+pub type RawPtr = *mut core::ffi::c_void;
 
 #[doc(hidden)]
 pub use const_sha1::ConstBuffer;
@@ -2,10 +2,46 @@
 #[macro_export]
 macro_rules! include_bindings {
     () => {
-        ::std::include!(::std::concat!(::std::env!("OUT_DIR"), "/windows.rs"));
+        ::core::include!(::core::concat!(::core::env!("OUT_DIR"), "/windows.rs"));
     };
 }
 
+/// Encodes a string literal as a null-terminated UTF-16 [`WideStr`](crate::WideStr) at compile
+/// time, suitable for passing directly to `PWSTR` parameters.
+#[macro_export]
+macro_rules! w {
+    ($s:literal) => {{
+        const INPUT: &[u8] = $s.as_bytes();
+        const OUTPUT_LEN: usize = $crate::utf16_len(INPUT) + 1;
+        static OUTPUT: [u16; OUTPUT_LEN] = $crate::encode_utf16(INPUT);
+        unsafe { $crate::WideStr::from_static(&OUTPUT) }
+    }};
+}
+
+/// Encodes a string literal as a `&'static` [`HSTRING`](crate::HSTRING) at compile time.
+///
+/// The string is a "fast pass" string backed by static data so no memory is allocated unless the
+/// string is cloned.
+#[macro_export]
+macro_rules! h {
+    ($s:literal) => {{
+        const INPUT: &[u8] = $s.as_bytes();
+        const OUTPUT_LEN: usize = $crate::utf16_len(INPUT) + 1;
+        static OUTPUT: [u16; OUTPUT_LEN] = $crate::encode_utf16(INPUT);
+        static HEADER: $crate::HStringReference = $crate::HStringReference::new(
+            &OUTPUT as *const [u16; OUTPUT_LEN] as *const u16,
+            (OUTPUT_LEN - 1) as u32,
+        );
+        // An empty string is represented by a null pointer.
+        static REFERENCE: ::core::option::Option<&$crate::HStringReference> = if OUTPUT_LEN == 1 {
+            ::core::option::Option::None
+        } else {
+            ::core::option::Option::Some(&HEADER)
+        };
+        $crate::HSTRING::from_reference(&REFERENCE)
+    }};
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! demand_load {
@@ -14,22 +50,24 @@ macro_rules! demand_load {
     } )* ) => {
         $($(
             #[allow(non_snake_case)]
-            unsafe fn $sym( $( $param: $pty ),* ) -> ::std::result::Result<$rt, $crate::HRESULT> {
-                static ONCE: ::std::sync::Once = ::std::sync::Once::new();
-                static mut VALUE: ::std::mem::MaybeUninit<::std::result::Result<$crate::RawPtr, $crate::HRESULT>> =
-                    ::std::mem::MaybeUninit::uninit();
+            unsafe fn $sym( $( $param: $pty ),* ) -> ::core::result::Result<$rt, $crate::HRESULT> {
+                // The address is looked up until it's found, without a lock so that the macro
+                // doesn't depend on `std`. Racing threads find and store the same address.
+                static VALUE: ::core::sync::atomic::AtomicPtr<::core::ffi::c_void> =
+                    ::core::sync::atomic::AtomicPtr::new(::core::ptr::null_mut());
+
+                let mut value = VALUE.load(::core::sync::atomic::Ordering::Relaxed);
 
-                ONCE.call_once(|| {
-                    VALUE = ::std::mem::MaybeUninit::new(
-                        $crate::delay_load($library, ::std::stringify!($sym))
-                    )
-                });
+                if value.is_null() {
+                    value = $crate::delay_load($library, ::core::stringify!($sym))?;
+                    VALUE.store(value, ::core::sync::atomic::Ordering::Relaxed);
+                }
 
                 // transmute() doesn't work on generic types, as you can't constrain to a
                 // function pointer, so it must be done here outside load_proc().
                 type FnPtr = extern "system" fn ( $( $param: $pty ),* ) -> $rt;
-                let f = ::std::mem::transmute::<$crate::RawPtr, FnPtr>(VALUE.assume_init()?);
-                ::std::result::Result::Ok( (f)( $( $param ),* ) )
+                let f = ::core::mem::transmute::<$crate::RawPtr, FnPtr>(value);
+                ::core::result::Result::Ok( (f)( $( $param ),* ) )
             }
         )*)*
     };
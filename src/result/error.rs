@@ -1,5 +1,5 @@
 use crate::*;
-use std::convert::TryInto;
+use core::convert::TryInto;
 
 use bindings::{
     Windows::Win32::Foundation::BSTR,
@@ -43,6 +43,14 @@ impl Error {
         Self { code, info: None }
     }
 
+    /// Creates an error from the calling thread's last error code, which Win32 functions set when
+    /// they fail.
+    #[cold]
+    #[inline(never)]
+    pub fn from_thread() -> Self {
+        HRESULT::from_thread().into()
+    }
+
     /// The error code describing the error.
     pub const fn code(&self) -> HRESULT {
         self.code
@@ -82,18 +90,29 @@ impl Error {
         self.code.message()
     }
 
-    /// Returns the win32 error code if the underlying HRESULT's facility is win32
-    fn win32_code(&self) -> Option<u32> {
-        let hresult = self.code.0;
-        if ((hresult >> 16) & 0x7FF) == 7 {
-            Some(hresult & 0xFFFF)
+    /// Prints the error code and message to standard error and exits the process with the
+    /// error code as its exit code, as Windows tools do. This is called when `main` fails if
+    /// it's marked with the `windows::main` attribute.
+    #[cfg(feature = "std")]
+    pub fn exit(&self) -> ! {
+        let message = self.message();
+
+        if message.is_empty() {
+            eprintln!("Error {:#010X}", self.code.0);
         } else {
-            None
+            eprintln!("Error {:#010X}: {}", self.code.0, message);
         }
+
+        std::process::exit(self.code.0 as i32)
+    }
+
+    /// Returns the win32 error code if the underlying HRESULT's facility is win32
+    fn win32_code(&self) -> Option<u32> {
+        self.code.to_win32()
     }
 }
 
-impl std::convert::From<Error> for HRESULT {
+impl core::convert::From<Error> for HRESULT {
     fn from(error: Error) -> Self {
         let code = error.code;
         let info = error.info.and_then(|info| info.cast().ok());
@@ -106,7 +125,11 @@ impl std::convert::From<Error> for HRESULT {
     }
 }
 
-impl std::convert::From<HRESULT> for Error {
+// Every call through the generated bindings that fails ends up here, so the conversion is kept
+// out of line and marked cold to leave only a call on each caller's error path.
+impl core::convert::From<HRESULT> for Error {
+    #[cold]
+    #[inline(never)]
     fn from(code: HRESULT) -> Self {
         let info: Option<IRestrictedErrorInfo> =
             unsafe { GetErrorInfo(0).and_then(|e| e.cast()).ok() };
@@ -137,8 +160,8 @@ impl std::convert::From<HRESULT> for Error {
     }
 }
 
-impl std::fmt::Debug for Error {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for Error {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let mut debug = fmt.debug_struct("Error");
         debug
             .field("code", &format_args!("{:#010X}", self.code.0))
@@ -150,14 +173,48 @@ impl std::fmt::Debug for Error {
     }
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         fmt.write_str(&self.message())
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+// The error information created by `RoOriginateError` is agile and may be used from any thread.
+unsafe impl Send for Error {}
+unsafe impl Sync for Error {}
+
+/// Errors with a win32 error code become OS errors so that their kind may be inspected, while any
+/// other error is wrapped so that it may be recovered by converting back to an [`Error`].
+#[cfg(feature = "std")]
+impl core::convert::From<Error> for std::io::Error {
+    fn from(error: Error) -> Self {
+        match error.win32_code() {
+            Some(code) => Self::from_raw_os_error(code as i32),
+            None => Self::new(std::io::ErrorKind::Other, error),
+        }
+    }
+}
+
+/// OS errors become the equivalent `HRESULT`, while an [`Error`] wrapped by an I/O error is
+/// returned unchanged. Any other I/O error becomes `E_FAIL` with the I/O error's message.
+#[cfg(feature = "std")]
+impl core::convert::From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        if let Some(code) = error.raw_os_error() {
+            return Self::new(HRESULT::from_win32(code as u32), "");
+        }
+
+        if error.get_ref().map_or(false, |inner| inner.is::<Self>()) {
+            return *error.into_inner().unwrap().downcast::<Self>().unwrap();
+        }
+
+        Self::new(HRESULT::E_FAIL, &error.to_string())
+    }
+}
+
 demand_load! {
     "combase.dll" {
         fn RoOriginateError(code: HRESULT, message: RawPtr) -> i32;
@@ -167,6 +224,7 @@ demand_load! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use bindings::Windows::Win32::Foundation::E_POINTER;
 
     #[test]
     fn win32_error_conversion() {
@@ -174,4 +232,43 @@ mod tests {
         let win32_error = code.win32_code();
         assert_eq!(win32_error, Some(18))
     }
+
+    #[test]
+    fn from_thread() {
+        use bindings::Windows::Win32::Foundation::{CloseHandle, HANDLE};
+
+        // ERROR_INVALID_HANDLE
+        let error = unsafe { CloseHandle(HANDLE(0)).ok().unwrap_err() };
+        assert_eq!(error.code(), HRESULT::from_win32(6));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_error() {
+        fn assert_error<T: std::error::Error + Send + Sync + 'static>() {}
+        assert_error::<Error>();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_error_conversion() {
+        // ERROR_FILE_NOT_FOUND
+        let error: std::io::Error = Error::new(HRESULT::from_win32(2), "").into();
+        assert_eq!(error.raw_os_error(), Some(2));
+        assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+
+        let error: Error = error.into();
+        assert_eq!(error.code(), HRESULT::from_win32(2));
+
+        let error: std::io::Error = Error::new(E_POINTER, "test message").into();
+        assert_eq!(error.kind(), std::io::ErrorKind::Other);
+
+        let error: Error = error.into();
+        assert_eq!(error.code(), E_POINTER);
+        assert_eq!(error.message(), "test message");
+
+        let error: Error = std::io::Error::new(std::io::ErrorKind::Other, "io message").into();
+        assert_eq!(error.code(), HRESULT::E_FAIL);
+        assert_eq!(error.message(), "io message");
+    }
 }
@@ -1,7 +1,7 @@
 use crate::*;
 
 use bindings::{
-    Windows::Win32::Foundation::{E_POINTER, PWSTR},
+    Windows::Win32::Foundation::{self, E_POINTER, PWSTR},
     Windows::Win32::System::Diagnostics::Debug::*,
 };
 
@@ -41,12 +41,13 @@ impl HRESULT {
         if self.is_ok() {
             Ok(())
         } else {
-            Err(self.into())
+            Err(Error::from(self))
         }
     }
 
     /// Returns the [`Option`] as a [`Result`] if the option is a [`Some`] value, returning
     /// a suitable error if not.
+    #[inline]
     pub fn and_some<T: Interface>(self, some: Option<T>) -> Result<T> {
         if self.is_ok() {
             if let Some(result) = some {
@@ -71,6 +72,7 @@ impl HRESULT {
     }
 
     /// If the [`Result`] is [`Ok`] converts the `T::Abi` into `T`.
+    #[inline]
     pub unsafe fn from_abi<T: Abi>(self, abi: T::Abi) -> Result<T> {
         if self.is_ok() {
             T::from_abi(abi)
@@ -93,28 +95,56 @@ impl HRESULT {
         Self(if error as i32 <= 0 {
             error
         } else {
-            (error & 0x0000_FFFF) | (7 << 16) | 0x8000_0000
+            (error & 0x0000_FFFF) | (FACILITY_WIN32.0 << 16) | 0x8000_0000
         })
     }
 
+    /// Returns the facility identifying the source of the code, such as `7` for codes created
+    /// with [`HRESULT::from_win32`].
+    ///
+    /// This is equivalent to [HRESULT_FACILITY](https://docs.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_facility).
+    #[inline]
+    pub const fn facility(self) -> u32 {
+        (self.0 >> 16) & 0x1FFF
+    }
+
+    /// Returns the code identifying the error within its facility.
+    ///
+    /// This is equivalent to [HRESULT_CODE](https://docs.microsoft.com/en-us/windows/win32/api/winerror/nf-winerror-hresult_code).
+    #[inline]
+    pub const fn code(self) -> u32 {
+        self.0 & 0xFFFF
+    }
+
+    /// Returns the win32 error code if `self` is a failure code created from one, reversing
+    /// [`HRESULT::from_win32`].
+    #[inline]
+    pub const fn to_win32(self) -> Option<u32> {
+        if self.is_err() && self.facility() == FACILITY_WIN32.0 {
+            Some(self.code())
+        } else {
+            None
+        }
+    }
+
     /// The error message describing the error.
     pub fn message(&self) -> String {
-        let mut message = HeapString(std::ptr::null_mut());
+        let mut message = HeapString(core::ptr::null_mut());
 
         unsafe {
             let size = FormatMessageW(
                 FORMAT_MESSAGE_ALLOCATE_BUFFER
                     | FORMAT_MESSAGE_FROM_SYSTEM
                     | FORMAT_MESSAGE_IGNORE_INSERTS,
-                std::ptr::null(),
+                core::ptr::null(),
                 self.0,
                 0,
-                PWSTR(std::mem::transmute(&mut message.0)),
+                PWSTR(core::mem::transmute(&mut message.0)),
                 0,
-                std::ptr::null_mut(),
+                core::ptr::null_mut(),
             );
 
-            String::from_utf16_lossy(std::slice::from_raw_parts(
+            String::from_utf16_lossy(core::slice::from_raw_parts(
                 message.0 as *const u16,
                 size as usize,
             ))
@@ -124,11 +154,70 @@ impl HRESULT {
     }
 }
 
+/// Well-known codes, with the values described by the metadata, so that code calling COM directly
+/// needn't hard-code them or import them from the metadata itself.
+impl HRESULT {
+    /// The operation succeeded.
+    pub const S_OK: Self = Foundation::S_OK;
+    /// The operation succeeded but returned a negative or partial result.
+    pub const S_FALSE: Self = Foundation::S_FALSE;
+    /// The operation was aborted.
+    pub const E_ABORT: Self = Foundation::E_ABORT;
+    /// Access was denied.
+    pub const E_ACCESSDENIED: Self = Foundation::E_ACCESSDENIED;
+    /// An index was out of bounds.
+    pub const E_BOUNDS: Self = Foundation::E_BOUNDS;
+    /// A collection was changed while it was being iterated.
+    pub const E_CHANGED_STATE: Self = Foundation::E_CHANGED_STATE;
+    /// An unspecified failure occurred.
+    pub const E_FAIL: Self = Foundation::E_FAIL;
+    /// A handle was invalid.
+    pub const E_HANDLE: Self = Foundation::E_HANDLE;
+    /// A delegate was assigned when not allowed.
+    pub const E_ILLEGAL_DELEGATE_ASSIGNMENT: Self = Foundation::E_ILLEGAL_DELEGATE_ASSIGNMENT;
+    /// A method was called at an unexpected time.
+    pub const E_ILLEGAL_METHOD_CALL: Self = Foundation::E_ILLEGAL_METHOD_CALL;
+    /// A state change was requested that is not allowed.
+    pub const E_ILLEGAL_STATE_CHANGE: Self = Foundation::E_ILLEGAL_STATE_CHANGE;
+    /// An argument was invalid.
+    pub const E_INVALIDARG: Self = Foundation::E_INVALIDARG;
+    /// The requested interface is not supported.
+    pub const E_NOINTERFACE: Self = Foundation::E_NOINTERFACE;
+    /// The method is not implemented.
+    pub const E_NOTIMPL: Self = Foundation::E_NOTIMPL;
+    /// Memory could not be allocated.
+    pub const E_OUTOFMEMORY: Self = Foundation::E_OUTOFMEMORY;
+    /// A pointer was invalid, such as an unexpected null pointer.
+    pub const E_POINTER: Self = Foundation::E_POINTER;
+    /// An unexpected failure occurred.
+    pub const E_UNEXPECTED: Self = Foundation::E_UNEXPECTED;
+    /// The class factory cannot provide the requested class.
+    pub const CLASS_E_CLASSNOTAVAILABLE: Self = Foundation::CLASS_E_CLASSNOTAVAILABLE;
+    /// The class does not support aggregation.
+    pub const CLASS_E_NOAGGREGATION: Self = Foundation::CLASS_E_NOAGGREGATION;
+    /// The class is not licensed for use.
+    pub const CLASS_E_NOTLICENSED: Self = Foundation::CLASS_E_NOTLICENSED;
+    /// COM has not been initialized on the calling thread.
+    pub const CO_E_NOTINITIALIZED: Self = Foundation::CO_E_NOTINITIALIZED;
+    /// The class is not registered.
+    pub const REGDB_E_CLASSNOTREG: Self = Foundation::REGDB_E_CLASSNOTREG;
+    /// The call was rejected by the callee.
+    pub const RPC_E_CALL_REJECTED: Self = Foundation::RPC_E_CALL_REJECTED;
+    /// COM was already initialized on the calling thread with a different concurrency model.
+    pub const RPC_E_CHANGED_MODE: Self = Foundation::RPC_E_CHANGED_MODE;
+    /// The object has disconnected from its clients.
+    pub const RPC_E_DISCONNECTED: Self = Foundation::RPC_E_DISCONNECTED;
+    /// The callee is busy and the call should be retried later.
+    pub const RPC_E_SERVERCALL_RETRYLATER: Self = Foundation::RPC_E_SERVERCALL_RETRYLATER;
+    /// An interface was used from an apartment other than the one it belongs to.
+    pub const RPC_E_WRONG_THREAD: Self = Foundation::RPC_E_WRONG_THREAD;
+}
+
 unsafe impl Abi for HRESULT {
     type Abi = Self;
 }
 
-impl<T> std::convert::From<Result<T>> for HRESULT {
+impl<T> core::convert::From<Result<T>> for HRESULT {
     fn from(result: Result<T>) -> Self {
         if let Err(error) = result {
             return error.into();
@@ -149,3 +238,22 @@ impl Drop for HeapString {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts() {
+        let code = HRESULT::from_win32(5);
+        assert_eq!(code, HRESULT::E_ACCESSDENIED);
+        assert_eq!(code.facility(), 7);
+        assert_eq!(code.code(), 5);
+        assert_eq!(code.to_win32(), Some(5));
+
+        assert_eq!(HRESULT::E_NOINTERFACE.facility(), 0);
+        assert_eq!(HRESULT::E_NOINTERFACE.to_win32(), None);
+        assert_eq!(HRESULT::S_OK.to_win32(), None);
+        assert_eq!(HRESULT::RPC_E_WRONG_THREAD.facility(), 1);
+    }
+}
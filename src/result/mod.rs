@@ -6,4 +6,4 @@ pub use hresult::*;
 
 /// A [`Result`] type that provides Windows error information.
 #[must_use]
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,115 @@
+use crate::*;
+use bindings::Windows::Win32::Foundation::RPC_E_WRONG_THREAD;
+use std::thread::ThreadId;
+
+/// Holds an interface that may only be used from the thread that created it, checking on each
+/// use that it is being used from that thread.
+///
+/// Calling a non-agile object, such as a proxy or an object living in a single-threaded apartment,
+/// from another thread usually fails with `RPC_E_WRONG_THREAD` well away from where the object
+/// escaped its thread. The wrapper instead panics with a message naming the interface and both
+/// threads when dereferenced from the wrong thread, while [`ApartmentChecked::try_get`] returns
+/// the error instead. Agile objects are never checked.
+///
+/// The wrapper is both `Send` and `Sync` since the interface can only be reached from the
+/// original thread. Dropping the wrapper on another thread leaks the interface rather than
+/// releasing it from the wrong apartment.
+///
+/// ```ignore
+/// let window = windows::ApartmentChecked::new(core_window);
+/// std::thread::spawn(move || window.Activate());
+/// // panics: `CoreWindow` was created on thread ThreadId(1) but used on thread ThreadId(2)...
+/// ```
+pub struct ApartmentChecked<T: Interface> {
+    value: std::mem::ManuallyDrop<T>,
+    // The thread that created the wrapper, or `None` if the object is agile.
+    thread: Option<ThreadId>,
+}
+
+impl<T: Interface> ApartmentChecked<T> {
+    /// Wraps `value`, capturing the calling thread unless the object is agile.
+    pub fn new(value: T) -> Self {
+        let thread = if value.cast::<IAgileObject>().is_ok() {
+            None
+        } else {
+            Some(std::thread::current().id())
+        };
+
+        Self {
+            value: std::mem::ManuallyDrop::new(value),
+            thread,
+        }
+    }
+
+    /// Returns `true` if the interface may be used from the calling thread.
+    pub fn is_valid(&self) -> bool {
+        self.thread
+            .map_or(true, |thread| thread == std::thread::current().id())
+    }
+
+    /// Returns the interface, failing with `RPC_E_WRONG_THREAD` if called from another thread.
+    pub fn try_get(&self) -> Result<&T> {
+        if self.is_valid() {
+            Ok(&self.value)
+        } else {
+            Err(Error::fast_error(RPC_E_WRONG_THREAD))
+        }
+    }
+
+    /// Returns the interface, panicking if called from another thread.
+    pub fn get(&self) -> &T {
+        self.check();
+        &self.value
+    }
+
+    /// Returns the interface, panicking if called from another thread.
+    pub fn into_inner(mut self) -> T {
+        self.check();
+        let value = unsafe { std::mem::ManuallyDrop::take(&mut self.value) };
+        std::mem::forget(self);
+        value
+    }
+
+    fn check(&self) {
+        if let Some(thread) = self.thread {
+            let current = std::thread::current().id();
+
+            if thread != current {
+                panic!(
+                    "`{}` was created on thread {:?} but used on thread {:?}; non-agile objects must only be used from the apartment that created them",
+                    std::any::type_name::<T>(),
+                    thread,
+                    current
+                );
+            }
+        }
+    }
+}
+
+impl<T: Interface> std::ops::Deref for ApartmentChecked<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T: Interface> Drop for ApartmentChecked<T> {
+    fn drop(&mut self) {
+        if self.is_valid() {
+            unsafe { std::mem::ManuallyDrop::drop(&mut self.value) }
+        }
+    }
+}
+
+impl<T: Interface> std::fmt::Debug for ApartmentChecked<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApartmentChecked")
+            .field("thread", &self.thread)
+            .finish()
+    }
+}
+
+// The interface can only be reached from the thread that created it.
+unsafe impl<T: Interface> Send for ApartmentChecked<T> {}
+unsafe impl<T: Interface> Sync for ApartmentChecked<T> {}
@@ -0,0 +1,87 @@
+use std::cell::Cell;
+use std::sync::atomic::{AtomicPtr, AtomicUsize, Ordering};
+
+// The head of an intrusive, append-only list of every counter that has been hit at least once.
+static COUNTERS: AtomicPtr<ApiCounter> = AtomicPtr::new(std::ptr::null_mut());
+
+thread_local! {
+    // The name of the generated function most recently called on this thread.
+    static LAST_CALL: Cell<Option<&'static str>> = Cell::new(None);
+}
+
+/// A per-function call counter inserted by bindings generated with `api_stats = true` when the
+/// consuming crate enables its `api_stats` feature and should not be used directly.
+#[doc(hidden)]
+pub struct ApiCounter {
+    name: &'static str,
+    count: AtomicUsize,
+    next: AtomicPtr<ApiCounter>,
+}
+
+impl ApiCounter {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            count: AtomicUsize::new(0),
+            next: AtomicPtr::new(std::ptr::null_mut()),
+        }
+    }
+
+    pub fn increment(&'static self) {
+        // The thread-local may already have been destroyed if called while the thread exits.
+        let _ = LAST_CALL.try_with(|last| last.set(Some(self.name)));
+
+        // Only the first call registers the counter so the common path is a single atomic add.
+        if self.count.fetch_add(1, Ordering::Relaxed) == 0 {
+            let this = self as *const Self as *mut Self;
+            let mut head = COUNTERS.load(Ordering::Acquire);
+
+            loop {
+                self.next.store(head, Ordering::Relaxed);
+
+                match COUNTERS.compare_exchange_weak(
+                    head,
+                    this,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                ) {
+                    Ok(_) => break,
+                    Err(current) => head = current,
+                }
+            }
+        }
+    }
+}
+
+/// Returns the name and call count of every generated Windows API function that has been called
+/// so far, sorted by name.
+///
+/// Counters are only present when the bindings are generated with `api_stats = true` and the
+/// crate containing them enables its `api_stats` feature. The names can be used to trim the
+/// types and functions imported by the `build` macro.
+pub fn api_usage() -> Vec<(&'static str, usize)> {
+    let mut usage = Vec::new();
+    let mut next = COUNTERS.load(Ordering::Acquire);
+
+    while let Some(counter) = unsafe { next.as_ref() } {
+        usage.push((counter.name, counter.count.load(Ordering::Relaxed)));
+        next = counter.next.load(Ordering::Relaxed);
+    }
+
+    usage.sort_unstable();
+    usage
+}
+
+// Returns the name of the generated function most recently called on the calling thread.
+pub(crate) fn last_call() -> Option<&'static str> {
+    LAST_CALL.try_with(|last| last.get()).unwrap_or(None)
+}
+
+/// Writes the report returned by [`api_usage`] to `writer`, one function per line.
+pub fn write_api_usage<W: std::io::Write>(mut writer: W) -> std::io::Result<()> {
+    for (name, count) in api_usage() {
+        writeln!(writer, "{} {}", name, count)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::*;
+use bindings::Windows::Foundation::Metadata::ApiInformation;
+
+/// Returns `true` if the WinRT type with the given full name, such as
+/// `Windows.UI.Xaml.Hosting.DesktopWindowXamlSource`, is present on the running version of
+/// Windows. The generated bindings use this to implement the `is_present` function of each class.
+///
+/// Types are reported as missing if the presence of the type cannot be determined, such as on
+/// versions of Windows without `ApiInformation`.
+pub fn is_type_present(name: &str) -> bool {
+    ApiInformation::IsTypePresent(name).unwrap_or(false)
+}
+
+/// Returns `true` if the given major version of the API contract with the given full name, such
+/// as `Windows.Foundation.UniversalApiContract`, is present on the running version of Windows.
+pub fn is_api_contract_present(name: &str, major: u16) -> bool {
+    ApiInformation::IsApiContractPresentByMajor(name, major).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn present() {
+        assert!(is_type_present("Windows.Foundation.Uri"));
+        assert!(!is_type_present("Windows.Foundation.Missing"));
+        assert!(is_api_contract_present(
+            "Windows.Foundation.UniversalApiContract",
+            1
+        ));
+        assert!(!is_api_contract_present(
+            "Windows.Foundation.UniversalApiContract",
+            u16::MAX
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+use super::prop_variant::wide_slice;
+use crate::*;
+use bindings::Windows::Win32::{
+    System::Com::CoTaskMemFree,
+    UI::Shell::{GetCurrentProcessExplicitAppUserModelID, SetCurrentProcessExplicitAppUserModelID},
+};
+
+// The `System.AppUserModel` property set, whose keys aren't described by the metadata.
+const APP_USER_MODEL: Guid = Guid::from_values(
+    0x9F4C_2855,
+    0x9F79,
+    0x4B39,
+    [0xA8, 0xD0, 0xE1, 0xD4, 0x2D, 0xE1, 0xD5, 0xF3],
+);
+
+const RELAUNCH_COMMAND: PropertyKey = PropertyKey::new(APP_USER_MODEL, 2);
+const RELAUNCH_ICON_RESOURCE: PropertyKey = PropertyKey::new(APP_USER_MODEL, 3);
+const RELAUNCH_DISPLAY_NAME_RESOURCE: PropertyKey = PropertyKey::new(APP_USER_MODEL, 4);
+const ID: PropertyKey = PropertyKey::new(APP_USER_MODEL, 5);
+const PREVENT_PINNING: PropertyKey = PropertyKey::new(APP_USER_MODEL, 9);
+
+/// Sets the AppUserModelID of the process, such as `Contoso.Editor`, which decides how the
+/// process's windows are grouped on the taskbar and which app toast notifications are attributed
+/// to. It must be set before the process shows any UI.
+pub fn set_app_user_model_id(id: &str) -> Result<()> {
+    unsafe { SetCurrentProcessExplicitAppUserModelID(id) }
+}
+
+/// Returns the AppUserModelID set with [`set_app_user_model_id`], or `None` if the process uses
+/// the ID that Windows assigns it.
+pub fn app_user_model_id() -> Option<String> {
+    unsafe {
+        let id = GetCurrentProcessExplicitAppUserModelID().ok()?;
+        let result = String::from_utf16_lossy(wide_slice(id));
+        CoTaskMemFree(id.0 as _);
+        Some(result)
+    }
+}
+
+/// The AppUserModel properties of a top-level window, which group the window on the taskbar
+/// separately from the rest of the process and decide how it is relaunched once pinned.
+///
+/// The properties must be removed with [`WindowAppUserModel::clear`] before the window is
+/// destroyed, such as when it receives `WM_DESTROY`.
+///
+/// ```ignore
+/// let model = windows::WindowAppUserModel::new(hwnd.0)?;
+/// model.set_id("Contoso.Editor.Document")?;
+/// model.set_relaunch_command(r#""C:\Contoso\editor.exe" --document"#, "Contoso Document")?;
+/// model.set_relaunch_icon(r"C:\Contoso\editor.exe,-101")?;
+/// ```
+pub struct WindowAppUserModel {
+    store: PropertyStore,
+}
+
+impl WindowAppUserModel {
+    /// Opens the properties of the window.
+    pub fn new(window: isize) -> Result<Self> {
+        Ok(Self {
+            store: PropertyStore::for_window(window)?,
+        })
+    }
+
+    /// Returns the window's AppUserModelID, or `None` if it shares that of the process.
+    pub fn id(&self) -> Result<Option<String>> {
+        self.store.get(&ID)
+    }
+
+    /// Sets the window's AppUserModelID, grouping it on the taskbar with the other windows and
+    /// processes sharing the ID.
+    pub fn set_id(&self, id: &str) -> Result<()> {
+        self.store.set(&ID, id.to_string())
+    }
+
+    /// Sets the command line that relaunches the window once pinned to the taskbar, along with
+    /// the name shown for it. The command is only used if the window also has an ID, and the
+    /// name may be an indirect string such as `@shell32.dll,-1000`.
+    pub fn set_relaunch_command(&self, command: &str, display_name: &str) -> Result<()> {
+        self.store.set(&RELAUNCH_COMMAND, command.to_string())?;
+        self.store
+            .set(&RELAUNCH_DISPLAY_NAME_RESOURCE, display_name.to_string())
+    }
+
+    /// Sets the icon shown for the pinned window, as a path and resource index such as
+    /// `C:\Contoso\editor.exe,-101`.
+    pub fn set_relaunch_icon(&self, icon: &str) -> Result<()> {
+        self.store.set(&RELAUNCH_ICON_RESOURCE, icon.to_string())
+    }
+
+    /// Sets whether the window may be pinned to the taskbar or added to the Start menu. This must
+    /// be set before the window's ID.
+    pub fn set_prevent_pinning(&self, prevent: bool) -> Result<()> {
+        self.store.set(&PREVENT_PINNING, prevent)
+    }
+
+    /// Removes the properties set on the window.
+    pub fn clear(&self) -> Result<()> {
+        [
+            PREVENT_PINNING,
+            RELAUNCH_ICON_RESOURCE,
+            RELAUNCH_DISPLAY_NAME_RESOURCE,
+            RELAUNCH_COMMAND,
+            ID,
+        ]
+        .iter()
+        .try_for_each(|key| self.store.remove(key))
+    }
+}
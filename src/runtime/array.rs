@@ -11,7 +11,7 @@ pub struct Array<T: RuntimeType> {
 impl<T: RuntimeType> Default for Array<T> {
     fn default() -> Self {
         Array {
-            data: std::ptr::null_mut(),
+            data: core::ptr::null_mut(),
             len: 0,
         }
     }
@@ -25,9 +25,9 @@ impl<T: RuntimeType> Array<T> {
 
     /// Creates an array of the given length with default values.
     pub fn with_len(len: usize) -> Self {
-        assert!(len < std::u32::MAX as usize);
+        assert!(len < core::u32::MAX as usize);
         let bytes_amount = len
-            .checked_mul(std::mem::size_of::<T>())
+            .checked_mul(core::mem::size_of::<T>())
             .expect("Attempted to allocate too large an Array");
 
         // WinRT arrays must be allocated with CoTaskMemAlloc.
@@ -45,7 +45,7 @@ impl<T: RuntimeType> Array<T> {
         // bytes making the entire array zero initialized. We have assured
         // above that the data ptr is not null.
         unsafe {
-            std::ptr::write_bytes(data, 0, len);
+            core::ptr::write_bytes(data, 0, len);
         }
 
         let len = len as u32;
@@ -68,18 +68,18 @@ impl<T: RuntimeType> Array<T> {
             return;
         }
 
-        let mut data = std::ptr::null_mut();
+        let mut data = core::ptr::null_mut();
         let mut len = 0;
 
-        std::mem::swap(&mut data, &mut self.data);
-        std::mem::swap(&mut len, &mut self.len);
+        core::mem::swap(&mut data, &mut self.data);
+        core::mem::swap(&mut len, &mut self.len);
 
         // SAFETY: At this point, self has been reset to zero so any panics in T's destructor would
         // only leak data not leave the array in bad state.
         unsafe {
             // Call the destructors of all the elements of the old array
             // SAFETY: the slice cannot be used after the call to `drop_in_place`
-            std::ptr::drop_in_place(std::slice::from_raw_parts_mut(data, len as usize));
+            core::ptr::drop_in_place(core::slice::from_raw_parts_mut(data, len as usize));
             // Free the data memory where the elements were
             // SAFETY: we have unique access to the data pointer at this point
             // so freeing it is the right thing to do
@@ -87,6 +87,27 @@ impl<T: RuntimeType> Array<T> {
         }
     }
 
+    /// Moves the elements into a `Vec`, freeing the array's buffer. The elements themselves are
+    /// moved rather than copied, so interfaces and strings aren't `AddRef`ed or duplicated.
+    pub fn into_vec(mut self) -> Vec<T::DefaultType> {
+        let len = self.len();
+        let mut vec = Vec::with_capacity(len);
+
+        if !self.data.is_null() {
+            // SAFETY: the elements are moved into `vec` and the array is reset below so that
+            // they aren't dropped twice
+            unsafe {
+                core::ptr::copy_nonoverlapping(self.data, vec.as_mut_ptr(), len);
+                vec.set_len(len);
+                CoTaskMemFree(self.data as _);
+            }
+        }
+
+        self.data = core::ptr::null_mut();
+        self.len = 0;
+        vec
+    }
+
     #[doc(hidden)]
     /// Get a mutable pointer to the array's length
     ///
@@ -106,16 +127,44 @@ impl<T: RuntimeType> Array<T> {
         &mut self.data as *mut _ as *mut _
     }
 
+    #[doc(hidden)]
+    /// Borrow a pass array received from the ABI as a slice
+    ///
+    /// # Safety
+    /// `data` must point to `len` valid elements for the lifetime `'a`, or may be null if `len`
+    /// is zero
+    pub unsafe fn from_abi_slice<'a>(data: *const T::Abi, len: u32) -> &'a [T::DefaultType] {
+        if data.is_null() || len == 0 {
+            return &[];
+        }
+
+        core::slice::from_raw_parts(data as *const T::DefaultType, len as usize)
+    }
+
+    #[doc(hidden)]
+    /// Borrow a fill array received from the ABI as a mutable slice
+    ///
+    /// # Safety
+    /// `data` must point to `len` valid elements that aren't otherwise accessed for the lifetime
+    /// `'a`, or may be null if `len` is zero
+    pub unsafe fn from_abi_slice_mut<'a>(data: *mut T::Abi, len: u32) -> &'a mut [T::DefaultType] {
+        if data.is_null() || len == 0 {
+            return &mut [];
+        }
+
+        core::slice::from_raw_parts_mut(data as *mut T::DefaultType, len as usize)
+    }
+
     #[doc(hidden)]
     /// Turn the array into a pointer to its data and its length
     pub fn into_abi(self) -> (*mut T::Abi, u32) {
         let abi = (self.data as *mut _, self.len);
-        std::mem::forget(self);
+        core::mem::forget(self);
         abi
     }
 }
 
-impl<T: RuntimeType> std::ops::Deref for Array<T> {
+impl<T: RuntimeType> core::ops::Deref for Array<T> {
     type Target = [T::DefaultType];
 
     fn deref(&self) -> &[T::DefaultType] {
@@ -124,18 +173,18 @@ impl<T: RuntimeType> std::ops::Deref for Array<T> {
         }
 
         // SAFETY: data must not be null if the array is not empty
-        unsafe { std::slice::from_raw_parts(self.data, self.len as usize) }
+        unsafe { core::slice::from_raw_parts(self.data, self.len as usize) }
     }
 }
 
-impl<T: RuntimeType> std::ops::DerefMut for Array<T> {
+impl<T: RuntimeType> core::ops::DerefMut for Array<T> {
     fn deref_mut(&mut self) -> &mut [T::DefaultType] {
         if self.is_empty() {
             return &mut [];
         }
 
         // SAFETY: data must not be null if the array is not empty
-        unsafe { std::slice::from_raw_parts_mut(self.data, self.len as usize) }
+        unsafe { core::slice::from_raw_parts_mut(self.data, self.len as usize) }
     }
 }
 
@@ -165,4 +214,14 @@ mod tests {
         assert!(empty[1] == 0);
         assert!(empty[2] == 0);
     }
+
+    #[test]
+    fn into_vec() {
+        let mut array = Array::<HSTRING>::with_len(2);
+        array[0] = "hello".into();
+        array[1] = "world".into();
+        assert_eq!(array.into_vec(), ["hello", "world"]);
+
+        assert!(Array::<u32>::new().into_vec().is_empty());
+    }
 }
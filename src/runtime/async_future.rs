@@ -0,0 +1,119 @@
+use crate::*;
+use bindings::Windows::Foundation::{AsyncStatus, IAsyncInfo};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A future awaiting a WinRT async action or operation, which the generated async interfaces
+/// turn into when awaited if the bindings are generated with the `into_future` option.
+///
+/// The future sets the operation's completion callback when it's first polled and afterwards
+/// only replaces the waker that the callback wakes, so that it may be polled by different tasks,
+/// as by `select!` and `join!`, without missing its completion. It's `Send` when the operation
+/// is, as the WinRT async interfaces are since their objects must be agile, so that it may be
+/// awaited by a task spawned on a multithreaded executor.
+///
+/// ```ignore
+/// tokio::select! {
+///     result = reader.LoadAsync(3)? => { result?; }
+///     _ = tokio::time::sleep(Duration::from_secs(1)) => { /* timed out */ }
+/// }
+/// ```
+pub struct AsyncFuture<A: AsyncOperation> {
+    operation: A,
+    // The waker that the completion callback wakes, which is `None` until the future is first
+    // polled. It's shared with the callback so that polling needn't mutate the future.
+    waker: Arc<Mutex<Option<Waker>>>,
+}
+
+impl<A: AsyncOperation> AsyncFuture<A> {
+    /// Creates a future awaiting the operation.
+    pub fn new(operation: A) -> Self {
+        Self {
+            operation,
+            waker: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    fn is_started(&self) -> Result<bool> {
+        Ok(self.operation.cast::<IAsyncInfo>()?.Status()? == AsyncStatus::Started)
+    }
+}
+
+impl<A: AsyncOperation> Future for AsyncFuture<A> {
+    type Output = Result<A::Output>;
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<Self::Output> {
+        if !self.is_started()? {
+            return Poll::Ready(self.operation.get_results());
+        }
+
+        let first = {
+            let mut waker = self.waker.lock().unwrap();
+
+            match &mut *waker {
+                Some(waker) if waker.will_wake(context.waker()) => false,
+                Some(waker) => {
+                    *waker = context.waker().clone();
+                    false
+                }
+                None => {
+                    *waker = Some(context.waker().clone());
+                    true
+                }
+            }
+        };
+
+        // The callback is set without holding the lock, as it may be called immediately.
+        if first {
+            let callback = self.waker.clone();
+
+            self.operation.set_completed(Box::new(move || {
+                if let Some(waker) = &*callback.lock().unwrap() {
+                    waker.wake_by_ref();
+                }
+            }))?;
+        }
+
+        // The operation may have completed, and woken the previous waker, before the waker was
+        // replaced.
+        if self.is_started()? {
+            Poll::Pending
+        } else {
+            Poll::Ready(self.operation.get_results())
+        }
+    }
+}
+
+/// Runs the closure on tokio's pool of blocking threads, for async work whose operation or
+/// results aren't agile and so can't be awaited by a task that may move between threads.
+/// The closure starts the operation and waits for it with `get`, so that only its own result
+/// crosses threads.
+///
+/// ```ignore
+/// let text = windows::spawn_blocking(|| {
+///     let file = StorageFile::GetFileFromPathAsync(path)?.get()?;
+///     Ok(FileIO::ReadTextAsync(file)?.get()?.to_string())
+/// })
+/// .await?;
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn spawn_blocking<F, T>(f: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let task = tokio::task::spawn_blocking(move || {
+        // The blocking threads join the multithreaded apartment so that they may call WinRT
+        // APIs, and if they already have then initializing it again has no effect.
+        let _ = initialize_mta();
+        f()
+    });
+
+    match task.await {
+        Ok(result) => result,
+        Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+        Err(error) => Err(Error::new(HRESULT::E_ABORT, &error.to_string())),
+    }
+}
@@ -0,0 +1,16 @@
+use crate::*;
+
+/// A WinRT async action or operation, which the generated bindings implement for
+/// `IAsyncAction`, `IAsyncOperation<T>`, and their variants reporting progress, so that it may
+/// be awaited through an `AsyncFuture`.
+pub trait AsyncOperation: Interface {
+    /// The type of the operation's results, which is `()` for actions.
+    type Output;
+
+    /// Sets the callback called once the operation completes, or immediately if it already
+    /// has. The callback may only be set once.
+    fn set_completed(&self, callback: Box<dyn Fn() + Send>) -> Result<()>;
+
+    /// Returns the results of the completed operation.
+    fn get_results(&self) -> Result<Self::Output>;
+}
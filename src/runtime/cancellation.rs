@@ -0,0 +1,254 @@
+use crate::*;
+use bindings::{
+    Windows::Foundation::IAsyncInfo,
+    Windows::Win32::Foundation::{CloseHandle, HANDLE, PSTR},
+    Windows::Win32::System::Diagnostics::Debug::ERROR_CANCELLED,
+    Windows::Win32::System::Threading::{CreateEventA, SetEvent},
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Waker};
+
+type Callback = Box<dyn FnOnce() + Send>;
+
+/// Signals cancellation to Rust code, WinRT async operations, and Win32 code waiting on a handle
+/// from a single place.
+///
+/// Clones of a token share the same state so that any of them may cancel the work observing the
+/// others. Cancellation can be observed by polling [`CancellationToken::is_cancelled`], awaiting
+/// [`CancellationToken::cancelled`], waiting on [`CancellationToken::handle`], or registering a
+/// callback, such as cancelling a WinRT async operation with [`CancellationToken::link`].
+///
+/// ```ignore
+/// let token = windows::CancellationToken::new()?;
+/// let operation = file.OpenAsync(FileAccessMode::Read)?;
+/// token.link(&operation)?;
+///
+/// // Elsewhere, such as from a button's click handler:
+/// token.cancel();
+/// ```
+#[derive(Clone)]
+pub struct CancellationToken(Arc<Inner>);
+
+struct Inner {
+    cancelled: AtomicBool,
+    event: HANDLE,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    callbacks: Vec<Callback>,
+    wakers: Vec<Waker>,
+}
+
+impl CancellationToken {
+    /// Creates a token that has not been cancelled.
+    pub fn new() -> Result<Self> {
+        // The event is manual-reset so that every waiter observes cancellation.
+        let event = unsafe { CreateEventA(std::ptr::null_mut(), true, false, PSTR::NULL) };
+
+        if event.is_null() {
+            return Err(HRESULT::from_thread().into());
+        }
+
+        Ok(Self(Arc::new(Inner {
+            cancelled: AtomicBool::new(false),
+            event,
+            state: Mutex::new(State::default()),
+        })))
+    }
+
+    /// Cancels the token, waking any tasks awaiting cancellation and running the registered
+    /// callbacks on the calling thread. Cancelling a token more than once has no further effect.
+    pub fn cancel(&self) {
+        let state = {
+            let mut state = self.lock();
+
+            if self.0.cancelled.swap(true, Ordering::AcqRel) {
+                return;
+            }
+
+            unsafe {
+                SetEvent(self.0.event);
+            }
+
+            std::mem::take(&mut *state)
+        };
+
+        // The lock is released first since a callback may use the token.
+        for waker in state.wakers {
+            waker.wake();
+        }
+
+        for callback in state.callbacks {
+            callback();
+        }
+    }
+
+    /// Returns `true` if the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns an error with the `ERROR_CANCELLED` code reported by cancelled WinRT async
+    /// operations if the token has been cancelled.
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(Error::fast_error(HRESULT::from_win32(ERROR_CANCELLED.0)))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns a future that completes once the token has been cancelled.
+    pub fn cancelled(&self) -> Cancelled {
+        Cancelled(self.clone())
+    }
+
+    /// Returns a manual-reset event handle that is signaled once the token has been cancelled,
+    /// such as for passing to `WaitForMultipleObjects` along with the handles of other work.
+    ///
+    /// The handle is owned by the token and remains valid while any clone of it is alive.
+    pub fn handle(&self) -> isize {
+        self.0.event.0
+    }
+
+    /// Registers a callback to be run once the token has been cancelled, or runs it immediately
+    /// if the token has already been cancelled.
+    pub fn on_cancel<F: FnOnce() + Send + 'static>(&self, callback: F) {
+        {
+            let mut state = self.lock();
+
+            if !self.is_cancelled() {
+                state.callbacks.push(Box::new(callback));
+                return;
+            }
+        }
+
+        callback();
+    }
+
+    /// Cancels the WinRT async action or operation with `IAsyncInfo::Cancel` once the token has
+    /// been cancelled, so that awaiting it fails with `ERROR_CANCELLED`.
+    ///
+    /// The operation is cancelled from the thread cancelling the token, so an operation that
+    /// isn't agile is only cancelled if the token is cancelled from the thread that linked it.
+    pub fn link<T: Interface>(&self, operation: &T) -> Result<()> {
+        let info = ApartmentChecked::new(operation.cast::<IAsyncInfo>()?);
+
+        self.on_cancel(move || {
+            if let Ok(info) = info.try_get() {
+                let _ = info.Cancel();
+            }
+        });
+
+        Ok(())
+    }
+
+    // The state may be used while another thread is panicking.
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.0
+            .state
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+impl std::fmt::Debug for CancellationToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CancellationToken")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+impl Drop for Inner {
+    fn drop(&mut self) {
+        unsafe {
+            CloseHandle(self.event);
+        }
+    }
+}
+
+/// A future that completes once a [`CancellationToken`] has been cancelled, returned by
+/// [`CancellationToken::cancelled`].
+#[must_use = "futures do nothing unless awaited"]
+pub struct Cancelled(CancellationToken);
+
+impl Future for Cancelled {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, context: &mut Context) -> Poll<()> {
+        if self.0.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        let mut state = self.0.lock();
+
+        // The token may have been cancelled before the lock was taken.
+        if self.0.is_cancelled() {
+            return Poll::Ready(());
+        }
+
+        if !state
+            .wakers
+            .iter()
+            .any(|waker| waker.will_wake(context.waker()))
+        {
+            state.wakers.push(context.waker().clone());
+        }
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bindings::Windows::Win32::System::Threading::{WaitForSingleObject, WAIT_OBJECT_0};
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn cancel() -> Result<()> {
+        let token = CancellationToken::new()?;
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        token.on_cancel(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+
+        assert!(!token.is_cancelled());
+        assert!(token.check().is_ok());
+        assert_ne!(
+            unsafe { WaitForSingleObject(HANDLE(token.handle()), 0) },
+            WAIT_OBJECT_0
+        );
+
+        let other = token.clone();
+        std::thread::spawn(move || other.cancel()).join().unwrap();
+        token.cancel();
+
+        assert!(token.is_cancelled());
+        assert_eq!(
+            token.check().unwrap_err().code(),
+            HRESULT::from_win32(ERROR_CANCELLED.0)
+        );
+        assert_eq!(
+            unsafe { WaitForSingleObject(HANDLE(token.handle()), 0) },
+            WAIT_OBJECT_0
+        );
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        // Callbacks registered after cancellation run immediately.
+        let counter = calls.clone();
+        token.on_cancel(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1666 @@
+#![allow(non_snake_case)]
+
+use crate::*;
+use bindings::Windows::{
+    Foundation::Collections::{
+        CollectionChange, IIterable, IIterable_abi, IIterator, IIterator_abi, IKeyValuePair,
+        IKeyValuePair_abi, IMap, IMapView, IMapView_abi, IMap_abi, IObservableVector,
+        IObservableVector_abi, IVector, IVectorChangedEventArgs, IVectorChangedEventArgs_abi,
+        IVectorView, IVectorView_abi, IVector_abi, VectorChangedEventHandler,
+    },
+    Foundation::EventRegistrationToken,
+    Win32::Foundation::{E_BOUNDS, E_CHANGED_STATE, E_NOINTERFACE},
+};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Creates an `IVector<T>` backed by a `Vec`, such as for the `ItemsSource` of a XAML list or
+/// any other WinRT API expecting a collection.
+///
+/// The object also implements `IIterable<T>`, and its `GetView` returns a live `IVectorView<T>`
+/// of the same elements. Any change made through the vector invalidates the iterators created
+/// before it, which then fail with `E_CHANGED_STATE`. Like objects implemented with
+/// `#[implement]`, the vector is agile and may be called from any thread.
+///
+/// ```ignore
+/// let names: IVector<HSTRING> = windows::vector::<HSTRING>(vec!["a".into(), "b".into()]).cast()?;
+/// names.Append("c")?;
+/// ```
+pub fn vector<T>(values: Vec<T::DefaultType>) -> IInspectable
+where
+    T: RuntimeType + 'static,
+    T::DefaultType: Clone + PartialEq,
+{
+    into_inspectable(StdVector::<T>::new(shared(values)))
+}
+
+/// Creates an `IObservableVector<T>` backed by a `Vec`, which raises `VectorChanged` after each
+/// change made through the vector, such as for the `ItemsSource` of a XAML list whose items
+/// are added and removed while it is shown.
+///
+/// The object is otherwise the same as that returned by [`vector`], implementing `IVector<T>`
+/// and `IIterable<T>` as well. Handlers are called on the thread making the change, after the
+/// change has been made.
+///
+/// ```ignore
+/// let items: IObservableVector<IInspectable> = windows::observable_vector::<IInspectable>(Vec::new()).cast()?;
+/// items.VectorChanged(VectorChangedEventHandler::new(|_, args| {
+///     println!("{:?}", args.as_ref().unwrap().CollectionChange()?);
+///     Ok(())
+/// }))?;
+/// ```
+pub fn observable_vector<T>(values: Vec<T::DefaultType>) -> IInspectable
+where
+    T: RuntimeType + 'static,
+    T::DefaultType: Clone + PartialEq,
+{
+    into_inspectable(StdObservableVector::<T>::new(shared(values)))
+}
+
+/// Creates an `IVectorView<T>` backed by a `Vec`, which also implements `IIterable<T>`.
+pub fn vector_view<T>(values: Vec<T::DefaultType>) -> IInspectable
+where
+    T: RuntimeType + 'static,
+    T::DefaultType: Clone + PartialEq,
+{
+    into_inspectable(StdVectorView::<T>::new(shared(values)))
+}
+
+/// Creates an `IMap<K, V>` backed by a `HashMap`, which also implements
+/// `IIterable<IKeyValuePair<K, V>>`.
+///
+/// As with [`vector`], the map's `GetView` returns a live `IMapView<K, V>`, and any change made
+/// through the map invalidates the iterators created before it. The order of iteration is that
+/// of the `HashMap`.
+pub fn map<K, V>(values: HashMap<K::DefaultType, V::DefaultType>) -> IInspectable
+where
+    K: RuntimeType + 'static,
+    V: RuntimeType + 'static,
+    K::DefaultType: Clone + Hash + Eq,
+    V::DefaultType: Clone,
+{
+    into_inspectable(StdMap::<K, V>::new(shared(values)))
+}
+
+/// Creates an `IMapView<K, V>` backed by a `HashMap`, which also implements
+/// `IIterable<IKeyValuePair<K, V>>`.
+pub fn map_view<K, V>(values: HashMap<K::DefaultType, V::DefaultType>) -> IInspectable
+where
+    K: RuntimeType + 'static,
+    V: RuntimeType + 'static,
+    K::DefaultType: Clone + Hash + Eq,
+    V::DefaultType: Clone,
+{
+    into_inspectable(StdMapView::<K, V>::new(shared(values)))
+}
+
+/// Creates an `IIterable<T>` over a Rust iterable, which is cloned for each iterator created
+/// by `First`.
+///
+/// ```ignore
+/// let squares: IIterable<i32> = windows::iterable::<i32, _>((1..=10).map(|x| x * x)).cast()?;
+/// ```
+pub fn iterable<T, I>(iterable: I) -> IInspectable
+where
+    T: RuntimeType + 'static,
+    I: IntoIterator<Item = T::DefaultType> + Clone + 'static,
+    T::DefaultType: Clone,
+{
+    into_inspectable(StdIterable::<T, I> {
+        vtable: &StdIterable::<T, I>::VTABLE,
+        count: RefCount::new(1),
+        iterable,
+    })
+}
+
+// The elements of a collection along with a version that is incremented by each change, so
+// that iterators can detect that they've been invalidated.
+struct Versioned<C> {
+    items: C,
+    version: u32,
+}
+
+type Shared<C> = Arc<RwLock<Versioned<C>>>;
+
+fn shared<C>(items: C) -> Shared<C> {
+    Arc::new(RwLock::new(Versioned { items, version: 0 }))
+}
+
+// A COM object whose vtable pointers are the leading fields of a `#[repr(C)]` struct, the
+// first of which provides its identity. Other modules of the runtime build their objects the
+// same way.
+pub(super) trait Object: Sized + 'static {
+    fn count(&self) -> &RefCount;
+
+    // Returns the index of the object's vtable implementing the interface, if any.
+    fn find(iid: &Guid) -> Option<usize>;
+
+    fn iids() -> Vec<Guid>;
+}
+
+pub(super) fn into_abi<O: Object>(object: O) -> RawPtr {
+    Box::into_raw(Box::new(object)) as _
+}
+
+pub(super) fn into_inspectable<O: Object>(object: O) -> IInspectable {
+    // SAFETY: the pointer is that of the object's first vtable, which implements `IInspectable`
+    unsafe { std::mem::transmute(into_abi(object)) }
+}
+
+pub(super) unsafe fn object<'a, O: Object, const N: usize>(this: RawPtr) -> &'a O {
+    &*((this as *mut RawPtr).sub(N) as *const O)
+}
+
+pub(super) unsafe extern "system" fn query_interface<O: Object, const N: usize>(
+    this: RawPtr,
+    iid: &Guid,
+    interface: *mut RawPtr,
+) -> HRESULT {
+    let base = (this as *mut RawPtr).sub(N);
+
+    let index = if iid == &IUnknown::IID || iid == &IInspectable::IID || iid == &IAgileObject::IID {
+        Some(0)
+    } else {
+        O::find(iid)
+    };
+
+    match index {
+        Some(index) => {
+            *interface = base.add(index) as _;
+            (*(base as *const O)).count().add_ref();
+            HRESULT(0)
+        }
+        None => {
+            *interface = std::ptr::null_mut();
+            E_NOINTERFACE
+        }
+    }
+}
+
+pub(super) unsafe extern "system" fn add_ref<O: Object, const N: usize>(this: RawPtr) -> u32 {
+    object::<O, N>(this).count().add_ref()
+}
+
+pub(super) unsafe extern "system" fn release<O: Object, const N: usize>(this: RawPtr) -> u32 {
+    let remaining = object::<O, N>(this).count().release();
+
+    if remaining == 0 {
+        drop(Box::from_raw((this as *mut RawPtr).sub(N) as *mut O));
+    }
+
+    remaining
+}
+
+pub(super) unsafe extern "system" fn get_iids<O: Object>(
+    _: RawPtr,
+    count: *mut u32,
+    values: *mut *mut Guid,
+) -> HRESULT {
+    copy_iids(&O::iids(), count, values)
+}
+
+pub(super) unsafe extern "system" fn get_runtime_class_name(
+    _: RawPtr,
+    value: *mut RawPtr,
+) -> HRESULT {
+    *value = std::ptr::null_mut();
+    HRESULT(0)
+}
+
+pub(super) unsafe extern "system" fn get_trust_level(_: RawPtr, value: *mut i32) -> HRESULT {
+    *value = 0;
+    HRESULT(0)
+}
+
+// Borrows a value passed by the caller.
+unsafe fn borrow<T: RuntimeType>(abi: &T::Abi) -> &T::DefaultType {
+    &*(abi as *const T::Abi as *const T::DefaultType)
+}
+
+// Transfers ownership of a value to the caller.
+unsafe fn write<T: RuntimeType>(abi: *mut T::Abi, value: T::DefaultType) {
+    (abi as *mut T::DefaultType).write(value)
+}
+
+fn bounds() -> Error {
+    Error::fast_error(E_BOUNDS)
+}
+
+fn check_index(index: u32, len: usize) -> Result<usize> {
+    let index = index as usize;
+
+    if index < len {
+        Ok(index)
+    } else {
+        Err(bounds())
+    }
+}
+
+unsafe fn vector_get_at<T: RuntimeType>(
+    shared: &Shared<Vec<T::DefaultType>>,
+    index: u32,
+    result: *mut T::Abi,
+) -> HRESULT
+where
+    T::DefaultType: Clone,
+{
+    let shared = shared.read().unwrap();
+
+    match check_index(index, shared.items.len()) {
+        Ok(index) => {
+            write::<T>(result, shared.items[index].clone());
+            HRESULT(0)
+        }
+        Err(error) => error.into(),
+    }
+}
+
+unsafe fn vector_index_of<T: RuntimeType>(
+    shared: &Shared<Vec<T::DefaultType>>,
+    value: &T::Abi,
+    index: *mut u32,
+    result: *mut bool,
+) -> HRESULT
+where
+    T::DefaultType: PartialEq,
+{
+    let value = borrow::<T>(value);
+    let position = shared.read().unwrap().items.iter().position(|v| v == value);
+
+    *index = position.unwrap_or(0) as u32;
+    *result = position.is_some();
+    HRESULT(0)
+}
+
+unsafe fn vector_get_many<T: RuntimeType>(
+    shared: &Shared<Vec<T::DefaultType>>,
+    start: u32,
+    capacity: u32,
+    items: *mut T::Abi,
+    result: *mut u32,
+) -> HRESULT
+where
+    T::DefaultType: Clone,
+{
+    let shared = shared.read().unwrap();
+    let start = start as usize;
+
+    // Starting at the end of the vector is allowed and returns no elements.
+    if start > shared.items.len() {
+        *result = 0;
+        return bounds().into();
+    }
+
+    let values = &shared.items[start..];
+    let len = values.len().min(capacity as usize);
+
+    for (offset, value) in values[..len].iter().enumerate() {
+        write::<T>(items.add(offset), value.clone());
+    }
+
+    *result = len as u32;
+    HRESULT(0)
+}
+
+unsafe fn map_lookup<K: RuntimeType, V: RuntimeType>(
+    shared: &Shared<HashMap<K::DefaultType, V::DefaultType>>,
+    key: &K::Abi,
+    result: *mut V::Abi,
+) -> HRESULT
+where
+    K::DefaultType: Hash + Eq,
+    V::DefaultType: Clone,
+{
+    match shared.read().unwrap().items.get(borrow::<K>(key)) {
+        Some(value) => {
+            write::<V>(result, value.clone());
+            HRESULT(0)
+        }
+        None => bounds().into(),
+    }
+}
+
+unsafe fn map_has_key<K: RuntimeType, V: RuntimeType>(
+    shared: &Shared<HashMap<K::DefaultType, V::DefaultType>>,
+    key: &K::Abi,
+    result: *mut bool,
+) -> HRESULT
+where
+    K::DefaultType: Hash + Eq,
+{
+    *result = shared.read().unwrap().items.contains_key(borrow::<K>(key));
+
+    HRESULT(0)
+}
+
+#[repr(C)]
+struct StdVector<T: RuntimeType + 'static> {
+    vector: *const IVector_abi<T>,
+    iterable: *const IIterable_abi<T>,
+    count: RefCount,
+    shared: Shared<Vec<T::DefaultType>>,
+}
+
+impl<T: RuntimeType + 'static> Object for StdVector<T>
+where
+    T::DefaultType: Clone + PartialEq,
+{
+    fn count(&self) -> &RefCount {
+        &self.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &IVector::<T>::IID {
+            Some(0)
+        } else if iid == &IIterable::<T>::IID {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        vec![IVector::<T>::IID, IIterable::<T>::IID]
+    }
+}
+
+impl<T: RuntimeType + 'static> StdVector<T>
+where
+    T::DefaultType: Clone + PartialEq,
+{
+    const VECTOR: IVector_abi<T> = IVector_abi(
+        query_interface::<Self, 0>,
+        add_ref::<Self, 0>,
+        release::<Self, 0>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::GetAt,
+        Self::Size,
+        Self::GetView,
+        Self::IndexOf,
+        Self::SetAt,
+        Self::InsertAt,
+        Self::RemoveAt,
+        Self::Append,
+        Self::RemoveAtEnd,
+        Self::Clear,
+        Self::GetMany,
+        Self::ReplaceAll,
+        PhantomData,
+    );
+
+    const ITERABLE: IIterable_abi<T> = IIterable_abi(
+        query_interface::<Self, 1>,
+        add_ref::<Self, 1>,
+        release::<Self, 1>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::First,
+        PhantomData,
+    );
+
+    fn new(shared: Shared<Vec<T::DefaultType>>) -> Self {
+        Self {
+            vector: &Self::VECTOR,
+            iterable: &Self::ITERABLE,
+            count: RefCount::new(1),
+            shared,
+        }
+    }
+
+    // Applies a change to the elements, invalidating any iterators, and returns the index of the
+    // affected element. The index is found while the lock is held so that it can't be skewed by
+    // a concurrent change.
+    unsafe fn change<F: FnOnce(&mut Vec<T::DefaultType>) -> Result<u32>>(
+        this: RawPtr,
+        f: F,
+    ) -> Result<u32> {
+        let mut shared = object::<Self, 0>(this).shared.write().unwrap();
+        let index = f(&mut shared.items)?;
+        shared.version = shared.version.wrapping_add(1);
+        Ok(index)
+    }
+
+    unsafe fn set_at(this: RawPtr, index: u32, value: T::Abi) -> Result<u32> {
+        let value = borrow::<T>(&value).clone();
+
+        Self::change(this, |items| {
+            let position = check_index(index, items.len())?;
+            items[position] = value;
+            Ok(index)
+        })
+    }
+
+    unsafe fn insert_at(this: RawPtr, index: u32, value: T::Abi) -> Result<u32> {
+        let value = borrow::<T>(&value).clone();
+
+        Self::change(this, |items| {
+            // Inserting at the end of the vector is allowed.
+            let position = check_index(index, items.len() + 1)?;
+            items.insert(position, value);
+            Ok(index)
+        })
+    }
+
+    unsafe fn remove_at(this: RawPtr, index: u32) -> Result<u32> {
+        Self::change(this, |items| {
+            let position = check_index(index, items.len())?;
+            items.remove(position);
+            Ok(index)
+        })
+    }
+
+    unsafe fn append(this: RawPtr, value: T::Abi) -> Result<u32> {
+        let value = borrow::<T>(&value).clone();
+
+        Self::change(this, |items| {
+            items.push(value);
+            Ok(items.len() as u32 - 1)
+        })
+    }
+
+    unsafe fn remove_at_end(this: RawPtr) -> Result<u32> {
+        Self::change(this, |items| {
+            items.pop().ok_or_else(bounds)?;
+            Ok(items.len() as u32)
+        })
+    }
+
+    // Clearing or replacing the elements resets the vector, which has no affected index.
+    unsafe fn clear(this: RawPtr) -> Result<u32> {
+        Self::change(this, |items| {
+            items.clear();
+            Ok(0)
+        })
+    }
+
+    unsafe fn replace_all(this: RawPtr, len: u32, values: *const T::Abi) -> Result<u32> {
+        let values = Array::<T>::from_abi_slice(values, len).to_vec();
+
+        Self::change(this, |items| {
+            *items = values;
+            Ok(0)
+        })
+    }
+
+    unsafe extern "system" fn GetAt(this: RawPtr, index: u32, result: *mut T::Abi) -> HRESULT {
+        vector_get_at::<T>(&object::<Self, 0>(this).shared, index, result)
+    }
+
+    unsafe extern "system" fn Size(this: RawPtr, result: *mut u32) -> HRESULT {
+        *result = object::<Self, 0>(this).shared.read().unwrap().items.len() as u32;
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn GetView(this: RawPtr, result: *mut RawPtr) -> HRESULT {
+        let shared = object::<Self, 0>(this).shared.clone();
+        *result = into_abi(StdVectorView::<T>::new(shared));
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn IndexOf(
+        this: RawPtr,
+        value: T::Abi,
+        index: *mut u32,
+        result: *mut bool,
+    ) -> HRESULT {
+        vector_index_of::<T>(&object::<Self, 0>(this).shared, &value, index, result)
+    }
+
+    unsafe extern "system" fn SetAt(this: RawPtr, index: u32, value: T::Abi) -> HRESULT {
+        Self::set_at(this, index, value).into()
+    }
+
+    unsafe extern "system" fn InsertAt(this: RawPtr, index: u32, value: T::Abi) -> HRESULT {
+        Self::insert_at(this, index, value).into()
+    }
+
+    unsafe extern "system" fn RemoveAt(this: RawPtr, index: u32) -> HRESULT {
+        Self::remove_at(this, index).into()
+    }
+
+    unsafe extern "system" fn Append(this: RawPtr, value: T::Abi) -> HRESULT {
+        Self::append(this, value).into()
+    }
+
+    unsafe extern "system" fn RemoveAtEnd(this: RawPtr) -> HRESULT {
+        Self::remove_at_end(this).into()
+    }
+
+    unsafe extern "system" fn Clear(this: RawPtr) -> HRESULT {
+        Self::clear(this).into()
+    }
+
+    unsafe extern "system" fn GetMany(
+        this: RawPtr,
+        start: u32,
+        capacity: u32,
+        items: *mut T::Abi,
+        result: *mut u32,
+    ) -> HRESULT {
+        vector_get_many::<T>(
+            &object::<Self, 0>(this).shared,
+            start,
+            capacity,
+            items,
+            result,
+        )
+    }
+
+    unsafe extern "system" fn ReplaceAll(this: RawPtr, len: u32, values: *const T::Abi) -> HRESULT {
+        Self::replace_all(this, len, values).into()
+    }
+
+    unsafe extern "system" fn First(this: RawPtr, result: *mut RawPtr) -> HRESULT {
+        let shared = object::<Self, 1>(this).shared.clone();
+        *result = into_abi(StdIterator::<T>::new(VectorCursor::new(shared)));
+        HRESULT(0)
+    }
+}
+
+// An observable vector shares the layout and methods of `StdVector`, following the vtable of
+// `IObservableVector<T>`, and raises `VectorChanged` after each successful change.
+#[repr(C)]
+struct StdObservableVector<T: RuntimeType + 'static> {
+    observable: *const IObservableVector_abi<T>,
+    vector: StdVector<T>,
+    changed: Event<VectorChangedEventHandler<T>>,
+}
+
+impl<T: RuntimeType + 'static> Object for StdObservableVector<T>
+where
+    T::DefaultType: Clone + PartialEq,
+{
+    fn count(&self) -> &RefCount {
+        &self.vector.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &IObservableVector::<T>::IID {
+            Some(0)
+        } else {
+            StdVector::<T>::find(iid).map(|index| index + 1)
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        let mut iids = StdVector::<T>::iids();
+        iids.insert(0, IObservableVector::<T>::IID);
+        iids
+    }
+}
+
+impl<T: RuntimeType + 'static> StdObservableVector<T>
+where
+    T::DefaultType: Clone + PartialEq,
+{
+    const OBSERVABLE: IObservableVector_abi<T> = IObservableVector_abi(
+        query_interface::<Self, 0>,
+        add_ref::<Self, 0>,
+        release::<Self, 0>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::add_VectorChanged,
+        Self::remove_VectorChanged,
+        PhantomData,
+    );
+
+    // The methods of `StdVector` find it from the pointer to its first vtable, which
+    // immediately follows that of the observable vector.
+    const VECTOR: IVector_abi<T> = IVector_abi(
+        query_interface::<Self, 1>,
+        add_ref::<Self, 1>,
+        release::<Self, 1>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        StdVector::<T>::GetAt,
+        StdVector::<T>::Size,
+        StdVector::<T>::GetView,
+        StdVector::<T>::IndexOf,
+        Self::SetAt,
+        Self::InsertAt,
+        Self::RemoveAt,
+        Self::Append,
+        Self::RemoveAtEnd,
+        Self::Clear,
+        StdVector::<T>::GetMany,
+        Self::ReplaceAll,
+        PhantomData,
+    );
+
+    const ITERABLE: IIterable_abi<T> = IIterable_abi(
+        query_interface::<Self, 2>,
+        add_ref::<Self, 2>,
+        release::<Self, 2>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        StdVector::<T>::First,
+        PhantomData,
+    );
+
+    fn new(shared: Shared<Vec<T::DefaultType>>) -> Self {
+        Self {
+            observable: &Self::OBSERVABLE,
+            vector: StdVector {
+                vector: &Self::VECTOR,
+                iterable: &Self::ITERABLE,
+                count: RefCount::new(1),
+                shared,
+            },
+            changed: Event::new(),
+        }
+    }
+
+    // Raises `VectorChanged` if the change made through the vector's `this` pointer succeeded,
+    // given the index of the affected element.
+    unsafe fn raise(this: RawPtr, result: Result<u32>, change: CollectionChange) -> HRESULT {
+        let index = match result {
+            Ok(index) => index,
+            Err(error) => return error.into(),
+        };
+
+        let object = object::<Self, 1>(this);
+
+        if object.changed.is_empty() {
+            return HRESULT(0);
+        }
+
+        object.vector.count.add_ref();
+        let sender: IObservableVector<T> = std::mem::transmute(object as *const Self);
+
+        let args: IVectorChangedEventArgs =
+            std::mem::transmute(into_abi(StdVectorChangedEventArgs::new(change, index)));
+
+        object
+            .changed
+            .call(|handler| handler.Invoke(&sender, &args))
+            .into()
+    }
+
+    unsafe extern "system" fn add_VectorChanged(
+        this: RawPtr,
+        handler: RawPtr,
+        result: *mut EventRegistrationToken,
+    ) -> HRESULT {
+        let handler: &Option<VectorChangedEventHandler<T>> = std::mem::transmute(&handler);
+
+        match object::<Self, 0>(this).changed.add(handler) {
+            Ok(token) => {
+                *result = EventRegistrationToken { Value: token };
+                HRESULT(0)
+            }
+            Err(error) => error.into(),
+        }
+    }
+
+    unsafe extern "system" fn remove_VectorChanged(
+        this: RawPtr,
+        token: EventRegistrationToken,
+    ) -> HRESULT {
+        object::<Self, 0>(this).changed.remove(token.Value);
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn SetAt(this: RawPtr, index: u32, value: T::Abi) -> HRESULT {
+        let result = StdVector::<T>::set_at(this, index, value);
+        Self::raise(this, result, CollectionChange::ItemChanged)
+    }
+
+    unsafe extern "system" fn InsertAt(this: RawPtr, index: u32, value: T::Abi) -> HRESULT {
+        let result = StdVector::<T>::insert_at(this, index, value);
+        Self::raise(this, result, CollectionChange::ItemInserted)
+    }
+
+    unsafe extern "system" fn RemoveAt(this: RawPtr, index: u32) -> HRESULT {
+        let result = StdVector::<T>::remove_at(this, index);
+        Self::raise(this, result, CollectionChange::ItemRemoved)
+    }
+
+    unsafe extern "system" fn Append(this: RawPtr, value: T::Abi) -> HRESULT {
+        let result = StdVector::<T>::append(this, value);
+        Self::raise(this, result, CollectionChange::ItemInserted)
+    }
+
+    unsafe extern "system" fn RemoveAtEnd(this: RawPtr) -> HRESULT {
+        let result = StdVector::<T>::remove_at_end(this);
+        Self::raise(this, result, CollectionChange::ItemRemoved)
+    }
+
+    unsafe extern "system" fn Clear(this: RawPtr) -> HRESULT {
+        let result = StdVector::<T>::clear(this);
+        Self::raise(this, result, CollectionChange::Reset)
+    }
+
+    unsafe extern "system" fn ReplaceAll(this: RawPtr, len: u32, values: *const T::Abi) -> HRESULT {
+        let result = StdVector::<T>::replace_all(this, len, values);
+        Self::raise(this, result, CollectionChange::Reset)
+    }
+}
+
+#[repr(C)]
+struct StdVectorChangedEventArgs {
+    vtable: *const IVectorChangedEventArgs_abi,
+    count: RefCount,
+    change: CollectionChange,
+    index: u32,
+}
+
+impl Object for StdVectorChangedEventArgs {
+    fn count(&self) -> &RefCount {
+        &self.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &IVectorChangedEventArgs::IID {
+            Some(0)
+        } else {
+            None
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        vec![IVectorChangedEventArgs::IID]
+    }
+}
+
+impl StdVectorChangedEventArgs {
+    const VTABLE: IVectorChangedEventArgs_abi = IVectorChangedEventArgs_abi(
+        query_interface::<Self, 0>,
+        add_ref::<Self, 0>,
+        release::<Self, 0>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::CollectionChange,
+        Self::Index,
+    );
+
+    fn new(change: CollectionChange, index: u32) -> Self {
+        Self {
+            vtable: &Self::VTABLE,
+            count: RefCount::new(1),
+            change,
+            index,
+        }
+    }
+
+    unsafe extern "system" fn CollectionChange(
+        this: RawPtr,
+        result: *mut CollectionChange,
+    ) -> HRESULT {
+        *result = object::<Self, 0>(this).change;
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn Index(this: RawPtr, result: *mut u32) -> HRESULT {
+        *result = object::<Self, 0>(this).index;
+        HRESULT(0)
+    }
+}
+
+#[repr(C)]
+struct StdVectorView<T: RuntimeType + 'static> {
+    view: *const IVectorView_abi<T>,
+    iterable: *const IIterable_abi<T>,
+    count: RefCount,
+    shared: Shared<Vec<T::DefaultType>>,
+}
+
+impl<T: RuntimeType + 'static> Object for StdVectorView<T>
+where
+    T::DefaultType: Clone + PartialEq,
+{
+    fn count(&self) -> &RefCount {
+        &self.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &IVectorView::<T>::IID {
+            Some(0)
+        } else if iid == &IIterable::<T>::IID {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        vec![IVectorView::<T>::IID, IIterable::<T>::IID]
+    }
+}
+
+impl<T: RuntimeType + 'static> StdVectorView<T>
+where
+    T::DefaultType: Clone + PartialEq,
+{
+    const VIEW: IVectorView_abi<T> = IVectorView_abi(
+        query_interface::<Self, 0>,
+        add_ref::<Self, 0>,
+        release::<Self, 0>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::GetAt,
+        Self::Size,
+        Self::IndexOf,
+        Self::GetMany,
+        PhantomData,
+    );
+
+    const ITERABLE: IIterable_abi<T> = IIterable_abi(
+        query_interface::<Self, 1>,
+        add_ref::<Self, 1>,
+        release::<Self, 1>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::First,
+        PhantomData,
+    );
+
+    fn new(shared: Shared<Vec<T::DefaultType>>) -> Self {
+        Self {
+            view: &Self::VIEW,
+            iterable: &Self::ITERABLE,
+            count: RefCount::new(1),
+            shared,
+        }
+    }
+
+    unsafe extern "system" fn GetAt(this: RawPtr, index: u32, result: *mut T::Abi) -> HRESULT {
+        vector_get_at::<T>(&object::<Self, 0>(this).shared, index, result)
+    }
+
+    unsafe extern "system" fn Size(this: RawPtr, result: *mut u32) -> HRESULT {
+        *result = object::<Self, 0>(this).shared.read().unwrap().items.len() as u32;
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn IndexOf(
+        this: RawPtr,
+        value: T::Abi,
+        index: *mut u32,
+        result: *mut bool,
+    ) -> HRESULT {
+        vector_index_of::<T>(&object::<Self, 0>(this).shared, &value, index, result)
+    }
+
+    unsafe extern "system" fn GetMany(
+        this: RawPtr,
+        start: u32,
+        capacity: u32,
+        items: *mut T::Abi,
+        result: *mut u32,
+    ) -> HRESULT {
+        vector_get_many::<T>(
+            &object::<Self, 0>(this).shared,
+            start,
+            capacity,
+            items,
+            result,
+        )
+    }
+
+    unsafe extern "system" fn First(this: RawPtr, result: *mut RawPtr) -> HRESULT {
+        let shared = object::<Self, 1>(this).shared.clone();
+        *result = into_abi(StdIterator::<T>::new(VectorCursor::new(shared)));
+        HRESULT(0)
+    }
+}
+
+#[repr(C)]
+struct StdMap<K: RuntimeType + 'static, V: RuntimeType + 'static> {
+    map: *const IMap_abi<K, V>,
+    iterable: *const IIterable_abi<IKeyValuePair<K, V>>,
+    count: RefCount,
+    shared: Shared<HashMap<K::DefaultType, V::DefaultType>>,
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> Object for StdMap<K, V>
+where
+    K::DefaultType: Clone + Hash + Eq,
+    V::DefaultType: Clone,
+{
+    fn count(&self) -> &RefCount {
+        &self.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &IMap::<K, V>::IID {
+            Some(0)
+        } else if iid == &IIterable::<IKeyValuePair<K, V>>::IID {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        vec![IMap::<K, V>::IID, IIterable::<IKeyValuePair<K, V>>::IID]
+    }
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> StdMap<K, V>
+where
+    K::DefaultType: Clone + Hash + Eq,
+    V::DefaultType: Clone,
+{
+    const MAP: IMap_abi<K, V> = IMap_abi(
+        query_interface::<Self, 0>,
+        add_ref::<Self, 0>,
+        release::<Self, 0>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::Lookup,
+        Self::Size,
+        Self::HasKey,
+        Self::GetView,
+        Self::Insert,
+        Self::Remove,
+        Self::Clear,
+        PhantomData,
+        PhantomData,
+    );
+
+    const ITERABLE: IIterable_abi<IKeyValuePair<K, V>> = IIterable_abi(
+        query_interface::<Self, 1>,
+        add_ref::<Self, 1>,
+        release::<Self, 1>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::First,
+        PhantomData,
+    );
+
+    fn new(shared: Shared<HashMap<K::DefaultType, V::DefaultType>>) -> Self {
+        Self {
+            map: &Self::MAP,
+            iterable: &Self::ITERABLE,
+            count: RefCount::new(1),
+            shared,
+        }
+    }
+
+    unsafe extern "system" fn Lookup(this: RawPtr, key: K::Abi, result: *mut V::Abi) -> HRESULT {
+        map_lookup::<K, V>(&object::<Self, 0>(this).shared, &key, result)
+    }
+
+    unsafe extern "system" fn Size(this: RawPtr, result: *mut u32) -> HRESULT {
+        *result = object::<Self, 0>(this).shared.read().unwrap().items.len() as u32;
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn HasKey(this: RawPtr, key: K::Abi, result: *mut bool) -> HRESULT {
+        map_has_key::<K, V>(&object::<Self, 0>(this).shared, &key, result)
+    }
+
+    unsafe extern "system" fn GetView(this: RawPtr, result: *mut RawPtr) -> HRESULT {
+        let shared = object::<Self, 0>(this).shared.clone();
+        *result = into_abi(StdMapView::<K, V>::new(shared));
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn Insert(
+        this: RawPtr,
+        key: K::Abi,
+        value: V::Abi,
+        result: *mut bool,
+    ) -> HRESULT {
+        let key = borrow::<K>(&key).clone();
+        let value = borrow::<V>(&value).clone();
+        let mut shared = object::<Self, 0>(this).shared.write().unwrap();
+
+        *result = shared.items.insert(key, value).is_some();
+        shared.version = shared.version.wrapping_add(1);
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn Remove(this: RawPtr, key: K::Abi) -> HRESULT {
+        let mut shared = object::<Self, 0>(this).shared.write().unwrap();
+
+        if shared.items.remove(borrow::<K>(&key)).is_none() {
+            return bounds().into();
+        }
+
+        shared.version = shared.version.wrapping_add(1);
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn Clear(this: RawPtr) -> HRESULT {
+        let mut shared = object::<Self, 0>(this).shared.write().unwrap();
+
+        shared.items.clear();
+        shared.version = shared.version.wrapping_add(1);
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn First(this: RawPtr, result: *mut RawPtr) -> HRESULT {
+        let shared = object::<Self, 1>(this).shared.clone();
+        *result = into_abi(StdIterator::<IKeyValuePair<K, V>>::new(MapCursor::new(
+            shared,
+        )));
+        HRESULT(0)
+    }
+}
+
+#[repr(C)]
+struct StdMapView<K: RuntimeType + 'static, V: RuntimeType + 'static> {
+    view: *const IMapView_abi<K, V>,
+    iterable: *const IIterable_abi<IKeyValuePair<K, V>>,
+    count: RefCount,
+    shared: Shared<HashMap<K::DefaultType, V::DefaultType>>,
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> Object for StdMapView<K, V>
+where
+    K::DefaultType: Clone + Hash + Eq,
+    V::DefaultType: Clone,
+{
+    fn count(&self) -> &RefCount {
+        &self.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &IMapView::<K, V>::IID {
+            Some(0)
+        } else if iid == &IIterable::<IKeyValuePair<K, V>>::IID {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        vec![IMapView::<K, V>::IID, IIterable::<IKeyValuePair<K, V>>::IID]
+    }
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> StdMapView<K, V>
+where
+    K::DefaultType: Clone + Hash + Eq,
+    V::DefaultType: Clone,
+{
+    const VIEW: IMapView_abi<K, V> = IMapView_abi(
+        query_interface::<Self, 0>,
+        add_ref::<Self, 0>,
+        release::<Self, 0>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::Lookup,
+        Self::Size,
+        Self::HasKey,
+        Self::Split,
+        PhantomData,
+        PhantomData,
+    );
+
+    const ITERABLE: IIterable_abi<IKeyValuePair<K, V>> = IIterable_abi(
+        query_interface::<Self, 1>,
+        add_ref::<Self, 1>,
+        release::<Self, 1>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::First,
+        PhantomData,
+    );
+
+    fn new(shared: Shared<HashMap<K::DefaultType, V::DefaultType>>) -> Self {
+        Self {
+            view: &Self::VIEW,
+            iterable: &Self::ITERABLE,
+            count: RefCount::new(1),
+            shared,
+        }
+    }
+
+    unsafe extern "system" fn Lookup(this: RawPtr, key: K::Abi, result: *mut V::Abi) -> HRESULT {
+        map_lookup::<K, V>(&object::<Self, 0>(this).shared, &key, result)
+    }
+
+    unsafe extern "system" fn Size(this: RawPtr, result: *mut u32) -> HRESULT {
+        *result = object::<Self, 0>(this).shared.read().unwrap().items.len() as u32;
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn HasKey(this: RawPtr, key: K::Abi, result: *mut bool) -> HRESULT {
+        map_has_key::<K, V>(&object::<Self, 0>(this).shared, &key, result)
+    }
+
+    unsafe extern "system" fn Split(_: RawPtr, first: *mut RawPtr, second: *mut RawPtr) -> HRESULT {
+        // Splitting is only a hint for parallel iteration, so it's fine not to split at all.
+        *first = std::ptr::null_mut();
+        *second = std::ptr::null_mut();
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn First(this: RawPtr, result: *mut RawPtr) -> HRESULT {
+        let shared = object::<Self, 1>(this).shared.clone();
+        *result = into_abi(StdIterator::<IKeyValuePair<K, V>>::new(MapCursor::new(
+            shared,
+        )));
+        HRESULT(0)
+    }
+}
+
+#[repr(C)]
+struct StdKeyValuePair<K: RuntimeType + 'static, V: RuntimeType + 'static> {
+    vtable: *const IKeyValuePair_abi<K, V>,
+    count: RefCount,
+    key: K::DefaultType,
+    value: V::DefaultType,
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> Object for StdKeyValuePair<K, V>
+where
+    K::DefaultType: Clone,
+    V::DefaultType: Clone,
+{
+    fn count(&self) -> &RefCount {
+        &self.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &IKeyValuePair::<K, V>::IID {
+            Some(0)
+        } else {
+            None
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        vec![IKeyValuePair::<K, V>::IID]
+    }
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> StdKeyValuePair<K, V>
+where
+    K::DefaultType: Clone,
+    V::DefaultType: Clone,
+{
+    const VTABLE: IKeyValuePair_abi<K, V> = IKeyValuePair_abi(
+        query_interface::<Self, 0>,
+        add_ref::<Self, 0>,
+        release::<Self, 0>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::Key,
+        Self::Value,
+        PhantomData,
+        PhantomData,
+    );
+
+    fn create(key: K::DefaultType, value: V::DefaultType) -> IKeyValuePair<K, V> {
+        let pair = into_abi(Self {
+            vtable: &Self::VTABLE,
+            count: RefCount::new(1),
+            key,
+            value,
+        });
+
+        // SAFETY: the pointer is that of the object's only vtable
+        unsafe { std::mem::transmute_copy(&pair) }
+    }
+
+    unsafe extern "system" fn Key(this: RawPtr, result: *mut K::Abi) -> HRESULT {
+        write::<K>(result, object::<Self, 0>(this).key.clone());
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn Value(this: RawPtr, result: *mut V::Abi) -> HRESULT {
+        write::<V>(result, object::<Self, 0>(this).value.clone());
+        HRESULT(0)
+    }
+}
+
+#[repr(C)]
+struct StdIterable<T: RuntimeType + 'static, I> {
+    vtable: *const IIterable_abi<T>,
+    count: RefCount,
+    iterable: I,
+}
+
+impl<T: RuntimeType + 'static, I> Object for StdIterable<T, I>
+where
+    I: IntoIterator<Item = T::DefaultType> + Clone + 'static,
+    T::DefaultType: Clone,
+{
+    fn count(&self) -> &RefCount {
+        &self.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &IIterable::<T>::IID {
+            Some(0)
+        } else {
+            None
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        vec![IIterable::<T>::IID]
+    }
+}
+
+impl<T: RuntimeType + 'static, I> StdIterable<T, I>
+where
+    I: IntoIterator<Item = T::DefaultType> + Clone + 'static,
+    T::DefaultType: Clone,
+{
+    const VTABLE: IIterable_abi<T> = IIterable_abi(
+        query_interface::<Self, 0>,
+        add_ref::<Self, 0>,
+        release::<Self, 0>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::First,
+        PhantomData,
+    );
+
+    unsafe extern "system" fn First(this: RawPtr, result: *mut RawPtr) -> HRESULT {
+        let mut iter = object::<Self, 0>(this).iterable.clone().into_iter();
+        let current = iter.next();
+
+        *result = into_abi(StdIterator::<T>::new(IterCursor { iter, current }));
+        HRESULT(0)
+    }
+}
+
+// The position of an `IIterator<T>` within the collection it iterates over.
+trait Cursor<T>: 'static {
+    fn current(&self) -> Result<Option<T>>;
+    fn move_next(&mut self) -> Result<()>;
+}
+
+struct VectorCursor<T> {
+    shared: Shared<Vec<T>>,
+    version: u32,
+    index: usize,
+}
+
+impl<T> VectorCursor<T> {
+    fn new(shared: Shared<Vec<T>>) -> Self {
+        let version = shared.read().unwrap().version;
+
+        Self {
+            shared,
+            version,
+            index: 0,
+        }
+    }
+}
+
+impl<T: Clone + 'static> Cursor<T> for VectorCursor<T> {
+    fn current(&self) -> Result<Option<T>> {
+        let shared = self.shared.read().unwrap();
+        check_version(shared.version, self.version)?;
+        Ok(shared.items.get(self.index).cloned())
+    }
+
+    fn move_next(&mut self) -> Result<()> {
+        let shared = self.shared.read().unwrap();
+        check_version(shared.version, self.version)?;
+
+        if self.index < shared.items.len() {
+            self.index += 1;
+        }
+
+        Ok(())
+    }
+}
+
+// Iterates over a snapshot of the map's pairs, since a `HashMap` has no stable position to
+// resume from, while still checking that the map hasn't changed.
+struct MapCursor<K: RuntimeType + 'static, V: RuntimeType + 'static> {
+    shared: Shared<HashMap<K::DefaultType, V::DefaultType>>,
+    version: u32,
+    pairs: std::vec::IntoIter<(K::DefaultType, V::DefaultType)>,
+    current: Option<(K::DefaultType, V::DefaultType)>,
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> MapCursor<K, V>
+where
+    K::DefaultType: Clone,
+    V::DefaultType: Clone,
+{
+    fn new(shared: Shared<HashMap<K::DefaultType, V::DefaultType>>) -> Self {
+        let (version, pairs) = {
+            let shared = shared.read().unwrap();
+
+            let pairs: Vec<_> = shared
+                .items
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect();
+
+            (shared.version, pairs)
+        };
+
+        let mut pairs = pairs.into_iter();
+        let current = pairs.next();
+
+        Self {
+            shared,
+            version,
+            pairs,
+            current,
+        }
+    }
+}
+
+impl<K: RuntimeType + 'static, V: RuntimeType + 'static> Cursor<Option<IKeyValuePair<K, V>>>
+    for MapCursor<K, V>
+where
+    K::DefaultType: Clone,
+    V::DefaultType: Clone,
+{
+    fn current(&self) -> Result<Option<Option<IKeyValuePair<K, V>>>> {
+        check_version(self.shared.read().unwrap().version, self.version)?;
+
+        Ok(self
+            .current
+            .as_ref()
+            .map(|(key, value)| Some(StdKeyValuePair::<K, V>::create(key.clone(), value.clone()))))
+    }
+
+    fn move_next(&mut self) -> Result<()> {
+        check_version(self.shared.read().unwrap().version, self.version)?;
+
+        if self.current.is_some() {
+            self.current = self.pairs.next();
+        }
+
+        Ok(())
+    }
+}
+
+struct IterCursor<I: Iterator> {
+    iter: I,
+    current: Option<I::Item>,
+}
+
+impl<I: Iterator + 'static> Cursor<I::Item> for IterCursor<I>
+where
+    I::Item: Clone,
+{
+    fn current(&self) -> Result<Option<I::Item>> {
+        Ok(self.current.clone())
+    }
+
+    fn move_next(&mut self) -> Result<()> {
+        if self.current.is_some() {
+            self.current = self.iter.next();
+        }
+
+        Ok(())
+    }
+}
+
+fn check_version(version: u32, expected: u32) -> Result<()> {
+    if version == expected {
+        Ok(())
+    } else {
+        Err(Error::fast_error(E_CHANGED_STATE))
+    }
+}
+
+#[repr(C)]
+struct StdIterator<T: RuntimeType + 'static> {
+    vtable: *const IIterator_abi<T>,
+    count: RefCount,
+    cursor: Mutex<Box<dyn Cursor<T::DefaultType>>>,
+}
+
+impl<T: RuntimeType + 'static> Object for StdIterator<T> {
+    fn count(&self) -> &RefCount {
+        &self.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &IIterator::<T>::IID {
+            Some(0)
+        } else {
+            None
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        vec![IIterator::<T>::IID]
+    }
+}
+
+impl<T: RuntimeType + 'static> StdIterator<T> {
+    const VTABLE: IIterator_abi<T> = IIterator_abi(
+        query_interface::<Self, 0>,
+        add_ref::<Self, 0>,
+        release::<Self, 0>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::Current,
+        Self::HasCurrent,
+        Self::MoveNext,
+        Self::GetMany,
+        PhantomData,
+    );
+
+    fn new<C: Cursor<T::DefaultType>>(cursor: C) -> Self {
+        Self {
+            vtable: &Self::VTABLE,
+            count: RefCount::new(1),
+            cursor: Mutex::new(Box::new(cursor)),
+        }
+    }
+
+    unsafe fn cursor<'a>(
+        this: RawPtr,
+    ) -> std::sync::MutexGuard<'a, Box<dyn Cursor<T::DefaultType>>> {
+        object::<Self, 0>(this).cursor.lock().unwrap()
+    }
+
+    unsafe extern "system" fn Current(this: RawPtr, result: *mut T::Abi) -> HRESULT {
+        match Self::cursor(this).current() {
+            Ok(Some(value)) => {
+                write::<T>(result, value);
+                HRESULT(0)
+            }
+            Ok(None) => bounds().into(),
+            Err(error) => error.into(),
+        }
+    }
+
+    unsafe extern "system" fn HasCurrent(this: RawPtr, result: *mut bool) -> HRESULT {
+        match Self::cursor(this).current() {
+            Ok(current) => {
+                *result = current.is_some();
+                HRESULT(0)
+            }
+            Err(error) => error.into(),
+        }
+    }
+
+    unsafe extern "system" fn MoveNext(this: RawPtr, result: *mut bool) -> HRESULT {
+        let mut cursor = Self::cursor(this);
+
+        match cursor.move_next().and_then(|_| cursor.current()) {
+            Ok(current) => {
+                *result = current.is_some();
+                HRESULT(0)
+            }
+            Err(error) => error.into(),
+        }
+    }
+
+    unsafe extern "system" fn GetMany(
+        this: RawPtr,
+        capacity: u32,
+        items: *mut T::Abi,
+        result: *mut u32,
+    ) -> HRESULT {
+        let mut cursor = Self::cursor(this);
+        let mut len = 0;
+
+        while len < capacity as usize {
+            match cursor.current() {
+                Ok(Some(value)) => write::<T>(items.add(len), value),
+                Ok(None) => break,
+                Err(error) => {
+                    *result = 0;
+                    return error.into();
+                }
+            }
+
+            len += 1;
+
+            if let Err(error) = cursor.move_next() {
+                *result = 0;
+                return error.into();
+            }
+        }
+
+        *result = len as u32;
+        HRESULT(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector() -> Result<()> {
+        let vector: IVector<i32> = super::vector::<i32>(vec![1, 2, 3]).cast()?;
+        let iterable: IIterable<i32> = vector.cast()?;
+        let iterator = iterable.First()?;
+
+        vector.Append(4)?;
+        assert_eq!(vector.Size()?, 4);
+        assert_eq!(vector.GetAt(3)?, 4);
+        assert_eq!(vector.GetAt(4).unwrap_err().code(), E_BOUNDS);
+
+        // The iterator was invalidated by appending to the vector.
+        assert_eq!(iterator.Current().unwrap_err().code(), E_CHANGED_STATE);
+
+        let view = vector.GetView()?;
+        vector.RemoveAt(0)?;
+        assert_eq!(view.GetAt(0)?, 2);
+
+        let mut index = 0;
+        assert!(view.IndexOf(4, &mut index)?);
+        assert_eq!(index, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn observable_vector() -> Result<()> {
+        let vector: IObservableVector<i32> =
+            super::observable_vector::<i32>(vec![1, 2, 3]).cast()?;
+
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        let handler_changes = changes.clone();
+
+        let token = vector.VectorChanged(VectorChangedEventHandler::new(
+            move |sender: &Option<IObservableVector<i32>>,
+                  args: &Option<IVectorChangedEventArgs>| {
+                let args = args.as_ref().unwrap();
+                let size = sender.as_ref().unwrap().Size()?;
+
+                handler_changes.lock().unwrap().push((
+                    args.CollectionChange()?,
+                    args.Index()?,
+                    size,
+                ));
+
+                Ok(())
+            },
+        ))?;
+
+        vector.Append(4)?;
+        vector.InsertAt(0, 0)?;
+        vector.SetAt(1, 10)?;
+        vector.RemoveAt(2)?;
+        vector.RemoveAtEnd()?;
+        assert_eq!(vector.RemoveAt(5).unwrap_err().code(), E_BOUNDS);
+        vector.Clear()?;
+
+        // A failed change isn't reported.
+        assert_eq!(vector.RemoveAtEnd().unwrap_err().code(), E_BOUNDS);
+
+        vector.RemoveVectorChanged(token)?;
+        vector.Append(5)?;
+
+        assert_eq!(
+            *changes.lock().unwrap(),
+            [
+                (CollectionChange::ItemInserted, 3, 4),
+                (CollectionChange::ItemInserted, 0, 5),
+                (CollectionChange::ItemChanged, 1, 5),
+                (CollectionChange::ItemRemoved, 2, 4),
+                (CollectionChange::ItemRemoved, 3, 3),
+                (CollectionChange::Reset, 0, 0),
+            ]
+        );
+
+        let iterable: IIterable<i32> = vector.cast()?;
+        assert_eq!(iterable.First()?.Current()?, 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn map() -> Result<()> {
+        let mut values = HashMap::new();
+        values.insert(HSTRING::from("one"), 1);
+
+        let map: IMap<HSTRING, i32> = super::map::<HSTRING, i32>(values).cast()?;
+        assert!(!map.Insert("two", 2)?);
+        assert!(map.Insert("two", 3)?);
+        assert_eq!(map.Lookup("two")?, 3);
+        assert!(map.HasKey("one")?);
+        assert_eq!(map.Lookup("three").unwrap_err().code(), E_BOUNDS);
+
+        let iterable: IIterable<IKeyValuePair<HSTRING, i32>> = map.cast()?;
+        let iterator = iterable.First()?;
+        let mut sum = 0;
+
+        while iterator.HasCurrent()? {
+            sum += iterator.Current()?.Value()?;
+            iterator.MoveNext()?;
+        }
+
+        assert_eq!(sum, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn iterable() -> Result<()> {
+        let iterable: IIterable<i32> = super::iterable::<i32, _>(1..=3).cast()?;
+
+        for _ in 0..2 {
+            let iterator = iterable.First()?;
+            let mut values = Vec::new();
+
+            while iterator.HasCurrent()? {
+                values.push(iterator.Current()?);
+                iterator.MoveNext()?;
+            }
+
+            assert_eq!(values, [1, 2, 3]);
+        }
+
+        Ok(())
+    }
+}
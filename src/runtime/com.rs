@@ -3,12 +3,14 @@ use bindings::Windows::Win32::System::Com::*;
 
 /// Initializes COM for use by the calling thread for the multi-threaded apartment (MTA).
 pub fn initialize_mta() -> Result<()> {
-    unsafe { CoInitializeEx(std::ptr::null_mut(), COINIT_MULTITHREADED) }
+    reset_late_release();
+    unsafe { CoInitializeEx(core::ptr::null_mut(), COINIT_MULTITHREADED) }
 }
 
 /// Initializes COM for use by the calling thread for a single-threaded apartment (STA).
 pub fn initialize_sta() -> Result<()> {
-    unsafe { CoInitializeEx(std::ptr::null_mut(), COINIT_APARTMENTTHREADED) }
+    reset_late_release();
+    unsafe { CoInitializeEx(core::ptr::null_mut(), COINIT_APARTMENTTHREADED) }
 }
 
 /// Creates a COM object with the given CLSID.
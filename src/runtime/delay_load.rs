@@ -2,7 +2,7 @@ use crate::*;
 
 use bindings::Windows::Win32::System::LibraryLoader::{FreeLibrary, GetProcAddress, LoadLibraryA};
 
-pub fn delay_load(library: &str, function: &str) -> std::result::Result<RawPtr, HRESULT> {
+pub fn delay_load(library: &str, function: &str) -> core::result::Result<RawPtr, HRESULT> {
     unsafe {
         let library = LoadLibraryA(library);
 
@@ -0,0 +1,532 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Devices::DeviceAndDriverInstallation::{
+        CM_MapCrToWin32Err, CM_Register_Notification, CM_Unregister_Notification,
+        SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInfo, SetupDiEnumDeviceInterfaces,
+        SetupDiGetClassDevsW, SetupDiGetDeviceInstanceIdW, SetupDiGetDeviceInterfaceDetailW,
+        SetupDiGetDeviceRegistryPropertyW, CM_NOTIFY_ACTION,
+        CM_NOTIFY_ACTION_DEVICEINTERFACEARRIVAL, CM_NOTIFY_ACTION_DEVICEINTERFACEREMOVAL,
+        CM_NOTIFY_EVENT_DATA, CM_NOTIFY_FILTER, CM_NOTIFY_FILTER_FLAG_ALL_INTERFACE_CLASSES,
+        CM_NOTIFY_FILTER_TYPE_DEVICEINTERFACE, CONFIGRET, CR_SUCCESS, DIGCF_ALLCLASSES,
+        DIGCF_DEVICEINTERFACE, DIGCF_PRESENT, HCMNOTIFICATION, SPDRP_COMPATIBLEIDS,
+        SPDRP_DEVICEDESC, SPDRP_DRIVER, SPDRP_FRIENDLYNAME, SPDRP_HARDWAREID,
+        SPDRP_LOCATION_INFORMATION, SPDRP_MFG, SPDRP_SERVICE, SP_DEVICE_INTERFACE_DATA,
+        SP_DEVICE_INTERFACE_DETAIL_DATA_W, SP_DEVINFO_DATA,
+    },
+    Foundation::{HWND, PWSTR},
+    System::Diagnostics::Debug::{
+        ERROR_GEN_FAILURE, ERROR_INSUFFICIENT_BUFFER, ERROR_INVALID_DATA, ERROR_NO_MORE_ITEMS,
+    },
+    System::Registry::REG_MULTI_SZ,
+};
+use std::ffi::c_void;
+use std::sync::mpsc::Receiver;
+use std::sync::Mutex;
+
+// The size that `SP_DEVICE_INTERFACE_DETAIL_DATA_W` is declared with, which is packed on x86.
+const DETAIL_DATA_SIZE: u32 = std::mem::size_of::<SP_DEVICE_INTERFACE_DETAIL_DATA_W>() as u32;
+
+/// A set of devices enumerated by SetupAPI, whose records are read with [`DeviceInfoSet::iter`].
+///
+/// ```ignore
+/// for device in windows::DeviceInfoSet::interface(&GUID_DEVINTERFACE_HID)?.iter() {
+///     let device = device?;
+///     println!("{} {:?}", device.instance_id, device.interface_paths);
+/// }
+/// ```
+pub struct DeviceInfoSet {
+    handle: *mut c_void,
+    interface: Option<Guid>,
+}
+
+impl DeviceInfoSet {
+    /// Enumerates the devices of every setup class that are present on the system.
+    pub fn present() -> Result<Self> {
+        Self::new(None, DIGCF_PRESENT | DIGCF_ALLCLASSES, None)
+    }
+
+    /// Enumerates the present devices of a setup class, such as `GUID_DEVCLASS_USB`.
+    pub fn class(class: &Guid) -> Result<Self> {
+        Self::new(Some(class), DIGCF_PRESENT, None)
+    }
+
+    /// Enumerates the present devices exposing a device interface class, such as
+    /// `GUID_DEVINTERFACE_HID`, along with the paths of their interfaces of that class.
+    pub fn interface(interface: &Guid) -> Result<Self> {
+        Self::new(
+            Some(interface),
+            DIGCF_PRESENT | DIGCF_DEVICEINTERFACE,
+            Some(*interface),
+        )
+    }
+
+    fn new(class: Option<&Guid>, flags: u32, interface: Option<Guid>) -> Result<Self> {
+        let class = match class {
+            Some(class) => class as *const Guid,
+            None => std::ptr::null(),
+        };
+
+        let handle =
+            unsafe { SetupDiGetClassDevsW(class, PWSTR::default(), HWND::default(), flags) };
+
+        // The set is `INVALID_HANDLE_VALUE` on failure.
+        if handle as isize == -1 {
+            Err(HRESULT::from_thread().into())
+        } else {
+            Ok(Self { handle, interface })
+        }
+    }
+
+    /// Returns an iterator over the records of the devices in the set.
+    pub fn iter(&self) -> DeviceInfoIter<'_> {
+        DeviceInfoIter {
+            set: self,
+            index: 0,
+            done: false,
+        }
+    }
+
+    fn record(&self, data: &mut SP_DEVINFO_DATA) -> Result<DeviceInfo> {
+        Ok(DeviceInfo {
+            instance_id: self.instance_id(data)?,
+            class: data.ClassGuid,
+            description: self.string_property(data, SPDRP_DEVICEDESC)?,
+            friendly_name: self.string_property(data, SPDRP_FRIENDLYNAME)?,
+            manufacturer: self.string_property(data, SPDRP_MFG)?,
+            location: self.string_property(data, SPDRP_LOCATION_INFORMATION)?,
+            service: self.string_property(data, SPDRP_SERVICE)?,
+            driver: self.string_property(data, SPDRP_DRIVER)?,
+            hardware_ids: self.strings_property(data, SPDRP_HARDWAREID)?,
+            compatible_ids: self.strings_property(data, SPDRP_COMPATIBLEIDS)?,
+            interface_paths: match &self.interface {
+                Some(interface) => self.interface_paths(data, interface)?,
+                None => Vec::new(),
+            },
+        })
+    }
+
+    fn instance_id(&self, data: &mut SP_DEVINFO_DATA) -> Result<String> {
+        let mut buffer: Vec<u16> = vec![0; 256];
+
+        loop {
+            let mut required = 0;
+
+            let result = unsafe {
+                SetupDiGetDeviceInstanceIdW(
+                    self.handle,
+                    data,
+                    PWSTR(buffer.as_mut_ptr()),
+                    buffer.len() as u32,
+                    &mut required,
+                )
+            };
+
+            if result.as_bool() {
+                return Ok(String::from_utf16_lossy(until_null(&buffer)));
+            }
+
+            let error = HRESULT::from_thread();
+
+            if error != HRESULT::from_win32(ERROR_INSUFFICIENT_BUFFER.0)
+                || required as usize <= buffer.len()
+            {
+                return Err(error.into());
+            }
+
+            buffer.resize(required as usize, 0);
+        }
+    }
+
+    // Reads a registry property of the device, returning its type and value, or `None` if the
+    // device doesn't have the property.
+    fn property(
+        &self,
+        data: &mut SP_DEVINFO_DATA,
+        property: u32,
+    ) -> Result<Option<(u32, Vec<u16>)>> {
+        let mut buffer: Vec<u16> = vec![0; 128];
+
+        loop {
+            let mut kind = 0;
+            let mut required = 0;
+
+            let result = unsafe {
+                SetupDiGetDeviceRegistryPropertyW(
+                    self.handle,
+                    data,
+                    property,
+                    &mut kind,
+                    buffer.as_mut_ptr() as _,
+                    (buffer.len() * 2) as u32,
+                    &mut required,
+                )
+            };
+
+            if result.as_bool() {
+                buffer.truncate(required as usize / 2);
+                return Ok(Some((kind, buffer)));
+            }
+
+            let error = HRESULT::from_thread();
+
+            if error == HRESULT::from_win32(ERROR_INVALID_DATA.0) {
+                return Ok(None);
+            }
+
+            if error != HRESULT::from_win32(ERROR_INSUFFICIENT_BUFFER.0)
+                || required as usize <= buffer.len() * 2
+            {
+                return Err(error.into());
+            }
+
+            buffer.resize((required as usize + 1) / 2, 0);
+        }
+    }
+
+    fn string_property(&self, data: &mut SP_DEVINFO_DATA, property: u32) -> Result<Option<String>> {
+        Ok(self
+            .property(data, property)?
+            .map(|(_, value)| String::from_utf16_lossy(until_null(&value))))
+    }
+
+    fn strings_property(&self, data: &mut SP_DEVINFO_DATA, property: u32) -> Result<Vec<String>> {
+        Ok(match self.property(data, property)? {
+            Some((kind, value)) if kind == REG_MULTI_SZ.0 => split_multi_string(&value),
+            Some((_, value)) => vec![String::from_utf16_lossy(until_null(&value))],
+            None => Vec::new(),
+        })
+    }
+
+    fn interface_paths(&self, data: &mut SP_DEVINFO_DATA, interface: &Guid) -> Result<Vec<String>> {
+        let mut paths = Vec::new();
+
+        for index in 0.. {
+            let mut interface_data = SP_DEVICE_INTERFACE_DATA {
+                cbSize: std::mem::size_of::<SP_DEVICE_INTERFACE_DATA>() as u32,
+                ..Default::default()
+            };
+
+            let result = unsafe {
+                SetupDiEnumDeviceInterfaces(
+                    self.handle,
+                    data,
+                    interface,
+                    index,
+                    &mut interface_data,
+                )
+            };
+
+            if !result.as_bool() {
+                let error = HRESULT::from_thread();
+
+                if error == HRESULT::from_win32(ERROR_NO_MORE_ITEMS.0) {
+                    break;
+                }
+
+                return Err(error.into());
+            }
+
+            paths.push(self.interface_path(&mut interface_data)?);
+        }
+
+        Ok(paths)
+    }
+
+    fn interface_path(&self, interface_data: &mut SP_DEVICE_INTERFACE_DATA) -> Result<String> {
+        let mut required = 0;
+
+        // The first call only reports the size of the detail data, which holds the path.
+        unsafe {
+            SetupDiGetDeviceInterfaceDetailW(
+                self.handle,
+                interface_data,
+                std::ptr::null_mut(),
+                0,
+                &mut required,
+                std::ptr::null_mut(),
+            );
+        }
+
+        if required < DETAIL_DATA_SIZE {
+            return Err(HRESULT::from_thread().into());
+        }
+
+        // The detail data is read into 8-byte aligned memory for its `cbSize` field.
+        let mut buffer: Vec<u64> = vec![0; (required as usize + 7) / 8];
+        let detail = buffer.as_mut_ptr() as *mut SP_DEVICE_INTERFACE_DETAIL_DATA_W;
+
+        unsafe {
+            (*detail).cbSize = DETAIL_DATA_SIZE;
+
+            SetupDiGetDeviceInterfaceDetailW(
+                self.handle,
+                interface_data,
+                detail,
+                required,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+            .ok()?;
+
+            // The path follows the `cbSize` field and fills the rest of the detail data.
+            let path = std::slice::from_raw_parts(
+                (*detail).DevicePath.as_ptr(),
+                (required as usize - 4) / 2,
+            );
+
+            Ok(String::from_utf16_lossy(until_null(path)))
+        }
+    }
+}
+
+impl Drop for DeviceInfoSet {
+    fn drop(&mut self) {
+        unsafe {
+            SetupDiDestroyDeviceInfoList(self.handle);
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a DeviceInfoSet {
+    type Item = Result<DeviceInfo>;
+    type IntoIter = DeviceInfoIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the records of the devices in a [`DeviceInfoSet`].
+pub struct DeviceInfoIter<'a> {
+    set: &'a DeviceInfoSet,
+    index: u32,
+    done: bool,
+}
+
+impl<'a> Iterator for DeviceInfoIter<'a> {
+    type Item = Result<DeviceInfo>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut data = SP_DEVINFO_DATA {
+            cbSize: std::mem::size_of::<SP_DEVINFO_DATA>() as u32,
+            ..Default::default()
+        };
+
+        let result = unsafe { SetupDiEnumDeviceInfo(self.set.handle, self.index, &mut data) };
+
+        if !result.as_bool() {
+            self.done = true;
+            let error = HRESULT::from_thread();
+
+            return if error == HRESULT::from_win32(ERROR_NO_MORE_ITEMS.0) {
+                None
+            } else {
+                Some(Err(error.into()))
+            };
+        }
+
+        self.index += 1;
+        Some(self.set.record(&mut data))
+    }
+}
+
+/// A device enumerated by a [`DeviceInfoSet`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct DeviceInfo {
+    /// The device instance identifier, such as `USB\VID_045E&PID_07A5\5&2B9A2A5&0&2`, which is
+    /// also the `Id` of the WinRT `DeviceInformation` of kind `Device` for the device.
+    pub instance_id: String,
+    /// The setup class of the device.
+    pub class: Guid,
+    pub description: Option<String>,
+    pub friendly_name: Option<String>,
+    pub manufacturer: Option<String>,
+    pub location: Option<String>,
+    /// The name of the service of the device's driver.
+    pub service: Option<String>,
+    /// The driver's key under the device class key, identifying the driver package in use.
+    pub driver: Option<String>,
+    /// The hardware identifiers, from the most to the least specific.
+    pub hardware_ids: Vec<String>,
+    pub compatible_ids: Vec<String>,
+    /// The paths of the device's interfaces of the class the set was created for, which may be
+    /// opened with [`Device::open`] and are the `Id` of the interfaces' WinRT
+    /// `DeviceInformation`. This is empty if the set wasn't created for an interface class.
+    pub interface_paths: Vec<String>,
+}
+
+/// A device interface arriving or being removed, as reported to a [`DeviceNotification`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum DeviceInterfaceEvent {
+    Arrived { class: Guid, path: String },
+    Removed { class: Guid, path: String },
+}
+
+type NotificationHandler = Box<dyn Fn(DeviceInterfaceEvent) + Send + Sync>;
+
+/// A registration for notifications of device interfaces arriving and being removed, which
+/// complements [`DeviceInfoSet::interface`] for watching the devices it enumerates.
+///
+/// The handler is called on a thread pool thread. Dropping the registration waits for any
+/// running handler to return, so it must not be dropped from within the handler.
+///
+/// ```ignore
+/// let (_notification, events) = windows::DeviceNotification::channel(Some(&GUID_DEVINTERFACE_HID))?;
+///
+/// for event in events {
+///     println!("{:?}", event);
+/// }
+/// ```
+pub struct DeviceNotification {
+    handle: HCMNOTIFICATION,
+    handler: *mut NotificationHandler,
+}
+
+impl DeviceNotification {
+    /// Registers `handler` for the interfaces of the given class, or of every class if `class`
+    /// is `None`.
+    pub fn new<F: Fn(DeviceInterfaceEvent) + Send + Sync + 'static>(
+        class: Option<&Guid>,
+        handler: F,
+    ) -> Result<Self> {
+        let mut filter = CM_NOTIFY_FILTER {
+            cbSize: std::mem::size_of::<CM_NOTIFY_FILTER>() as u32,
+            FilterType: CM_NOTIFY_FILTER_TYPE_DEVICEINTERFACE,
+            ..Default::default()
+        };
+
+        match class {
+            Some(class) => filter.u.DeviceInterface.ClassGuid = *class,
+            None => filter.Flags = CM_NOTIFY_FILTER_FLAG_ALL_INTERFACE_CLASSES,
+        }
+
+        let handler: NotificationHandler = Box::new(handler);
+        let handler = Box::into_raw(Box::new(handler));
+        let mut handle = 0;
+
+        unsafe {
+            let result =
+                CM_Register_Notification(&mut filter, handler as _, Some(notify), &mut handle);
+
+            if result != CR_SUCCESS {
+                drop(Box::from_raw(handler));
+                return Err(config_error(result));
+            }
+        }
+
+        Ok(Self {
+            handle: HCMNOTIFICATION(handle),
+            handler,
+        })
+    }
+
+    /// Registers for the interfaces of the given class, or of every class if `class` is `None`,
+    /// returning the events through a channel that is closed once the registration is dropped.
+    pub fn channel(class: Option<&Guid>) -> Result<(Self, Receiver<DeviceInterfaceEvent>)> {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let sender = Mutex::new(sender);
+
+        let notification = Self::new(class, move |event| {
+            if let Ok(sender) = sender.lock() {
+                // The receiver may have been dropped before the registration.
+                let _ = sender.send(event);
+            }
+        })?;
+
+        Ok((notification, receiver))
+    }
+}
+
+impl Drop for DeviceNotification {
+    fn drop(&mut self) {
+        unsafe {
+            CM_Unregister_Notification(self.handle);
+            drop(Box::from_raw(self.handler));
+        }
+    }
+}
+
+unsafe impl Send for DeviceNotification {}
+unsafe impl Sync for DeviceNotification {}
+
+unsafe extern "system" fn notify(
+    _: HCMNOTIFICATION,
+    context: *mut c_void,
+    action: CM_NOTIFY_ACTION,
+    data: *mut CM_NOTIFY_EVENT_DATA,
+    size: u32,
+) -> u32 {
+    if data.is_null() {
+        return 0;
+    }
+
+    let class = (*data).u.DeviceInterface.ClassGuid;
+
+    // The symbolic link fills the rest of the event data.
+    let link = (*data).u.DeviceInterface.SymbolicLink.as_ptr();
+    let len = (size as usize).saturating_sub(link as usize - data as usize) / 2;
+    let path = String::from_utf16_lossy(until_null(std::slice::from_raw_parts(link, len)));
+
+    let event = if action == CM_NOTIFY_ACTION_DEVICEINTERFACEARRIVAL {
+        DeviceInterfaceEvent::Arrived { class, path }
+    } else if action == CM_NOTIFY_ACTION_DEVICEINTERFACEREMOVAL {
+        DeviceInterfaceEvent::Removed { class, path }
+    } else {
+        return 0;
+    };
+
+    (*(context as *const NotificationHandler))(event);
+    0
+}
+
+fn config_error(result: CONFIGRET) -> Error {
+    HRESULT::from_win32(unsafe { CM_MapCrToWin32Err(result, ERROR_GEN_FAILURE.0) }).into()
+}
+
+fn until_null(wide: &[u16]) -> &[u16] {
+    &wide[..wide.iter().position(|c| *c == 0).unwrap_or(wide.len())]
+}
+
+// Splits a `REG_MULTI_SZ` value, a list of strings each followed by a null and ended by an
+// empty string.
+fn split_multi_string(wide: &[u16]) -> Vec<String> {
+    wide.split(|c| *c == 0)
+        .take_while(|value| !value.is_empty())
+        .map(String::from_utf16_lossy)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multi_string() {
+        let wide: Vec<u16> = "USB\\VID_045E&PID_07A5\0USB\\VID_045E\0\0"
+            .encode_utf16()
+            .collect();
+
+        assert_eq!(
+            split_multi_string(&wide),
+            ["USB\\VID_045E&PID_07A5", "USB\\VID_045E"]
+        );
+
+        assert!(split_multi_string(&[0, 0]).is_empty());
+        assert!(split_multi_string(&[]).is_empty());
+    }
+
+    #[test]
+    fn present() -> Result<()> {
+        let devices = DeviceInfoSet::present()?
+            .iter()
+            .collect::<Result<Vec<DeviceInfo>>>()?;
+
+        assert!(!devices.is_empty());
+        assert!(devices.iter().all(|device| !device.instance_id.is_empty()));
+        Ok(())
+    }
+}
@@ -0,0 +1,341 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::{CloseHandle, HANDLE},
+    Storage::FileSystem::{
+        CreateFileW, FILE_ACCESS_FLAGS, FILE_FLAGS_AND_ATTRIBUTES, FILE_FLAG_BACKUP_SEMANTICS,
+        FILE_GENERIC_READ, FILE_GENERIC_WRITE, FILE_SHARE_DELETE, FILE_SHARE_READ,
+        FILE_SHARE_WRITE, OPEN_EXISTING,
+    },
+    System::Diagnostics::Debug::{ERROR_INSUFFICIENT_BUFFER, ERROR_MORE_DATA},
+    System::SystemServices::DeviceIoControl,
+};
+use std::ffi::c_void;
+use std::path::Path;
+
+// The size of the first buffer tried by `Device::control_vec`.
+const INITIAL_BUFFER_SIZE: usize = 1024;
+
+/// How the I/O manager passes the buffers of a [`ControlCode`] to the driver.
+///
+/// The method doesn't change how the control code is called from user mode, but with the direct
+/// methods the output buffer may also provide data to the driver, as described by the driver's
+/// documentation for the control code.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum TransferMethod {
+    Buffered = 0,
+    InDirect = 1,
+    OutDirect = 2,
+    Neither = 3,
+}
+
+/// A control code for [`Device`], as computed by the `CTL_CODE` macro.
+///
+/// ```ignore
+/// const FSCTL_SET_SPARSE: ControlCode =
+///     ControlCode::new(0x9, 49, TransferMethod::Buffered, ControlCode::ANY_ACCESS);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ControlCode(pub u32);
+
+impl ControlCode {
+    pub const ANY_ACCESS: u32 = 0;
+    pub const READ_ACCESS: u32 = 1;
+    pub const WRITE_ACCESS: u32 = 2;
+
+    /// Builds a control code from the type of device, the function number, the transfer method,
+    /// and the access required by the control code.
+    pub const fn new(device_type: u32, function: u32, method: TransferMethod, access: u32) -> Self {
+        Self((device_type << 16) | (access << 14) | (function << 2) | method as u32)
+    }
+
+    pub fn device_type(self) -> u32 {
+        self.0 >> 16
+    }
+
+    pub fn function(self) -> u32 {
+        (self.0 >> 2) & 0xFFF
+    }
+
+    pub fn method(self) -> TransferMethod {
+        match self.0 & 3 {
+            0 => TransferMethod::Buffered,
+            1 => TransferMethod::InDirect,
+            2 => TransferMethod::OutDirect,
+            _ => TransferMethod::Neither,
+        }
+    }
+
+    pub fn access(self) -> u32 {
+        (self.0 >> 14) & 3
+    }
+}
+
+impl From<u32> for ControlCode {
+    fn from(code: u32) -> Self {
+        Self(code)
+    }
+}
+
+/// Describes a control code along with the types of its input and output buffers, so that it
+/// may be sent with [`Device::call`].
+///
+/// ```ignore
+/// struct SetSparse;
+///
+/// unsafe impl DeviceControl for SetSparse {
+///     const CODE: ControlCode = ControlCode(FSCTL_SET_SPARSE);
+///     type Input = FILE_SET_SPARSE_BUFFER;
+///     type Output = ();
+/// }
+///
+/// device.call::<SetSparse>(&FILE_SET_SPARSE_BUFFER { SetSparse: 1 })?;
+/// ```
+///
+/// # Safety
+/// The control code must only read a value of type `Input` and must write either nothing or a
+/// valid value of type `Output`.
+pub unsafe trait DeviceControl {
+    const CODE: ControlCode;
+    type Input: Copy;
+    type Output: Copy + Default;
+}
+
+/// A handle to a device, volume, file, or directory for sending control codes to its driver with
+/// `DeviceIoControl`. The handle is closed when dropped.
+pub struct Device(HANDLE);
+
+impl Device {
+    /// Opens a device, such as `\\.\C:` or `\\.\PhysicalDrive0`, or a file or directory for
+    /// reading and writing.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::create(
+            path.as_ref(),
+            FILE_GENERIC_READ | FILE_GENERIC_WRITE,
+            FILE_FLAG_BACKUP_SEMANTICS,
+        )
+    }
+
+    /// Opens a device, file, or directory with the given access rights, a combination of the
+    /// `FILE_*` access flags or `0` to only query the device's metadata.
+    pub fn open_with_access<P: AsRef<Path>>(path: P, access: u32) -> Result<Self> {
+        Self::create(
+            path.as_ref(),
+            FILE_ACCESS_FLAGS(access),
+            FILE_FLAG_BACKUP_SEMANTICS,
+        )
+    }
+
+    // Backup semantics are needed to open directories.
+    pub(crate) fn create(
+        path: &Path,
+        access: FILE_ACCESS_FLAGS,
+        flags: FILE_FLAGS_AND_ATTRIBUTES,
+    ) -> Result<Self> {
+        let handle = unsafe {
+            CreateFileW(
+                path,
+                access,
+                FILE_SHARE_READ | FILE_SHARE_WRITE | FILE_SHARE_DELETE,
+                std::ptr::null_mut(),
+                OPEN_EXISTING,
+                flags,
+                HANDLE::default(),
+            )
+        };
+
+        if handle.is_invalid() {
+            Err(HRESULT::from_thread().into())
+        } else {
+            Ok(Self(handle))
+        }
+    }
+
+    /// Takes ownership of an open handle, which is closed when the `Device` is dropped.
+    ///
+    /// # Safety
+    /// `handle` must be a valid handle that isn't closed elsewhere.
+    pub unsafe fn from_raw(handle: isize) -> Self {
+        Self(HANDLE(handle))
+    }
+
+    /// Returns the handle without affecting its ownership.
+    pub fn as_raw(&self) -> isize {
+        self.0 .0
+    }
+
+    /// Sends the control code described by `T`.
+    pub fn call<T: DeviceControl>(&self, input: &T::Input) -> Result<T::Output> {
+        unsafe { self.control(T::CODE, input) }
+    }
+
+    /// Sends a control code with an input value of type `I`, returning the output value of type
+    /// `O`. Either type may be `()` if the control code has no input or output.
+    ///
+    /// # Safety
+    /// The control code must only read a value of type `I` and must write either nothing or a
+    /// valid value of type `O`.
+    pub unsafe fn control<I: Copy, O: Copy + Default>(
+        &self,
+        code: ControlCode,
+        input: &I,
+    ) -> Result<O> {
+        let mut output = O::default();
+
+        self.io(
+            code.0,
+            input as *const _ as _,
+            std::mem::size_of::<I>() as u32,
+            &mut output as *mut _ as _,
+            std::mem::size_of::<O>() as u32,
+        )?;
+
+        Ok(output)
+    }
+
+    /// Sends a control code with variable-length input and output buffers, returning the number
+    /// of bytes written to `output`.
+    ///
+    /// Some control codes require buffers aligned for the structures they hold, which a `[u8]`
+    /// doesn't guarantee. Use [`Device::control_vec`] for those that don't have a fixed output
+    /// size.
+    pub fn control_bytes(
+        &self,
+        code: ControlCode,
+        input: &[u8],
+        output: &mut [u8],
+    ) -> Result<usize> {
+        unsafe {
+            self.io(
+                code.0,
+                input.as_ptr() as _,
+                input.len() as u32,
+                output.as_mut_ptr() as _,
+                output.len() as u32,
+            )
+        }
+        .map(|len| len as usize)
+    }
+
+    /// Sends a control code whose output has a variable length, such as a list of extents,
+    /// growing the output buffer until it is large enough to hold the whole output.
+    pub fn control_vec(&self, code: ControlCode, input: &[u8]) -> Result<Vec<u8>> {
+        // The output is read into 8-byte aligned memory for the structures it holds.
+        let mut buffer: Vec<u64> = vec![0; INITIAL_BUFFER_SIZE / 8];
+
+        loop {
+            let result = unsafe {
+                self.io(
+                    code.0,
+                    input.as_ptr() as _,
+                    input.len() as u32,
+                    buffer.as_mut_ptr() as _,
+                    (buffer.len() * 8) as u32,
+                )
+            };
+
+            match result {
+                Ok(len) => {
+                    let bytes = unsafe {
+                        std::slice::from_raw_parts(buffer.as_ptr() as *const u8, len as usize)
+                    };
+
+                    return Ok(bytes.to_vec());
+                }
+                Err(error)
+                    if (error.code() == HRESULT::from_win32(ERROR_INSUFFICIENT_BUFFER.0)
+                        || error.code() == HRESULT::from_win32(ERROR_MORE_DATA.0))
+                        && buffer.len() * 16 <= u32::MAX as usize =>
+                {
+                    let len = buffer.len() * 2;
+                    buffer.resize(len, 0);
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    // Sends a control code to the driver, returning the number of bytes written to `output`.
+    pub(crate) unsafe fn io(
+        &self,
+        code: u32,
+        input: *const c_void,
+        input_len: u32,
+        output: *mut c_void,
+        output_len: u32,
+    ) -> Result<u32> {
+        let mut returned = 0;
+
+        DeviceIoControl(
+            self.0,
+            code,
+            input as _,
+            input_len,
+            output,
+            output_len,
+            &mut returned,
+            std::ptr::null_mut(),
+        )
+        .ok()?;
+
+        Ok(returned)
+    }
+
+    pub(crate) fn handle(&self) -> HANDLE {
+        self.0
+    }
+}
+
+impl Drop for Device {
+    fn drop(&mut self) {
+        unsafe {
+            CloseHandle(self.0);
+        }
+    }
+}
+
+unsafe impl Send for Device {}
+unsafe impl Sync for Device {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bindings::Windows::Win32::System::SystemServices::{
+        FSCTL_READ_USN_JOURNAL, FSCTL_SET_SPARSE,
+    };
+
+    struct SetSparse;
+
+    unsafe impl DeviceControl for SetSparse {
+        const CODE: ControlCode = ControlCode::new(0x9, 49, TransferMethod::Buffered, 0);
+        type Input = u8;
+        type Output = ();
+    }
+
+    #[test]
+    fn code() {
+        assert_eq!(SetSparse::CODE, ControlCode(FSCTL_SET_SPARSE));
+
+        let code = ControlCode(FSCTL_READ_USN_JOURNAL);
+        assert_eq!(code.device_type(), 0x9);
+        assert_eq!(code.function(), 46);
+        assert_eq!(code.method(), TransferMethod::Neither);
+        assert_eq!(code.access(), ControlCode::ANY_ACCESS);
+    }
+
+    #[test]
+    fn call() -> Result<()> {
+        let path = std::env::temp_dir().join(format!("windows-device-{}", std::process::id()));
+        std::fs::write(&path, b"data")?;
+
+        let device = Device::open(&path)?;
+        device.call::<SetSparse>(&1)?;
+        drop(device);
+
+        let info = file_info(&path)?;
+        assert!(info.attributes.contains(FileAttributes::SPARSE_FILE));
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
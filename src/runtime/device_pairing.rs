@@ -0,0 +1,171 @@
+use crate::*;
+use bindings::Windows::{
+    Devices::Enumeration::{
+        DeviceInformation, DevicePairingKinds, DevicePairingRequestedEventArgs,
+        DevicePairingResultStatus, DevicePicker,
+    },
+    Foundation::{Rect, TypedEventHandler},
+    Win32::Foundation::{E_FAIL, E_POINTER},
+};
+
+// The names of the `DevicePairingResultStatus` values, for reporting why pairing failed.
+const PAIRING_STATUS_NAMES: [&str; 20] = [
+    "Paired",
+    "NotReadyToPair",
+    "NotPaired",
+    "AlreadyPaired",
+    "ConnectionRejected",
+    "TooManyConnections",
+    "HardwareFailure",
+    "AuthenticationTimeout",
+    "AuthenticationNotAllowed",
+    "AuthenticationFailure",
+    "NoSupportedProfiles",
+    "ProtectionLevelCouldNotBeMet",
+    "AccessDenied",
+    "InvalidCeremonyData",
+    "PairingCanceled",
+    "OperationAlreadyInProgress",
+    "RequiredHandlerNotRegistered",
+    "RejectedByHandler",
+    "RemoteDeviceHasAssociation",
+    "Failed",
+];
+
+/// A step of pairing a device with [`pair_device`] that needs the user's involvement.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PairingRequest {
+    /// The user only needs to confirm that the device should be paired.
+    ConfirmOnly,
+    /// The PIN should be shown to the user, who enters it on the device.
+    DisplayPin(String),
+    /// The user must provide the PIN shown by or printed on the device.
+    ProvidePin,
+    /// Both the app and the device show the PIN, and the user must confirm that they match.
+    ConfirmPinMatch(String),
+}
+
+/// The answer to a [`PairingRequest`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PairingResponse {
+    Accept,
+    /// Accepts a [`PairingRequest::ProvidePin`] request with the PIN provided by the user.
+    AcceptWithPin(String),
+    Reject,
+}
+
+/// Pairs the device with the given `DeviceInformation` identifier, such as one returned by
+/// [`pick_device`], calling `handler` on a background thread for each step that needs the
+/// user's involvement.
+///
+/// This registers the custom pairing handler that WinRT requires of desktop apps, which can't
+/// rely on the system's pairing UI. A device that is already paired is left as it is.
+///
+/// ```ignore
+/// windows::pair_device(&id, |request| match request {
+///     PairingRequest::ProvidePin => PairingResponse::AcceptWithPin(read_pin()),
+///     _ => PairingResponse::Accept,
+/// })
+/// .await?;
+/// ```
+pub async fn pair_device<F>(id: &str, handler: F) -> Result<()>
+where
+    F: Fn(PairingRequest) -> PairingResponse + Send + 'static,
+{
+    let pairing = DeviceInformation::CreateFromIdAsync(id)?.await?.Pairing()?;
+
+    if pairing.IsPaired()? {
+        return Ok(());
+    }
+
+    let custom = pairing.Custom()?;
+
+    let token = custom.PairingRequested(TypedEventHandler::new(
+        move |_, args: &Option<DevicePairingRequestedEventArgs>| match args {
+            Some(args) => respond(args, &handler),
+            None => Ok(()),
+        },
+    ))?;
+
+    let kinds = DevicePairingKinds::ConfirmOnly
+        | DevicePairingKinds::DisplayPin
+        | DevicePairingKinds::ProvidePin
+        | DevicePairingKinds::ConfirmPinMatch;
+
+    // The handler is removed even if pairing fails.
+    let result = match custom.PairAsync(kinds) {
+        Ok(operation) => operation.await,
+        Err(error) => Err(error),
+    };
+
+    custom.RemovePairingRequested(token)?;
+    let status = result?.Status()?;
+
+    if status == DevicePairingResultStatus::Paired
+        || status == DevicePairingResultStatus::AlreadyPaired
+    {
+        return Ok(());
+    }
+
+    let name = match PAIRING_STATUS_NAMES.get(status.0 as usize) {
+        Some(name) => name.to_string(),
+        None => status.0.to_string(),
+    };
+
+    Err(Error::new(
+        E_FAIL,
+        &format!("The device could not be paired: {}", name),
+    ))
+}
+
+fn respond<F: Fn(PairingRequest) -> PairingResponse>(
+    args: &DevicePairingRequestedEventArgs,
+    handler: &F,
+) -> Result<()> {
+    let kind = args.PairingKind()?;
+
+    let request = if kind == DevicePairingKinds::ConfirmOnly {
+        PairingRequest::ConfirmOnly
+    } else if kind == DevicePairingKinds::DisplayPin {
+        PairingRequest::DisplayPin(args.Pin()?.to_string())
+    } else if kind == DevicePairingKinds::ProvidePin {
+        PairingRequest::ProvidePin
+    } else if kind == DevicePairingKinds::ConfirmPinMatch {
+        PairingRequest::ConfirmPinMatch(args.Pin()?.to_string())
+    } else {
+        // Other kinds, such as password credentials, weren't offered to the device.
+        return Ok(());
+    };
+
+    match handler(request) {
+        PairingResponse::Accept => args.Accept(),
+        PairingResponse::AcceptWithPin(pin) => args.AcceptWithPin(pin),
+        PairingResponse::Reject => Ok(()),
+    }
+}
+
+/// Shows the system's device picker over the given window of a desktop app, returning the
+/// `DeviceInformation` identifier of the chosen device, or `None` if the picker was dismissed.
+///
+/// The devices shown are those matching any of the AQS `selectors`, such as those returned by
+/// `BluetoothDevice::GetDeviceSelector`, or all devices if there are none. This must be called
+/// from the thread that owns the window.
+pub async fn pick_device(window: isize, selectors: &[&str]) -> Result<Option<String>> {
+    let picker = DevicePicker::new()?;
+
+    // Desktop apps have no `CoreWindow`, so the picker must be told which window owns it.
+    initialize_with_window(&picker, window)?;
+
+    let supported = picker.Filter()?.SupportedDeviceSelectors()?;
+
+    for selector in selectors {
+        supported.Append(*selector)?;
+    }
+
+    // A dismissed picker completes with no device, which is reported as `E_POINTER`.
+    match picker.PickSingleDeviceAsync(Rect::default())?.await {
+        Ok(device) => Ok(Some(device.Id()?.to_string())),
+        Err(error) if error.code() == E_POINTER => Ok(None),
+        Err(error) => Err(error),
+    }
+}
@@ -0,0 +1,393 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::{BSTR, DISP_E_EXCEPTION, E_POINTER, PWSTR},
+    System::{
+        OleAutomation::{
+            IDispatch, VARIANT_0_0_0_abi, VariantChangeType, VariantClear, VariantCopy,
+            DISPATCH_METHOD, DISPATCH_PROPERTYGET, DISPATCH_PROPERTYPUT, DISPATCH_PROPERTYPUTREF,
+            DISPID_PROPERTYPUT, DISPPARAMS, EXCEPINFO, VARENUM, VARIANT, VT_BOOL, VT_BSTR,
+            VT_DISPATCH, VT_EMPTY, VT_I4, VT_R8, VT_UNKNOWN,
+        },
+        SystemServices::{LANG_NEUTRAL, MAKELANGID, MAKELCID, SORT_DEFAULT, SUBLANG_DEFAULT},
+    },
+};
+
+// The `LOCALE_USER_DEFAULT` macro, which the metadata doesn't describe.
+const LOCALE_USER_DEFAULT: u32 = MAKELCID(
+    MAKELANGID(LANG_NEUTRAL, SUBLANG_DEFAULT),
+    SORT_DEFAULT as u16,
+);
+
+/// Calls the methods and properties of an automation object by name through its `IDispatch`
+/// interface, for objects such as those of the Office applications or `WScript.Shell` that are
+/// meant to be scripted and aren't usefully described by metadata.
+///
+/// Arguments and results are [`Variant`] values, which are created from Rust values with `into`
+/// and read with the coercions of `VariantChangeType`. Exceptions raised by the object are
+/// returned as errors carrying the object's description of the exception.
+///
+/// ```ignore
+/// let shell = windows::Dispatch::create("WScript.Shell")?;
+/// let path = shell.call("ExpandEnvironmentStrings", &["%WINDIR%".into()])?;
+/// println!("{}", path.string()?);
+///
+/// let excel = windows::Dispatch::create("Excel.Application")?;
+/// excel.put("Visible", true)?;
+/// let workbook = excel.get("Workbooks")?.dispatch()?.call("Add", &[])?;
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Dispatch(IDispatch);
+
+impl Dispatch {
+    /// Wraps an object implementing `IDispatch`.
+    pub fn new<T: Interface>(object: &T) -> Result<Self> {
+        object.cast().map(Self)
+    }
+
+    /// Creates the automation object registered with the given ProgID, such as
+    /// `"Scripting.Dictionary"`.
+    pub fn create(progid: &str) -> Result<Self> {
+        create_instance(&Guid::from_progid(progid)?).map(Self)
+    }
+
+    /// Returns the value of the named property.
+    pub fn get(&self, name: &str) -> Result<Variant> {
+        self.invoke(self.id(name)?, DISPATCH_PROPERTYGET as u16, &[], false)
+    }
+
+    /// Sets the value of the named property. Automation objects are assigned by reference, as
+    /// with `Set` in Visual Basic, and other values are assigned by value.
+    pub fn put<T: Into<Variant>>(&self, name: &str, value: T) -> Result<()> {
+        let value = value.into();
+
+        let flags = if value.vartype() == VT_DISPATCH.0 as u16 {
+            DISPATCH_PROPERTYPUTREF
+        } else {
+            DISPATCH_PROPERTYPUT
+        };
+
+        self.invoke(self.id(name)?, flags as u16, &[value], true)
+            .map(|_| ())
+    }
+
+    /// Calls the named method with the given arguments, returning its result, which is empty
+    /// if it has none. Properties taking arguments, such as a collection's `Item`, may also be
+    /// read this way.
+    pub fn call(&self, name: &str, args: &[Variant]) -> Result<Variant> {
+        self.invoke(
+            self.id(name)?,
+            (DISPATCH_METHOD | DISPATCH_PROPERTYGET) as u16,
+            args,
+            false,
+        )
+    }
+
+    /// Returns the dispatch identifier of the named method or property, as it is looked up before
+    /// each call.
+    pub fn id(&self, name: &str) -> Result<i32> {
+        let mut wide: Vec<u16> = name.encode_utf16().chain(std::iter::once(0)).collect();
+        let mut name = PWSTR(wide.as_mut_ptr());
+
+        unsafe {
+            self.0
+                .GetIDsOfNames(&Guid::zeroed(), &mut name, 1, LOCALE_USER_DEFAULT)
+        }
+    }
+
+    /// Queries the object for another of its interfaces.
+    pub fn interface<T: Interface>(&self) -> Result<T> {
+        self.0.cast()
+    }
+
+    fn invoke(&self, id: i32, flags: u16, args: &[Variant], put: bool) -> Result<Variant> {
+        // Dispatch arguments are passed in reverse order, borrowing the caller's values.
+        let mut args: Vec<VARIANT> = args
+            .iter()
+            .rev()
+            .map(|arg| unsafe { std::ptr::read(&arg.0) })
+            .collect();
+
+        let mut named = DISPID_PROPERTYPUT;
+
+        let mut params = DISPPARAMS {
+            rgvarg: args.as_mut_ptr(),
+            rgdispidNamedArgs: if put {
+                &mut named
+            } else {
+                std::ptr::null_mut()
+            },
+            cArgs: args.len() as u32,
+            cNamedArgs: put as u32,
+        };
+
+        let mut result = Variant::new();
+        let mut exception = EXCEPINFO::default();
+
+        let invoked = unsafe {
+            self.0.Invoke(
+                id,
+                &Guid::zeroed(),
+                LOCALE_USER_DEFAULT,
+                flags,
+                &mut params,
+                &mut result.0,
+                &mut exception,
+                std::ptr::null_mut(),
+            )
+        };
+
+        match invoked {
+            Ok(()) => Ok(result),
+            Err(error) if error.code() == DISP_E_EXCEPTION => Err(exception_error(exception)),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+// Describes an exception raised by an automation object with the code and description it gave.
+fn exception_error(mut exception: EXCEPINFO) -> Error {
+    if let Some(fill_in) = exception.pfnDeferredFillIn {
+        unsafe {
+            let _ = fill_in(&mut exception as *mut EXCEPINFO as _);
+        }
+    }
+
+    let code = if exception.scode != 0 {
+        HRESULT(exception.scode as u32)
+    } else {
+        DISP_E_EXCEPTION
+    };
+
+    Error::new(
+        code,
+        &String::from_utf16_lossy(exception.bstrDescription.as_wide()),
+    )
+}
+
+/// An owned `VARIANT`, the value of an argument or result of a method or property called
+/// through [`Dispatch`], that is cleared with `VariantClear` when dropped.
+///
+/// Values are created from `i32`, `f64`, `bool`, strings, and [`Dispatch`] objects, and read
+/// with the coercions of `VariantChangeType`, so that a value holding a number may also be read
+/// as a string.
+#[repr(transparent)]
+pub struct Variant(VARIANT);
+
+impl Variant {
+    /// Creates an empty value.
+    pub fn new() -> Self {
+        Self(VARIANT::default())
+    }
+
+    /// The `VARENUM` value describing the type of the value.
+    pub fn vartype(&self) -> u16 {
+        unsafe { self.0.Anonymous.Anonymous.vt }
+    }
+
+    /// Returns `true` if the value is empty, as the results of methods without one are.
+    pub fn is_empty(&self) -> bool {
+        self.vartype() == VT_EMPTY.0 as u16
+    }
+
+    /// Returns the value as an `i32`.
+    pub fn i32(&self) -> Result<i32> {
+        unsafe { Ok(self.convert(VT_I4)?.value().lVal) }
+    }
+
+    /// Returns the value as an `f64`.
+    pub fn f64(&self) -> Result<f64> {
+        unsafe { Ok(self.convert(VT_R8)?.value().dblVal) }
+    }
+
+    /// Returns the value as a `bool`.
+    pub fn bool(&self) -> Result<bool> {
+        unsafe { Ok(self.convert(VT_BOOL)?.value().boolVal != 0) }
+    }
+
+    /// Returns the value as a `String`.
+    pub fn string(&self) -> Result<String> {
+        let value = self.convert(VT_BSTR)?;
+
+        unsafe {
+            // The string is borrowed from the converted value, which releases it.
+            let string = &*(&value.value().bstrVal as *const _ as *const BSTR);
+            Ok(String::from_utf16_lossy(string.as_wide()))
+        }
+    }
+
+    /// Returns the value as an interface, or `None` if it is null.
+    pub fn unknown(&self) -> Result<Option<IUnknown>> {
+        let value = self.convert(VT_UNKNOWN)?;
+
+        unsafe {
+            // The interface is borrowed from the converted value, which releases it.
+            let unknown = &*(&value.value().punkVal as *const RawPtr as *const Option<IUnknown>);
+            Ok(unknown.clone())
+        }
+    }
+
+    /// Returns the value as an automation object, failing with `E_POINTER` if it is null, as
+    /// `Nothing` is.
+    pub fn dispatch(&self) -> Result<Dispatch> {
+        let value = self.convert(VT_DISPATCH)?;
+
+        unsafe {
+            // The interface is borrowed from the converted value, which releases it.
+            let dispatch = &*(&value.value().pdispVal as *const RawPtr as *const Option<IDispatch>);
+
+            match dispatch {
+                Some(dispatch) => Ok(Dispatch(dispatch.clone())),
+                None => Err(Error::new(E_POINTER, "The value is null")),
+            }
+        }
+    }
+
+    /// Returns a copy of the value, failing if the value can't be copied, as when there is too
+    /// little memory to copy a string or array.
+    pub fn try_clone(&self) -> Result<Self> {
+        let mut value = Self::new();
+        unsafe { VariantCopy(&mut value.0, &self.0)? };
+        Ok(value)
+    }
+
+    /// Returns a pointer to the `VARIANT` for types without an accessor.
+    pub fn as_raw(&self) -> *const std::ffi::c_void {
+        &self.0 as *const VARIANT as _
+    }
+
+    /// Returns a pointer to the `VARIANT` for writing a value. Any value the pointer is written
+    /// through is cleared when the `Variant` is dropped.
+    pub fn as_mut_raw(&mut self) -> *mut std::ffi::c_void {
+        &mut self.0 as *mut VARIANT as _
+    }
+
+    fn convert(&self, vartype: VARENUM) -> Result<Self> {
+        let mut value = Self::new();
+        unsafe { VariantChangeType(&mut value.0, &self.0, 0, vartype.0 as u16)? };
+        Ok(value)
+    }
+
+    unsafe fn value(&self) -> &VARIANT_0_0_0_abi {
+        &self.0.Anonymous.Anonymous.Anonymous
+    }
+
+    fn with(vartype: VARENUM, init: impl FnOnce(&mut VARIANT_0_0_0_abi)) -> Self {
+        let mut value = VARIANT::default();
+
+        unsafe {
+            value.Anonymous.Anonymous.vt = vartype.0 as u16;
+            init(&mut value.Anonymous.Anonymous.Anonymous);
+        }
+
+        Self(value)
+    }
+}
+
+impl Default for Variant {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Variant {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = VariantClear(&mut self.0);
+        }
+    }
+}
+
+impl std::fmt::Debug for Variant {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = fmt.debug_struct("Variant");
+        debug.field("vartype", &self.vartype());
+
+        // Objects aren't converted, as that would call their default property.
+        if !matches!(VARENUM(self.vartype().into()), VT_DISPATCH | VT_UNKNOWN) {
+            if let Ok(value) = self.string() {
+                debug.field("value", &value);
+            }
+        }
+
+        debug.finish()
+    }
+}
+
+impl From<i32> for Variant {
+    fn from(value: i32) -> Self {
+        Self::with(VT_I4, |variant| variant.lVal = value)
+    }
+}
+
+impl From<f64> for Variant {
+    fn from(value: f64) -> Self {
+        Self::with(VT_R8, |variant| variant.dblVal = value)
+    }
+}
+
+impl From<bool> for Variant {
+    fn from(value: bool) -> Self {
+        // A `VARIANT_BOOL` is true when all of its bits are set.
+        Self::with(VT_BOOL, |variant| {
+            variant.boolVal = if value { -1 } else { 0 }
+        })
+    }
+}
+
+impl From<&str> for Variant {
+    fn from(value: &str) -> Self {
+        // The variant takes ownership of the string, which is released by `VariantClear`.
+        let value = std::mem::ManuallyDrop::new(BSTR::from(value));
+        Self::with(VT_BSTR, |variant| unsafe {
+            variant.bstrVal = std::mem::transmute_copy(&*value)
+        })
+    }
+}
+
+impl From<String> for Variant {
+    fn from(value: String) -> Self {
+        value.as_str().into()
+    }
+}
+
+impl From<&Dispatch> for Variant {
+    fn from(value: &Dispatch) -> Self {
+        // The variant takes ownership of the reference, which is released by `VariantClear`.
+        let value = std::mem::ManuallyDrop::new(value.0.clone());
+        Self::with(VT_DISPATCH, |variant| unsafe {
+            variant.pdispVal = std::mem::transmute_copy(&*value)
+        })
+    }
+}
+
+impl From<Dispatch> for Variant {
+    fn from(value: Dispatch) -> Self {
+        (&value).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn variant() -> Result<()> {
+        let value = Variant::from(42);
+        assert_eq!(value.vartype(), VT_I4.0 as u16);
+        assert_eq!(value.i32()?, 42);
+        assert_eq!(value.f64()?, 42.0);
+        assert_eq!(value.string()?, "42");
+        assert!(value.unknown().is_err());
+
+        let value = Variant::from("1.5");
+        assert_eq!(value.try_clone()?.vartype(), VT_BSTR.0 as u16);
+        assert_eq!(value.string()?, "1.5");
+
+        assert!(Variant::from(true).bool()?);
+        assert!(!Variant::from(0).bool()?);
+        assert!(Variant::new().is_empty());
+        assert_eq!(Variant::new().string()?, "");
+
+        Ok(())
+    }
+}
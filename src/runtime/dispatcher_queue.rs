@@ -0,0 +1,223 @@
+use crate::*;
+use bindings::Windows::{
+    System::{DispatcherQueue, DispatcherQueueController, DispatcherQueueHandler},
+    Win32::{
+        Foundation::{E_ILLEGAL_METHOD_CALL, E_POINTER, RPC_E_WRONG_THREAD},
+        System::WinRT::{
+            CreateDispatcherQueueController, DispatcherQueueOptions, DQTAT_COM_STA,
+            DQTYPE_THREAD_CURRENT, DQTYPE_THREAD_DEDICATED,
+        },
+    },
+};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Wake, Waker};
+
+/// Runs futures on the thread of a `DispatcherQueue`, such as the UI thread of a XAML or
+/// Composition app, so that they may use the thread's objects across `await` points.
+///
+/// Each time a future is woken, such as by the completion of a WinRT async operation on a
+/// background thread, it is polled again from a callback enqueued on the queue. The queue's
+/// thread must therefore be pumping messages for the futures to make progress.
+///
+/// ```ignore
+/// let dispatcher = windows::Dispatcher::current()?.unwrap();
+///
+/// dispatcher.spawn_local(async move {
+///     // The text is read on a background thread, but the text block is updated on this one.
+///     if let Ok(text) = FileIO::ReadTextAsync(file).unwrap().await {
+///         let _ = text_block.SetText(text);
+///     }
+/// })?;
+/// ```
+#[derive(Clone)]
+pub struct Dispatcher {
+    queue: DispatcherQueue,
+}
+
+impl Dispatcher {
+    /// Returns the dispatcher for the calling thread's `DispatcherQueue`, or `None` if the
+    /// thread doesn't have one.
+    pub fn current() -> Result<Option<Self>> {
+        match DispatcherQueue::GetForCurrentThread() {
+            Ok(queue) => Ok(Some(Self { queue })),
+            Err(error) if error.code() == E_POINTER => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Creates a dispatcher for a `DispatcherQueue`, such as the one returned by a XAML
+    /// element's `DispatcherQueue` property.
+    pub fn new<T: Interface>(queue: &T) -> Result<Self> {
+        Ok(Self {
+            queue: queue.cast()?,
+        })
+    }
+
+    /// Returns the dispatcher's `DispatcherQueue`.
+    pub fn queue<T: Interface>(&self) -> Result<T> {
+        self.queue.cast()
+    }
+
+    /// Returns `true` if the calling thread is the dispatcher's thread.
+    pub fn has_thread_access(&self) -> Result<bool> {
+        self.queue.HasThreadAccess()
+    }
+
+    /// Runs the future on the dispatcher's thread. This may be called from any thread.
+    ///
+    /// The future is first polled from a callback enqueued on the queue rather than before
+    /// returning, and fails to be spawned if the queue has been shut down.
+    pub fn spawn<F: Future<Output = ()> + Send + 'static>(&self, future: F) -> Result<()> {
+        Task::spawn(&self.queue, future)
+    }
+
+    /// Runs a future that isn't `Send`, such as one holding UI objects that aren't agile, on the
+    /// dispatcher's thread. This must be called from the dispatcher's thread.
+    pub fn spawn_local<F: Future<Output = ()> + 'static>(&self, future: F) -> Result<()> {
+        if !self.has_thread_access()? {
+            return Err(Error::new(
+                RPC_E_WRONG_THREAD,
+                "Local futures must be spawned from the dispatcher's thread",
+            ));
+        }
+
+        Task::spawn(&self.queue, future)
+    }
+}
+
+/// Runs the future on the thread of the `DispatcherQueue`, as with [`Dispatcher::spawn`].
+pub fn spawn_on<T: Interface, F: Future<Output = ()> + Send + 'static>(
+    queue: &T,
+    future: F,
+) -> Result<()> {
+    Dispatcher::new(queue)?.spawn(future)
+}
+
+/// Owns a `DispatcherQueueController`, creating a `DispatcherQueue` for the calling thread or a
+/// new thread, such as for a desktop app hosting XAML islands or Composition visuals.
+///
+/// The queue is shut down when the thread is dropped, without waiting for the callbacks that
+/// are still queued.
+pub struct DispatcherThread {
+    controller: DispatcherQueueController,
+}
+
+impl DispatcherThread {
+    /// Creates a `DispatcherQueue` for the calling thread, which must run a message loop for the
+    /// queue's callbacks to be called. The thread is initialized as a COM STA if it hasn't
+    /// already been initialized.
+    pub fn current() -> Result<Self> {
+        Self::create(DispatcherQueueOptions {
+            dwSize: std::mem::size_of::<DispatcherQueueOptions>() as _,
+            threadType: DQTYPE_THREAD_CURRENT,
+            apartmentType: DQTAT_COM_STA,
+        })
+    }
+
+    /// Creates a `DispatcherQueue` running on a new COM STA thread that pumps messages until the
+    /// queue is shut down.
+    pub fn dedicated() -> Result<Self> {
+        Self::create(DispatcherQueueOptions {
+            dwSize: std::mem::size_of::<DispatcherQueueOptions>() as _,
+            threadType: DQTYPE_THREAD_DEDICATED,
+            apartmentType: DQTAT_COM_STA,
+        })
+    }
+
+    fn create(options: DispatcherQueueOptions) -> Result<Self> {
+        let controller = unsafe { CreateDispatcherQueueController(options)? };
+        Ok(Self { controller })
+    }
+
+    /// Returns the dispatcher for the thread's `DispatcherQueue`.
+    pub fn dispatcher(&self) -> Result<Dispatcher> {
+        Ok(Dispatcher {
+            queue: self.controller.DispatcherQueue()?,
+        })
+    }
+
+    /// Returns the thread's `DispatcherQueueController`.
+    pub fn controller<T: Interface>(&self) -> Result<T> {
+        self.controller.cast()
+    }
+}
+
+impl Drop for DispatcherThread {
+    fn drop(&mut self) {
+        let _ = self.controller.ShutdownQueueAsync();
+    }
+}
+
+struct Task {
+    queue: DispatcherQueue,
+    future: Mutex<Option<Pin<Box<dyn Future<Output = ()>>>>>,
+}
+
+// SAFETY: the future is only polled or dropped on the queue's thread, while the queue itself is
+// agile, so the task may be shared with the threads waking it.
+unsafe impl Send for Task {}
+unsafe impl Sync for Task {}
+
+impl Task {
+    fn spawn<F: Future<Output = ()> + 'static>(queue: &DispatcherQueue, future: F) -> Result<()> {
+        let task = Arc::new(Self {
+            queue: queue.clone(),
+            future: Mutex::new(Some(Box::pin(future))),
+        });
+
+        if task.schedule()? {
+            Ok(())
+        } else {
+            Err(Error::new(
+                E_ILLEGAL_METHOD_CALL,
+                "The dispatcher queue has been shut down",
+            ))
+        }
+    }
+
+    fn schedule(self: &Arc<Self>) -> Result<bool> {
+        let task = self.clone();
+
+        self.queue.TryEnqueue(DispatcherQueueHandler::new(move || {
+            task.poll();
+            Ok(())
+        }))
+    }
+
+    fn poll(self: &Arc<Self>) {
+        let mut future = self.future.lock().unwrap();
+
+        if let Some(pending) = future.as_mut() {
+            let waker = Waker::from(self.clone());
+
+            // Waking only enqueues another poll, so a future waking itself can't deadlock here.
+            if pending
+                .as_mut()
+                .poll(&mut Context::from_waker(&waker))
+                .is_ready()
+            {
+                *future = None;
+            }
+        }
+    }
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        let _ = self.schedule();
+    }
+}
+
+impl Drop for Task {
+    fn drop(&mut self) {
+        // A future left pending when the queue shuts down may be released on another thread,
+        // where dropping it could release objects that belong to the queue's thread.
+        if let Some(future) = self.future.get_mut().unwrap().take() {
+            if !self.queue.HasThreadAccess().unwrap_or(false) {
+                std::mem::forget(future);
+            }
+        }
+    }
+}
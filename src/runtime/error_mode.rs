@@ -0,0 +1,174 @@
+use crate::*;
+use bindings::Windows::Win32::System::Diagnostics::Debug::{
+    GetErrorMode, GetThreadErrorMode, SetErrorMode, SetThreadErrorMode, SEM_FAILCRITICALERRORS,
+    SEM_NOOPENFILEERRORBOX, THREAD_ERROR_MODE,
+};
+use std::marker::PhantomData;
+use std::sync::Mutex;
+
+// The error modes that stop the system from displaying critical-error and missing-file dialogs,
+// such as when probing an empty removable drive or loading a library with missing dependencies.
+const SUPPRESS_DIALOGS: u32 = SEM_FAILCRITICALERRORS.0 | SEM_NOOPENFILEERRORBOX.0;
+
+/// Sets the calling thread's error mode until dropped, at which point the previous mode is
+/// restored.
+///
+/// Since the error mode belongs to the thread, the guard is neither `Send` nor `Sync` and other
+/// threads are unaffected.
+///
+/// ```ignore
+/// let _guard = windows::ThreadErrorMode::suppress_dialogs()?;
+/// ```
+#[must_use]
+pub struct ThreadErrorMode {
+    previous: THREAD_ERROR_MODE,
+    phantom: PhantomData<*const ()>,
+}
+
+impl ThreadErrorMode {
+    /// Sets the calling thread's error mode to `mode`, a combination of the `SEM_*` flags.
+    pub fn set(mode: u32) -> Result<Self> {
+        let mut previous = THREAD_ERROR_MODE::default();
+
+        unsafe {
+            SetThreadErrorMode(THREAD_ERROR_MODE(mode), &mut previous).ok()?;
+        }
+
+        Ok(Self {
+            previous,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Stops the system from displaying critical-error and missing-file dialogs for the calling
+    /// thread, in addition to its current error mode.
+    pub fn suppress_dialogs() -> Result<Self> {
+        let current = unsafe { GetThreadErrorMode() };
+        Self::set(current | SUPPRESS_DIALOGS)
+    }
+
+    /// Returns the error mode that is restored when the guard is dropped.
+    pub fn previous(&self) -> u32 {
+        self.previous.0
+    }
+}
+
+impl Drop for ThreadErrorMode {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = SetThreadErrorMode(self.previous, std::ptr::null_mut());
+        }
+    }
+}
+
+/// Stops the system from displaying critical-error and missing-file dialogs for the whole
+/// process until dropped.
+///
+/// The process error mode is shared by all threads so the guards are counted: the first guard
+/// adds the flags and the last guard to be dropped restores the process's previous error mode,
+/// regardless of the order in which guards on different threads are dropped. Prefer
+/// [`ThreadErrorMode`] unless the dialogs may be raised by threads that you do not control.
+#[must_use]
+pub struct ProcessErrorMode(());
+
+struct ProcessState {
+    count: usize,
+    previous: u32,
+}
+
+fn process_state() -> &'static Mutex<ProcessState> {
+    use std::{
+        mem::MaybeUninit,
+        ptr::{addr_of, addr_of_mut},
+        sync::Once,
+    };
+    static ONCE: Once = Once::new();
+    static mut VALUE: MaybeUninit<Mutex<ProcessState>> = MaybeUninit::uninit();
+
+    ONCE.call_once(|| {
+        // This is safe because `Once` provides thread-safe one-time initialization
+        unsafe {
+            addr_of_mut!(VALUE).write(MaybeUninit::new(Mutex::new(ProcessState {
+                count: 0,
+                previous: 0,
+            })))
+        }
+    });
+
+    // This is safe because `call_once` has already been called. Going through a raw pointer
+    // avoids forming a reference to the mutable static itself.
+    unsafe { &*addr_of!(VALUE).cast::<Mutex<ProcessState>>() }
+}
+
+impl ProcessErrorMode {
+    /// Stops the system from displaying critical-error and missing-file dialogs for the process,
+    /// in addition to its current error mode.
+    pub fn suppress_dialogs() -> Self {
+        let mut state = process_state()
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        if state.count == 0 {
+            unsafe {
+                state.previous = GetErrorMode();
+                SetErrorMode(THREAD_ERROR_MODE(state.previous | SUPPRESS_DIALOGS));
+            }
+        }
+
+        state.count += 1;
+        Self(())
+    }
+}
+
+impl Drop for ProcessErrorMode {
+    fn drop(&mut self) {
+        let mut state = process_state()
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+        state.count -= 1;
+
+        if state.count == 0 {
+            unsafe {
+                SetErrorMode(THREAD_ERROR_MODE(state.previous));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn thread() -> Result<()> {
+        let original = unsafe { GetThreadErrorMode() };
+
+        {
+            let outer = ThreadErrorMode::suppress_dialogs()?;
+            assert_eq!(outer.previous(), original);
+            assert_eq!(unsafe { GetThreadErrorMode() }, original | SUPPRESS_DIALOGS);
+
+            let inner = ThreadErrorMode::set(SEM_FAILCRITICALERRORS.0)?;
+            assert_eq!(inner.previous(), original | SUPPRESS_DIALOGS);
+            assert_eq!(unsafe { GetThreadErrorMode() }, SEM_FAILCRITICALERRORS.0);
+        }
+
+        assert_eq!(unsafe { GetThreadErrorMode() }, original);
+        Ok(())
+    }
+
+    #[test]
+    fn process() {
+        let original = unsafe { GetErrorMode() };
+        let first = ProcessErrorMode::suppress_dialogs();
+        let second = std::thread::spawn(ProcessErrorMode::suppress_dialogs)
+            .join()
+            .unwrap();
+
+        // The guards may be dropped in any order.
+        drop(first);
+        assert_eq!(unsafe { GetErrorMode() }, original | SUPPRESS_DIALOGS);
+        drop(second);
+        assert_eq!(unsafe { GetErrorMode() }, original);
+    }
+}
@@ -0,0 +1,153 @@
+use crate::*;
+use bindings::Windows::Win32::Foundation::{E_POINTER, RPC_E_DISCONNECTED};
+use std::sync::Mutex;
+
+// Errors returned by handlers whose process or script context has gone away.
+const RPC_S_SERVER_UNAVAILABLE: HRESULT = HRESULT(0x8007_06BA);
+const JSCRIPT_E_CANTEXECUTE: HRESULT = HRESULT(0x8902_0001);
+
+/// The handlers of an event raised by an object implemented with `#[implement]`, such as the
+/// `PropertyChanged` event of a view model, which the `add_*` and `remove_*` methods of the
+/// event forward to.
+///
+/// ```ignore
+/// #[implement(Windows::Foundation::Collections::IObservableMap<HSTRING, i32>)]
+/// struct Scores {
+///     changed: windows::Event<MapChangedEventHandler<HSTRING, i32>>,
+/// }
+///
+/// impl Scores {
+///     fn MapChanged(&self, handler: &Option<MapChangedEventHandler<HSTRING, i32>>) -> windows::Result<EventRegistrationToken> {
+///         Ok(EventRegistrationToken { Value: self.changed.add(handler)? })
+///     }
+///
+///     fn RemoveMapChanged(&self, token: &EventRegistrationToken) -> windows::Result<()> {
+///         self.changed.remove(token.Value);
+///         Ok(())
+///     }
+/// }
+/// ```
+pub struct Event<T: Interface> {
+    handlers: Mutex<Handlers<T>>,
+}
+
+struct Handlers<T> {
+    next_token: i64,
+    entries: Vec<(i64, T)>,
+}
+
+impl<T: Interface + Clone> Event<T> {
+    /// Creates an event without any handlers.
+    pub fn new() -> Self {
+        Self {
+            handlers: Mutex::new(Handlers {
+                next_token: 1,
+                entries: Vec::new(),
+            }),
+        }
+    }
+
+    /// Adds a handler, returning the token that removes it.
+    pub fn add(&self, handler: &Option<T>) -> Result<i64> {
+        let handler = handler
+            .as_ref()
+            .ok_or_else(|| Error::new(E_POINTER, "The event handler is null"))?;
+
+        let mut handlers = self.handlers.lock().unwrap();
+        let token = handlers.next_token;
+        handlers.next_token += 1;
+        handlers.entries.push((token, handler.clone()));
+        Ok(token)
+    }
+
+    /// Removes the handler added with the token, if it hasn't already been removed.
+    pub fn remove(&self, token: i64) {
+        self.handlers
+            .lock()
+            .unwrap()
+            .entries
+            .retain(|(entry, _)| *entry != token);
+    }
+
+    /// Removes every handler.
+    pub fn clear(&self) {
+        self.handlers.lock().unwrap().entries.clear();
+    }
+
+    /// Returns whether the event has no handlers.
+    pub fn is_empty(&self) -> bool {
+        self.handlers.lock().unwrap().entries.is_empty()
+    }
+
+    /// Raises the event by calling `invoke` with each handler, in the order they were added.
+    ///
+    /// The handlers are called without holding any lock so that they may add or remove handlers
+    /// of their own. Handlers failing because their process or script context has gone away are
+    /// removed, while any other error stops the event and is returned.
+    pub fn call<F: FnMut(&T) -> Result<()>>(&self, mut invoke: F) -> Result<()> {
+        let entries = self.handlers.lock().unwrap().entries.clone();
+
+        for (token, handler) in &entries {
+            if let Err(error) = invoke(handler) {
+                match error.code() {
+                    RPC_E_DISCONNECTED | RPC_S_SERVER_UNAVAILABLE | JSCRIPT_E_CANTEXECUTE => {
+                        self.remove(*token)
+                    }
+                    _ => return Err(error),
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Interface + Clone> Default for Event<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bindings::Windows::Foundation::Collections::IVector;
+
+    #[test]
+    fn event() -> Result<()> {
+        let event = Event::<IVector<i32>>::new();
+        assert!(event.is_empty());
+        assert_eq!(event.add(&None).unwrap_err().code(), E_POINTER);
+
+        let first: IVector<i32> = vector::<i32>(vec![]).cast()?;
+        let second: IVector<i32> = vector::<i32>(vec![]).cast()?;
+        let token = event.add(&Some(first.clone()))?;
+        event.add(&Some(second.clone()))?;
+
+        event.call(|handler| handler.Append(1))?;
+        assert_eq!((first.Size()?, second.Size()?), (1, 1));
+
+        // Disconnected handlers are removed while other errors are returned.
+        event.call(|handler| {
+            if handler == &first {
+                Err(Error::fast_error(RPC_E_DISCONNECTED))
+            } else {
+                handler.Append(2)
+            }
+        })?;
+
+        assert_eq!(
+            event
+                .call(|_| Err(Error::fast_error(E_POINTER)))
+                .unwrap_err()
+                .code(),
+            E_POINTER
+        );
+
+        event.remove(token);
+        event.call(|handler| handler.Append(3))?;
+        assert_eq!((first.Size()?, second.Size()?), (1, 3));
+
+        Ok(())
+    }
+}
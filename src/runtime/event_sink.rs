@@ -0,0 +1,369 @@
+#![allow(non_snake_case)]
+
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::{
+        BSTR, DISP_E_BADPARAMCOUNT, DISP_E_TYPEMISMATCH, E_NOINTERFACE, E_NOTIMPL, PWSTR,
+    },
+    System::Com::{IConnectionPoint, IConnectionPointContainer},
+    System::OleAutomation::{
+        EXCEPINFO_abi, IDispatch, IDispatch_abi, ITypeInfo, VARIANT_0_0_0_abi, VARIANT_abi,
+        VariantChangeType, DISPPARAMS, VARENUM, VARIANT, VT_BOOL, VT_BSTR, VT_BYREF, VT_I4, VT_R8,
+        VT_UNKNOWN,
+    },
+};
+use std::collections::BTreeMap;
+
+type Handler = Box<dyn Fn(&EventArgs) -> Result<()> + Send + Sync>;
+
+/// Handles the events raised by an automation object through an event dispatch interface, such
+/// as those of the Office applications, that is only described by type information rather than
+/// metadata.
+///
+/// The sink is built by registering a closure for each event of interest by name or dispatch
+/// identifier and then connected to the object raising the events. Events without a handler are
+/// ignored. Since the object may raise events from any thread in the multithreaded apartment, the
+/// handlers must be both `Send` and `Sync`.
+///
+/// ```ignore
+/// // The type information of the `ApplicationEvents` interface, such as from the type library.
+/// let type_info = type_lib.GetTypeInfoOfGuid(&APPLICATION_EVENTS)?;
+///
+/// let _connection = windows::EventSink::new(&type_info)?
+///     .on("Quit", |_| {
+///         println!("quit");
+///         Ok(())
+///     })?
+///     .connect(&application)?;
+/// ```
+pub struct EventSink {
+    type_info: ITypeInfo,
+    iid: Guid,
+    handlers: BTreeMap<i32, Handler>,
+}
+
+impl EventSink {
+    /// Creates a sink for the event dispatch interface described by `type_info`, which must be
+    /// an `ITypeInfo` interface.
+    pub fn new<T: Interface>(type_info: &T) -> Result<Self> {
+        let type_info: ITypeInfo = type_info.cast()?;
+
+        let iid = unsafe {
+            let mut attributes = std::ptr::null_mut();
+            type_info.GetTypeAttr(&mut attributes)?;
+            let iid = (*attributes).guid;
+            type_info.ReleaseTypeAttr(attributes);
+            iid
+        };
+
+        Ok(Self {
+            type_info,
+            iid,
+            handlers: BTreeMap::new(),
+        })
+    }
+
+    /// Returns the IID of the event dispatch interface.
+    pub fn iid(&self) -> Guid {
+        self.iid
+    }
+
+    /// Registers the handler for the event with the given name, failing if the event dispatch
+    /// interface has no such event.
+    pub fn on<F: Fn(&EventArgs) -> Result<()> + Send + Sync + 'static>(
+        self,
+        name: &str,
+        handler: F,
+    ) -> Result<Self> {
+        let mut name: Vec<u16> = name.encode_utf16().chain(std::iter::once(0)).collect();
+        let mut name = PWSTR(name.as_mut_ptr());
+        let id = unsafe { self.type_info.GetIDsOfNames(&mut name, 1)? };
+        Ok(self.on_id(id, handler))
+    }
+
+    /// Registers the handler for the event with the given dispatch identifier.
+    pub fn on_id<F: Fn(&EventArgs) -> Result<()> + Send + Sync + 'static>(
+        mut self,
+        id: i32,
+        handler: F,
+    ) -> Self {
+        self.handlers.insert(id, Box::new(handler));
+        self
+    }
+
+    /// Connects the sink to the connection point of `source` for the event dispatch interface,
+    /// until the returned connection is dropped.
+    pub fn connect<T: Interface>(self, source: &T) -> Result<EventConnection> {
+        let iid = self.iid;
+        EventConnection::with_iid(source, &iid, &self.into_unknown())
+    }
+
+    /// Returns the sink as a COM object implementing `IDispatch` and the event dispatch
+    /// interface, for connecting to sources that do not use connection points.
+    pub fn into_unknown(self) -> IUnknown {
+        let sink = Box::new(Sink {
+            vtable: &Sink::VTABLE,
+            count: RefCount::new(1),
+            iid: self.iid,
+            handlers: self.handlers,
+        });
+
+        unsafe { std::mem::transmute(sink) }
+    }
+}
+
+/// Keeps a sink connected to the connection point of the object raising its events until
+/// dropped.
+///
+/// Any COM object implementing the source's outgoing interface may be connected, such as one
+/// built with `#[implement]` or an [`EventSink`], and the cookie returned by `Advise` is kept
+/// so that the sink is disconnected with `Unadvise` when the connection is dropped.
+///
+/// ```ignore
+/// let sink: IShellWindowsEvents = ShellEvents().into();
+/// let _connection = windows::EventConnection::new(&shell_windows, &sink)?;
+/// ```
+pub struct EventConnection {
+    point: IConnectionPoint,
+    cookie: u32,
+}
+
+impl EventConnection {
+    /// Connects `sink` to the connection point of `source` for the sink's interface, which
+    /// `source` must implement `IConnectionPointContainer` to provide.
+    pub fn new<T: Interface, S: Interface>(source: &T, sink: &S) -> Result<Self> {
+        Self::with_iid(source, &S::IID, sink)
+    }
+
+    /// Connects `sink` to the connection point of `source` for the outgoing interface with the
+    /// given IID, for sinks whose interface isn't described by metadata, such as those
+    /// implementing an event dispatch interface with `IDispatch`.
+    pub fn with_iid<T: Interface, S: Interface>(source: &T, iid: &Guid, sink: &S) -> Result<Self> {
+        let container: IConnectionPointContainer = source.cast()?;
+        let sink: IUnknown = sink.cast()?;
+
+        unsafe {
+            let point = container.FindConnectionPoint(iid)?;
+            let cookie = point.Advise(&sink)?;
+            Ok(Self { point, cookie })
+        }
+    }
+
+    /// Returns the cookie identifying the connection to the connection point.
+    pub fn cookie(&self) -> u32 {
+        self.cookie
+    }
+}
+
+impl Drop for EventConnection {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = self.point.Unadvise(self.cookie);
+        }
+    }
+}
+
+/// The arguments of an event, in the order in which they are declared by the event dispatch
+/// interface.
+///
+/// Arguments are converted to the requested type where possible, in the same way as by
+/// `VariantChangeType`.
+pub struct EventArgs<'a> {
+    // Dispatch arguments are stored in reverse order.
+    args: &'a [VARIANT],
+}
+
+impl<'a> EventArgs<'a> {
+    /// Returns the number of arguments.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Returns `true` if the event has no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Returns the argument as an `i32`.
+    pub fn i32(&self, index: usize) -> Result<i32> {
+        unsafe { Ok(self.convert(index, VT_I4)?.lVal) }
+    }
+
+    /// Returns the argument as an `f64`.
+    pub fn f64(&self, index: usize) -> Result<f64> {
+        unsafe { Ok(self.convert(index, VT_R8)?.dblVal) }
+    }
+
+    /// Returns the argument as a `bool`.
+    pub fn bool(&self, index: usize) -> Result<bool> {
+        unsafe { Ok(self.convert(index, VT_BOOL)?.boolVal != 0) }
+    }
+
+    /// Returns the argument as a `String`.
+    pub fn string(&self, index: usize) -> Result<String> {
+        unsafe {
+            let value = self.convert(index, VT_BSTR)?;
+
+            // The converted value owns the string, which is released by the `BSTR`.
+            let value: BSTR = std::mem::transmute(value.bstrVal);
+            Ok(String::from_utf16_lossy(value.as_wide()))
+        }
+    }
+
+    /// Returns the argument as an interface, or `None` if it is null.
+    pub fn unknown(&self, index: usize) -> Result<Option<IUnknown>> {
+        unsafe {
+            let value = self.convert(index, VT_UNKNOWN)?;
+
+            // The converted value owns the reference, which is released by the `IUnknown`.
+            Ok(std::mem::transmute(value.punkVal))
+        }
+    }
+
+    /// Sets the value of a `bool` argument passed by reference, such as the `Cancel` argument of
+    /// events that may be cancelled.
+    pub fn set_bool(&self, index: usize, value: bool) -> Result<()> {
+        let arg = self.arg(index)?;
+
+        unsafe {
+            if i32::from(arg.Anonymous.Anonymous.vt) != VT_BYREF.0 | VT_BOOL.0 {
+                return Err(Error::fast_error(DISP_E_TYPEMISMATCH));
+            }
+
+            // A `VARIANT_BOOL` is true when all of its bits are set.
+            *arg.Anonymous.Anonymous.Anonymous.pboolVal = if value { -1 } else { 0 };
+        }
+
+        Ok(())
+    }
+
+    /// Returns a pointer to the argument's `VARIANT` for types without an accessor.
+    pub fn as_raw(&self, index: usize) -> Result<*const std::ffi::c_void> {
+        self.arg(index)
+            .map(|arg| arg as *const VARIANT as *const std::ffi::c_void)
+    }
+
+    fn arg(&self, index: usize) -> Result<&VARIANT> {
+        if index < self.args.len() {
+            Ok(&self.args[self.args.len() - 1 - index])
+        } else {
+            Err(Error::fast_error(DISP_E_BADPARAMCOUNT))
+        }
+    }
+
+    // The caller takes ownership of any string or interface held by the converted value.
+    unsafe fn convert(&self, index: usize, vt: VARENUM) -> Result<VARIANT_0_0_0_abi> {
+        let mut value = VARIANT::default();
+        VariantChangeType(&mut value, self.arg(index)?, 0, vt.0 as u16)?;
+        Ok(value.Anonymous.Anonymous.Anonymous)
+    }
+}
+
+#[repr(C)]
+struct Sink {
+    vtable: *const IDispatch_abi,
+    count: RefCount,
+    iid: Guid,
+    handlers: BTreeMap<i32, Handler>,
+}
+
+impl Sink {
+    const VTABLE: IDispatch_abi = IDispatch_abi(
+        Self::QueryInterface,
+        Self::AddRef,
+        Self::Release,
+        Self::GetTypeInfoCount,
+        Self::GetTypeInfo,
+        Self::GetIDsOfNames,
+        Self::Invoke,
+    );
+
+    unsafe fn from_ptr<'a>(this: RawPtr) -> &'a Self {
+        &*(this as *const Self)
+    }
+
+    unsafe extern "system" fn QueryInterface(
+        this: RawPtr,
+        iid: &Guid,
+        interface: *mut RawPtr,
+    ) -> HRESULT {
+        let sink = Self::from_ptr(this);
+
+        if iid == &IUnknown::IID || iid == &IDispatch::IID || iid == &sink.iid {
+            sink.count.add_ref();
+            *interface = this;
+            HRESULT(0)
+        } else {
+            *interface = std::ptr::null_mut();
+            E_NOINTERFACE
+        }
+    }
+
+    unsafe extern "system" fn AddRef(this: RawPtr) -> u32 {
+        Self::from_ptr(this).count.add_ref()
+    }
+
+    unsafe extern "system" fn Release(this: RawPtr) -> u32 {
+        let remaining = Self::from_ptr(this).count.release();
+
+        if remaining == 0 {
+            drop(Box::from_raw(this as *mut Self));
+        }
+
+        remaining
+    }
+
+    unsafe extern "system" fn GetTypeInfoCount(_: RawPtr, count: *mut u32) -> HRESULT {
+        *count = 0;
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn GetTypeInfo(
+        _: RawPtr,
+        _: u32,
+        _: u32,
+        type_info: *mut RawPtr,
+    ) -> HRESULT {
+        *type_info = std::ptr::null_mut();
+        E_NOTIMPL
+    }
+
+    unsafe extern "system" fn GetIDsOfNames(
+        _: RawPtr,
+        _: *const Guid,
+        _: *mut PWSTR,
+        _: u32,
+        _: u32,
+        _: *mut i32,
+    ) -> HRESULT {
+        E_NOTIMPL
+    }
+
+    unsafe extern "system" fn Invoke(
+        this: RawPtr,
+        id: i32,
+        _: *const Guid,
+        _: u32,
+        _: u16,
+        params: *mut DISPPARAMS,
+        _: *mut VARIANT_abi,
+        _: *mut EXCEPINFO_abi,
+        _: *mut u32,
+    ) -> HRESULT {
+        let handler = match Self::from_ptr(this).handlers.get(&id) {
+            Some(handler) => handler,
+            None => return HRESULT(0),
+        };
+
+        let args = if params.is_null() || (*params).cArgs == 0 {
+            &[]
+        } else {
+            std::slice::from_raw_parts((*params).rgvarg, (*params).cArgs as usize)
+        };
+
+        match handler(&EventArgs { args }) {
+            Ok(()) => HRESULT(0),
+            Err(error) => error.code(),
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use crate::*;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+/// A stream of the values sent by the handler of a WinRT event, such as the `Added` event of a
+/// `DeviceWatcher` or the `VectorChanged` event of an `IObservableVector<T>`, which removes the
+/// handler when dropped.
+///
+/// The stream is created with a pair of closures: the first adds the event's handler, given the
+/// [`EventSender`] that the handler sends its values through, and returns the registration token,
+/// while the second removes the handler with that token once the stream is dropped. Values are
+/// buffered until the stream is polled for them, and the stream ends once the source releases the
+/// handler, such as when the source is destroyed.
+///
+/// The stream is `Send`, so that it may be consumed by a task on a multithreaded executor, which
+/// requires the closure removing the handler to be `Send`. Agile sources, as most classes raising
+/// events are, may be moved into the closure as they are, while other sources may be kept in an
+/// [`ApartmentChecked`] and removed with `try_get`, so that the handler is removed if the stream is
+/// dropped on the source's thread.
+///
+/// With the `futures` feature the stream implements `futures::Stream`.
+///
+/// ```ignore
+/// let watcher = DeviceInformation::CreateWatcher()?;
+/// let source = watcher.clone();
+///
+/// let mut added = windows::EventStream::new(
+///     |sender| watcher.Added(TypedEventHandler::new(move |_, info: &Option<DeviceInformation>| {
+///         sender.send(info.clone())
+///     })),
+///     move |token| source.RemoveAdded(token),
+/// )?;
+///
+/// watcher.Start()?;
+///
+/// while let Some(Some(info)) = added.next().await {
+///     println!("{}", info.Name()?);
+/// }
+/// ```
+pub struct EventStream<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+    remove: Option<Box<dyn FnOnce() + Send>>,
+}
+
+struct Shared<T> {
+    values: VecDeque<T>,
+    // The number of buffered values after which the oldest value is discarded, if any.
+    capacity: Option<usize>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+impl<T> EventStream<T> {
+    /// Adds the event's handler with `add`, buffering every value it sends until the stream is
+    /// polled for them. The handler is removed by calling `remove` with the token returned by
+    /// `add` when the stream is dropped, and any error it returns is ignored.
+    pub fn new<K, A, R>(add: A, remove: R) -> Result<Self>
+    where
+        A: FnOnce(EventSender<T>) -> Result<K>,
+        R: FnOnce(K) -> Result<()> + Send + 'static,
+        K: Send + 'static,
+    {
+        Self::create(None, add, remove)
+    }
+
+    /// Adds the event's handler as [`EventStream::new`] does, but only buffers the most recent
+    /// `capacity` values, discarding the oldest value once the buffer is full, for events such as
+    /// sensor readings where only recent values matter.
+    pub fn with_capacity<K, A, R>(capacity: usize, add: A, remove: R) -> Result<Self>
+    where
+        A: FnOnce(EventSender<T>) -> Result<K>,
+        R: FnOnce(K) -> Result<()> + Send + 'static,
+        K: Send + 'static,
+    {
+        assert!(
+            capacity > 0,
+            "The capacity of an event stream must not be zero."
+        );
+        Self::create(Some(capacity), add, remove)
+    }
+
+    fn create<K, A, R>(capacity: Option<usize>, add: A, remove: R) -> Result<Self>
+    where
+        A: FnOnce(EventSender<T>) -> Result<K>,
+        R: FnOnce(K) -> Result<()> + Send + 'static,
+        K: Send + 'static,
+    {
+        let shared = Arc::new(Mutex::new(Shared {
+            values: VecDeque::new(),
+            capacity,
+            waker: None,
+            closed: false,
+        }));
+
+        let token = add(EventSender {
+            shared: shared.clone(),
+        })?;
+
+        Ok(Self {
+            shared,
+            remove: Some(Box::new(move || {
+                let _ = remove(token);
+            })),
+        })
+    }
+
+    /// Polls for the next value, returning `None` once the source has released the handler and
+    /// every buffered value has been returned.
+    pub fn poll_next(&mut self, context: &mut Context) -> Poll<Option<T>> {
+        let mut shared = self.shared.lock().unwrap();
+
+        if let Some(value) = shared.values.pop_front() {
+            Poll::Ready(Some(value))
+        } else if shared.closed {
+            Poll::Ready(None)
+        } else {
+            match &mut shared.waker {
+                Some(waker) if waker.will_wake(context.waker()) => {}
+                waker => *waker = Some(context.waker().clone()),
+            }
+
+            Poll::Pending
+        }
+    }
+
+    /// Waits for the next value, returning `None` once the source has released the handler and
+    /// every buffered value has been returned.
+    pub fn next(&mut self) -> impl Future<Output = Option<T>> + '_ {
+        std::future::poll_fn(move |context| self.poll_next(context))
+    }
+}
+
+impl<T> Drop for EventStream<T> {
+    fn drop(&mut self) {
+        // The stream is closed before removing the handler so that values sent by a handler that
+        // could not be removed are discarded rather than buffered indefinitely. The buffered
+        // values are dropped once the lock is released.
+        let _values = {
+            let mut shared = self.shared.lock().unwrap();
+            shared.closed = true;
+            shared.waker = None;
+            std::mem::take(&mut shared.values)
+        };
+
+        if let Some(remove) = self.remove.take() {
+            remove();
+        }
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> futures::Stream for EventStream<T> {
+    type Item = T;
+
+    fn poll_next(self: std::pin::Pin<&mut Self>, context: &mut Context) -> Poll<Option<T>> {
+        self.get_mut().poll_next(context)
+    }
+}
+
+/// Sends the values of an event handler to its [`EventStream`].
+///
+/// The sender is moved into the handler, which may be called from any thread, and the stream
+/// ends once the handler, and so the sender, has been released by the event's source.
+pub struct EventSender<T> {
+    shared: Arc<Mutex<Shared<T>>>,
+}
+
+impl<T> EventSender<T> {
+    /// Buffers the value for the stream, waking the task waiting for it. The value is discarded
+    /// if the stream has been dropped.
+    ///
+    /// This always succeeds so that it may be returned by the handler.
+    pub fn send(&self, value: T) -> Result<()> {
+        let waker = {
+            let mut shared = self.shared.lock().unwrap();
+
+            if shared.closed {
+                return Ok(());
+            }
+
+            if shared.capacity == Some(shared.values.len()) {
+                shared.values.pop_front();
+            }
+
+            shared.values.push_back(value);
+            shared.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Drop for EventSender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut shared = self.shared.lock().unwrap();
+            shared.closed = true;
+            shared.waker.take()
+        };
+
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bindings::Windows::{
+        Foundation::{
+            Collections::{IObservableVector, IVector, VectorChangedEventHandler},
+            EventRegistrationToken,
+        },
+        Win32::Foundation::E_FAIL,
+    };
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::task::Wake;
+
+    #[derive(Default)]
+    struct Flag(AtomicBool);
+
+    impl Wake for Flag {
+        fn wake(self: Arc<Self>) {
+            self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    fn stream(
+        source: &IObservableVector<i32>,
+        capacity: Option<usize>,
+    ) -> Result<EventStream<u32>> {
+        let add = |sender: EventSender<u32>| {
+            source.VectorChanged(VectorChangedEventHandler::new(move |_, args| {
+                sender.send(args.as_ref().unwrap().Index()?)
+            }))
+        };
+
+        let source = ApartmentChecked::new(source.clone());
+        let remove =
+            move |token: EventRegistrationToken| source.try_get()?.RemoveVectorChanged(token);
+
+        match capacity {
+            Some(capacity) => EventStream::with_capacity(capacity, add, remove),
+            None => EventStream::new(add, remove),
+        }
+    }
+
+    #[test]
+    fn event_stream() -> Result<()> {
+        let source: IObservableVector<i32> = observable_vector::<i32>(vec![]).cast()?;
+        let vector: IVector<i32> = source.cast()?;
+        let mut events = stream(&source, None)?;
+
+        let flag = Arc::new(Flag::default());
+        let waker = Waker::from(flag.clone());
+        let mut context = Context::from_waker(&waker);
+
+        assert!(events.poll_next(&mut context).is_pending());
+
+        vector.Append(1)?;
+        vector.Append(2)?;
+        assert!(flag.0.load(Ordering::SeqCst));
+
+        assert_eq!(events.poll_next(&mut context), Poll::Ready(Some(0)));
+        assert_eq!(events.poll_next(&mut context), Poll::Ready(Some(1)));
+        assert!(events.poll_next(&mut context).is_pending());
+
+        // Dropping the stream removes the handler, which releases the sender.
+        let shared = events.shared.clone();
+        drop(events);
+        assert!(shared.lock().unwrap().closed);
+        vector.Append(3)?;
+        assert!(shared.lock().unwrap().values.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove_error() -> Result<()> {
+        let source: IObservableVector<i32> = observable_vector::<i32>(vec![]).cast()?;
+        let vector: IVector<i32> = source.cast()?;
+
+        let events = EventStream::new(
+            |sender: EventSender<u32>| {
+                source.VectorChanged(VectorChangedEventHandler::new(move |_, args| {
+                    sender.send(args.as_ref().unwrap().Index()?)
+                }))
+            },
+            |_: EventRegistrationToken| Err(Error::fast_error(E_FAIL)),
+        )?;
+
+        vector.Append(1)?;
+        let shared = events.shared.clone();
+        drop(events);
+        assert!(shared.lock().unwrap().values.is_empty());
+
+        // The handler is still registered but its values are discarded.
+        vector.Append(2)?;
+        assert!(shared.lock().unwrap().values.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn capacity() -> Result<()> {
+        let source: IObservableVector<i32> = observable_vector::<i32>(vec![]).cast()?;
+        let vector: IVector<i32> = source.cast()?;
+        let mut events = stream(&source, Some(2))?;
+
+        for value in 0..4 {
+            vector.Append(value)?;
+        }
+
+        let waker = Waker::from(Arc::new(Flag::default()));
+        let mut context = Context::from_waker(&waker);
+
+        assert_eq!(events.poll_next(&mut context), Poll::Ready(Some(2)));
+        assert_eq!(events.poll_next(&mut context), Poll::Ready(Some(3)));
+        assert!(events.poll_next(&mut context).is_pending());
+
+        Ok(())
+    }
+}
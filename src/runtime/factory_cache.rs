@@ -1,14 +1,14 @@
 use crate::*;
 use bindings::Windows::Win32::Foundation::CO_E_NOTINITIALIZED;
-use std::marker::PhantomData;
-use std::sync::atomic::{AtomicPtr, Ordering};
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicPtr, Ordering};
 
 type DllGetActivationFactory = extern "system" fn(name: RawPtr, factory: *mut RawPtr) -> HRESULT;
 
 /// Attempts to load and cache the factory interface for the given WinRT class. This is automatically
 // used by the generated bindings and should not generally be used directly.
 pub struct FactoryCache<C, I> {
-    shared: AtomicPtr<std::ffi::c_void>,
+    shared: AtomicPtr<core::ffi::c_void>,
     _c: PhantomData<C>,
     _i: PhantomData<I>,
 }
@@ -16,7 +16,7 @@ pub struct FactoryCache<C, I> {
 impl<C, I> FactoryCache<C, I> {
     pub const fn new() -> Self {
         Self {
-            shared: AtomicPtr::new(::std::ptr::null_mut()),
+            shared: AtomicPtr::new(::core::ptr::null_mut()),
             _c: PhantomData,
             _i: PhantomData,
         }
@@ -31,7 +31,7 @@ impl<C: RuntimeName, I: Interface> FactoryCache<C, I> {
 
             // If a pointer is found, the cache is primed and we're good to go.
             if !ptr.is_null() {
-                return callback(unsafe { std::mem::transmute(&ptr) });
+                return callback(unsafe { core::mem::transmute(&ptr) });
             }
 
             // Otherwise, we load the factory the usual way.
@@ -42,14 +42,18 @@ impl<C: RuntimeName, I: Interface> FactoryCache<C, I> {
                 if self
                     .shared
                     .compare_exchange_weak(
-                        std::ptr::null_mut(),
-                        unsafe { std::mem::transmute_copy(&factory) },
+                        core::ptr::null_mut(),
+                        unsafe { core::mem::transmute_copy(&factory) },
                         Ordering::Relaxed,
                         Ordering::Relaxed,
                     )
                     .is_ok()
                 {
-                    std::mem::forget(factory);
+                    core::mem::forget(factory);
+
+                    // The cache is a static in the generated bindings so its address remains
+                    // valid until `shutdown` releases the factory.
+                    register_factory(&self.shared);
                 }
             } else {
                 // Otherwise, for non-agile factories we simply use the factory
@@ -76,7 +80,7 @@ pub fn factory<C: RuntimeName, I: Interface>() -> Result<I> {
         // If this fails because combase hasn't been loaded yet then load combase
         // automatically so that it "just works" for apartment-agnostic code.
         if code == CO_E_NOTINITIALIZED {
-            let mut _cookie = std::ptr::null_mut();
+            let mut _cookie = core::ptr::null_mut();
 
             // Won't get any delay-load errors here if we got CO_E_NOTINITIALIZED, so quiet the
             // warning from the #[must_use] on the returned Result<>.
@@ -109,15 +113,15 @@ pub fn factory<C: RuntimeName, I: Interface>() -> Result<I> {
             library.push_str(".dll");
 
             if let Ok(function) = delay_load(&library, "DllGetActivationFactory") {
-                let function: DllGetActivationFactory = std::mem::transmute(function);
-                let mut abi = std::ptr::null_mut();
+                let function: DllGetActivationFactory = core::mem::transmute(function);
+                let mut abi = core::ptr::null_mut();
                 let _ = function(name.abi(), &mut abi);
 
                 if abi.is_null() {
                     continue;
                 }
 
-                let factory: IActivationFactory = std::mem::transmute(abi);
+                let factory: IActivationFactory = core::mem::transmute(abi);
                 return factory.cast();
             }
         }
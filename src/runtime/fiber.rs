@@ -0,0 +1,289 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::E_ILLEGAL_METHOD_CALL,
+    System::Threading::{
+        ConvertFiberToThread, ConvertThreadToFiberEx, CreateFiberEx, DeleteFiber, SwitchToFiber,
+    },
+    System::WindowsProgramming::FIBER_FLAG_FLOAT_SWITCH,
+};
+use std::any::Any;
+use std::cell::Cell;
+use std::ffi::c_void;
+use std::marker::PhantomData;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+type Start = Box<dyn FnOnce(&FiberContext)>;
+
+thread_local! {
+    // The fiber running on this thread, or null if the thread has not been converted to a fiber.
+    static CURRENT: Cell<*mut c_void> = Cell::new(std::ptr::null_mut());
+}
+
+/// Converts the calling thread to a fiber until dropped, allowing it to run [`Fiber`]s.
+///
+/// The conversion belongs to the thread so the guard is neither `Send` nor `Sync`. It must be
+/// dropped on the thread's original stack rather than within a fiber, which is guaranteed as long
+/// as the guard is not moved into a fiber's closure.
+///
+/// ```ignore
+/// let _thread = windows::ThreadFiber::convert()?;
+/// let fiber = windows::Fiber::new(|context| {
+///     println!("first");
+///     context.suspend();
+///     println!("second");
+/// })?;
+///
+/// while !fiber.is_finished() {
+///     fiber.resume()?;
+/// }
+/// ```
+#[must_use]
+pub struct ThreadFiber {
+    phantom: PhantomData<*const ()>,
+}
+
+impl ThreadFiber {
+    /// Converts the calling thread to a fiber, failing if the thread is already a fiber.
+    pub fn convert() -> Result<Self> {
+        let fiber =
+            unsafe { ConvertThreadToFiberEx(std::ptr::null_mut(), FIBER_FLAG_FLOAT_SWITCH) };
+
+        if fiber.is_null() {
+            return Err(HRESULT::from_thread().into());
+        }
+
+        CURRENT.with(|current| current.set(fiber));
+
+        Ok(Self {
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl Drop for ThreadFiber {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(std::ptr::null_mut()));
+
+        unsafe {
+            ConvertFiberToThread();
+        }
+    }
+}
+
+struct State {
+    handle: Cell<*mut c_void>,
+    // The fiber that most recently resumed this fiber and that it returns to when suspended.
+    caller: Cell<*mut c_void>,
+    start: Cell<Option<Start>>,
+    running: Cell<bool>,
+    finished: Cell<bool>,
+    panic: Cell<Option<Box<dyn Any + Send>>>,
+}
+
+/// A fiber running a closure on its own stack, which is only switched to when resumed.
+///
+/// The closure runs when the fiber is first resumed and continues until it either calls
+/// [`FiberContext::suspend`], at which point the call to [`Fiber::resume`] returns, or finishes. A
+/// panic within the closure is caught and propagated by the call to `resume`.
+///
+/// A fiber is owned by the thread that creates it so is neither `Send` nor `Sync`. Dropping a fiber
+/// that is suspended before its closure has finished frees its stack without unwinding it, so any
+/// values held by the closure at that point are leaked rather than dropped.
+pub struct Fiber {
+    state: Box<State>,
+    phantom: PhantomData<*const ()>,
+}
+
+impl Fiber {
+    /// Creates a fiber with the default stack size that runs `f` when first resumed.
+    pub fn new<F: FnOnce(&FiberContext) + 'static>(f: F) -> Result<Self> {
+        Self::with_stack_size(0, f)
+    }
+
+    /// Creates a fiber with the given stack size in bytes, or the default stack size if zero.
+    pub fn with_stack_size<F: FnOnce(&FiberContext) + 'static>(
+        stack_size: usize,
+        f: F,
+    ) -> Result<Self> {
+        let state = Box::new(State {
+            handle: Cell::new(std::ptr::null_mut()),
+            caller: Cell::new(std::ptr::null_mut()),
+            start: Cell::new(Some(Box::new(f))),
+            running: Cell::new(false),
+            finished: Cell::new(false),
+            panic: Cell::new(None),
+        });
+
+        let handle = unsafe {
+            CreateFiberEx(
+                0,
+                stack_size,
+                FIBER_FLAG_FLOAT_SWITCH,
+                Some(start),
+                &*state as *const State as *mut c_void,
+            )
+        };
+
+        if handle.is_null() {
+            return Err(HRESULT::from_thread().into());
+        }
+
+        state.handle.set(handle);
+
+        Ok(Self {
+            state,
+            phantom: PhantomData,
+        })
+    }
+
+    /// Switches to the fiber, returning once it suspends itself or finishes.
+    ///
+    /// Fails if the calling thread has not been converted to a fiber with [`ThreadFiber`], if the
+    /// fiber is already running, or if the fiber has finished.
+    pub fn resume(&self) -> Result<()> {
+        let caller = CURRENT.with(|current| current.get());
+
+        if caller.is_null() || self.state.running.get() || self.state.finished.get() {
+            return Err(Error::fast_error(E_ILLEGAL_METHOD_CALL));
+        }
+
+        self.state.caller.set(caller);
+        self.state.running.set(true);
+        CURRENT.with(|current| current.set(self.state.handle.get()));
+
+        unsafe {
+            SwitchToFiber(self.state.handle.get());
+        }
+
+        CURRENT.with(|current| current.set(caller));
+        self.state.running.set(false);
+
+        if let Some(panic) = self.state.panic.take() {
+            resume_unwind(panic);
+        }
+
+        Ok(())
+    }
+
+    /// Returns `true` once the fiber's closure has returned.
+    pub fn is_finished(&self) -> bool {
+        self.state.finished.get()
+    }
+}
+
+impl Drop for Fiber {
+    fn drop(&mut self) {
+        unsafe {
+            DeleteFiber(self.state.handle.get());
+        }
+    }
+}
+
+/// Gives the closure running on a [`Fiber`] access to the fiber that resumed it.
+pub struct FiberContext {
+    state: *const State,
+}
+
+impl FiberContext {
+    /// Switches back to the fiber that resumed this fiber, returning when this fiber is next
+    /// resumed.
+    pub fn suspend(&self) {
+        unsafe {
+            SwitchToFiber((*self.state).caller.get());
+        }
+    }
+}
+
+unsafe extern "system" fn start(parameter: *mut c_void) {
+    let state = &*(parameter as *const State);
+    let context = FiberContext {
+        state: parameter as *const State,
+    };
+
+    if let Some(f) = state.start.take() {
+        // Unwinding must not cross the fiber's entry point so the panic is instead handed back
+        // to the fiber that resumed it.
+        if let Err(panic) = catch_unwind(AssertUnwindSafe(|| f(&context))) {
+            state.panic.set(Some(panic));
+        }
+    }
+
+    state.finished.set(true);
+
+    // Returning from a fiber's entry point would exit the thread, so the fiber instead switches
+    // back for the last time and is never resumed again.
+    SwitchToFiber(state.caller.get());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+
+    #[test]
+    fn resume() -> Result<()> {
+        let steps = Rc::new(Cell::new(0));
+        let fiber_steps = steps.clone();
+
+        let fiber = Fiber::new(move |context| {
+            fiber_steps.set(1);
+            context.suspend();
+            fiber_steps.set(2);
+        })?;
+
+        // The thread must be converted before switching to a fiber.
+        assert!(fiber.resume().is_err());
+
+        let _thread = ThreadFiber::convert()?;
+        assert!(ThreadFiber::convert().is_err());
+
+        fiber.resume()?;
+        assert_eq!(steps.get(), 1);
+        assert!(!fiber.is_finished());
+
+        fiber.resume()?;
+        assert_eq!(steps.get(), 2);
+        assert!(fiber.is_finished());
+        assert!(fiber.resume().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested() -> Result<()> {
+        let _thread = ThreadFiber::convert()?;
+        let order = Rc::new(Cell::new(Vec::new()));
+        let outer_order = order.clone();
+
+        let outer = Fiber::new(move |_| {
+            let inner_order = outer_order.clone();
+            let inner = Fiber::new(move |_| push(&inner_order, "inner")).unwrap();
+
+            push(&outer_order, "outer");
+            inner.resume().unwrap();
+            push(&outer_order, "outer");
+        })?;
+
+        outer.resume()?;
+        assert!(outer.is_finished());
+        assert_eq!(order.take(), ["outer", "inner", "outer"]);
+        Ok(())
+    }
+
+    #[test]
+    fn panic() -> Result<()> {
+        let _thread = ThreadFiber::convert()?;
+        let fiber = Fiber::new(|_| panic!("fiber"))?;
+
+        let result = catch_unwind(AssertUnwindSafe(|| fiber.resume()));
+        assert_eq!(*result.unwrap_err().downcast::<&str>().unwrap(), "fiber");
+        assert!(fiber.is_finished());
+        Ok(())
+    }
+
+    fn push(order: &Cell<Vec<&'static str>>, value: &'static str) {
+        let mut values = order.take();
+        values.push(value);
+        order.set(values);
+    }
+}
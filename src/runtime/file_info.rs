@@ -0,0 +1,353 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::FILETIME,
+    Storage::FileSystem::{
+        FindClose, FindFileHandle, FindFirstStreamW, FindNextStreamW, FindStreamInfoStandard,
+        GetFileAttributesExW, GetFileExInfoStandard, SetFileAttributesW, SetFileTime,
+        FILE_ATTRIBUTE_ARCHIVE, FILE_ATTRIBUTE_COMPRESSED, FILE_ATTRIBUTE_DIRECTORY,
+        FILE_ATTRIBUTE_ENCRYPTED, FILE_ATTRIBUTE_HIDDEN, FILE_ATTRIBUTE_NORMAL,
+        FILE_ATTRIBUTE_NOT_CONTENT_INDEXED, FILE_ATTRIBUTE_OFFLINE, FILE_ATTRIBUTE_READONLY,
+        FILE_ATTRIBUTE_REPARSE_POINT, FILE_ATTRIBUTE_SPARSE_FILE, FILE_ATTRIBUTE_SYSTEM,
+        FILE_ATTRIBUTE_TEMPORARY, FILE_FLAGS_AND_ATTRIBUTES, FILE_FLAG_BACKUP_SEMANTICS,
+        FILE_WRITE_ATTRIBUTES, WIN32_FILE_ATTRIBUTE_DATA, WIN32_FIND_STREAM_DATA,
+    },
+    System::Diagnostics::Debug::ERROR_HANDLE_EOF,
+    System::SystemServices::{
+        COMPRESSION_FORMAT_DEFAULT, COMPRESSION_FORMAT_NONE, FSCTL_SET_COMPRESSION,
+        FSCTL_SET_SPARSE,
+    },
+};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// The number of 100-nanosecond intervals between 1601-01-01, the `FILETIME` epoch, and
+// 1970-01-01, the `UNIX_EPOCH`.
+const UNIX_EPOCH_TICKS: u64 = 116_444_736_000_000_000;
+const TICKS_PER_SECOND: u64 = 10_000_000;
+
+/// The attributes of a file or directory, such as whether it is read-only or hidden.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct FileAttributes(pub u32);
+
+impl FileAttributes {
+    pub const READONLY: Self = Self(FILE_ATTRIBUTE_READONLY.0);
+    pub const HIDDEN: Self = Self(FILE_ATTRIBUTE_HIDDEN.0);
+    pub const SYSTEM: Self = Self(FILE_ATTRIBUTE_SYSTEM.0);
+    pub const DIRECTORY: Self = Self(FILE_ATTRIBUTE_DIRECTORY.0);
+    pub const ARCHIVE: Self = Self(FILE_ATTRIBUTE_ARCHIVE.0);
+    pub const NORMAL: Self = Self(FILE_ATTRIBUTE_NORMAL.0);
+    pub const TEMPORARY: Self = Self(FILE_ATTRIBUTE_TEMPORARY.0);
+    pub const SPARSE_FILE: Self = Self(FILE_ATTRIBUTE_SPARSE_FILE.0);
+    pub const REPARSE_POINT: Self = Self(FILE_ATTRIBUTE_REPARSE_POINT.0);
+    pub const COMPRESSED: Self = Self(FILE_ATTRIBUTE_COMPRESSED.0);
+    pub const OFFLINE: Self = Self(FILE_ATTRIBUTE_OFFLINE.0);
+    pub const NOT_CONTENT_INDEXED: Self = Self(FILE_ATTRIBUTE_NOT_CONTENT_INDEXED.0);
+    pub const ENCRYPTED: Self = Self(FILE_ATTRIBUTE_ENCRYPTED.0);
+
+    /// Returns `true` if all of the attributes in `other` are set.
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl std::ops::BitOr for FileAttributes {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for FileAttributes {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0
+    }
+}
+
+/// The attributes, size, and timestamps of a file or directory, as returned by [`file_info`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct FileInfo {
+    pub attributes: FileAttributes,
+    pub len: u64,
+    pub created: SystemTime,
+    pub accessed: SystemTime,
+    pub written: SystemTime,
+}
+
+/// The timestamps to change with [`set_file_times`]. Those left as `None` are unchanged.
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct FileTimes {
+    pub created: Option<SystemTime>,
+    pub accessed: Option<SystemTime>,
+    pub written: Option<SystemTime>,
+}
+
+/// An NTFS data stream of a file, as returned by [`file_streams`].
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FileStream {
+    /// The name of the stream in the form `:name:$DATA`, which may be appended to the file's path
+    /// to open the stream. The file's unnamed default stream is named `::$DATA`.
+    pub name: String,
+    pub len: u64,
+}
+
+/// Returns the attributes, size, and timestamps of a file or directory without opening it.
+pub fn file_info<P: AsRef<Path>>(path: P) -> Result<FileInfo> {
+    let mut data = WIN32_FILE_ATTRIBUTE_DATA::default();
+
+    unsafe {
+        GetFileAttributesExW(
+            path.as_ref(),
+            GetFileExInfoStandard,
+            &mut data as *mut _ as _,
+        )
+        .ok()?;
+    }
+
+    Ok(FileInfo {
+        attributes: FileAttributes(data.dwFileAttributes),
+        len: ((data.nFileSizeHigh as u64) << 32) | data.nFileSizeLow as u64,
+        created: from_file_time(data.ftCreationTime),
+        accessed: from_file_time(data.ftLastAccessTime),
+        written: from_file_time(data.ftLastWriteTime),
+    })
+}
+
+/// Sets the attributes of a file or directory. Attributes such as [`FileAttributes::DIRECTORY`],
+/// [`FileAttributes::SPARSE_FILE`], and [`FileAttributes::COMPRESSED`] can't be changed this way
+/// and are ignored.
+pub fn set_file_attributes<P: AsRef<Path>>(path: P, attributes: FileAttributes) -> Result<()> {
+    unsafe { SetFileAttributesW(path.as_ref(), FILE_FLAGS_AND_ATTRIBUTES(attributes.0)).ok() }
+}
+
+/// Sets the timestamps of a file or directory, leaving any that are `None` unchanged.
+///
+/// File systems store timestamps with different precision, so a timestamp that is read back may
+/// differ slightly from the one that was set.
+pub fn set_file_times<P: AsRef<Path>>(path: P, times: &FileTimes) -> Result<()> {
+    let file = Device::create(
+        path.as_ref(),
+        FILE_WRITE_ATTRIBUTES,
+        FILE_FLAG_BACKUP_SEMANTICS,
+    )?;
+
+    let created = times.created.map(to_file_time);
+    let accessed = times.accessed.map(to_file_time);
+    let written = times.written.map(to_file_time);
+
+    unsafe {
+        SetFileTime(
+            file.handle(),
+            optional(&created),
+            optional(&accessed),
+            optional(&written),
+        )
+        .ok()
+    }
+}
+
+/// Returns the data streams of a file, including its unnamed default stream. Directories only
+/// have streams if named streams have been added to them.
+///
+/// Alternate data streams are only supported by NTFS and ReFS; other file systems report the
+/// default stream alone or fail.
+pub fn file_streams<P: AsRef<Path>>(path: P) -> Result<Vec<FileStream>> {
+    let mut streams = Vec::new();
+    let mut data = WIN32_FIND_STREAM_DATA::default();
+
+    unsafe {
+        let find = FindFirstStreamW(
+            path.as_ref(),
+            FindStreamInfoStandard,
+            &mut data as *mut _ as _,
+            0,
+        );
+
+        if find.0 == -1 {
+            let error = HRESULT::from_thread();
+
+            return if error == HRESULT::from_win32(ERROR_HANDLE_EOF.0) {
+                Ok(streams)
+            } else {
+                Err(error.into())
+            };
+        }
+
+        let result = loop {
+            streams.push(FileStream {
+                name: String::from_utf16_lossy(until_null(&data.cStreamName)),
+                len: data.StreamSize as u64,
+            });
+
+            if !FindNextStreamW(find, &mut data as *mut _ as _).as_bool() {
+                let error = HRESULT::from_thread();
+
+                break if error == HRESULT::from_win32(ERROR_HANDLE_EOF.0) {
+                    Ok(streams)
+                } else {
+                    Err(error.into())
+                };
+            }
+        };
+
+        // The stream and file search handles are closed the same way.
+        FindClose(FindFileHandle(find.0));
+        result
+    }
+}
+
+/// Sets whether a file is sparse, so that ranges of zeros written to it, or later deallocated,
+/// don't take up space on disk. Files on file systems without sparse support can't be made
+/// sparse.
+pub fn set_sparse<P: AsRef<Path>>(path: P, sparse: bool) -> Result<()> {
+    let file = Device::open(path)?;
+
+    // The `FILE_SET_SPARSE_BUFFER` holds a single `BOOLEAN`.
+    unsafe { file.control(ControlCode(FSCTL_SET_SPARSE), &(sparse as u8)) }
+}
+
+/// Sets whether a file or directory is compressed by the file system using its default
+/// compression format. Compressing a directory sets the default for files later created in it
+/// without compressing those already there.
+pub fn set_compressed<P: AsRef<Path>>(path: P, compressed: bool) -> Result<()> {
+    let file = Device::open(path)?;
+
+    let format = if compressed {
+        COMPRESSION_FORMAT_DEFAULT
+    } else {
+        COMPRESSION_FORMAT_NONE
+    } as u16;
+
+    unsafe { file.control(ControlCode(FSCTL_SET_COMPRESSION), &format) }
+}
+
+fn optional(time: &Option<FILETIME>) -> *const FILETIME {
+    match time {
+        Some(time) => time,
+        None => std::ptr::null(),
+    }
+}
+
+fn until_null(wide: &[u16]) -> &[u16] {
+    &wide[..wide.iter().position(|c| *c == 0).unwrap_or(wide.len())]
+}
+
+fn from_file_time(time: FILETIME) -> SystemTime {
+    from_file_ticks(((time.dwHighDateTime as u64) << 32) | time.dwLowDateTime as u64)
+}
+
+// Converts a `FILETIME` held as a single integer, as in many file system structures.
+pub(crate) fn from_file_ticks(ticks: u64) -> SystemTime {
+    if ticks >= UNIX_EPOCH_TICKS {
+        UNIX_EPOCH + ticks_to_duration(ticks - UNIX_EPOCH_TICKS)
+    } else {
+        UNIX_EPOCH - ticks_to_duration(UNIX_EPOCH_TICKS - ticks)
+    }
+}
+
+// Times outside the range of `FILETIME` are clamped to it.
+fn to_file_time(time: SystemTime) -> FILETIME {
+    let ticks = match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => UNIX_EPOCH_TICKS.saturating_add(duration_to_ticks(after)),
+        Err(before) => UNIX_EPOCH_TICKS.saturating_sub(duration_to_ticks(before.duration())),
+    };
+
+    FILETIME {
+        dwLowDateTime: ticks as u32,
+        dwHighDateTime: (ticks >> 32) as u32,
+    }
+}
+
+fn ticks_to_duration(ticks: u64) -> Duration {
+    Duration::new(
+        ticks / TICKS_PER_SECOND,
+        (ticks % TICKS_PER_SECOND) as u32 * 100,
+    )
+}
+
+fn duration_to_ticks(duration: Duration) -> u64 {
+    duration
+        .as_secs()
+        .saturating_mul(TICKS_PER_SECOND)
+        .saturating_add(duration.subsec_nanos() as u64 / 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_time() {
+        let time = UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_700);
+        assert_eq!(from_file_time(to_file_time(time)), time);
+
+        let time = UNIX_EPOCH - Duration::from_secs(86_400);
+        assert_eq!(from_file_time(to_file_time(time)), time);
+
+        let epoch = to_file_time(UNIX_EPOCH);
+        assert_eq!(
+            ((epoch.dwHighDateTime as u64) << 32) | epoch.dwLowDateTime as u64,
+            UNIX_EPOCH_TICKS
+        );
+    }
+
+    #[test]
+    fn info() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("windows-info-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir)?;
+
+        let path = dir.join("file.txt");
+        std::fs::write(&path, b"data")?;
+        std::fs::write(dir.join("file.txt:extra"), b"more data")?;
+
+        let info = file_info(&path)?;
+        assert_eq!(info.len, 4);
+        assert!(!info.attributes.contains(FileAttributes::DIRECTORY));
+        assert!(file_info(&dir)?
+            .attributes
+            .contains(FileAttributes::DIRECTORY));
+
+        set_file_attributes(&path, info.attributes | FileAttributes::READONLY)?;
+        assert!(file_info(&path)?
+            .attributes
+            .contains(FileAttributes::READONLY));
+        set_file_attributes(&path, FileAttributes::NORMAL)?;
+
+        let written = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        set_file_times(
+            &path,
+            &FileTimes {
+                written: Some(written),
+                ..Default::default()
+            },
+        )?;
+        let times = file_info(&path)?;
+        assert_eq!(times.written, written);
+        assert_eq!(times.created, info.created);
+
+        let streams = file_streams(&path)?;
+        assert_eq!(
+            streams,
+            [
+                FileStream {
+                    name: "::$DATA".to_string(),
+                    len: 4
+                },
+                FileStream {
+                    name: ":extra:$DATA".to_string(),
+                    len: 9
+                }
+            ]
+        );
+        assert!(file_streams(&dir)?.is_empty());
+
+        set_sparse(&path, true)?;
+        assert!(file_info(&path)?
+            .attributes
+            .contains(FileAttributes::SPARSE_FILE));
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,289 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::PWSTR,
+    Storage::FileSystem::{
+        CreateHardLinkW, CreateSymbolicLinkW, MoveFileExW, ReplaceFileW,
+        FILE_FLAG_BACKUP_SEMANTICS, FILE_FLAG_OPEN_REPARSE_POINT, FILE_GENERIC_WRITE,
+        MOVEFILE_COPY_ALLOWED, MOVEFILE_REPLACE_EXISTING, MOVEFILE_WRITE_THROUGH,
+        REPLACEFILE_IGNORE_MERGE_ERRORS, REPLACEFILE_WRITE_THROUGH, SYMBOLIC_LINK_FLAGS,
+        SYMBOLIC_LINK_FLAG_ALLOW_UNPRIVILEGED_CREATE, SYMBOLIC_LINK_FLAG_DIRECTORY,
+    },
+    System::Diagnostics::Debug::{
+        ERROR_FILE_NOT_FOUND, ERROR_INVALID_PARAMETER, ERROR_UNABLE_TO_MOVE_REPLACEMENT_2,
+    },
+    System::SystemServices::{FSCTL_SET_REPARSE_POINT, IO_REPARSE_TAG_MOUNT_POINT},
+};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Replaces the contents of the file at `path` so that the file is never left partially written,
+/// even if the process or system stops while it is being written.
+///
+/// The contents are written to a temporary file in the same directory and flushed to disk before
+/// replacing the file with `ReplaceFileW`, which preserves the original file's attributes,
+/// security descriptor, and other metadata. If the file doesn't exist it is created instead.
+pub fn write_atomic<P: AsRef<Path>>(path: P, contents: &[u8]) -> Result<()> {
+    replace_with(path.as_ref(), contents, None)
+}
+
+/// Replaces the contents of the file at `path` like [`write_atomic`], keeping the previous
+/// contents of the file at `backup`. If the file is only partly replaced the previous contents
+/// are moved back to `path`.
+pub fn write_atomic_with_backup<P: AsRef<Path>, B: AsRef<Path>>(
+    path: P,
+    contents: &[u8],
+    backup: B,
+) -> Result<()> {
+    replace_with(path.as_ref(), contents, Some(backup.as_ref()))
+}
+
+/// Renames a file or directory, replacing any file at `to` and returning only once the change
+/// has been flushed to disk. Files may also be moved to another volume, in which case they are
+/// copied and then deleted.
+pub fn rename_durable<P: AsRef<Path>, Q: AsRef<Path>>(from: P, to: Q) -> Result<()> {
+    let flags = MOVEFILE_REPLACE_EXISTING | MOVEFILE_COPY_ALLOWED | MOVEFILE_WRITE_THROUGH;
+    unsafe { MoveFileExW(from.as_ref(), to.as_ref(), flags).ok() }
+}
+
+/// Creates a hard link at `link` to the existing file at `target`, which must be on the same
+/// volume.
+pub fn create_hard_link<P: AsRef<Path>, Q: AsRef<Path>>(link: P, target: Q) -> Result<()> {
+    unsafe { CreateHardLinkW(link.as_ref(), target.as_ref(), std::ptr::null_mut()).ok() }
+}
+
+/// Creates a symbolic link at `link` to `target`, which may be a file or a directory and may be
+/// relative to the directory containing the link.
+///
+/// Creating a symbolic link requires either the `SeCreateSymbolicLinkPrivilege` privilege,
+/// usually held by administrators, or that developer mode is enabled.
+pub fn create_symbolic_link<P: AsRef<Path>, Q: AsRef<Path>>(link: P, target: Q) -> Result<()> {
+    let (link, target) = (link.as_ref(), target.as_ref());
+
+    // The link's kind must match the target's since a directory link to a file can't be opened.
+    let resolved = match link.parent() {
+        Some(parent) if target.is_relative() => parent.join(target),
+        _ => target.to_path_buf(),
+    };
+
+    let mut flags = SYMBOLIC_LINK_FLAGS::default();
+
+    if resolved.is_dir() {
+        flags |= SYMBOLIC_LINK_FLAG_DIRECTORY;
+    }
+
+    // The target is passed as is, rather than with a long path prefix, so that relative links
+    // remain relative.
+    let target = target.to_string_lossy();
+
+    unsafe {
+        if CreateSymbolicLinkW(
+            link,
+            &*target,
+            flags | SYMBOLIC_LINK_FLAG_ALLOW_UNPRIVILEGED_CREATE,
+        ) != 0
+        {
+            return Ok(());
+        }
+
+        // Versions of Windows before the flag was introduced reject it.
+        let error = HRESULT::from_thread();
+
+        if error != HRESULT::from_win32(ERROR_INVALID_PARAMETER.0) {
+            return Err(error.into());
+        }
+
+        if CreateSymbolicLinkW(link, &*target, flags) != 0 {
+            Ok(())
+        } else {
+            Err(HRESULT::from_thread().into())
+        }
+    }
+}
+
+/// Creates a directory junction at `link` to the directory at `target`, which must be on a local
+/// volume. Unlike a symbolic link, a junction doesn't require any privilege to create.
+pub fn create_junction<P: AsRef<Path>, Q: AsRef<Path>>(link: P, target: Q) -> Result<()> {
+    let link = link.as_ref();
+
+    // Junctions always refer to an absolute path without the extended-length prefix.
+    let target = from_wide_path(&to_wide_path(&std::fs::canonicalize(target)?));
+    let print: Vec<u16> = target.to_string_lossy().encode_utf16().collect();
+    let substitute: Vec<u16> = r"\??\"
+        .encode_utf16()
+        .chain(print.iter().copied())
+        .collect();
+
+    // The `REPARSE_DATA_BUFFER` holds the substitute name followed by the print name, each
+    // null-terminated, after a header of 16-bit fields.
+    let names_len = (substitute.len() + print.len() + 2) * 2;
+    let tag = IO_REPARSE_TAG_MOUNT_POINT as u32;
+    let mut buffer: Vec<u16> = vec![
+        tag as u16,
+        (tag >> 16) as u16,
+        (names_len + 8) as u16,
+        0,
+        0,
+        (substitute.len() * 2) as u16,
+        ((substitute.len() + 1) * 2) as u16,
+        (print.len() * 2) as u16,
+    ];
+
+    buffer.extend_from_slice(&substitute);
+    buffer.push(0);
+    buffer.extend_from_slice(&print);
+    buffer.push(0);
+
+    std::fs::create_dir(link)?;
+
+    let result = Device::create(
+        link,
+        FILE_GENERIC_WRITE,
+        FILE_FLAG_BACKUP_SEMANTICS | FILE_FLAG_OPEN_REPARSE_POINT,
+    )
+    .and_then(|device| unsafe {
+        device.io(
+            FSCTL_SET_REPARSE_POINT,
+            buffer.as_ptr() as _,
+            (buffer.len() * 2) as u32,
+            std::ptr::null_mut(),
+            0,
+        )
+    });
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => {
+            let _ = std::fs::remove_dir(link);
+            Err(error)
+        }
+    }
+}
+
+fn replace_with(path: &Path, contents: &[u8], backup: Option<&Path>) -> Result<()> {
+    let temp = temp_path(path);
+
+    let result = write_flushed(&temp, contents).and_then(|_| unsafe {
+        let backup_param = backup.map(to_wide_path);
+        let backup_ptr = backup_param
+            .as_ref()
+            .map_or(std::ptr::null_mut(), |backup| backup.as_ptr() as *mut u16);
+
+        if ReplaceFileW(
+            path,
+            &*temp,
+            PWSTR(backup_ptr),
+            REPLACEFILE_WRITE_THROUGH | REPLACEFILE_IGNORE_MERGE_ERRORS,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+        .as_bool()
+        {
+            return Ok(());
+        }
+
+        let error = HRESULT::from_thread();
+
+        if error == HRESULT::from_win32(ERROR_FILE_NOT_FOUND.0) && !path.exists() {
+            // There is no file to replace.
+            return rename_durable(&temp, path);
+        }
+
+        // The original file was moved to the backup but the replacement could not take its place.
+        if error == HRESULT::from_win32(ERROR_UNABLE_TO_MOVE_REPLACEMENT_2.0) {
+            if let Some(backup) = backup {
+                let _ = rename_durable(backup, path);
+            }
+        }
+
+        Err(error.into())
+    });
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp);
+    }
+
+    result
+}
+
+fn write_flushed(path: &Path, contents: &[u8]) -> Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)?;
+
+    file.write_all(contents)?;
+    file.sync_all()?;
+    Ok(())
+}
+
+// Returns a unique path in the same directory as `path` so that the file can be renamed without
+// being copied to another volume.
+fn temp_path(path: &Path) -> PathBuf {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(
+        ".{}-{}.tmp",
+        std::process::id(),
+        COUNT.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("windows-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn atomic() -> Result<()> {
+        let dir = temp_dir("atomic");
+        let path = dir.join("file.txt");
+        let backup = dir.join("file.bak");
+
+        write_atomic(&path, b"first")?;
+        assert_eq!(std::fs::read(&path)?, b"first");
+
+        write_atomic_with_backup(&path, b"second", &backup)?;
+        assert_eq!(std::fs::read(&path)?, b"second");
+        assert_eq!(std::fs::read(&backup)?, b"first");
+
+        // No temporary files are left behind.
+        assert_eq!(std::fs::read_dir(&dir)?.count(), 2);
+
+        rename_durable(&path, dir.join("renamed.txt"))?;
+        assert!(!path.exists());
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn links() -> Result<()> {
+        let dir = temp_dir("links");
+        let target = dir.join("target");
+        std::fs::create_dir(&target)?;
+        std::fs::write(target.join("file.txt"), b"linked")?;
+
+        create_hard_link(dir.join("hard.txt"), target.join("file.txt"))?;
+        assert_eq!(std::fs::read(dir.join("hard.txt"))?, b"linked");
+
+        create_junction(dir.join("junction"), &target)?;
+        assert_eq!(
+            std::fs::read(dir.join("junction").join("file.txt"))?,
+            b"linked"
+        );
+        assert!(create_junction(dir.join("junction"), &target).is_err());
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
@@ -67,6 +67,47 @@ impl Guid {
         )
     }
 
+    /// Returns the WinRT signature of an interface or delegate with this IID, such as
+    /// `{96369f54-8eb6-48f0-abce-c1b211e627c3}` for `IStringable`. The signature of a generic
+    /// interface or delegate is combined with those of its type arguments by
+    /// [`pinterface_signature`](crate::pinterface_signature).
+    pub const fn signature(&self) -> crate::ConstBuffer {
+        const fn push_hex(
+            buffer: crate::ConstBuffer,
+            value: u32,
+            digits: u32,
+        ) -> crate::ConstBuffer {
+            let mut buffer = buffer;
+            let mut digit = digits;
+
+            while digit > 0 {
+                digit -= 1;
+                let nibble = (value >> (digit * 4)) & 0xF;
+                buffer = buffer.push_slice(&[b"0123456789abcdef"[nibble as usize]]);
+            }
+
+            buffer
+        }
+
+        let mut buffer = crate::ConstBuffer::from_slice(b"{");
+        buffer = push_hex(buffer, self.data1, 8).push_slice(b"-");
+        buffer = push_hex(buffer, self.data2 as u32, 4).push_slice(b"-");
+        buffer = push_hex(buffer, self.data3 as u32, 4).push_slice(b"-");
+
+        let mut index = 0;
+
+        while index < 8 {
+            if index == 2 {
+                buffer = buffer.push_slice(b"-");
+            }
+
+            buffer = push_hex(buffer, self.data4[index] as u32, 2);
+            index += 1;
+        }
+
+        buffer.push_slice(b"}")
+    }
+
     /// Looks up a CLSID in the registry using the [CLSIDFromProgID](https://docs.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-clsidfromprogid) function.
     pub fn from_progid(progid: &str) -> crate::Result<Guid> {
         unsafe { CLSIDFromProgID(progid) }
@@ -82,8 +123,8 @@ unsafe impl RuntimeType for Guid {
     const SIGNATURE: crate::ConstBuffer = crate::ConstBuffer::from_slice(b"g16");
 }
 
-impl std::fmt::Debug for Guid {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for Guid {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "{:08X?}-{:04X?}-{:04X?}-{:02X?}{:02X?}-{:02X?}{:02X?}{:02X?}{:02X?}{:02X?}{:02X?}",
@@ -142,7 +183,7 @@ trait HexReader {
     fn next_u32(&mut self) -> u32;
 }
 
-impl HexReader for std::str::Bytes<'_> {
+impl HexReader for core::str::Bytes<'_> {
     fn next_u8(&mut self) -> u8 {
         let value = self.next().unwrap();
         match value {
@@ -172,4 +213,15 @@ mod tests {
         let unique = Guid::new().unwrap();
         assert_ne!(zeroed, unique);
     }
+
+    #[test]
+    fn test_signature() {
+        let guid = Guid::from("96369F54-8EB6-48F0-ABCE-C1B211E627C3");
+        let signature = guid.signature();
+
+        assert_eq!(
+            &signature.as_slice()[..38],
+            b"{96369f54-8eb6-48f0-abce-c1b211e627c3}"
+        );
+    }
 }
@@ -1,11 +1,105 @@
 use crate::*;
 
 use bindings::Windows::Win32::System::Memory::*;
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// Allocates the memory backing the strings created by the crate, such as each [`HSTRING`].
+///
+/// An allocator may be installed with [`set_allocator`] to pool, reserve, or record these
+/// allocations, such as to keep a real-time thread from calling into the process heap or to
+/// detect strings allocated on a hot path.
+///
+/// # Safety
+///
+/// Strings are shared with Windows, which frees any string it releases last with `HeapFree` on
+/// the process heap, while strings created by Windows may be freed with [`Allocator::free`].
+/// An implementation must therefore only return blocks allocated from the process heap and must
+/// accept any block allocated from the process heap, as [`ProcessHeap`] does. It may keep freed
+/// blocks for reuse rather than returning them to the heap, or forward to another allocator
+/// satisfying the same requirements.
+pub unsafe trait Allocator: Sync {
+    /// Allocates a block of at least `bytes` bytes, returning null on failure.
+    fn alloc(&self, bytes: usize) -> RawPtr;
+
+    /// Frees a block from the process heap.
+    ///
+    /// # Safety
+    /// `ptr` must have been allocated from the process heap and must not be used afterwards.
+    unsafe fn free(&self, ptr: RawPtr);
+}
+
+/// The default [`Allocator`], which calls the process heap for each allocation.
+pub struct ProcessHeap;
+
+unsafe impl Allocator for ProcessHeap {
+    fn alloc(&self, bytes: usize) -> RawPtr {
+        unsafe { HeapAlloc(GetProcessHeap(), HEAP_NONE, bytes) }
+    }
+
+    unsafe fn free(&self, ptr: RawPtr) {
+        HeapFree(GetProcessHeap(), HEAP_NONE, ptr);
+    }
+}
+
+// The installed allocator, or null for the process heap.
+static ALLOCATOR: AtomicPtr<&'static dyn Allocator> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Installs the allocator used for strings created from then on and for freeing any string.
+///
+/// Since every allocator hands out and accepts blocks from the process heap, the allocator may be
+/// replaced at any time, including while strings allocated by the previous allocator are alive.
+pub fn set_allocator(allocator: &'static dyn Allocator) {
+    // The fat reference is boxed so that it may be stored atomically. Replaced allocators are
+    // leaked since another thread may still be using them.
+    let allocator = Box::into_raw(Box::new(allocator));
+    ALLOCATOR.store(allocator, Ordering::Release);
+}
+
+fn allocator() -> &'static dyn Allocator {
+    let allocator = ALLOCATOR.load(Ordering::Acquire);
+
+    if allocator.is_null() {
+        &ProcessHeap
+    } else {
+        unsafe { *allocator }
+    }
+}
 
 pub fn heap_alloc(bytes: usize) -> RawPtr {
-    unsafe { HeapAlloc(GetProcessHeap(), HEAP_NONE, bytes) }
+    allocator().alloc(bytes)
 }
 
 pub unsafe fn heap_free(ptr: RawPtr) {
-    HeapFree(GetProcessHeap(), HEAP_NONE, ptr);
+    allocator().free(ptr);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::sync::atomic::AtomicUsize;
+
+    struct Counting(AtomicUsize);
+
+    unsafe impl Allocator for Counting {
+        fn alloc(&self, bytes: usize) -> RawPtr {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            ProcessHeap.alloc(bytes)
+        }
+
+        unsafe fn free(&self, ptr: RawPtr) {
+            ProcessHeap.free(ptr)
+        }
+    }
+
+    #[test]
+    fn set_allocator_counts() {
+        static COUNTING: Counting = Counting(AtomicUsize::new(0));
+        set_allocator(&COUNTING);
+
+        let value: HSTRING = "allocated".into();
+        assert_eq!(value, "allocated");
+        assert!(COUNTING.0.load(Ordering::Relaxed) >= 1);
+
+        set_allocator(&ProcessHeap);
+    }
 }
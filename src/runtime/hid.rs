@@ -0,0 +1,595 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Devices::HumanInterfaceDevice::{
+        HidD_FreePreparsedData, HidD_GetAttributes, HidD_GetFeature, HidD_GetHidGuid,
+        HidD_GetInputReport, HidD_GetManufacturerString, HidD_GetPreparsedData,
+        HidD_GetProductString, HidD_GetSerialNumberString, HidD_SetFeature, HidP_Feature,
+        HidP_GetButtonCaps, HidP_GetCaps, HidP_GetValueCaps, HidP_Input, HidP_Output,
+        HIDD_ATTRIBUTES, HIDP_BUTTON_CAPS, HIDP_CAPS, HIDP_REPORT_TYPE, HIDP_VALUE_CAPS,
+    },
+    Foundation::{HANDLE, NTSTATUS},
+    Storage::FileSystem::{ReadFile, WriteFile},
+    System::Diagnostics::Debug::ERROR_INVALID_PARAMETER,
+};
+use std::ffi::c_void;
+use std::ops::RangeInclusive;
+use std::path::Path;
+
+// This value is defined by a macro in `hidpi.h` and isn't described by the metadata.
+const HIDP_STATUS_SUCCESS: i32 = 0x0011_0000;
+
+// The longest string a HID device may report is 126 characters, plus the terminating null.
+const MAX_STRING_LEN: usize = 127;
+
+/// The kind of report described by a [`HidDevice`]'s capabilities.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum HidReportType {
+    Input,
+    Output,
+    Feature,
+}
+
+impl HidReportType {
+    fn abi(self) -> HIDP_REPORT_TYPE {
+        match self {
+            Self::Input => HidP_Input,
+            Self::Output => HidP_Output,
+            Self::Feature => HidP_Feature,
+        }
+    }
+}
+
+/// The vendor, product, and version of a [`HidDevice`].
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct HidAttributes {
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub version: u16,
+}
+
+/// The top-level capabilities of a [`HidDevice`], parsed from its report descriptor.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct HidCaps {
+    pub usage_page: u16,
+    pub usage: u16,
+    /// The length of the input reports, including the leading report ID.
+    pub input_report_len: usize,
+    /// The length of the output reports, including the leading report ID.
+    pub output_report_len: usize,
+    /// The length of the feature reports, including the leading report ID.
+    pub feature_report_len: usize,
+    pub link_collection_nodes: u16,
+}
+
+/// The buttons described by a HID report descriptor, where each usage in `usages` is a button
+/// that is either on or off.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct HidButtonCaps {
+    pub usage_page: u16,
+    pub report_id: u8,
+    pub usages: RangeInclusive<u16>,
+    pub data_indices: RangeInclusive<u16>,
+    pub link_collection: u16,
+    pub is_absolute: bool,
+}
+
+/// The values described by a HID report descriptor, such as the axes of a joystick.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct HidValueCaps {
+    pub usage_page: u16,
+    pub report_id: u8,
+    pub usages: RangeInclusive<u16>,
+    pub data_indices: RangeInclusive<u16>,
+    pub link_collection: u16,
+    pub is_absolute: bool,
+    /// Whether the value has a null state, reported as a value outside of `logical_range`.
+    pub has_null: bool,
+    pub bit_size: u16,
+    pub report_count: u16,
+    pub logical_range: RangeInclusive<i32>,
+    pub physical_range: RangeInclusive<i32>,
+    pub units: u32,
+    pub units_exp: u32,
+}
+
+/// A HID top-level collection, such as a game controller or a vendor-defined device, opened for
+/// reading and writing reports.
+///
+/// ```ignore
+/// // The game pads of the generic desktop page.
+/// for pad in windows::HidDevice::open_by_usage(0x01, 0x05)? {
+///     println!("{:?} {:?}", pad.product()?, pad.value_caps(windows::HidReportType::Input)?);
+///
+///     for report in pad.input_reports().take(10) {
+///         println!("{:?}", report?);
+///     }
+/// }
+/// ```
+pub struct HidDevice {
+    device: Device,
+    preparsed: isize,
+    caps: HidCaps,
+}
+
+impl HidDevice {
+    /// Returns the device interface class of HID collections, such as for enumerating them with
+    /// [`DeviceInfoSet::interface`].
+    pub fn interface_class() -> Guid {
+        let mut class = Guid::zeroed();
+        unsafe { HidD_GetHidGuid(&mut class) };
+        class
+    }
+
+    /// Opens the HID collection with the given interface path for reading and writing.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_device(Device::open(path)?)
+    }
+
+    /// Opens the present HID collections with the given usage page and usage, such as `0x01`
+    /// and `0x05` for game pads.
+    ///
+    /// Collections that can't be opened for reading and writing, such as the keyboards and mice
+    /// that the system reserves for itself, are skipped.
+    pub fn open_by_usage(usage_page: u16, usage: u16) -> Result<Vec<Self>> {
+        let mut devices = Vec::new();
+
+        for info in &DeviceInfoSet::interface(&Self::interface_class())? {
+            for path in info?.interface_paths {
+                // Any collection may be opened without access to read its capabilities.
+                let caps = match Device::open_with_access(&path, 0).and_then(Self::from_device) {
+                    Ok(device) => device.caps,
+                    Err(_) => continue,
+                };
+
+                if caps.usage_page == usage_page && caps.usage == usage {
+                    if let Ok(device) = Self::open(&path) {
+                        devices.push(device);
+                    }
+                }
+            }
+        }
+
+        Ok(devices)
+    }
+
+    /// Takes ownership of a device opened by the caller, such as one opened for overlapped I/O
+    /// or with particular sharing.
+    pub fn from_device(device: Device) -> Result<Self> {
+        let mut preparsed = 0;
+
+        unsafe {
+            if HidD_GetPreparsedData(device.handle(), &mut preparsed) == 0 {
+                return Err(HRESULT::from_thread().into());
+            }
+        }
+
+        let mut caps = HIDP_CAPS::default();
+
+        // The preparsed data is freed by `drop` if reading the capabilities fails.
+        let mut hid = Self {
+            device,
+            preparsed,
+            caps: HidCaps::default(),
+        };
+
+        check(unsafe { HidP_GetCaps(hid.preparsed, &mut caps) })?;
+
+        hid.caps = HidCaps {
+            usage_page: caps.UsagePage,
+            usage: caps.Usage,
+            input_report_len: caps.InputReportByteLength as usize,
+            output_report_len: caps.OutputReportByteLength as usize,
+            feature_report_len: caps.FeatureReportByteLength as usize,
+            link_collection_nodes: caps.NumberLinkCollectionNodes,
+        };
+
+        Ok(hid)
+    }
+
+    /// Returns the underlying device, such as for sending it control codes.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+
+    /// Returns the top-level capabilities of the collection.
+    pub fn caps(&self) -> &HidCaps {
+        &self.caps
+    }
+
+    pub fn attributes(&self) -> Result<HidAttributes> {
+        let mut attributes = HIDD_ATTRIBUTES {
+            Size: std::mem::size_of::<HIDD_ATTRIBUTES>() as u32,
+            ..Default::default()
+        };
+
+        unsafe {
+            if HidD_GetAttributes(self.device.handle(), &mut attributes) == 0 {
+                return Err(HRESULT::from_thread().into());
+            }
+        }
+
+        Ok(HidAttributes {
+            vendor_id: attributes.VendorID,
+            product_id: attributes.ProductID,
+            version: attributes.VersionNumber,
+        })
+    }
+
+    pub fn manufacturer(&self) -> Result<String> {
+        self.string(|handle, buffer, len| unsafe {
+            HidD_GetManufacturerString(handle, buffer, len)
+        })
+    }
+
+    pub fn product(&self) -> Result<String> {
+        self.string(|handle, buffer, len| unsafe { HidD_GetProductString(handle, buffer, len) })
+    }
+
+    pub fn serial_number(&self) -> Result<String> {
+        self.string(|handle, buffer, len| unsafe {
+            HidD_GetSerialNumberString(handle, buffer, len)
+        })
+    }
+
+    /// Returns the buttons described by the reports of the given type.
+    pub fn button_caps(&self, report_type: HidReportType) -> Result<Vec<HidButtonCaps>> {
+        let mut len = self.caps_len(report_type).0;
+
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut caps = vec![HIDP_BUTTON_CAPS::default(); len as usize];
+
+        check(unsafe {
+            HidP_GetButtonCaps(
+                report_type.abi(),
+                caps.as_mut_ptr(),
+                &mut len,
+                self.preparsed,
+            )
+        })?;
+
+        caps.truncate(len as usize);
+        Ok(caps.iter().map(button_caps).collect())
+    }
+
+    /// Returns the values described by the reports of the given type.
+    pub fn value_caps(&self, report_type: HidReportType) -> Result<Vec<HidValueCaps>> {
+        let mut len = self.caps_len(report_type).1;
+
+        if len == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut caps = vec![HIDP_VALUE_CAPS::default(); len as usize];
+
+        check(unsafe {
+            HidP_GetValueCaps(
+                report_type.abi(),
+                caps.as_mut_ptr(),
+                &mut len,
+                self.preparsed,
+            )
+        })?;
+
+        caps.truncate(len as usize);
+        Ok(caps.iter().map(value_caps).collect())
+    }
+
+    /// Waits for the next input report from the collection's input buffer. The first byte of
+    /// the report is its report ID, or zero if the collection doesn't use report IDs.
+    pub fn read_input_report(&self) -> Result<Vec<u8>> {
+        let mut report = vec![0; self.caps.input_report_len];
+        let mut read = 0;
+
+        unsafe {
+            ReadFile(
+                self.device.handle(),
+                report.as_mut_ptr() as _,
+                report.len() as u32,
+                &mut read,
+                std::ptr::null_mut(),
+            )
+            .ok()?;
+        }
+
+        report.truncate(read as usize);
+        Ok(report)
+    }
+
+    /// Returns an iterator that waits for each input report in turn, ending after the first
+    /// error, such as when the device is removed.
+    pub fn input_reports(&self) -> HidInputReports<'_> {
+        HidInputReports {
+            device: self,
+            done: false,
+        }
+    }
+
+    /// Reads the current input report with the given report ID directly from the device, rather
+    /// than waiting for the device to send it.
+    pub fn get_input_report(&self, report_id: u8) -> Result<Vec<u8>> {
+        let mut report = vec![0; self.caps.input_report_len];
+        self.get_report(report_id, &mut report, |handle, report, len| unsafe {
+            HidD_GetInputReport(handle, report, len)
+        })?;
+        Ok(report)
+    }
+
+    /// Writes an output report, whose first byte is its report ID or zero if the collection
+    /// doesn't use report IDs. Shorter reports are padded with zeros.
+    pub fn write_output_report(&self, report: &[u8]) -> Result<()> {
+        let report = padded(report, self.caps.output_report_len);
+        let mut written = 0;
+
+        unsafe {
+            WriteFile(
+                self.device.handle(),
+                report.as_ptr() as _,
+                report.len() as u32,
+                &mut written,
+                std::ptr::null_mut(),
+            )
+            .ok()
+        }
+    }
+
+    /// Reads the feature report with the given report ID.
+    pub fn get_feature_report(&self, report_id: u8) -> Result<Vec<u8>> {
+        let mut report = vec![0; self.caps.feature_report_len];
+        self.get_report(report_id, &mut report, |handle, report, len| unsafe {
+            HidD_GetFeature(handle, report, len)
+        })?;
+        Ok(report)
+    }
+
+    /// Writes a feature report, whose first byte is its report ID or zero if the collection
+    /// doesn't use report IDs. Shorter reports are padded with zeros.
+    pub fn set_feature_report(&self, report: &[u8]) -> Result<()> {
+        let mut report = padded(report, self.caps.feature_report_len);
+
+        unsafe {
+            if HidD_SetFeature(
+                self.device.handle(),
+                report.as_mut_ptr() as _,
+                report.len() as u32,
+            ) == 0
+            {
+                return Err(HRESULT::from_thread().into());
+            }
+        }
+
+        Ok(())
+    }
+
+    // Returns the number of button and value capabilities of the given report type.
+    fn caps_len(&self, report_type: HidReportType) -> (u16, u16) {
+        let mut caps = HIDP_CAPS::default();
+
+        // The capabilities have already been read successfully.
+        unsafe { HidP_GetCaps(self.preparsed, &mut caps) };
+
+        match report_type {
+            HidReportType::Input => (caps.NumberInputButtonCaps, caps.NumberInputValueCaps),
+            HidReportType::Output => (caps.NumberOutputButtonCaps, caps.NumberOutputValueCaps),
+            HidReportType::Feature => (caps.NumberFeatureButtonCaps, caps.NumberFeatureValueCaps),
+        }
+    }
+
+    // Reads a report into `report` with one of the `HidD_Get*` functions.
+    fn get_report<F: FnOnce(HANDLE, *mut c_void, u32) -> u8>(
+        &self,
+        report_id: u8,
+        report: &mut [u8],
+        get: F,
+    ) -> Result<()> {
+        if report.is_empty() {
+            return Err(HRESULT::from_win32(ERROR_INVALID_PARAMETER.0).into());
+        }
+
+        report[0] = report_id;
+
+        if get(
+            self.device.handle(),
+            report.as_mut_ptr() as _,
+            report.len() as u32,
+        ) == 0
+        {
+            return Err(HRESULT::from_thread().into());
+        }
+
+        Ok(())
+    }
+
+    // Reads a string with one of the `HidD_Get*String` functions.
+    fn string<F: FnOnce(HANDLE, *mut c_void, u32) -> u8>(&self, get: F) -> Result<String> {
+        let mut buffer = [0u16; MAX_STRING_LEN];
+
+        if get(
+            self.device.handle(),
+            buffer.as_mut_ptr() as _,
+            (buffer.len() * 2) as u32,
+        ) == 0
+        {
+            return Err(HRESULT::from_thread().into());
+        }
+
+        let len = buffer.iter().position(|c| *c == 0).unwrap_or(buffer.len());
+        Ok(String::from_utf16_lossy(&buffer[..len]))
+    }
+}
+
+impl Drop for HidDevice {
+    fn drop(&mut self) {
+        unsafe {
+            HidD_FreePreparsedData(self.preparsed);
+        }
+    }
+}
+
+unsafe impl Send for HidDevice {}
+unsafe impl Sync for HidDevice {}
+
+/// An iterator over the input reports of a [`HidDevice`], which waits for each report in turn.
+pub struct HidInputReports<'a> {
+    device: &'a HidDevice,
+    done: bool,
+}
+
+impl<'a> Iterator for HidInputReports<'a> {
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let report = self.device.read_input_report();
+        self.done = report.is_err();
+        Some(report)
+    }
+}
+
+fn check(status: NTSTATUS) -> Result<()> {
+    if status.0 == HIDP_STATUS_SUCCESS {
+        Ok(())
+    } else {
+        // The equivalent of `HRESULT_FROM_NT`.
+        Err(HRESULT(status.0 as u32 | 0x1000_0000).into())
+    }
+}
+
+fn padded(report: &[u8], len: usize) -> Vec<u8> {
+    let mut report = report.to_vec();
+
+    if report.len() < len {
+        report.resize(len, 0);
+    }
+
+    report
+}
+
+fn button_caps(caps: &HIDP_BUTTON_CAPS) -> HidButtonCaps {
+    let (usages, data_indices) = unsafe {
+        if caps.IsRange != 0 {
+            let range = caps.Anonymous.Range;
+            (
+                range.UsageMin..=range.UsageMax,
+                range.DataIndexMin..=range.DataIndexMax,
+            )
+        } else {
+            let single = caps.Anonymous.NotRange;
+            (
+                single.Usage..=single.Usage,
+                single.DataIndex..=single.DataIndex,
+            )
+        }
+    };
+
+    HidButtonCaps {
+        usage_page: caps.UsagePage,
+        report_id: caps.ReportID,
+        usages,
+        data_indices,
+        link_collection: caps.LinkCollection,
+        is_absolute: caps.IsAbsolute != 0,
+    }
+}
+
+fn value_caps(caps: &HIDP_VALUE_CAPS) -> HidValueCaps {
+    let (usages, data_indices) = unsafe {
+        if caps.IsRange != 0 {
+            let range = caps.Anonymous.Range;
+            (
+                range.UsageMin..=range.UsageMax,
+                range.DataIndexMin..=range.DataIndexMax,
+            )
+        } else {
+            let single = caps.Anonymous.NotRange;
+            (
+                single.Usage..=single.Usage,
+                single.DataIndex..=single.DataIndex,
+            )
+        }
+    };
+
+    HidValueCaps {
+        usage_page: caps.UsagePage,
+        report_id: caps.ReportID,
+        usages,
+        data_indices,
+        link_collection: caps.LinkCollection,
+        is_absolute: caps.IsAbsolute != 0,
+        has_null: caps.HasNull != 0,
+        bit_size: caps.BitSize,
+        report_count: caps.ReportCount,
+        logical_range: caps.LogicalMin..=caps.LogicalMax,
+        physical_range: caps.PhysicalMin..=caps.PhysicalMax,
+        units: caps.Units,
+        units_exp: caps.UnitsExp,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bindings::Windows::Win32::Devices::HumanInterfaceDevice::{
+        HIDP_BUTTON_CAPS_0_1, HIDP_VALUE_CAPS_0_0,
+    };
+
+    #[test]
+    fn caps() {
+        let mut caps = HIDP_VALUE_CAPS {
+            UsagePage: 0x01,
+            ReportID: 2,
+            IsAbsolute: 1,
+            BitSize: 8,
+            ReportCount: 1,
+            LogicalMin: -127,
+            LogicalMax: 127,
+            ..Default::default()
+        };
+
+        caps.Anonymous.NotRange = HIDP_VALUE_CAPS_0_0 {
+            Usage: 0x30,
+            DataIndex: 4,
+            ..Default::default()
+        };
+
+        let value = value_caps(&caps);
+        assert_eq!(value.usages, 0x30..=0x30);
+        assert_eq!(value.data_indices, 4..=4);
+        assert_eq!(value.logical_range, -127..=127);
+        assert!(value.is_absolute);
+        assert!(!value.has_null);
+
+        let mut caps = HIDP_BUTTON_CAPS {
+            UsagePage: 0x09,
+            IsRange: 1,
+            ..Default::default()
+        };
+
+        caps.Anonymous.Range = HIDP_BUTTON_CAPS_0_1 {
+            UsageMin: 1,
+            UsageMax: 16,
+            DataIndexMin: 5,
+            DataIndexMax: 20,
+            ..Default::default()
+        };
+
+        let button = button_caps(&caps);
+        assert_eq!(button.usage_page, 0x09);
+        assert_eq!(button.usages, 1..=16);
+        assert_eq!(button.data_indices, 5..=20);
+    }
+
+    #[test]
+    fn status() {
+        assert!(check(NTSTATUS(HIDP_STATUS_SUCCESS)).is_ok());
+
+        // HIDP_STATUS_INVALID_PREPARSED_DATA
+        let error = check(NTSTATUS(0xC011_0001u32 as i32)).unwrap_err();
+        assert_eq!(error.code(), HRESULT(0xD011_0001));
+    }
+}
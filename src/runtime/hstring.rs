@@ -1,6 +1,6 @@
 use crate::*;
-use std::convert::TryFrom;
-use std::result::Result as StdResult;
+use core::convert::TryFrom;
+use core::result::Result as StdResult;
 
 // TODO: move to HSTRING generated code?
 
@@ -14,7 +14,7 @@ impl HSTRING {
     ///
     /// This function does not allocate memory.
     pub fn new() -> Self {
-        Self(std::ptr::null_mut())
+        Self(core::ptr::null_mut())
     }
 
     /// Returns `true` if the string is empty.
@@ -39,7 +39,7 @@ impl HSTRING {
         }
 
         let header = self.0;
-        unsafe { std::slice::from_raw_parts((*header).data, (*header).len as usize) }
+        unsafe { core::slice::from_raw_parts((*header).data, (*header).len as usize) }
     }
 
     /// Create a `HSTRING` from a slice of 16 bit characters (wchars).
@@ -70,7 +70,14 @@ impl HSTRING {
             }
         }
 
-        self.0 = std::ptr::null_mut();
+        self.0 = core::ptr::null_mut();
+    }
+
+    /// Returns the string referred to by a "fast pass" header created by the [`h`](crate::h)
+    /// macro. The pointer-sized `reference` has the same representation as an `HSTRING`.
+    #[doc(hidden)]
+    pub fn from_reference(reference: &'static Option<&'static HStringReference>) -> &'static Self {
+        unsafe { &*(reference as *const Option<&HStringReference> as *const Self) }
     }
 
     /// # Safety
@@ -87,12 +94,12 @@ impl HSTRING {
         for (index, wide) in iter.enumerate() {
             debug_assert!((index as u32) < len);
 
-            std::ptr::write((*ptr).data.add(index), wide);
+            core::ptr::write((*ptr).data.add(index), wide);
             (*ptr).len = index as u32 + 1;
         }
 
         // Write a 0 byte to the end of the buffer.
-        std::ptr::write((*ptr).data.offset((*ptr).len as isize), 0);
+        core::ptr::write((*ptr).data.offset((*ptr).len as isize), 0);
         Self(ptr)
     }
 }
@@ -123,7 +130,7 @@ impl Clone for HSTRING {
             return Self::new();
         }
 
-        unsafe { Self((*self.0).duplicate()) }
+        unsafe { Self(Header::duplicate(self.0)) }
     }
 }
 
@@ -136,25 +143,31 @@ impl Drop for HSTRING {
 unsafe impl Send for HSTRING {}
 unsafe impl Sync for HSTRING {}
 
-impl std::fmt::Display for HSTRING {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        use std::fmt::Write;
-        for c in std::char::decode_utf16(self.as_wide().iter().cloned()) {
-            f.write_char(c.map_err(|_| std::fmt::Error)?)?
+impl core::fmt::Display for HSTRING {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+        // Unpaired surrogates are displayed as the replacement character, as by `String::from_utf16_lossy`.
+        for c in core::char::decode_utf16(self.as_wide().iter().cloned()) {
+            f.write_char(c.unwrap_or(core::char::REPLACEMENT_CHARACTER))?
         }
         Ok(())
     }
 }
 
-impl std::fmt::Debug for HSTRING {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for HSTRING {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", self)
     }
 }
 
 impl From<&str> for HSTRING {
     fn from(value: &str) -> Self {
-        unsafe { Self::from_wide_iter(value.encode_utf16(), value.len() as u32) }
+        // Short strings are converted on the stack first so that the allocation fits the string,
+        // rather than its length in UTF-8.
+        match WideBuffer::new(value) {
+            Some(buffer) => Self::from_wide(buffer.as_wide()),
+            None => unsafe { Self::from_wide_iter(value.encode_utf16(), value.len() as u32) },
+        }
     }
 }
 
@@ -176,6 +189,14 @@ impl PartialEq for HSTRING {
     }
 }
 
+impl Eq for HSTRING {}
+
+impl core::hash::Hash for HSTRING {
+    fn hash<H: core::hash::Hasher>(&self, hasher: &mut H) {
+        self.as_wide().hash(hasher)
+    }
+}
+
 impl PartialEq<String> for HSTRING {
     fn eq(&self, other: &String) -> bool {
         self == other.as_str()
@@ -184,13 +205,13 @@ impl PartialEq<String> for HSTRING {
 
 impl PartialEq<str> for HSTRING {
     fn eq(&self, other: &str) -> bool {
-        self == other
+        self.as_wide().iter().copied().eq(other.encode_utf16())
     }
 }
 
 impl PartialEq<&str> for HSTRING {
     fn eq(&self, other: &&str) -> bool {
-        self.as_wide().iter().copied().eq(other.encode_utf16())
+        self == *other
     }
 }
 
@@ -200,8 +221,20 @@ impl PartialEq<HSTRING> for &str {
     }
 }
 
+impl PartialEq<HSTRING> for str {
+    fn eq(&self, other: &HSTRING) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<HSTRING> for String {
+    fn eq(&self, other: &HSTRING) -> bool {
+        other == self
+    }
+}
+
 impl<'a> TryFrom<&'a HSTRING> for String {
-    type Error = std::string::FromUtf16Error;
+    type Error = alloc::string::FromUtf16Error;
 
     fn try_from(hstring: &HSTRING) -> StdResult<Self, Self::Error> {
         String::from_utf16(hstring.as_wide())
@@ -209,7 +242,7 @@ impl<'a> TryFrom<&'a HSTRING> for String {
 }
 
 impl TryFrom<HSTRING> for String {
-    type Error = std::string::FromUtf16Error;
+    type Error = alloc::string::FromUtf16Error;
 
     fn try_from(hstring: HSTRING) -> StdResult<Self, Self::Error> {
         String::try_from(&hstring)
@@ -218,6 +251,33 @@ impl TryFrom<HSTRING> for String {
 
 const REFERENCE_FLAG: u32 = 1;
 
+/// The header of a "fast pass" string backed by static data. This is created by the
+/// [`h`](crate::h) macro and should not be used directly.
+#[doc(hidden)]
+#[repr(C)]
+pub struct HStringReference {
+    flags: u32,
+    len: u32,
+    _0: u32,
+    _1: u32,
+    data: *const u16,
+}
+
+impl HStringReference {
+    pub const fn new(data: *const u16, len: u32) -> Self {
+        Self {
+            flags: REFERENCE_FLAG,
+            len,
+            _0: 0,
+            _1: 0,
+            data,
+        }
+    }
+}
+
+// The data is immutable and has static lifetime.
+unsafe impl Sync for HStringReference {}
+
 #[repr(C)]
 pub struct Header {
     flags: u32,
@@ -225,7 +285,7 @@ pub struct Header {
     _0: u32,
     _1: u32,
     data: *mut u16,
-    shared: std::mem::MaybeUninit<Shared>,
+    shared: core::mem::MaybeUninit<Shared>,
 }
 
 #[repr(C)]
@@ -238,7 +298,7 @@ impl Header {
     fn alloc(len: u32) -> *mut Header {
         debug_assert!(len != 0);
         // Allocate enough space for header and two bytes per character.
-        let alloc_size = std::mem::size_of::<Header>() + 2 * len as usize;
+        let alloc_size = core::mem::size_of::<Header>() + 2 * len as usize;
 
         let header = heap_alloc(alloc_size) as *mut Header;
 
@@ -255,19 +315,18 @@ impl Header {
         header
     }
 
-    fn duplicate(&mut self) -> *mut Header {
-        if self.flags & REFERENCE_FLAG == 0 {
+    // A "fast pass" header only has the leading fields of `Header`, so this must not form a
+    // reference to the entire header until it knows the string is reference counted.
+    unsafe fn duplicate(header: *mut Header) -> *mut Header {
+        if (*header).flags & REFERENCE_FLAG == 0 {
             // If this is not a "fast pass" string then simply increment the reference count.
-            unsafe {
-                (*self.shared.as_ptr()).count.add_ref();
-                self
-            }
+            (*(*header).shared.as_ptr()).count.add_ref();
+            header
         } else {
             // Otherwise, allocate a new string and copy the value into the new string.
-            let copy = Header::alloc(self.len);
-            unsafe {
-                std::ptr::copy_nonoverlapping(self.data, (*copy).data, self.len as usize + 1);
-            }
+            let len = (*header).len;
+            let copy = Header::alloc(len);
+            core::ptr::copy_nonoverlapping((*header).data, (*copy).data, len as usize + 1);
             copy
         }
     }
@@ -315,6 +374,21 @@ mod tests {
     fn display_format() {
         let value = StringType::from("Hello world");
         assert!(format!("{}", value) == "Hello world");
+
+        // An unpaired surrogate doesn't stop the rest of the string from being displayed.
+        let value = StringType::from_wide(&[0x48, 0xD800, 0x69]);
+        assert!(value.to_string() == "H\u{FFFD}i");
+    }
+
+    #[test]
+    fn compare_strings() {
+        let value = StringType::from("Hello");
+        assert!(value == *"Hello");
+        assert!(*"Hello" == value);
+        assert!("Hello" == value);
+        assert!(String::from("Hello") == value);
+        assert!(value == String::from("Hello"));
+        assert!(String::from("World") != value);
     }
 
     #[test]
@@ -326,7 +400,7 @@ mod tests {
     #[test]
     fn abi_transfer() {
         fn perform_transfer(from: StringType, to: &mut StringType) {
-            let from = std::mem::ManuallyDrop::new(from);
+            let from = core::mem::ManuallyDrop::new(from);
             unsafe {
                 let to = to.set_abi();
                 let from = from.abi();
@@ -341,6 +415,22 @@ mod tests {
         assert!(format!("{}", to) == "Hello");
     }
 
+    #[test]
+    fn reference() {
+        let hello: &StringType = crate::h!("Hello");
+        assert!(!hello.is_empty());
+        assert!(hello.len() == 5);
+        assert!(*hello == "Hello");
+
+        let clone = hello.clone();
+        assert!(clone == "Hello");
+        assert!(clone.as_wide().as_ptr() != hello.as_wide().as_ptr());
+
+        let empty: &StringType = crate::h!("");
+        assert!(empty.is_empty());
+        assert!(empty.clone().is_empty());
+    }
+
     #[test]
     fn from_empty_string() {
         let h = StringType::from("");
@@ -0,0 +1,36 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::{E_NOINTERFACE, HWND},
+    UI::Shell::IInitializeWithWindow,
+};
+
+/// Associates an object that shows UI, such as a `FileOpenPicker`, `FolderPicker`, or
+/// `DevicePicker`, with the window of a desktop app that owns the UI.
+///
+/// Such objects are owned by the `CoreWindow` of UWP apps, but desktop apps have no `CoreWindow`
+/// and the objects fail when shown until they are initialized with a window. Objects requiring a
+/// window are those implementing the `IInitializeWithWindow` interop interface, which may be
+/// checked with [`requires_window`]. Other objects fail with `E_NOINTERFACE`.
+///
+/// ```ignore
+/// let picker = FileOpenPicker::new()?;
+/// windows::initialize_with_window(&picker, hwnd.0)?;
+/// picker.FileTypeFilter()?.Append("*")?;
+/// let file = picker.PickSingleFileAsync()?.await?;
+/// ```
+pub fn initialize_with_window<T: Interface>(object: &T, window: isize) -> Result<()> {
+    match object.cast::<IInitializeWithWindow>() {
+        Ok(initialize) => unsafe { initialize.Initialize(HWND(window)) },
+        Err(error) if error.code() == E_NOINTERFACE => Err(Error::new(
+            E_NOINTERFACE,
+            "The object cannot be initialized with a window",
+        )),
+        Err(error) => Err(error),
+    }
+}
+
+/// Returns `true` if the object must be initialized with [`initialize_with_window`] before
+/// showing its UI from a desktop app.
+pub fn requires_window<T: Interface>(object: &T) -> bool {
+    object.cast::<IInitializeWithWindow>().is_ok()
+}
@@ -0,0 +1,307 @@
+#![allow(non_snake_case)]
+
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::{BOOL, E_NOINTERFACE},
+    System::Com::{CoRegisterMallocSpy, CoRevokeMallocSpy, IMallocSpy, IMallocSpy_abi},
+};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::ffi::c_void;
+use std::sync::{Arc, Mutex};
+
+type Allocations = Arc<Mutex<BTreeMap<usize, Allocation>>>;
+
+thread_local! {
+    // The block being reallocated and the size requested by the allocation in progress on this
+    // thread, between the spy's `Pre*` and `Post*` notifications.
+    static PENDING: Cell<(usize, usize)> = Cell::new((0, 0));
+}
+
+/// A block of memory allocated by the COM task allocator, such as with `CoTaskMemAlloc`, that has
+/// not yet been freed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Allocation {
+    /// The address of the block.
+    pub address: usize,
+    /// The size of the block in bytes.
+    pub size: usize,
+    /// The name of the generated function most recently called on the allocating thread, if the
+    /// bindings are generated with `api_stats = true`.
+    pub api: Option<&'static str>,
+}
+
+/// Tracks the memory allocated by the COM task allocator until dropped so that leaks can be found
+/// while testing, by registering an `IMallocSpy` with COM.
+///
+/// Allocations are tracked across all threads of the process. Each allocation is attributed to
+/// the generated function most recently called on the allocating thread, which is usually the
+/// function that returned the memory to be freed by the caller. Calls are only recorded by
+/// bindings generated with `api_stats = true` when the crate containing them enables its
+/// `api_stats` feature, and calls to interface methods are not recorded.
+///
+/// Only one spy may be registered by a process at a time.
+///
+/// ```ignore
+/// let check = windows::LeakCheck::start()?;
+/// run_scenario()?;
+///
+/// for allocation in check.outstanding() {
+///     println!("{:?}", allocation);
+/// }
+/// ```
+#[must_use]
+pub struct LeakCheck {
+    allocations: Allocations,
+}
+
+impl LeakCheck {
+    /// Registers the spy, failing if a spy is already registered by the process.
+    pub fn start() -> Result<Self> {
+        let allocations = Allocations::default();
+
+        let spy = Box::new(Spy {
+            vtable: &Spy::VTABLE,
+            count: RefCount::new(1),
+            allocations: allocations.clone(),
+        });
+
+        // COM holds its own reference to the spy until it is revoked and every block allocated
+        // while it was registered has been freed.
+        let spy: IMallocSpy = unsafe { std::mem::transmute(spy) };
+        unsafe { CoRegisterMallocSpy(&spy)? };
+
+        Ok(Self { allocations })
+    }
+
+    /// Returns the blocks allocated since the spy was registered that have not yet been freed,
+    /// sorted by address.
+    pub fn outstanding(&self) -> Vec<Allocation> {
+        lock(&self.allocations).values().cloned().collect()
+    }
+}
+
+impl Drop for LeakCheck {
+    fn drop(&mut self) {
+        unsafe {
+            // Revoking fails if blocks allocated while the spy was registered have not been freed
+            // in which case COM releases the spy once they have been.
+            let _ = CoRevokeMallocSpy();
+        }
+    }
+}
+
+// The allocator may be called from any thread, including while another thread is panicking.
+fn lock(allocations: &Allocations) -> std::sync::MutexGuard<'_, BTreeMap<usize, Allocation>> {
+    allocations
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+fn set_pending(previous: usize, size: usize) {
+    let _ = PENDING.try_with(|pending| pending.set((previous, size)));
+}
+
+fn take_pending() -> (usize, usize) {
+    PENDING
+        .try_with(|pending| pending.replace((0, 0)))
+        .unwrap_or((0, 0))
+}
+
+#[repr(C)]
+struct Spy {
+    vtable: *const IMallocSpy_abi,
+    count: RefCount,
+    allocations: Allocations,
+}
+
+impl Spy {
+    const VTABLE: IMallocSpy_abi = IMallocSpy_abi(
+        Self::QueryInterface,
+        Self::AddRef,
+        Self::Release,
+        Self::PreAlloc,
+        Self::PostAlloc,
+        Self::PreFree,
+        Self::PostFree,
+        Self::PreRealloc,
+        Self::PostRealloc,
+        Self::PreGetSize,
+        Self::PostGetSize,
+        Self::PreDidAlloc,
+        Self::PostDidAlloc,
+        Self::PreHeapMinimize,
+        Self::PostHeapMinimize,
+    );
+
+    unsafe fn from_ptr<'a>(this: RawPtr) -> &'a Self {
+        &*(this as *const Self)
+    }
+
+    fn insert(&self, address: usize, size: usize) {
+        let allocation = Allocation {
+            address,
+            size,
+            api: last_call(),
+        };
+
+        lock(&self.allocations).insert(address, allocation);
+    }
+
+    fn remove(&self, address: usize) {
+        lock(&self.allocations).remove(&address);
+    }
+
+    unsafe extern "system" fn QueryInterface(
+        this: RawPtr,
+        iid: &Guid,
+        interface: *mut RawPtr,
+    ) -> HRESULT {
+        if iid == &IUnknown::IID || iid == &IMallocSpy::IID {
+            Self::from_ptr(this).count.add_ref();
+            *interface = this;
+            HRESULT(0)
+        } else {
+            *interface = std::ptr::null_mut();
+            E_NOINTERFACE
+        }
+    }
+
+    unsafe extern "system" fn AddRef(this: RawPtr) -> u32 {
+        Self::from_ptr(this).count.add_ref()
+    }
+
+    unsafe extern "system" fn Release(this: RawPtr) -> u32 {
+        let remaining = Self::from_ptr(this).count.release();
+
+        if remaining == 0 {
+            drop(Box::from_raw(this as *mut Self));
+        }
+
+        remaining
+    }
+
+    // The spy doesn't add any header to the blocks so requests and results are passed through
+    // unchanged.
+
+    unsafe extern "system" fn PreAlloc(_: RawPtr, size: usize) -> usize {
+        set_pending(0, size);
+        size
+    }
+
+    unsafe extern "system" fn PostAlloc(this: RawPtr, actual: *mut c_void) -> *mut c_void {
+        let (_, size) = take_pending();
+
+        if !actual.is_null() {
+            Self::from_ptr(this).insert(actual as usize, size);
+        }
+
+        actual
+    }
+
+    unsafe extern "system" fn PreFree(
+        this: RawPtr,
+        request: *mut c_void,
+        spyed: BOOL,
+    ) -> *mut c_void {
+        if spyed.as_bool() && !request.is_null() {
+            Self::from_ptr(this).remove(request as usize);
+        }
+
+        request
+    }
+
+    unsafe extern "system" fn PostFree(_: RawPtr, _: BOOL) {}
+
+    unsafe extern "system" fn PreRealloc(
+        _: RawPtr,
+        request: *mut c_void,
+        size: usize,
+        new_request: *mut *mut c_void,
+        spyed: BOOL,
+    ) -> usize {
+        // A block allocated before the spy was registered isn't tracked, but the block that
+        // replaces it is.
+        set_pending(if spyed.as_bool() { request as usize } else { 0 }, size);
+        *new_request = request;
+        size
+    }
+
+    unsafe extern "system" fn PostRealloc(
+        this: RawPtr,
+        actual: *mut c_void,
+        _: BOOL,
+    ) -> *mut c_void {
+        let spy = Self::from_ptr(this);
+        let (previous, size) = take_pending();
+
+        // Reallocating to zero bytes frees the block, while a failed reallocation leaves the
+        // original block in place.
+        if !actual.is_null() || size == 0 {
+            if previous != 0 {
+                spy.remove(previous);
+            }
+
+            if !actual.is_null() {
+                spy.insert(actual as usize, size);
+            }
+        }
+
+        actual
+    }
+
+    unsafe extern "system" fn PreGetSize(_: RawPtr, request: *mut c_void, _: BOOL) -> *mut c_void {
+        request
+    }
+
+    unsafe extern "system" fn PostGetSize(_: RawPtr, actual: usize, _: BOOL) -> usize {
+        actual
+    }
+
+    unsafe extern "system" fn PreDidAlloc(_: RawPtr, request: *mut c_void, _: BOOL) -> *mut c_void {
+        request
+    }
+
+    unsafe extern "system" fn PostDidAlloc(_: RawPtr, _: *mut c_void, _: BOOL, actual: i32) -> i32 {
+        actual
+    }
+
+    unsafe extern "system" fn PreHeapMinimize(_: RawPtr) {}
+
+    unsafe extern "system" fn PostHeapMinimize(_: RawPtr) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bindings::Windows::Win32::System::Com::{CoTaskMemAlloc, CoTaskMemFree};
+
+    #[test]
+    fn outstanding() -> Result<()> {
+        initialize_mta()?;
+        let check = LeakCheck::start()?;
+        assert!(LeakCheck::start().is_err());
+
+        unsafe {
+            let first = CoTaskMemAlloc(16);
+            let second = CoTaskMemAlloc(32);
+
+            let outstanding = check.outstanding();
+            assert!(outstanding
+                .iter()
+                .any(|a| a.address == first as usize && a.size == 16));
+            assert!(outstanding
+                .iter()
+                .any(|a| a.address == second as usize && a.size == 32));
+
+            CoTaskMemFree(first);
+            CoTaskMemFree(second);
+
+            let outstanding = check.outstanding();
+            assert!(outstanding.iter().all(|a| a.address != first as usize));
+            assert!(outstanding.iter().all(|a| a.address != second as usize));
+        }
+
+        Ok(())
+    }
+}
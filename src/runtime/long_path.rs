@@ -0,0 +1,196 @@
+use crate::*;
+use bindings::Windows::Win32::{Foundation::PWSTR, Storage::FileSystem::GetFullPathNameW};
+use std::path::{Path, PathBuf};
+
+// The longest path that every file API accepts without the extended-length prefix, which is
+// `MAX_PATH` less the room `CreateDirectoryW` reserves for an 8.3 file name.
+const MAX_SHORT_PATH: usize = 248;
+
+const VERBATIM: &[u16] = &[b'\\' as u16, b'\\' as u16, b'?' as u16, b'\\' as u16];
+const DEVICE: &[u16] = &[b'\\' as u16, b'\\' as u16, b'.' as u16, b'\\' as u16];
+const NT: &[u16] = &[b'\\' as u16, b'?' as u16, b'?' as u16, b'\\' as u16];
+const UNC: &[u16] = &[b'U' as u16, b'N' as u16, b'C' as u16, b'\\' as u16];
+
+/// Converts a path to a null-terminated wide string for a Win32 file API, adding the
+/// extended-length `\\?\` prefix if the path is too long to be passed as is.
+///
+/// Long paths are first made absolute and normalized, as extended-length paths are passed to the
+/// file system without further processing. UNC paths such as `\\server\share\file` become
+/// `\\?\UNC\server\share\file`. Paths that already have a `\\?\`, `\\.\`, or `\??\` prefix are
+/// never changed. If the path cannot be made absolute it is returned without a prefix, leaving
+/// the file API to report the error.
+///
+/// The generated bindings use this when a `Path` is passed for a `PWSTR` parameter.
+pub fn to_wide_path(path: &Path) -> Vec<u16> {
+    let wide = encode(path);
+
+    if wide.len() < MAX_SHORT_PATH || is_prefixed(&wide) {
+        return terminate(wide);
+    }
+
+    let wide = terminate(wide);
+
+    let full = match full_path(&wide) {
+        Some(full) => full,
+        None => return wide,
+    };
+
+    let mut result = Vec::with_capacity(full.len() + VERBATIM.len() + UNC.len() + 1);
+    result.extend_from_slice(VERBATIM);
+
+    if full.starts_with(&[b'\\' as u16, b'\\' as u16]) {
+        result.extend_from_slice(UNC);
+        result.extend_from_slice(&full[2..]);
+    } else {
+        result.extend_from_slice(&full);
+    }
+
+    terminate(result)
+}
+
+/// Converts a wide string returned by a Win32 file API to a path, ending at the first null
+/// character, if any, and removing an extended-length prefix added by [`to_wide_path`] so that
+/// `\\?\C:\file` becomes `C:\file` and `\\?\UNC\server\share` becomes `\\server\share`.
+///
+/// Other verbatim paths, such as `\\?\Volume{...}\file`, have no equivalent without the prefix
+/// and are returned unchanged.
+pub fn from_wide_path(wide: &[u16]) -> PathBuf {
+    let len = wide.iter().position(|c| *c == 0).unwrap_or(wide.len());
+    let wide = &wide[..len];
+
+    let path = if let Some(rest) = wide.strip_prefix(VERBATIM) {
+        if let Some(share) = rest.strip_prefix(UNC) {
+            let mut path = vec![b'\\' as u16, b'\\' as u16];
+            path.extend_from_slice(share);
+            path
+        } else if is_drive(rest) {
+            rest.to_vec()
+        } else {
+            wide.to_vec()
+        }
+    } else {
+        wide.to_vec()
+    };
+
+    decode(&path)
+}
+
+// Paths are only converted losslessly on Windows, where they may hold unpaired surrogates.
+#[cfg(windows)]
+fn encode(path: &Path) -> Vec<u16> {
+    std::os::windows::ffi::OsStrExt::encode_wide(path.as_os_str()).collect()
+}
+
+#[cfg(not(windows))]
+fn encode(path: &Path) -> Vec<u16> {
+    path.to_string_lossy().encode_utf16().collect()
+}
+
+#[cfg(windows)]
+fn decode(wide: &[u16]) -> PathBuf {
+    <std::ffi::OsString as std::os::windows::ffi::OsStringExt>::from_wide(wide).into()
+}
+
+#[cfg(not(windows))]
+fn decode(wide: &[u16]) -> PathBuf {
+    String::from_utf16_lossy(wide).into()
+}
+
+fn is_prefixed(wide: &[u16]) -> bool {
+    wide.starts_with(VERBATIM) || wide.starts_with(DEVICE) || wide.starts_with(NT)
+}
+
+// Whether the path starts with a drive letter followed by a root, as in `C:\`.
+fn is_drive(wide: &[u16]) -> bool {
+    matches!(wide, [letter, colon, slash, ..]
+        if *letter < 0x80
+            && (*letter as u8).is_ascii_alphabetic()
+            && *colon == b':' as u16
+            && *slash == b'\\' as u16)
+}
+
+fn terminate(mut wide: Vec<u16>) -> Vec<u16> {
+    wide.push(0);
+    wide
+}
+
+// Returns the absolute and normalized form of the null-terminated path, without a terminator.
+fn full_path(wide: &[u16]) -> Option<Vec<u16>> {
+    let mut buffer = Vec::new();
+
+    // The buffer is grown until the full path fits, as the current directory may change between
+    // calls.
+    loop {
+        let len = unsafe {
+            GetFullPathNameW(
+                PWSTR(wide.as_ptr() as _),
+                buffer.len() as u32,
+                PWSTR(buffer.as_mut_ptr()),
+                std::ptr::null_mut(),
+            )
+        } as usize;
+
+        if len == 0 {
+            return None;
+        }
+
+        // The length excludes the terminator when the path fits and includes it otherwise.
+        if len < buffer.len() {
+            buffer.truncate(len);
+            return Some(buffer);
+        }
+
+        buffer.resize(len, 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wide(value: &str) -> Vec<u16> {
+        value.encode_utf16().chain(std::iter::once(0)).collect()
+    }
+
+    #[test]
+    fn short() {
+        assert_eq!(to_wide_path(Path::new(r"C:\file")), wide(r"C:\file"));
+        assert_eq!(to_wide_path(Path::new("file")), wide("file"));
+    }
+
+    #[test]
+    fn long() {
+        let name = "a".repeat(MAX_SHORT_PATH);
+
+        assert_eq!(
+            to_wide_path(Path::new(&format!(r"C:\dir\..\{}", name))),
+            wide(&format!(r"\\?\C:\{}", name))
+        );
+
+        assert_eq!(
+            to_wide_path(Path::new(&format!(r"\\server\share/{}", name))),
+            wide(&format!(r"\\?\UNC\server\share\{}", name))
+        );
+
+        let relative = to_wide_path(Path::new(&name));
+        let current = std::env::current_dir().unwrap().join(&name);
+        assert_eq!(from_wide_path(&relative), current);
+
+        let prefixed = format!(r"\\?\C:\{}\..", name);
+        assert_eq!(to_wide_path(Path::new(&prefixed)), wide(&prefixed));
+    }
+
+    #[test]
+    fn from_wide() {
+        assert_eq!(from_wide_path(&wide(r"\\?\C:\file")), Path::new(r"C:\file"));
+        assert_eq!(
+            from_wide_path(&wide(r"\\?\UNC\server\share\file")),
+            Path::new(r"\\server\share\file")
+        );
+        assert_eq!(
+            from_wide_path(&wide(r"\\?\Volume{1}\file")),
+            Path::new(r"\\?\Volume{1}\file")
+        );
+        assert_eq!(from_wide_path(&wide(r"C:\file")), Path::new(r"C:\file"));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::{E_NOINTERFACE, E_OUTOFMEMORY},
+    System::Com::{CoCreateFreeThreadedMarshaler, CoTaskMemAlloc},
+};
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+// The IID of `IMarshal`, which is answered by the free-threaded marshaler.
+const IMARSHAL: Guid = Guid::from_values(
+    0x0000_0003,
+    0x0000,
+    0x0000,
+    [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+);
+
+/// The free-threaded marshaler aggregated by implementations that opt in with
+/// `#[implement(marshal = free_threaded)]`.
+///
+/// The marshaler is only created the first time `IMarshal` is requested, as most objects are never
+/// marshaled.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct FreeThreadedMarshaler(AtomicPtr<core::ffi::c_void>);
+
+impl FreeThreadedMarshaler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers a query for `IMarshal` on behalf of the controlling `outer` object.
+    ///
+    /// # Safety
+    /// `outer` must be the identity `IUnknown` of the object that owns this marshaler.
+    pub unsafe fn query(&self, outer: RawPtr, iid: &Guid, interface: *mut RawPtr) -> HRESULT {
+        if iid != &IMARSHAL {
+            return E_NOINTERFACE;
+        }
+
+        let mut inner = self.0.load(Ordering::Acquire);
+
+        if inner.is_null() {
+            // The outer object is borrowed rather than owned since an aggregated object must not
+            // hold a reference to its controlling unknown.
+            let outer: &IUnknown = &*(&outer as *const RawPtr as *const IUnknown);
+
+            let created = match CoCreateFreeThreadedMarshaler(outer) {
+                Ok(created) => core::mem::transmute::<IUnknown, RawPtr>(created),
+                Err(error) => return error.code(),
+            };
+
+            match self.0.compare_exchange(
+                core::ptr::null_mut(),
+                created,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => inner = created,
+                Err(existing) => {
+                    // Another thread won the race so its marshaler is used instead.
+                    drop(core::mem::transmute::<RawPtr, IUnknown>(created));
+                    inner = existing;
+                }
+            }
+        }
+
+        let inner: &IUnknown = &*(&inner as *const RawPtr as *const IUnknown);
+        inner.query(iid, interface)
+    }
+}
+
+impl Drop for FreeThreadedMarshaler {
+    fn drop(&mut self) {
+        let inner = *self.0.get_mut();
+
+        if !inner.is_null() {
+            unsafe { drop(core::mem::transmute::<RawPtr, IUnknown>(inner)) }
+        }
+    }
+}
+
+/// Returns a copy of `iids` allocated with `CoTaskMemAlloc`, as required by `IInspectable::GetIids`.
+///
+/// # Safety
+/// `count` and `values` must be valid for writes.
+#[doc(hidden)]
+pub unsafe fn copy_iids(iids: &[Guid], count: *mut u32, values: *mut *mut Guid) -> HRESULT {
+    *count = 0;
+    *values = core::ptr::null_mut();
+
+    if iids.is_empty() {
+        return HRESULT(0);
+    }
+
+    let buffer = CoTaskMemAlloc(core::mem::size_of_val(iids)) as *mut Guid;
+
+    if buffer.is_null() {
+        return E_OUTOFMEMORY;
+    }
+
+    core::ptr::copy_nonoverlapping(iids.as_ptr(), buffer, iids.len());
+    *count = iids.len() as u32;
+    *values = buffer;
+    HRESULT(0)
+}
@@ -0,0 +1,93 @@
+use crate::*;
+use bindings::Windows::{
+    Foundation::{IMemoryBuffer, IMemoryBufferReference},
+    Win32::System::WinRT::IMemoryBufferByteAccess,
+};
+
+/// The bytes of a WinRT memory buffer, such as the pixel data of a `SoftwareBitmap` or the
+/// samples of an `AudioFrame`, borrowed through an `IMemoryBufferReference` as a `[u8]`.
+///
+/// The reference is closed when the bytes are dropped, releasing the lock that the buffer's
+/// owner holds while any reference is open.
+///
+/// ```ignore
+/// let buffer = bitmap.LockBuffer(BitmapBufferAccessMode::ReadWrite)?;
+/// let mut bytes = windows::MemoryBufferBytes::from_buffer(&buffer)?;
+///
+/// for pixel in bytes.chunks_exact_mut(4) {
+///     pixel[3] = 255;
+/// }
+/// ```
+pub struct MemoryBufferBytes {
+    reference: IMemoryBufferReference,
+    data: *mut u8,
+    len: usize,
+}
+
+impl MemoryBufferBytes {
+    /// Borrows the bytes of an open `IMemoryBufferReference`, which is closed when the bytes are
+    /// dropped.
+    pub fn new<T: Interface>(reference: &T) -> Result<Self> {
+        let reference: IMemoryBufferReference = reference.cast()?;
+        let access: IMemoryBufferByteAccess = reference.cast()?;
+
+        let mut data = std::ptr::null_mut();
+        let mut len = 0;
+
+        unsafe {
+            access.GetBuffer(&mut data, &mut len)?;
+        }
+
+        Ok(Self {
+            reference,
+            data,
+            len: len as usize,
+        })
+    }
+
+    /// Creates a reference to an `IMemoryBuffer`, such as a `BitmapBuffer` or an `AudioBuffer`,
+    /// and borrows its bytes.
+    pub fn from_buffer<T: Interface>(buffer: &T) -> Result<Self> {
+        let buffer: IMemoryBuffer = buffer.cast()?;
+        Self::new(&buffer.CreateReference()?)
+    }
+
+    /// Closes the reference, reporting any error that dropping the bytes would ignore.
+    pub fn close(self) -> Result<()> {
+        let bytes = std::mem::ManuallyDrop::new(self);
+
+        // SAFETY: the reference is moved out of the bytes, which are never dropped
+        let reference = unsafe { std::ptr::read(&bytes.reference) };
+        reference.Close()
+    }
+}
+
+impl std::ops::Deref for MemoryBufferBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        if self.data.is_null() {
+            return &[];
+        }
+
+        // SAFETY: the buffer stays valid until the reference is closed
+        unsafe { std::slice::from_raw_parts(self.data, self.len) }
+    }
+}
+
+impl std::ops::DerefMut for MemoryBufferBytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        if self.data.is_null() {
+            return &mut [];
+        }
+
+        // SAFETY: the buffer stays valid until the reference is closed
+        unsafe { std::slice::from_raw_parts_mut(self.data, self.len) }
+    }
+}
+
+impl Drop for MemoryBufferBytes {
+    fn drop(&mut self) {
+        let _ = self.reference.Close();
+    }
+}
@@ -1,25 +1,179 @@
+// The core of the runtime only depends on `core` and `alloc`, while the rest of it requires the
+// `std` feature.
+#[cfg(feature = "std")]
+mod apartment_checked;
+#[cfg(feature = "std")]
+mod api_counter;
+mod api_information;
+#[cfg(feature = "std")]
+mod app_user_model;
 mod array;
+#[cfg(feature = "std")]
+mod async_future;
+mod async_operation;
+#[cfg(feature = "std")]
+mod cancellation;
+#[cfg(feature = "std")]
+mod collections;
 mod com;
 mod delay_load;
+#[cfg(feature = "std")]
+mod device_info;
+#[cfg(feature = "std")]
+mod device_io;
+#[cfg(feature = "std")]
+mod device_pairing;
+#[cfg(feature = "std")]
+mod dispatch;
+#[cfg(feature = "std")]
+mod dispatcher_queue;
+#[cfg(feature = "std")]
+mod error_mode;
+#[cfg(feature = "std")]
+mod event;
+#[cfg(feature = "std")]
+mod event_sink;
+#[cfg(feature = "std")]
+mod event_stream;
 mod factory_cache;
+#[cfg(feature = "std")]
+mod fiber;
+#[cfg(feature = "std")]
+mod file_info;
+#[cfg(feature = "std")]
+mod file_system;
 mod guid;
 mod heap;
+#[cfg(feature = "std")]
+mod hid;
 mod hstring;
+#[cfg(feature = "std")]
+mod initialize_with_window;
+#[cfg(feature = "std")]
+mod leak_check;
+#[cfg(feature = "std")]
+mod long_path;
+mod marshaler;
+#[cfg(feature = "std")]
+mod memory_buffer;
+#[cfg(feature = "std")]
+mod module;
+#[cfg(not(feature = "std"))]
+mod no_std;
+#[cfg(feature = "std")]
+mod object_tracker;
 mod param;
+#[cfg(feature = "std")]
+mod prop_variant;
+#[cfg(feature = "std")]
+mod property_changed;
+#[cfg(feature = "std")]
+mod property_store;
 mod ref_count;
+#[cfg(feature = "std")]
+mod registration;
+#[cfg(feature = "std")]
+mod running_object;
+mod safe_array;
+#[cfg(feature = "std")]
+mod shutdown;
+mod signature;
+mod single_threaded_ref_count;
+#[cfg(feature = "std")]
+mod stream_io;
+#[cfg(feature = "std")]
+mod usn_journal;
 mod waiter;
 mod weak;
 mod weak_ref_count;
+mod wide_buffer;
+mod wide_str;
 
+#[cfg(feature = "std")]
+pub use apartment_checked::*;
+#[cfg(feature = "std")]
+pub use api_counter::*;
+pub use api_information::*;
+#[cfg(feature = "std")]
+pub use app_user_model::*;
 pub use array::*;
+#[cfg(feature = "std")]
+pub use async_future::*;
+pub use async_operation::*;
+#[cfg(feature = "std")]
+pub use cancellation::*;
+#[cfg(feature = "std")]
+pub use collections::*;
 pub use com::*;
 pub use delay_load::*;
+#[cfg(feature = "std")]
+pub use device_info::*;
+#[cfg(feature = "std")]
+pub use device_io::*;
+#[cfg(feature = "std")]
+pub use device_pairing::*;
+#[cfg(feature = "std")]
+pub use dispatch::*;
+#[cfg(feature = "std")]
+pub use dispatcher_queue::*;
+#[cfg(feature = "std")]
+pub use error_mode::*;
+#[cfg(feature = "std")]
+pub use event::*;
+#[cfg(feature = "std")]
+pub use event_sink::*;
+#[cfg(feature = "std")]
+pub use event_stream::*;
 pub use factory_cache::*;
+#[cfg(feature = "std")]
+pub use fiber::*;
+#[cfg(feature = "std")]
+pub use file_info::*;
+#[cfg(feature = "std")]
+pub use file_system::*;
 pub use guid::*;
 pub use heap::*;
+#[cfg(feature = "std")]
+pub use hid::*;
 pub use hstring::*;
+#[cfg(feature = "std")]
+pub use initialize_with_window::*;
+#[cfg(feature = "std")]
+pub use leak_check::*;
+#[cfg(feature = "std")]
+pub use long_path::*;
+pub use marshaler::*;
+#[cfg(feature = "std")]
+pub use memory_buffer::*;
+#[cfg(feature = "std")]
+pub use module::*;
+#[cfg(not(feature = "std"))]
+pub use no_std::*;
+#[cfg(feature = "std")]
+pub use object_tracker::*;
 pub use param::*;
+#[cfg(feature = "std")]
+pub use prop_variant::*;
+#[cfg(feature = "std")]
+pub use property_changed::*;
+#[cfg(feature = "std")]
+pub use property_store::*;
 pub use ref_count::*;
+#[cfg(feature = "std")]
+pub use registration::*;
+#[cfg(feature = "std")]
+pub use running_object::*;
+pub use safe_array::*;
+#[cfg(feature = "std")]
+pub use shutdown::*;
+pub use signature::*;
+pub use single_threaded_ref_count::*;
+#[cfg(feature = "std")]
+pub use stream_io::*;
+#[cfg(feature = "std")]
+pub use usn_journal::*;
 pub use waiter::*;
 pub use weak::*;
 pub use weak_ref_count::*;
+pub use wide_buffer::*;
+pub use wide_str::*;
@@ -0,0 +1,336 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::{BOOL, HANDLE, HINSTANCE, PWSTR},
+    System::ApplicationInstallationAndServicing::{
+        ActivateActCtx, CreateActCtxW, DeactivateActCtx, ReleaseActCtx, ACTCTXW,
+    },
+    System::Diagnostics::Debug::ERROR_RESOURCE_TYPE_NOT_FOUND,
+    System::LibraryLoader::{
+        EnumResourceNamesW, FindResourceW, GetModuleFileNameW, GetModuleHandleExW, LoadResource,
+        LockResource, SizeofResource, GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS,
+        GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+    },
+    System::WindowsProgramming::{ACTCTX_FLAG_HMODULE_VALID, ACTCTX_FLAG_RESOURCE_NAME_VALID},
+};
+use std::marker::PhantomData;
+
+/// An executable or DLL loaded into the current process.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Module(isize);
+
+impl Module {
+    /// Returns the module containing the calling code.
+    ///
+    /// When this crate is linked into a DLL this is the DLL rather than the process's executable,
+    /// as required when registering window classes or COM servers implemented by the DLL.
+    pub fn current() -> Result<Self> {
+        // Any address within this crate's code identifies the module that it was linked into.
+        Self::from_address(Self::current as fn() -> Result<Self> as usize)
+    }
+
+    /// Returns the module containing the given code or data address, such as a return address
+    /// captured from the stack.
+    pub fn from_address(address: usize) -> Result<Self> {
+        let mut module = HINSTANCE::default();
+
+        unsafe {
+            GetModuleHandleExW(
+                GET_MODULE_HANDLE_EX_FLAG_FROM_ADDRESS
+                    | GET_MODULE_HANDLE_EX_FLAG_UNCHANGED_REFCOUNT,
+                PWSTR(address as *mut u16),
+                &mut module,
+            )
+            .ok()?;
+        }
+
+        Ok(Self(module.0))
+    }
+
+    /// Creates a `Module` from a module handle.
+    ///
+    /// # Safety
+    /// The handle must refer to a module that remains loaded for as long as the `Module` is used.
+    pub unsafe fn from_raw(handle: isize) -> Self {
+        Self(handle)
+    }
+
+    /// Returns the module handle.
+    pub fn as_raw(&self) -> isize {
+        self.0
+    }
+
+    /// Returns the full path of the module's file.
+    pub fn file_name(&self) -> Result<String> {
+        let mut buffer = vec![0u16; 260];
+
+        loop {
+            let len = unsafe {
+                GetModuleFileNameW(
+                    HINSTANCE(self.0),
+                    PWSTR(buffer.as_mut_ptr()),
+                    buffer.len() as u32,
+                )
+            };
+
+            if len == 0 {
+                return Err(HRESULT::from_thread().into());
+            }
+
+            // The path is truncated if it does not fit within the buffer.
+            if (len as usize) < buffer.len() {
+                return Ok(String::from_utf16_lossy(&buffer[..len as usize]));
+            }
+
+            buffer.resize(buffer.len() * 2, 0);
+        }
+    }
+
+    /// Returns a copy of the data of the resource with the given type and name.
+    pub fn resource(&self, kind: &ResourceId, name: &ResourceId) -> Result<Vec<u8>> {
+        unsafe {
+            let module = HINSTANCE(self.0);
+            let info =
+                name.with_pwstr(|name| kind.with_pwstr(|kind| FindResourceW(module, name, kind)));
+
+            if info.0 == 0 {
+                return Err(HRESULT::from_thread().into());
+            }
+
+            let len = SizeofResource(module, info) as usize;
+            let data = LockResource(LoadResource(module, info)) as *const u8;
+
+            if data.is_null() {
+                return Err(HRESULT::from_thread().into());
+            }
+
+            Ok(std::slice::from_raw_parts(data, len).to_vec())
+        }
+    }
+
+    /// Returns the names of the resources of the given type.
+    pub fn resource_names(&self, kind: &ResourceId) -> Result<Vec<ResourceId>> {
+        unsafe extern "system" fn callback(
+            _: HINSTANCE,
+            _: PWSTR,
+            name: PWSTR,
+            names: isize,
+        ) -> BOOL {
+            (*(names as *mut Vec<ResourceId>)).push(ResourceId::from_pwstr(name));
+            BOOL(1)
+        }
+
+        let mut names = Vec::new();
+
+        let result = kind.with_pwstr(|kind| unsafe {
+            EnumResourceNamesW(
+                HINSTANCE(self.0),
+                kind,
+                Some(callback),
+                &mut names as *mut Vec<ResourceId> as isize,
+            )
+        });
+
+        if !result.as_bool() {
+            let error = HRESULT::from_thread();
+
+            // A module without any resources of the given type is not an error.
+            if error != HRESULT::from_win32(ERROR_RESOURCE_TYPE_NOT_FOUND.0) {
+                return Err(error.into());
+            }
+        }
+
+        Ok(names)
+    }
+}
+
+/// Identifies a resource or resource type by either integer or name.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ResourceId {
+    Integer(u16),
+    Name(String),
+}
+
+impl ResourceId {
+    /// Application-defined raw data, the `RT_RCDATA` resource type.
+    pub const RCDATA: Self = Self::Integer(10);
+
+    /// Version information, the `RT_VERSION` resource type.
+    pub const VERSION: Self = Self::Integer(16);
+
+    /// Side-by-side assembly manifests, the `RT_MANIFEST` resource type.
+    pub const MANIFEST: Self = Self::Integer(24);
+
+    // Integer resource identifiers are passed in place of a string pointer, as with the
+    // `MAKEINTRESOURCE` macro.
+    fn with_pwstr<R, F: FnOnce(PWSTR) -> R>(&self, f: F) -> R {
+        match self {
+            Self::Integer(value) => f(PWSTR(*value as usize as *mut u16)),
+            Self::Name(value) => {
+                let mut value: Vec<u16> = value.encode_utf16().chain(std::iter::once(0)).collect();
+                f(PWSTR(value.as_mut_ptr()))
+            }
+        }
+    }
+
+    unsafe fn from_pwstr(value: PWSTR) -> Self {
+        if (value.0 as usize) >> 16 == 0 {
+            return Self::Integer(value.0 as usize as u16);
+        }
+
+        let mut len = 0;
+
+        while *value.0.add(len) != 0 {
+            len += 1;
+        }
+
+        Self::Name(String::from_utf16_lossy(std::slice::from_raw_parts(
+            value.0, len,
+        )))
+    }
+}
+
+impl From<u16> for ResourceId {
+    fn from(value: u16) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<&str> for ResourceId {
+    fn from(value: &str) -> Self {
+        Self::Name(value.to_string())
+    }
+}
+
+/// An activation context created from a side-by-side assembly manifest, such as one selecting
+/// version 6 of the common controls library.
+pub struct ActivationContext(HANDLE);
+
+impl ActivationContext {
+    /// Creates an activation context from the manifest file at `path`.
+    pub fn from_file(path: &str) -> Result<Self> {
+        let mut path: Vec<u16> = path.encode_utf16().chain(std::iter::once(0)).collect();
+
+        let mut info = ACTCTXW {
+            cbSize: std::mem::size_of::<ACTCTXW>() as u32,
+            lpSource: PWSTR(path.as_mut_ptr()),
+            ..Default::default()
+        };
+
+        Self::create(&mut info)
+    }
+
+    /// Creates an activation context from a manifest resource embedded in `module`.
+    pub fn from_resource(module: Module, name: &ResourceId) -> Result<Self> {
+        let mut path: Vec<u16> = module
+            .file_name()?
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .collect();
+
+        name.with_pwstr(|name| {
+            let mut info = ACTCTXW {
+                cbSize: std::mem::size_of::<ACTCTXW>() as u32,
+                dwFlags: ACTCTX_FLAG_RESOURCE_NAME_VALID | ACTCTX_FLAG_HMODULE_VALID,
+                lpSource: PWSTR(path.as_mut_ptr()),
+                lpResourceName: name,
+                hModule: HINSTANCE(module.0),
+                ..Default::default()
+            };
+
+            Self::create(&mut info)
+        })
+    }
+
+    fn create(info: &mut ACTCTXW) -> Result<Self> {
+        let handle = unsafe { CreateActCtxW(info) };
+
+        if handle.is_invalid() {
+            Err(HRESULT::from_thread().into())
+        } else {
+            Ok(Self(handle))
+        }
+    }
+
+    /// Activates the context on the calling thread until the returned guard is dropped.
+    ///
+    /// Guards must be dropped in the reverse order in which they were created.
+    pub fn activate(&self) -> Result<ActivationScope<'_>> {
+        let mut cookie = 0;
+
+        unsafe {
+            ActivateActCtx(self.0, &mut cookie).ok()?;
+        }
+
+        Ok(ActivationScope {
+            cookie,
+            phantom: PhantomData,
+        })
+    }
+}
+
+impl Drop for ActivationContext {
+    fn drop(&mut self) {
+        unsafe { ReleaseActCtx(self.0) }
+    }
+}
+
+/// Keeps an [`ActivationContext`] active on the calling thread until dropped.
+///
+/// The activation belongs to the thread so the guard is neither `Send` nor `Sync`.
+#[must_use]
+pub struct ActivationScope<'a> {
+    cookie: usize,
+    phantom: PhantomData<(&'a ActivationContext, *const ())>,
+}
+
+impl<'a> Drop for ActivationScope<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = DeactivateActCtx(0, self.cookie);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn current() -> Result<()> {
+        let module = Module::current()?;
+        let path = std::env::current_exe().unwrap();
+        assert_eq!(module.file_name()?, path.to_string_lossy());
+
+        // The test executable has no raw data resources.
+        assert!(module.resource_names(&ResourceId::RCDATA)?.is_empty());
+        assert!(module
+            .resource(&ResourceId::RCDATA, &ResourceId::from(1))
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn activation_context() -> Result<()> {
+        let path = std::env::temp_dir().join("windows-activation-context.manifest");
+
+        std::fs::write(
+            &path,
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<assembly xmlns="urn:schemas-microsoft-com:asm.v1" manifestVersion="1.0">
+  <dependency>
+    <dependentAssembly>
+      <assemblyIdentity type="win32" name="Microsoft.Windows.Common-Controls" version="6.0.0.0" processorArchitecture="*" publicKeyToken="6595b64144ccf1df" language="*"/>
+    </dependentAssembly>
+  </dependency>
+</assembly>"#,
+        )
+        .unwrap();
+
+        let context = ActivationContext::from_file(&path.to_string_lossy())?;
+        let _scope = context.activate()?;
+
+        assert!(ActivationContext::from_file("missing.manifest").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use crate::*;
+use core::ffi::c_void;
+use core::sync::atomic::AtomicPtr;
+
+// Without `std` there is no `shutdown` to release the cached factories, so the factories are
+// held until the process exits, nor an object tracker, whose registry requires a lock.
+
+pub(crate) fn register_factory(_: *const AtomicPtr<c_void>) {}
+
+pub(crate) fn reset_late_release() {}
+
+pub(crate) fn check_release() {}
+
+pub fn track_object(_: RawPtr, _: &'static str) {}
+
+pub fn untrack_object(_: RawPtr) {}
@@ -0,0 +1,223 @@
+use crate::*;
+use bindings::Windows::Win32::System::Diagnostics::Debug::RtlCaptureStackBackTrace;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::thread::ThreadId;
+
+// The most frames captured for each object, which is the most `RtlCaptureStackBackTrace` allows
+// on older versions of Windows.
+const MAX_FRAMES: usize = 62;
+
+// Whether objects are currently being tracked, checked before taking the lock so that creating
+// and destroying objects is unaffected when no tracker is running.
+static TRACKING: AtomicBool = AtomicBool::new(false);
+
+struct Registry {
+    trackers: usize,
+    objects: BTreeMap<usize, LiveObject>,
+}
+
+fn registry() -> MutexGuard<'static, Registry> {
+    use std::{
+        mem::MaybeUninit,
+        ptr::{addr_of, addr_of_mut},
+        sync::Once,
+    };
+    static ONCE: Once = Once::new();
+    static mut VALUE: MaybeUninit<Mutex<Registry>> = MaybeUninit::uninit();
+
+    ONCE.call_once(|| {
+        // This is safe because `Once` provides thread-safe one-time initialization
+        unsafe {
+            addr_of_mut!(VALUE).write(MaybeUninit::new(Mutex::new(Registry {
+                trackers: 0,
+                objects: BTreeMap::new(),
+            })))
+        }
+    });
+
+    // This is safe because `call_once` has already been called, and the static is only reached
+    // through raw pointers. Objects may be destroyed while another thread is panicking so a
+    // poisoned lock is still used.
+    unsafe { &*addr_of!(VALUE).cast::<Mutex<Registry>>() }
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+/// An object implemented with `#[implement]` that was created while an [`ObjectTracker`] was
+/// running and has not yet been destroyed.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LiveObject {
+    /// The address of the object's `IUnknown` interface, which is the object's COM identity.
+    pub address: usize,
+    /// The name of the Rust type implementing the object.
+    pub type_name: &'static str,
+    /// The thread that created the object.
+    pub thread: ThreadId,
+    /// The return addresses on the stack when the object was created, innermost first.
+    pub frames: Vec<usize>,
+}
+
+impl std::fmt::Display for LiveObject {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} at {:#x} created on thread {:?}",
+            self.type_name, self.address, self.thread
+        )?;
+
+        // Frames are written relative to their module so that they can be resolved with a
+        // debugger or symbol tool, such as with `ln module+offset` in WinDbg.
+        for frame in &self.frames {
+            match Module::from_address(*frame).and_then(|module| {
+                let name = module.file_name()?;
+                let name = name.rsplit('\\').next().unwrap_or(&name).to_string();
+                Ok((name, *frame - module.as_raw() as usize))
+            }) {
+                Ok((name, offset)) => writeln!(f, "    {}+{:#x}", name, offset)?,
+                Err(_) => writeln!(f, "    {:#x}", frame)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Records each object implemented with `#[implement]` while running so that objects that are
+/// unexpectedly kept alive can be found along with the code that created them.
+///
+/// Objects are recorded from all threads of the process when created and forgotten when their
+/// last reference is released. Any objects still alive when the last running tracker is dropped,
+/// such as at the end of `main`, are written to standard error.
+///
+/// ```ignore
+/// let tracker = windows::ObjectTracker::start();
+/// run_scenario()?;
+///
+/// for object in tracker.live() {
+///     println!("{}", object);
+/// }
+/// ```
+#[must_use]
+pub struct ObjectTracker(());
+
+impl ObjectTracker {
+    /// Starts recording objects, sharing the record with any other running trackers.
+    pub fn start() -> Self {
+        let mut registry = registry();
+        registry.trackers += 1;
+        TRACKING.store(true, Ordering::Release);
+        Self(())
+    }
+
+    /// Returns the objects created since the first running tracker was started that have not yet
+    /// been destroyed, sorted by address.
+    pub fn live(&self) -> Vec<LiveObject> {
+        registry().objects.values().cloned().collect()
+    }
+
+    /// Writes the objects returned by [`ObjectTracker::live`] to `writer`.
+    pub fn write_live<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for object in self.live() {
+            write!(writer, "{}", object)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Drop for ObjectTracker {
+    fn drop(&mut self) {
+        let objects = {
+            let mut registry = registry();
+            registry.trackers -= 1;
+
+            if registry.trackers != 0 {
+                return;
+            }
+
+            TRACKING.store(false, Ordering::Release);
+            std::mem::take(&mut registry.objects)
+        };
+
+        if !objects.is_empty() {
+            eprintln!("{} objects are still alive:", objects.len());
+
+            for object in objects.values() {
+                eprint!("{}", object);
+            }
+        }
+    }
+}
+
+/// Records an object created by `#[implement]` and should not be called directly.
+#[doc(hidden)]
+pub fn track_object(address: RawPtr, type_name: &'static str) {
+    if !TRACKING.load(Ordering::Acquire) {
+        return;
+    }
+
+    let mut frames = vec![std::ptr::null_mut(); MAX_FRAMES];
+
+    // The frame of this function is skipped.
+    let len = unsafe {
+        RtlCaptureStackBackTrace(
+            1,
+            MAX_FRAMES as u32,
+            frames.as_mut_ptr(),
+            std::ptr::null_mut(),
+        )
+    };
+
+    let object = LiveObject {
+        address: address as usize,
+        type_name,
+        thread: std::thread::current().id(),
+        frames: frames[..len as usize]
+            .iter()
+            .map(|frame| *frame as usize)
+            .collect(),
+    };
+
+    let mut registry = registry();
+
+    // The tracker may have stopped while the frames were captured.
+    if registry.trackers != 0 {
+        registry.objects.insert(object.address, object);
+    }
+}
+
+/// Forgets an object destroyed by `#[implement]` and should not be called directly.
+#[doc(hidden)]
+pub fn untrack_object(address: RawPtr) {
+    if TRACKING.load(Ordering::Acquire) {
+        registry().objects.remove(&(address as usize));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn track() {
+        let tracker = ObjectTracker::start();
+        let mut value = 0u8;
+        let address = &mut value as *mut u8 as RawPtr;
+
+        track_object(address, "Tracked");
+        let live = tracker.live();
+        let object = live.iter().find(|o| o.address == address as usize).unwrap();
+        assert_eq!(object.type_name, "Tracked");
+        assert_eq!(object.thread, std::thread::current().id());
+        assert!(!object.frames.is_empty());
+
+        let mut report = Vec::new();
+        tracker.write_live(&mut report).unwrap();
+        assert!(String::from_utf8(report).unwrap().contains("Tracked at"));
+
+        untrack_object(address);
+        assert!(tracker.live().iter().all(|o| o.address != address as usize));
+    }
+}
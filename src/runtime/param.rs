@@ -10,15 +10,15 @@ pub enum Param<'a, T: Abi> {
     None,
 }
 
-impl<'a, T: Abi> Param<'a, T> {
-    pub fn abi(&mut self) -> T::Abi {
+impl<'a, T: Abi> ParamAbi<T> for Param<'a, T> {
+    fn abi(&mut self) -> T::Abi {
         match self {
             Param::Borrowed(value) => value.abi(),
             Param::Owned(value) => value.abi(),
             Param::Boxed(value) => value.abi(),
             // It is always safe to form an `Abi` type's binary representation from an all-zero
             // byte-pattern as this represents the null or default state for every type.
-            Param::None => unsafe { std::mem::zeroed() },
+            Param::None => unsafe { core::mem::zeroed() },
         }
     }
 }
@@ -28,3 +28,36 @@ impl<'a, T: Abi> Drop for Param<'a, T> {
         T::drop_param(self);
     }
 }
+
+// A string parameter converted from a Rust string, which holds short strings in a buffer rather
+// than allocating them. Only string parameters pay for the buffer, so the other parameters stay
+// pointer-sized. `StringParam` is used by the generated bindings and should not generally be used
+// directly.
+#[doc(hidden)]
+pub enum StringParam<'a, T: Abi> {
+    Param(Param<'a, T>),
+    Buffer(WideBuffer),
+}
+
+impl<'a> ParamAbi<HSTRING> for StringParam<'a, HSTRING> {
+    fn abi(&mut self) -> RawPtr {
+        match self {
+            StringParam::Param(param) => param.abi(),
+            StringParam::Buffer(buffer) => buffer.abi(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size() {
+        // A tag and a pointer, as the string buffers are held by `StringParam` instead.
+        assert_eq!(
+            core::mem::size_of::<Param<IUnknown>>(),
+            2 * core::mem::size_of::<usize>()
+        );
+    }
+}
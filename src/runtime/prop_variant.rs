@@ -0,0 +1,276 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::{E_OUTOFMEMORY, PWSTR},
+    Storage::StructuredStorage::{PropVariantClear, PropVariantCopy, PROPVARIANT},
+    System::Com::{CoTaskMemAlloc, CoTaskMemFree},
+    System::OleAutomation::{
+        VARENUM, VT_BOOL, VT_CLSID, VT_EMPTY, VT_I4, VT_I8, VT_LPWSTR, VT_R8, VT_UI4, VT_UI8,
+    },
+    System::PropertiesSystem::{
+        InitPropVariantFromStringVector, PropVariantToBoolean, PropVariantToDouble,
+        PropVariantToGUID, PropVariantToInt32, PropVariantToInt64, PropVariantToStringAlloc,
+        PropVariantToStringVectorAlloc, PropVariantToUInt32, PropVariantToUInt64,
+    },
+};
+
+/// An owned `PROPVARIANT`, the value of a property in an `IPropertyStore`, that is cleared
+/// with `PropVariantClear` when dropped.
+///
+/// Values are read and written as Rust types implementing [`PropertyValue`]. Values are read
+/// with the coercions of the `PropVariantTo*` functions, so that a property holding a `u32`
+/// may also be read as a `String`.
+#[repr(transparent)]
+pub struct PropVariant(PROPVARIANT);
+
+impl PropVariant {
+    /// Creates an empty value, which removes a property when written to a property store.
+    pub fn new() -> Self {
+        Self(PROPVARIANT::default())
+    }
+
+    /// Creates a value holding a copy of `value`.
+    pub fn from_value<T: PropertyValue>(value: &T) -> Result<Self> {
+        value.to_prop_variant()
+    }
+
+    /// Converts the value to `T`, failing if it can't be coerced.
+    pub fn value<T: PropertyValue>(&self) -> Result<T> {
+        T::from_prop_variant(self)
+    }
+
+    /// The `VARENUM` value describing the type of the value.
+    pub fn vartype(&self) -> u16 {
+        unsafe { self.0.Anonymous.Anonymous.vt }
+    }
+
+    /// Returns `true` if the value is empty, as it is for properties that aren't set.
+    pub fn is_empty(&self) -> bool {
+        self.vartype() == VT_EMPTY.0 as u16
+    }
+
+    /// Returns a pointer to the `PROPVARIANT` for types without a [`PropertyValue`].
+    pub fn as_raw(&self) -> *const std::ffi::c_void {
+        &self.0 as *const PROPVARIANT as _
+    }
+
+    /// Returns a pointer to the `PROPVARIANT` for writing a value. Any value the pointer is
+    /// written through is cleared when the `PropVariant` is dropped.
+    pub fn as_mut_raw(&mut self) -> *mut std::ffi::c_void {
+        &mut self.0 as *mut PROPVARIANT as _
+    }
+
+    pub(crate) fn from_raw(value: PROPVARIANT) -> Self {
+        Self(value)
+    }
+
+    pub(crate) fn abi(&self) -> *const PROPVARIANT {
+        &self.0
+    }
+
+    fn with(vartype: VARENUM, init: impl FnOnce(&mut PROPVARIANT)) -> Self {
+        let mut value = PROPVARIANT::default();
+
+        value.Anonymous.Anonymous.vt = vartype.0 as u16;
+
+        init(&mut value);
+        Self(value)
+    }
+}
+
+impl Default for PropVariant {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for PropVariant {
+    fn clone(&self) -> Self {
+        let mut value = Self::new();
+
+        unsafe {
+            PropVariantCopy(&mut value.0, &self.0).unwrap();
+        }
+
+        value
+    }
+}
+
+impl Drop for PropVariant {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = PropVariantClear(&mut self.0);
+        }
+    }
+}
+
+impl std::fmt::Debug for PropVariant {
+    fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = fmt.debug_struct("PropVariant");
+        debug.field("vartype", &self.vartype());
+
+        if let Ok(value) = self.value::<String>() {
+            debug.field("value", &value);
+        }
+
+        debug.finish()
+    }
+}
+
+/// A Rust type that may be read from and written to a [`PropVariant`].
+pub trait PropertyValue: Sized {
+    /// Reads the value, coercing it to `Self` if it holds another type.
+    fn from_prop_variant(value: &PropVariant) -> Result<Self>;
+
+    /// Creates a `PropVariant` holding a copy of the value.
+    fn to_prop_variant(&self) -> Result<PropVariant>;
+}
+
+impl PropertyValue for String {
+    fn from_prop_variant(value: &PropVariant) -> Result<Self> {
+        unsafe {
+            let string = PropVariantToStringAlloc(value.abi())?;
+            let result = String::from_utf16_lossy(wide_slice(string));
+            CoTaskMemFree(string.0 as _);
+            Ok(result)
+        }
+    }
+
+    fn to_prop_variant(&self) -> Result<PropVariant> {
+        let string = alloc_wide(self)?;
+        Ok(PropVariant::with(VT_LPWSTR, |value| {
+            value.Anonymous.Anonymous.Anonymous.pwszVal = string;
+        }))
+    }
+}
+
+impl PropertyValue for Vec<String> {
+    fn from_prop_variant(value: &PropVariant) -> Result<Self> {
+        unsafe {
+            let mut strings = std::ptr::null_mut();
+            let mut len = 0;
+            PropVariantToStringVectorAlloc(value.abi(), &mut strings, &mut len)?;
+
+            let result = (0..len as usize)
+                .map(|index| {
+                    let string = *strings.add(index);
+                    let result = String::from_utf16_lossy(wide_slice(string));
+                    CoTaskMemFree(string.0 as _);
+                    result
+                })
+                .collect();
+
+            CoTaskMemFree(strings as _);
+            Ok(result)
+        }
+    }
+
+    fn to_prop_variant(&self) -> Result<PropVariant> {
+        let mut wide: Vec<Vec<u16>> = self
+            .iter()
+            .map(|string| string.encode_utf16().chain(std::iter::once(0)).collect())
+            .collect();
+
+        let mut strings: Vec<PWSTR> = wide
+            .iter_mut()
+            .map(|string| PWSTR(string.as_mut_ptr()))
+            .collect();
+
+        // The strings are copied so the buffers needn't outlive the call.
+        unsafe {
+            InitPropVariantFromStringVector(strings.as_mut_ptr(), strings.len() as _)
+                .map(PropVariant::from_raw)
+        }
+    }
+}
+
+impl PropertyValue for bool {
+    fn from_prop_variant(value: &PropVariant) -> Result<Self> {
+        unsafe { PropVariantToBoolean(value.abi()).map(|value| value.as_bool()) }
+    }
+
+    fn to_prop_variant(&self) -> Result<PropVariant> {
+        // A `VARIANT_BOOL` is true when all of its bits are set.
+        let flag = if *self { -1 } else { 0 };
+
+        Ok(PropVariant::with(VT_BOOL, |value| {
+            value.Anonymous.Anonymous.Anonymous.boolVal = flag;
+        }))
+    }
+}
+
+impl PropertyValue for Guid {
+    fn from_prop_variant(value: &PropVariant) -> Result<Self> {
+        unsafe { PropVariantToGUID(value.abi()) }
+    }
+
+    fn to_prop_variant(&self) -> Result<PropVariant> {
+        let guid = unsafe { CoTaskMemAlloc(std::mem::size_of::<Guid>()) as *mut Guid };
+
+        if guid.is_null() {
+            return Err(Error::fast_error(E_OUTOFMEMORY));
+        }
+
+        unsafe {
+            guid.write(*self);
+        }
+
+        Ok(PropVariant::with(VT_CLSID, |value| {
+            value.Anonymous.Anonymous.Anonymous.puuid = guid;
+        }))
+    }
+}
+
+macro_rules! property_values {
+    ($($t:ty => $vartype:ident, $field:ident, $from:ident;)*) => {
+        $(
+            impl PropertyValue for $t {
+                fn from_prop_variant(value: &PropVariant) -> Result<Self> {
+                    unsafe { $from(value.abi()) }
+                }
+
+                fn to_prop_variant(&self) -> Result<PropVariant> {
+                    let copy = *self;
+
+                    Ok(PropVariant::with($vartype, |value| {
+                        value.Anonymous.Anonymous.Anonymous.$field = copy;
+                    }))
+                }
+            }
+        )*
+    };
+}
+
+property_values! {
+    i32 => VT_I4, lVal, PropVariantToInt32;
+    u32 => VT_UI4, ulVal, PropVariantToUInt32;
+    i64 => VT_I8, hVal, PropVariantToInt64;
+    u64 => VT_UI8, uhVal, PropVariantToUInt64;
+    f64 => VT_R8, dblVal, PropVariantToDouble;
+}
+
+// Copies the string to a buffer allocated with `CoTaskMemAlloc`, which is how the strings held
+// by a `PROPVARIANT` are allocated and released.
+fn alloc_wide(string: &str) -> Result<PWSTR> {
+    let len = string.encode_utf16().count() + 1;
+    let buffer = unsafe { CoTaskMemAlloc(len * 2) as *mut u16 };
+
+    if buffer.is_null() {
+        return Err(Error::fast_error(E_OUTOFMEMORY));
+    }
+
+    for (index, c) in string.encode_utf16().chain(std::iter::once(0)).enumerate() {
+        unsafe { buffer.add(index).write(c) };
+    }
+
+    Ok(PWSTR(buffer))
+}
+
+pub(crate) unsafe fn wide_slice<'a>(string: PWSTR) -> &'a [u16] {
+    let mut len = 0;
+
+    while *string.0.add(len) != 0 {
+        len += 1;
+    }
+
+    std::slice::from_raw_parts(string.0, len)
+}
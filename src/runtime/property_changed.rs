@@ -0,0 +1,76 @@
+use crate::*;
+use bindings::Windows::UI::Xaml::Data::{PropertyChangedEventArgs, PropertyChangedEventHandler};
+
+/// The `PropertyChanged` event of a view model implementing `INotifyPropertyChanged` with
+/// `#[implement]`, which tells XAML data bindings to read a property again after it changes.
+///
+/// Handlers may be of the `PropertyChangedEventHandler` type generated for any crate, since
+/// they're converted to the event's own type when added.
+///
+/// ```ignore
+/// #[implement(Windows::UI::Xaml::Data::INotifyPropertyChanged)]
+/// struct Person {
+///     name: RefCell<HSTRING>,
+///     changed: windows::PropertyChangedEvent,
+/// }
+///
+/// impl Person {
+///     fn PropertyChanged(&self, handler: &Option<PropertyChangedEventHandler>) -> windows::Result<EventRegistrationToken> {
+///         Ok(EventRegistrationToken { Value: self.changed.add(handler)? })
+///     }
+///
+///     fn RemovePropertyChanged(&self, token: &EventRegistrationToken) -> windows::Result<()> {
+///         self.changed.remove(token.Value);
+///         Ok(())
+///     }
+///
+///     fn set_name(&self, name: HSTRING) -> windows::Result<()> {
+///         *self.name.borrow_mut() = name;
+///         self.changed.notify(None, "Name")
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct PropertyChangedEvent {
+    event: Event<PropertyChangedEventHandler>,
+}
+
+impl PropertyChangedEvent {
+    /// Creates an event without any handlers.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a handler, returning the token that removes it.
+    pub fn add<T: Interface>(&self, handler: &Option<T>) -> Result<i64> {
+        let handler = match handler {
+            Some(handler) => Some(handler.cast()?),
+            None => None,
+        };
+
+        self.event.add(&handler)
+    }
+
+    /// Removes the handler added with the token, if it hasn't already been removed.
+    pub fn remove(&self, token: i64) {
+        self.event.remove(token)
+    }
+
+    /// Returns whether the event has no handlers.
+    pub fn is_empty(&self) -> bool {
+        self.event.is_empty()
+    }
+
+    /// Tells the handlers that the named property of the sender has changed. An empty name
+    /// indicates that all of the sender's properties have changed.
+    pub fn notify(&self, sender: Option<&IInspectable>, name: &str) -> Result<()> {
+        if self.event.is_empty() {
+            return Ok(());
+        }
+
+        let sender = sender.cloned();
+        let args = PropertyChangedEventArgs::CreateInstance(name)?;
+
+        self.event.call(|handler| handler.Invoke(&sender, &args))
+    }
+}
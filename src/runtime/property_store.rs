@@ -0,0 +1,162 @@
+use super::prop_variant::wide_slice;
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::HWND,
+    System::Com::CoTaskMemFree,
+    System::PropertiesSystem::{
+        IPropertyStore, PSGetNameFromPropertyKey, PSGetPropertyKeyFromName,
+        SHGetPropertyStoreForWindow, SHGetPropertyStoreFromParsingName, GPS_DEFAULT, GPS_READWRITE,
+        PROPERTYKEY,
+    },
+};
+
+/// Identifies a property, such as `System.Title`, by the GUID of its property set and the
+/// identifier of the property within the set.
+///
+/// The key has the layout of the `PROPERTYKEY` and `DEVPROPKEY` structs, whose `PKEY_*` and
+/// `DEVPKEY_*` constants are generated from the metadata, so that a generated key may be
+/// converted with `PropertyKey::new(key.fmtid, key.pid)`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PropertyKey {
+    pub fmtid: Guid,
+    pub pid: u32,
+}
+
+impl PropertyKey {
+    /// Creates a key from the GUID of its property set and the identifier of the property.
+    pub const fn new(fmtid: Guid, pid: u32) -> Self {
+        Self { fmtid, pid }
+    }
+
+    /// Returns the key of the property with the given canonical name, such as `System.Title` or
+    /// `System.AppUserModel.ID`, as registered with the property system.
+    pub fn from_name(name: &str) -> Result<Self> {
+        unsafe { PSGetPropertyKeyFromName(name).map(|key| Self::new(key.fmtid, key.pid)) }
+    }
+
+    /// Returns the canonical name of the property, such as `System.Title`.
+    pub fn name(&self) -> Result<String> {
+        unsafe {
+            let name = PSGetNameFromPropertyKey(self.abi())?;
+            let result = String::from_utf16_lossy(wide_slice(name));
+            CoTaskMemFree(name.0 as _);
+            Ok(result)
+        }
+    }
+
+    fn abi(&self) -> *const PROPERTYKEY {
+        self as *const Self as _
+    }
+}
+
+/// The properties of an item, such as the metadata of a file or the app identity of a window,
+/// held by an `IPropertyStore` and read and written as Rust types.
+///
+/// ```ignore
+/// let store = windows::PropertyStore::for_file(r"C:\music\track.mp3", true)?;
+/// let title = windows::PropertyKey::from_name("System.Title")?;
+///
+/// println!("{:?}", store.get::<String>(&title)?);
+/// store.set(&title, "New title".to_string())?;
+/// store.commit()?;
+/// ```
+#[derive(Clone)]
+pub struct PropertyStore {
+    store: IPropertyStore,
+}
+
+impl PropertyStore {
+    /// Wraps an existing `IPropertyStore`.
+    pub fn new<T: Interface>(store: &T) -> Result<Self> {
+        Ok(Self {
+            store: store.cast()?,
+        })
+    }
+
+    /// Opens the properties of the file or other shell item at the given path. Changes must
+    /// be committed with [`PropertyStore::commit`] and may only be made if `writable` is set.
+    pub fn for_file(path: &str, writable: bool) -> Result<Self> {
+        let flags = if writable { GPS_READWRITE } else { GPS_DEFAULT };
+
+        Ok(Self {
+            store: unsafe { SHGetPropertyStoreFromParsingName(path, None, flags)? },
+        })
+    }
+
+    /// Opens the properties of a top-level window, such as `System.AppUserModel.ID`, which
+    /// decide how the window is grouped on the taskbar.
+    pub fn for_window(window: isize) -> Result<Self> {
+        Ok(Self {
+            store: unsafe { SHGetPropertyStoreForWindow(HWND(window))? },
+        })
+    }
+
+    /// Returns the store's `IPropertyStore`.
+    pub fn store<T: Interface>(&self) -> Result<T> {
+        self.store.cast()
+    }
+
+    /// Returns the value of the property, or `None` if the property isn't set.
+    pub fn get<T: PropertyValue>(&self, key: &PropertyKey) -> Result<Option<T>> {
+        let value = self.get_raw(key)?;
+
+        if value.is_empty() {
+            Ok(None)
+        } else {
+            value.value().map(Some)
+        }
+    }
+
+    /// Returns the value of the property as it is held by the store.
+    pub fn get_raw(&self, key: &PropertyKey) -> Result<PropVariant> {
+        unsafe { self.store.GetValue(key.abi()).map(PropVariant::from_raw) }
+    }
+
+    /// Sets the value of the property.
+    pub fn set<T: PropertyValue>(&self, key: &PropertyKey, value: T) -> Result<()> {
+        self.set_raw(key, &value.to_prop_variant()?)
+    }
+
+    /// Sets the value of the property to a `PropVariant`, which removes the property if empty.
+    pub fn set_raw(&self, key: &PropertyKey, value: &PropVariant) -> Result<()> {
+        unsafe { self.store.SetValue(key.abi(), value.abi()) }
+    }
+
+    /// Sets the values of several properties, stopping at the first that fails.
+    pub fn set_all(&self, values: &[(PropertyKey, PropVariant)]) -> Result<()> {
+        values
+            .iter()
+            .try_for_each(|(key, value)| self.set_raw(key, value))
+    }
+
+    /// Removes the property.
+    pub fn remove(&self, key: &PropertyKey) -> Result<()> {
+        self.set_raw(key, &PropVariant::new())
+    }
+
+    /// Returns the keys of the properties held by the store.
+    pub fn keys(&self) -> Result<Vec<PropertyKey>> {
+        unsafe {
+            (0..self.store.GetCount()?)
+                .map(|index| {
+                    let key = self.store.GetAt(index)?;
+                    Ok(PropertyKey::new(key.fmtid, key.pid))
+                })
+                .collect()
+        }
+    }
+
+    /// Returns the key and value of every property held by the store.
+    pub fn entries(&self) -> Result<Vec<(PropertyKey, PropVariant)>> {
+        self.keys()?
+            .into_iter()
+            .map(|key| Ok((key, self.get_raw(&key)?)))
+            .collect()
+    }
+
+    /// Saves the changes made to the properties.
+    pub fn commit(&self) -> Result<()> {
+        unsafe { self.store.Commit() }
+    }
+}
@@ -0,0 +1,313 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::PWSTR,
+    System::Diagnostics::Debug::ERROR_FILE_NOT_FOUND,
+    System::Registry::{
+        RegCloseKey, RegCreateKeyExW, RegDeleteTreeW, RegSetValueExW, HKEY, HKEY_CURRENT_USER,
+        HKEY_LOCAL_MACHINE, KEY_WRITE, REG_DWORD, REG_OPTION_NON_VOLATILE, REG_SZ, REG_VALUE_TYPE,
+    },
+};
+
+/// The apartments in which a registered class may be activated.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ThreadingModel {
+    /// Single-threaded apartments only.
+    Apartment,
+    /// The multi-threaded apartment only.
+    Free,
+    /// Any apartment.
+    Both,
+    /// The neutral apartment, for COM classes only.
+    Neutral,
+}
+
+impl ThreadingModel {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Apartment => "Apartment",
+            Self::Free => "Free",
+            Self::Both => "Both",
+            Self::Neutral => "Neutral",
+        }
+    }
+
+    // WinRT has no neutral threading model and so treats such classes as agile.
+    fn activatable_name(self) -> &'static str {
+        match self {
+            Self::Apartment => "sta",
+            Self::Free => "mta",
+            Self::Both | Self::Neutral => "both",
+        }
+    }
+
+    // The `Threading` value of a WinRT activatable class registration.
+    fn activatable_value(self) -> u32 {
+        match self {
+            Self::Apartment => 1,
+            Self::Free => 2,
+            Self::Both | Self::Neutral => 0,
+        }
+    }
+}
+
+/// Selects the part of the registry that [`register_server`] and [`unregister_server`] update.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum RegistrationScope {
+    /// Registers classes for the current user only, which doesn't require elevation.
+    CurrentUser,
+    /// Registers classes for all users, which requires elevation.
+    LocalMachine,
+}
+
+impl RegistrationScope {
+    fn root(self) -> HKEY {
+        match self {
+            Self::CurrentUser => HKEY_CURRENT_USER,
+            Self::LocalMachine => HKEY_LOCAL_MACHINE,
+        }
+    }
+}
+
+/// A class implemented by a DLL, such as with `#[implement]`, and exposed by the DLL's
+/// `DllGetClassObject` or `DllGetActivationFactory` export.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ServerClass {
+    /// A COM class activated by CLSID, as with [`create_instance`].
+    Com {
+        clsid: Guid,
+        threading: ThreadingModel,
+    },
+    /// A WinRT class activated by its runtime class name, as with [`factory`].
+    Activatable {
+        name: String,
+        threading: ThreadingModel,
+    },
+}
+
+impl ServerClass {
+    /// Describes a COM class with the given CLSID.
+    pub fn com(clsid: Guid, threading: ThreadingModel) -> Self {
+        Self::Com { clsid, threading }
+    }
+
+    /// Describes a WinRT class with the given runtime class name.
+    pub fn activatable(name: &str, threading: ThreadingModel) -> Self {
+        Self::Activatable {
+            name: name.to_string(),
+            threading,
+        }
+    }
+}
+
+/// Writes the registry keys activating `classes` from the DLL containing the calling code, as
+/// required of a DLL's `DllRegisterServer` export called by `regsvr32`.
+///
+/// WinRT classes are only ever activated from the machine-wide registration and so ignore
+/// `scope`, always requiring elevation.
+///
+/// ```ignore
+/// #[no_mangle]
+/// extern "system" fn DllRegisterServer() -> windows::HRESULT {
+///     windows::register_server(&classes(), windows::RegistrationScope::LocalMachine).into()
+/// }
+/// ```
+pub fn register_server(classes: &[ServerClass], scope: RegistrationScope) -> Result<()> {
+    let path = Module::current()?.file_name()?;
+
+    for class in classes {
+        match class {
+            ServerClass::Com { clsid, threading } => {
+                let key =
+                    Key::create(scope.root(), &format!("{}\\InprocServer32", com_key(clsid)))?;
+                key.set_string("", &path)?;
+                key.set_string("ThreadingModel", threading.name())?;
+            }
+            ServerClass::Activatable { name, threading } => {
+                let key = Key::create(HKEY_LOCAL_MACHINE, &activatable_key(name))?;
+                key.set_string("DllPath", &path)?;
+                key.set_u32("ActivationType", 0)?;
+                key.set_u32("Threading", threading.activatable_value())?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes the registry keys written by [`register_server`], as required of a DLL's
+/// `DllUnregisterServer` export. Classes that aren't registered are ignored.
+pub fn unregister_server(classes: &[ServerClass], scope: RegistrationScope) -> Result<()> {
+    for class in classes {
+        match class {
+            ServerClass::Com { clsid, .. } => delete_tree(scope.root(), &com_key(clsid))?,
+            ServerClass::Activatable { name, .. } => {
+                delete_tree(HKEY_LOCAL_MACHINE, &activatable_key(name))?
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the `file` element of an application manifest activating `classes` from the DLL
+/// named `file_name` without registration.
+///
+/// The fragment belongs within the `assembly` element of the manifest of the application, or of
+/// an assembly that the application depends on. Registration-free activation of WinRT classes
+/// requires Windows 10 version 1903 or later.
+pub fn server_manifest(file_name: &str, classes: &[ServerClass]) -> String {
+    let mut manifest = format!("<file name=\"{}\">\n", escape(file_name));
+
+    for class in classes {
+        match class {
+            ServerClass::Com { clsid, threading } => manifest.push_str(&format!(
+                "  <comClass clsid=\"{{{:?}}}\" threadingModel=\"{}\"/>\n",
+                clsid,
+                threading.name()
+            )),
+            ServerClass::Activatable { name, threading } => manifest.push_str(&format!(
+                "  <activatableClass name=\"{}\" threadingModel=\"{}\" xmlns=\"urn:schemas-microsoft-com:winrt.v1\"/>\n",
+                escape(name),
+                threading.activatable_name()
+            )),
+        }
+    }
+
+    manifest.push_str("</file>\n");
+    manifest
+}
+
+fn com_key(clsid: &Guid) -> String {
+    format!("Software\\Classes\\CLSID\\{{{:?}}}", clsid)
+}
+
+fn activatable_key(name: &str) -> String {
+    format!(
+        "Software\\Microsoft\\WindowsRuntime\\ActivatableClassId\\{}",
+        name
+    )
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn wide(value: &str) -> Vec<u16> {
+    value.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+fn check(status: i32) -> Result<()> {
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(HRESULT::from_win32(status as u32).into())
+    }
+}
+
+fn delete_tree(root: HKEY, path: &str) -> Result<()> {
+    let mut path = wide(path);
+    let status = unsafe { RegDeleteTreeW(root, PWSTR(path.as_mut_ptr())) };
+
+    if status.0 as u32 == ERROR_FILE_NOT_FOUND.0 {
+        Ok(())
+    } else {
+        check(status.0)
+    }
+}
+
+// An open registry key, closed when dropped.
+struct Key(HKEY);
+
+impl Key {
+    fn create(root: HKEY, path: &str) -> Result<Self> {
+        let mut path = wide(path);
+        let mut key = HKEY::default();
+
+        check(
+            unsafe {
+                RegCreateKeyExW(
+                    root,
+                    PWSTR(path.as_mut_ptr()),
+                    0,
+                    PWSTR::default(),
+                    REG_OPTION_NON_VOLATILE,
+                    KEY_WRITE,
+                    std::ptr::null(),
+                    &mut key,
+                    std::ptr::null_mut(),
+                )
+            }
+            .0,
+        )?;
+
+        Ok(Self(key))
+    }
+
+    fn set_string(&self, name: &str, value: &str) -> Result<()> {
+        let value = wide(value);
+        self.set(name, REG_SZ, value.as_ptr() as _, value.len() * 2)
+    }
+
+    fn set_u32(&self, name: &str, value: u32) -> Result<()> {
+        self.set(name, REG_DWORD, &value as *const u32 as _, 4)
+    }
+
+    fn set(&self, name: &str, kind: REG_VALUE_TYPE, data: *const u8, len: usize) -> Result<()> {
+        let mut name = wide(name);
+
+        check(
+            unsafe { RegSetValueExW(self.0, PWSTR(name.as_mut_ptr()), 0, kind, data, len as u32) }
+                .0,
+        )
+    }
+}
+
+impl Drop for Key {
+    fn drop(&mut self) {
+        unsafe {
+            RegCloseKey(self.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest() {
+        let classes = [
+            ServerClass::com(
+                Guid::from("C4B7E69B-0C4C-4C3A-9C7A-2A3E3A0B4C5D"),
+                ThreadingModel::Both,
+            ),
+            ServerClass::activatable("Sample.Widget", ThreadingModel::Neutral),
+        ];
+
+        assert_eq!(
+            server_manifest("sample.dll", &classes),
+            r#"<file name="sample.dll">
+  <comClass clsid="{C4B7E69B-0C4C-4C3A-9C7A-2A3E3A0B4C5D}" threadingModel="Both"/>
+  <activatableClass name="Sample.Widget" threadingModel="both" xmlns="urn:schemas-microsoft-com:winrt.v1"/>
+</file>
+"#
+        );
+    }
+
+    #[test]
+    fn register() -> Result<()> {
+        let clsid = Guid::new()?;
+        let classes = [ServerClass::com(clsid, ThreadingModel::Apartment)];
+
+        register_server(&classes, RegistrationScope::CurrentUser)?;
+        unregister_server(&classes, RegistrationScope::CurrentUser)?;
+
+        // Removing a class that isn't registered succeeds.
+        unregister_server(&classes, RegistrationScope::CurrentUser)?;
+        Ok(())
+    }
+}
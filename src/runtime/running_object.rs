@@ -0,0 +1,141 @@
+use super::prop_variant::wide_slice;
+use crate::*;
+use bindings::Windows::Win32::{
+    Foundation::E_FAIL,
+    System::Com::{
+        CoTaskMemFree, CreateBindCtx, CreateItemMoniker, GetRunningObjectTable, IBindCtx, IMoniker,
+        IRunningObjectTable, MkParseDisplayName,
+    },
+    System::SystemServices::ROTFLAGS_REGISTRATIONKEEPSALIVE,
+};
+
+// The delimiter of the item monikers that objects are registered under, giving display names
+// such as `!Contoso.Editor`.
+const ITEM_DELIMITER: &str = "!";
+
+/// An object registered in the Running Object Table (ROT) under a name, so that other processes
+/// running as the same user can find it with [`running_object`]. The object is kept alive by the
+/// table and revoked when the registration is dropped.
+///
+/// ```ignore
+/// let application: IInspectable = Application().into();
+/// let _registration = windows::RunningObject::register(&application, "Contoso.Editor")?;
+///
+/// // In another process
+/// let application: IInspectable = windows::running_object("Contoso.Editor")?;
+/// ```
+pub struct RunningObject {
+    table: IRunningObjectTable,
+    moniker: IMoniker,
+    cookie: u32,
+}
+
+impl RunningObject {
+    /// Registers `object` under an item moniker with the given name.
+    pub fn register<T: Interface>(object: &T, name: &str) -> Result<Self> {
+        let moniker = unsafe { CreateItemMoniker(ITEM_DELIMITER, name)? };
+        Self::register_moniker(object, moniker)
+    }
+
+    /// Registers `object` under the moniker parsed from `display_name`, such as the path of the
+    /// document the object has open, for objects found with [`bind_to_object`].
+    pub fn register_display_name<T: Interface>(object: &T, display_name: &str) -> Result<Self> {
+        let moniker = parse_display_name(&bind_context()?, display_name)?;
+        Self::register_moniker(object, moniker)
+    }
+
+    /// Returns the display name of the moniker the object is registered under.
+    pub fn display_name(&self) -> Result<String> {
+        display_name(&bind_context()?, &self.moniker)
+    }
+
+    fn register_moniker<T: Interface>(object: &T, moniker: IMoniker) -> Result<Self> {
+        let object: IUnknown = object.cast()?;
+
+        unsafe {
+            let table = GetRunningObjectTable(0)?;
+            let cookie = table.Register(ROTFLAGS_REGISTRATIONKEEPSALIVE, object, &moniker)?;
+
+            Ok(Self {
+                table,
+                moniker,
+                cookie,
+            })
+        }
+    }
+}
+
+impl Drop for RunningObject {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = self.table.Revoke(self.cookie);
+        }
+    }
+}
+
+/// Returns the object registered in the Running Object Table under an item moniker with the
+/// given name, such as with [`RunningObject::register`], as the requested interface.
+pub fn running_object<T: Interface>(name: &str) -> Result<T> {
+    unsafe {
+        let moniker = CreateItemMoniker(ITEM_DELIMITER, name)?;
+        GetRunningObjectTable(0)?.GetObject(moniker)?.cast()
+    }
+}
+
+/// Returns the display names of the monikers of the objects registered in the Running Object
+/// Table, such as `!Contoso.Editor` for an object registered under an item moniker or the path
+/// of a document that is open.
+pub fn running_objects() -> Result<Vec<String>> {
+    let context = bind_context()?;
+    let mut names = Vec::new();
+
+    unsafe {
+        let monikers = GetRunningObjectTable(0)?.EnumRunning()?;
+
+        loop {
+            let mut moniker = None;
+            let mut fetched = 0;
+            monikers.Next(1, &mut moniker, &mut fetched)?;
+
+            match moniker {
+                Some(moniker) if fetched == 1 => names.push(display_name(&context, &moniker)?),
+                _ => break,
+            }
+        }
+    }
+
+    Ok(names)
+}
+
+/// Binds to the object named by a moniker display name, such as the path of a document, as the
+/// requested interface. Objects already running are found in the Running Object Table, while
+/// others may be activated by the moniker.
+pub fn bind_to_object<T: Interface>(display_name: &str) -> Result<T> {
+    let context = bind_context()?;
+    let moniker = parse_display_name(&context, display_name)?;
+    unsafe { moniker.BindToObject(&context, None) }
+}
+
+fn bind_context() -> Result<IBindCtx> {
+    unsafe { CreateBindCtx(0) }
+}
+
+fn parse_display_name(context: &IBindCtx, display_name: &str) -> Result<IMoniker> {
+    let mut eaten = 0;
+    let mut moniker = None;
+
+    unsafe {
+        MkParseDisplayName(context, display_name, &mut eaten, &mut moniker)?;
+    }
+
+    moniker.ok_or_else(|| Error::fast_error(E_FAIL))
+}
+
+fn display_name(context: &IBindCtx, moniker: &IMoniker) -> Result<String> {
+    unsafe {
+        let name = moniker.GetDisplayName(context, None)?;
+        let result = String::from_utf16_lossy(wide_slice(name));
+        CoTaskMemFree(name.0 as _);
+        Ok(result)
+    }
+}
@@ -0,0 +1,201 @@
+use crate::*;
+use core::convert::TryFrom;
+use core::marker::PhantomData;
+
+use bindings::Windows::Win32::{
+    Foundation::{DISP_E_BADVARTYPE, E_OUTOFMEMORY},
+    System::OleAutomation::{
+        SafeArrayAccessData, SafeArrayCreateVector, SafeArrayDestroy, SafeArrayGetDim,
+        SafeArrayGetLBound, SafeArrayGetUBound, SafeArrayGetVartype, SafeArrayUnaccessData,
+    },
+};
+
+/// A type that may be stored in a [`SafeArray`].
+///
+/// # Safety
+/// `VARTYPE` must be the `VARENUM` value whose element representation matches `Self`, as the
+/// array's elements are read and written as `Self` and released by `SafeArrayDestroy`.
+pub unsafe trait SafeArrayElement: Clone {
+    /// The `VARENUM` value describing the type's elements.
+    const VARTYPE: u16;
+}
+
+macro_rules! safe_array_elements {
+    ($($t:ty => $vartype:literal,)*) => {
+        $(
+            unsafe impl SafeArrayElement for $t {
+                const VARTYPE: u16 = $vartype;
+            }
+        )*
+    };
+}
+
+safe_array_elements! {
+    i16 => 2,
+    i32 => 3,
+    f32 => 4,
+    f64 => 5,
+    Option<IUnknown> => 13,
+    i8 => 16,
+    u8 => 17,
+    u16 => 18,
+    u32 => 19,
+    i64 => 20,
+    u64 => 21,
+}
+
+/// An automation `SAFEARRAY` holding a single dimension of `T` elements.
+///
+/// The generated bindings use `SafeArray` for `SAFEARRAY*` input parameters and `SAFEARRAY**`
+/// output parameters. The element type is not described by metadata so it is checked against
+/// the array's type when the elements are read.
+#[repr(transparent)]
+pub struct SafeArray<T: SafeArrayElement> {
+    ptr: RawPtr,
+    phantom: PhantomData<T>,
+}
+
+impl<T: SafeArrayElement> Default for SafeArray<T> {
+    fn default() -> Self {
+        Self {
+            ptr: core::ptr::null_mut(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: SafeArrayElement> SafeArray<T> {
+    /// Creates an empty `SafeArray`.
+    ///
+    /// This function does not allocate memory.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a `SafeArray` holding a copy of `values`.
+    pub fn from_slice(values: &[T]) -> Result<Self> {
+        assert!(values.len() < core::u32::MAX as usize);
+
+        let array = Self {
+            ptr: unsafe { SafeArrayCreateVector(T::VARTYPE, 0, values.len() as u32) as RawPtr },
+            phantom: PhantomData,
+        };
+
+        if array.ptr.is_null() {
+            return Err(Error::fast_error(E_OUTOFMEMORY));
+        }
+
+        array.access(|data| {
+            // The array is zero-initialized so there is nothing to drop when writing elements.
+            for (index, value) in values.iter().enumerate() {
+                unsafe { core::ptr::write(data.add(index), value.clone()) }
+            }
+        })?;
+
+        Ok(array)
+    }
+
+    /// Returns `true` if the array is null or has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of elements in the array's first dimension.
+    pub fn len(&self) -> usize {
+        if self.ptr.is_null() {
+            return 0;
+        }
+
+        unsafe {
+            match (SafeArrayGetLBound(self, 1), SafeArrayGetUBound(self, 1)) {
+                (Ok(lower), Ok(upper)) => (upper as i64 - lower as i64 + 1) as usize,
+                _ => 0,
+            }
+        }
+    }
+
+    /// Returns a copy of the array's elements.
+    ///
+    /// Fails with `DISP_E_BADVARTYPE` if the array does not have a single dimension of `T`.
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        if self.ptr.is_null() {
+            return Ok(Vec::new());
+        }
+
+        unsafe {
+            if SafeArrayGetDim(self) != 1 || SafeArrayGetVartype(self)? != T::VARTYPE {
+                return Err(Error::fast_error(DISP_E_BADVARTYPE));
+            }
+        }
+
+        let len = self.len();
+
+        self.access(|data| {
+            (0..len)
+                .map(|index| unsafe { (*data.add(index)).clone() })
+                .collect()
+        })
+    }
+
+    fn access<R, F: FnOnce(*mut T) -> R>(&self, f: F) -> Result<R> {
+        unsafe {
+            let mut data: RawPtr = core::ptr::null_mut();
+            SafeArrayAccessData(self, &mut data)?;
+            let result = f(data as *mut T);
+            SafeArrayUnaccessData(self)?;
+            Ok(result)
+        }
+    }
+}
+
+impl<T: SafeArrayElement> Drop for SafeArray<T> {
+    fn drop(&mut self) {
+        if !self.ptr.is_null() {
+            unsafe {
+                let _ = SafeArrayDestroy(&*self);
+            }
+        }
+    }
+}
+
+unsafe impl<T: SafeArrayElement> Abi for SafeArray<T> {
+    type Abi = RawPtr;
+
+    fn set_abi(&mut self) -> *mut RawPtr {
+        debug_assert!(self.ptr.is_null());
+        &mut self.ptr
+    }
+}
+
+impl<T: SafeArrayElement> TryFrom<&[T]> for SafeArray<T> {
+    type Error = Error;
+
+    fn try_from(values: &[T]) -> Result<Self> {
+        Self::from_slice(values)
+    }
+}
+
+impl<T: SafeArrayElement> TryFrom<Vec<T>> for SafeArray<T> {
+    type Error = Error;
+
+    fn try_from(values: Vec<T>) -> Result<Self> {
+        Self::from_slice(&values)
+    }
+}
+
+impl<T: SafeArrayElement> TryFrom<&SafeArray<T>> for Vec<T> {
+    type Error = Error;
+
+    fn try_from(array: &SafeArray<T>) -> Result<Self> {
+        array.to_vec()
+    }
+}
+
+impl<T: SafeArrayElement + core::fmt::Debug> core::fmt::Debug for SafeArray<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.to_vec() {
+            Ok(values) => values.fmt(f),
+            Err(_) => f.write_str("SafeArray"),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::*;
+use bindings::Windows::Win32::System::Com::CoUninitialize;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicBool, AtomicPtr, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+type Cleanup = Box<dyn FnOnce() + Send>;
+
+// Whether releasing an object should panic because `shutdown` has run with the check enabled.
+static RELEASED_LATE: AtomicBool = AtomicBool::new(false);
+
+// Whether `shutdown` should enable the check above.
+static CHECK_LATE_RELEASE: AtomicBool = AtomicBool::new(false);
+
+#[derive(Default)]
+struct Registry {
+    cleanups: Vec<Cleanup>,
+    // The addresses of the factory caches holding a factory, each of which is a static in the
+    // generated bindings.
+    factories: Vec<usize>,
+}
+
+fn registry() -> MutexGuard<'static, Registry> {
+    use std::{
+        mem::MaybeUninit,
+        ptr::{addr_of, addr_of_mut},
+        sync::Once,
+    };
+    static ONCE: Once = Once::new();
+    static mut VALUE: MaybeUninit<Mutex<Registry>> = MaybeUninit::uninit();
+
+    ONCE.call_once(|| {
+        // This is safe because `Once` provides thread-safe one-time initialization
+        unsafe { addr_of_mut!(VALUE).write(MaybeUninit::new(Mutex::new(Registry::default()))) }
+    });
+
+    // This is safe because `call_once` has already been called, and reading the static through
+    // `addr_of!` doesn't create a reference to it.
+    unsafe { &*addr_of!(VALUE).cast::<Mutex<Registry>>() }
+        .lock()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+/// Registers a callback to be run by [`shutdown`], such as to release the objects held by a
+/// static or thread-local variable while COM is still initialized.
+///
+/// Callbacks are run in the reverse of the order in which they were registered, so that objects
+/// created later are released first.
+///
+/// ```ignore
+/// thread_local! {
+///     static SETTINGS: RefCell<Option<ApplicationDataContainer>> = RefCell::new(None);
+/// }
+///
+/// windows::on_shutdown(|| SETTINGS.with(|settings| settings.borrow_mut().take()));
+/// ```
+pub fn on_shutdown<F: FnOnce() + Send + 'static>(cleanup: F) {
+    registry().cleanups.push(Box::new(cleanup));
+}
+
+/// Sets whether [`shutdown`] should make any later release of a COM object panic, so that
+/// objects outliving COM are found where they are released rather than crashing the process
+/// after it has been uninitialized. The check is disabled by default and is intended for
+/// debugging.
+pub fn set_late_release_check(enabled: bool) {
+    CHECK_LATE_RELEASE.store(enabled, Ordering::Relaxed);
+}
+
+/// Runs the callbacks registered with [`on_shutdown`] and releases the activation factories
+/// cached by the generated bindings, which would otherwise be released after COM has been
+/// uninitialized, if at all.
+///
+/// This should be called once every other thread has stopped using WinRT and COM objects, such
+/// as at the end of `main`. Factories are cached again if classes are used afterwards.
+pub fn shutdown() {
+    // The lock is released before each callback is run since a callback may register another.
+    loop {
+        let cleanup = registry().cleanups.pop();
+
+        match cleanup {
+            Some(cleanup) => cleanup(),
+            None => break,
+        }
+    }
+
+    let factories = std::mem::take(&mut registry().factories);
+
+    for factory in factories {
+        let shared = unsafe { &*(factory as *const AtomicPtr<c_void>) };
+        let ptr = shared.swap(std::ptr::null_mut(), Ordering::Relaxed);
+
+        if !ptr.is_null() {
+            unsafe { std::mem::drop(std::mem::transmute::<_, IUnknown>(ptr)) };
+        }
+    }
+
+    if CHECK_LATE_RELEASE.load(Ordering::Relaxed) {
+        RELEASED_LATE.store(true, Ordering::Relaxed);
+    }
+}
+
+/// Calls [`shutdown`] and then uninitializes COM for the calling thread, balancing a call to
+/// [`initialize_mta`] or [`initialize_sta`].
+pub fn uninitialize() {
+    shutdown();
+    unsafe { CoUninitialize() };
+}
+
+// Records a factory cache that is now holding a factory so that `shutdown` can release it.
+pub(crate) fn register_factory(shared: *const AtomicPtr<c_void>) {
+    registry().factories.push(shared as usize);
+}
+
+// Clears the late release check when COM is initialized again after `shutdown`.
+pub(crate) fn reset_late_release() {
+    RELEASED_LATE.store(false, Ordering::Relaxed);
+}
+
+// Called by `IUnknown` before each release.
+pub(crate) fn check_release() {
+    if RELEASED_LATE.load(Ordering::Relaxed) {
+        released_late();
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn released_late() {
+    panic!("A COM object was released after `windows::shutdown` was called");
+}
@@ -0,0 +1,61 @@
+use crate::*;
+
+/// The WinRT type signature of `T` and the IID derived from it, from which the IIDs of the
+/// specializations of generic interfaces and delegates, such as `IVector<HSTRING>`, are computed.
+///
+/// Both are associated constants so that they may be used in `const` contexts and are computed
+/// exactly as the generated bindings compute the `IID` of each specialization:
+///
+/// ```ignore
+/// const IID: Guid = Signature::<IVector<HSTRING>>::IID;
+/// assert_eq!(IID, IVector::<HSTRING>::IID);
+/// ```
+pub struct Signature<T>(core::marker::PhantomData<T>);
+
+impl<T: RuntimeType> Signature<T> {
+    /// The signature of `T`, such as `string` for `HSTRING`.
+    pub const VALUE: ConstBuffer = T::SIGNATURE;
+
+    /// The IID derived from the signature of `T`, which is the IID of a specialized generic
+    /// interface or delegate.
+    pub const IID: Guid = Guid::from_signature(T::SIGNATURE);
+}
+
+/// Returns the signature of the specialization of the generic interface or delegate with the
+/// `generic` IID for type arguments with the given signatures, such as
+/// `pinterface({913337e9-11a1-4345-a3a2-4e7f956e222d};string)` for `IVector<HSTRING>`.
+///
+/// Hand-written generic interfaces may compute the IID of each specialization by passing the
+/// result to [`Guid::from_signature`].
+pub const fn pinterface_signature(generic: &Guid, arguments: &[ConstBuffer]) -> ConstBuffer {
+    let mut buffer = ConstBuffer::from_slice(b"pinterface(").push_other(generic.signature());
+    let mut index = 0;
+
+    while index < arguments.len() {
+        buffer = buffer.push_slice(b";").push_other(arguments[index]);
+        index += 1;
+    }
+
+    buffer.push_slice(b")")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bindings::Windows::Foundation::Collections::{IIterable, IVector};
+
+    #[test]
+    fn test_specialized_iids() {
+        const VECTOR: Guid = Signature::<IVector<HSTRING>>::IID;
+        assert_eq!(VECTOR, Guid::from("98B9ACC1-4B56-532E-AC73-03D5291CCA90"));
+        assert_eq!(VECTOR, <IVector<HSTRING> as Interface>::IID);
+
+        let iterable = Guid::from_signature(pinterface_signature(
+            &Guid::from("FAA585EA-6214-4217-AFDA-7F46DE5869B3"),
+            &[Signature::<HSTRING>::VALUE],
+        ));
+
+        assert_eq!(iterable, Guid::from("E2FCC7C1-3BFC-5A0B-B2B0-72E769D1CB7E"));
+        assert_eq!(iterable, <IIterable<HSTRING> as Interface>::IID);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::*;
+use core::cell::Cell;
+use core::marker::PhantomData;
+
+/// A reference count for COM implementations whose objects are only ever used by the thread that
+/// created them, which avoids the cost of atomic operations.
+///
+/// The count is neither `Send` nor `Sync`, so neither is an object holding it. Unlike
+/// [`WeakRefCount`] it doesn't support weak references.
+#[derive(Default)]
+pub struct SingleThreadedRefCount(Cell<u32>, PhantomData<*const ()>);
+
+impl SingleThreadedRefCount {
+    /// Creates a new `SingleThreadedRefCount` with an initial value of `1`.
+    pub fn new() -> Self {
+        Self(Cell::new(1), PhantomData)
+    }
+
+    /// Increments the reference count, returning the new value.
+    pub fn add_ref(&self) -> u32 {
+        let count = self.0.get() + 1;
+        self.0.set(count);
+        count
+    }
+
+    /// Decrements the reference count, returning the new value.
+    pub fn release(&self) -> u32 {
+        let count = self
+            .0
+            .get()
+            .checked_sub(1)
+            .expect("Object has been over-released.");
+
+        self.0.set(count);
+        count
+    }
+
+    /// Returns null since the object doesn't implement `IWeakReferenceSource`.
+    pub unsafe fn query(&self, _: &Guid, _: RawPtr) -> RawPtr {
+        core::ptr::null_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count() {
+        let count = SingleThreadedRefCount::new();
+        assert_eq!(count.add_ref(), 2);
+        assert_eq!(count.release(), 1);
+        assert_eq!(count.release(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Object has been over-released.")]
+    fn over_release() {
+        let count = SingleThreadedRefCount::new();
+        count.release();
+        count.release();
+    }
+}
@@ -0,0 +1,639 @@
+#![allow(non_snake_case)]
+
+use super::collections::{
+    add_ref, get_iids, get_runtime_class_name, get_trust_level, into_abi, into_inspectable, object,
+    query_interface, release, Object,
+};
+use crate::*;
+use bindings::Windows::{
+    Foundation::{
+        AsyncOperationProgressHandler, AsyncOperationWithProgressCompletedHandler, AsyncStatus,
+        IAsyncInfo, IAsyncInfo_abi, IAsyncOperationWithProgress, IAsyncOperationWithProgress_abi,
+        IClosable, IClosable_abi,
+    },
+    Storage::Streams::{
+        Buffer, IBuffer, IInputStream, IInputStream_abi, IOutputStream, IRandomAccessStream,
+        InputStreamOptions,
+    },
+    Win32::Foundation::{E_ILLEGAL_DELEGATE_ASSIGNMENT, E_POINTER, RO_E_CLOSED},
+    Win32::System::WinRT::IBufferByteAccess,
+};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::sync::Mutex;
+
+// The capacity of the buffer that each read or write is made through, unless given.
+const DEFAULT_CAPACITY: u32 = 64 * 1024;
+
+type ReadOperation = IAsyncOperationWithProgress<IBuffer, u32>;
+
+/// Reads a WinRT `IInputStream`, such as the stream of a file or a network socket, through
+/// `std::io::Read`.
+///
+/// Each read waits for the stream's `ReadAsync` to complete, returning the bytes that are
+/// available once at least one is, so the reader should not be used on a thread that mustn't
+/// block, such as a UI thread.
+///
+/// ```ignore
+/// let stream = file.OpenAsync(FileAccessMode::Read)?.get()?;
+/// let mut text = String::new();
+/// windows::InputStreamReader::new(&stream)?.read_to_string(&mut text)?;
+/// ```
+pub struct InputStreamReader {
+    stream: IInputStream,
+    buffer: Buffer,
+}
+
+impl InputStreamReader {
+    /// Creates a reader for an object implementing `IInputStream`.
+    pub fn new<T: Interface>(stream: &T) -> Result<Self> {
+        Self::with_capacity(DEFAULT_CAPACITY, stream)
+    }
+
+    /// Creates a reader that reads at most `capacity` bytes from the stream at a time.
+    pub fn with_capacity<T: Interface>(capacity: u32, stream: &T) -> Result<Self> {
+        Ok(Self {
+            stream: stream.cast()?,
+            buffer: Buffer::Create(capacity)?,
+        })
+    }
+}
+
+impl Read for InputStreamReader {
+    fn read(&mut self, bytes: &mut [u8]) -> std::io::Result<usize> {
+        let stream = &self.stream;
+        read(&self.buffer, bytes, |buffer, count| {
+            stream.ReadAsync(buffer, count, InputStreamOptions::Partial)
+        })
+    }
+}
+
+/// Writes a WinRT `IOutputStream` through `std::io::Write`.
+///
+/// Each write waits for the stream's `WriteAsync` to complete, so the writer should not be used
+/// on a thread that mustn't block. Flushing waits for the stream's `FlushAsync`.
+///
+/// ```ignore
+/// let stream = file.OpenAsync(FileAccessMode::ReadWrite)?.get()?;
+/// let mut writer = windows::OutputStreamWriter::new(&stream)?;
+/// writer.write_all(b"hello")?;
+/// writer.flush()?;
+/// ```
+pub struct OutputStreamWriter {
+    stream: IOutputStream,
+    buffer: Buffer,
+}
+
+impl OutputStreamWriter {
+    /// Creates a writer for an object implementing `IOutputStream`.
+    pub fn new<T: Interface>(stream: &T) -> Result<Self> {
+        Self::with_capacity(DEFAULT_CAPACITY, stream)
+    }
+
+    /// Creates a writer that writes at most `capacity` bytes to the stream at a time.
+    pub fn with_capacity<T: Interface>(capacity: u32, stream: &T) -> Result<Self> {
+        Ok(Self {
+            stream: stream.cast()?,
+            buffer: Buffer::Create(capacity)?,
+        })
+    }
+}
+
+impl Write for OutputStreamWriter {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        let stream = &self.stream;
+        write(&self.buffer, bytes, |buffer| {
+            stream.WriteAsync(buffer)?.get()
+        })
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        flush(self.stream.FlushAsync()?.get()?)
+    }
+}
+
+/// Reads, writes, and seeks a WinRT `IRandomAccessStream`, such as an
+/// `InMemoryRandomAccessStream` or the stream of an open file, through `std::io::Read`, `Write`,
+/// and `Seek`.
+///
+/// Like [`InputStreamReader`] and [`OutputStreamWriter`], each call waits for the stream's
+/// async method to complete.
+///
+/// ```ignore
+/// let stream = InMemoryRandomAccessStream::new()?;
+/// let mut io = windows::RandomAccessStreamIo::new(&stream)?;
+/// io.write_all(b"hello")?;
+/// io.seek(SeekFrom::Start(0))?;
+/// ```
+pub struct RandomAccessStreamIo {
+    stream: IRandomAccessStream,
+    buffer: Buffer,
+}
+
+impl RandomAccessStreamIo {
+    /// Creates an adapter for an object implementing `IRandomAccessStream`.
+    pub fn new<T: Interface>(stream: &T) -> Result<Self> {
+        Self::with_capacity(DEFAULT_CAPACITY, stream)
+    }
+
+    /// Creates an adapter that reads or writes at most `capacity` bytes at a time.
+    pub fn with_capacity<T: Interface>(capacity: u32, stream: &T) -> Result<Self> {
+        Ok(Self {
+            stream: stream.cast()?,
+            buffer: Buffer::Create(capacity)?,
+        })
+    }
+}
+
+impl Read for RandomAccessStreamIo {
+    fn read(&mut self, bytes: &mut [u8]) -> std::io::Result<usize> {
+        let stream = &self.stream;
+        read(&self.buffer, bytes, |buffer, count| {
+            stream.ReadAsync(buffer, count, InputStreamOptions::Partial)
+        })
+    }
+}
+
+impl Write for RandomAccessStreamIo {
+    fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+        let stream = &self.stream;
+        write(&self.buffer, bytes, |buffer| {
+            stream.WriteAsync(buffer)?.get()
+        })
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        flush(self.stream.FlushAsync()?.get()?)
+    }
+}
+
+impl Seek for RandomAccessStreamIo {
+    fn seek(&mut self, position: SeekFrom) -> std::io::Result<u64> {
+        let (base, offset) = match position {
+            SeekFrom::Start(position) => (0, position as i64),
+            SeekFrom::Current(offset) => (self.stream.Position()?, offset),
+            SeekFrom::End(offset) => (self.stream.Size()?, offset),
+        };
+
+        let position = (base as i64)
+            .checked_add(offset)
+            .filter(|position| *position >= 0)
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                )
+            })? as u64;
+
+        self.stream.Seek(position)?;
+        Ok(position)
+    }
+}
+
+/// Creates an `IInputStream` that reads from a `std::io::Read`, such as a file or a slice of
+/// bytes, for WinRT APIs that read their input from a stream. The object also implements
+/// `IClosable`, which drops the reader.
+///
+/// The stream's `ReadAsync` reads from the reader before returning an operation that has already
+/// completed, so WinRT APIs calling it block while the reader does. Like objects implemented
+/// with `#[implement]`, the stream is agile and may be called from any thread, which is why the
+/// reader must be `Send`.
+///
+/// ```ignore
+/// let stream: IInputStream = windows::input_stream(std::fs::File::open("data.bin")?).cast()?;
+/// let reader = DataReader::CreateDataReader(stream)?;
+/// reader.LoadAsync(4)?.get()?;
+/// let value = reader.ReadUInt32()?;
+/// ```
+pub fn input_stream<R: Read + Send + 'static>(reader: R) -> IInspectable {
+    into_inspectable(StdInputStream {
+        input: &StdInputStream::<R>::INPUT,
+        closable: &StdInputStream::<R>::CLOSABLE,
+        count: RefCount::new(1),
+        reader: Mutex::new(Some(reader)),
+    })
+}
+
+// Returns a pointer to the bytes of a buffer, which remain valid while the buffer does.
+fn bytes<T: Interface>(buffer: &T) -> Result<*mut u8> {
+    let mut data = std::ptr::null_mut();
+    unsafe { buffer.cast::<IBufferByteAccess>()?.Buffer(&mut data)? };
+    Ok(data)
+}
+
+fn read<F>(buffer: &Buffer, bytes_read: &mut [u8], read_async: F) -> std::io::Result<usize>
+where
+    F: FnOnce(&Buffer, u32) -> Result<ReadOperation>,
+{
+    let count = buffer.Capacity()?.min(len_u32(bytes_read.len()));
+
+    if count == 0 {
+        return Ok(0);
+    }
+
+    // The stream may return a buffer other than the one it was given.
+    let result = read_async(buffer, count)?.get()?;
+    let len = result.Length()?.min(count) as usize;
+
+    if len > 0 {
+        unsafe {
+            std::ptr::copy_nonoverlapping(bytes(&result)?, bytes_read.as_mut_ptr(), len);
+        }
+    }
+
+    Ok(len)
+}
+
+fn write<F>(buffer: &Buffer, bytes_written: &[u8], write_async: F) -> std::io::Result<usize>
+where
+    F: FnOnce(&Buffer) -> Result<u32>,
+{
+    let len = buffer.Capacity()?.min(len_u32(bytes_written.len()));
+
+    if len == 0 {
+        return Ok(0);
+    }
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes_written.as_ptr(), bytes(buffer)?, len as usize);
+    }
+
+    buffer.SetLength(len)?;
+    Ok(write_async(buffer)? as usize)
+}
+
+// Buffers hold at most `u32::MAX` bytes.
+fn len_u32(len: usize) -> u32 {
+    std::convert::TryFrom::try_from(len).unwrap_or(u32::MAX)
+}
+
+fn flush(flushed: bool) -> std::io::Result<()> {
+    if flushed {
+        Ok(())
+    } else {
+        Err(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "the stream could not be flushed",
+        ))
+    }
+}
+
+#[repr(C)]
+struct StdInputStream<R: Read + Send + 'static> {
+    input: *const IInputStream_abi,
+    closable: *const IClosable_abi,
+    count: RefCount,
+    // The reader is dropped when the stream is closed.
+    reader: Mutex<Option<R>>,
+}
+
+impl<R: Read + Send + 'static> Object for StdInputStream<R> {
+    fn count(&self) -> &RefCount {
+        &self.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &IInputStream::IID {
+            Some(0)
+        } else if iid == &IClosable::IID {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        vec![IInputStream::IID, IClosable::IID]
+    }
+}
+
+impl<R: Read + Send + 'static> StdInputStream<R> {
+    const INPUT: IInputStream_abi = IInputStream_abi(
+        query_interface::<Self, 0>,
+        add_ref::<Self, 0>,
+        release::<Self, 0>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::ReadAsync,
+    );
+
+    const CLOSABLE: IClosable_abi = IClosable_abi(
+        query_interface::<Self, 1>,
+        add_ref::<Self, 1>,
+        release::<Self, 1>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::Close,
+    );
+
+    unsafe extern "system" fn ReadAsync(
+        this: RawPtr,
+        buffer: RawPtr,
+        count: u32,
+        options: InputStreamOptions,
+        result: *mut RawPtr,
+    ) -> HRESULT {
+        *result = std::ptr::null_mut();
+        let stream = object::<Self, 0>(this);
+        let buffer = &*(&buffer as *const RawPtr as *const Option<IBuffer>);
+
+        let buffer = match buffer {
+            Some(buffer) => buffer,
+            None => return E_POINTER,
+        };
+
+        match stream.read(buffer, count, options) {
+            Ok(()) => {
+                *result = into_abi(StdReadOperation::new(buffer.clone()));
+                HRESULT(0)
+            }
+            Err(error) => error.into(),
+        }
+    }
+
+    unsafe extern "system" fn Close(this: RawPtr) -> HRESULT {
+        object::<Self, 1>(this).reader.lock().unwrap().take();
+        HRESULT(0)
+    }
+
+    // Reads into the buffer, setting its length to the number of bytes read.
+    fn read(&self, buffer: &IBuffer, count: u32, options: InputStreamOptions) -> Result<()> {
+        let mut reader = self.reader.lock().unwrap();
+        let reader = reader
+            .as_mut()
+            .ok_or_else(|| Error::fast_error(RO_E_CLOSED))?;
+
+        let count = buffer.Capacity()?.min(count) as usize;
+        let data = bytes(buffer)?;
+        let mut len = 0;
+
+        // Without the `Partial` option the read only completes once `count` bytes have been read
+        // or the reader has reached its end.
+        let partial = options.0 & InputStreamOptions::Partial.0 != 0;
+
+        while len < count {
+            let bytes = unsafe { std::slice::from_raw_parts_mut(data.add(len), count - len) };
+
+            match reader.read(bytes) {
+                Ok(0) => break,
+                Ok(read) => len += read,
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error.into()),
+            }
+
+            if partial {
+                break;
+            }
+        }
+
+        buffer.SetLength(len as u32)
+    }
+}
+
+// The result of `ReadAsync`, which has completed by the time it's returned.
+#[repr(C)]
+struct StdReadOperation {
+    operation: *const IAsyncOperationWithProgress_abi<IBuffer, u32>,
+    info: *const IAsyncInfo_abi,
+    count: RefCount,
+    buffer: IBuffer,
+    completed: Mutex<Option<AsyncOperationWithProgressCompletedHandler<IBuffer, u32>>>,
+    progress: Mutex<Option<AsyncOperationProgressHandler<IBuffer, u32>>>,
+}
+
+impl Object for StdReadOperation {
+    fn count(&self) -> &RefCount {
+        &self.count
+    }
+
+    fn find(iid: &Guid) -> Option<usize> {
+        if iid == &ReadOperation::IID {
+            Some(0)
+        } else if iid == &IAsyncInfo::IID {
+            Some(1)
+        } else {
+            None
+        }
+    }
+
+    fn iids() -> Vec<Guid> {
+        vec![ReadOperation::IID, IAsyncInfo::IID]
+    }
+}
+
+impl StdReadOperation {
+    const OPERATION: IAsyncOperationWithProgress_abi<IBuffer, u32> =
+        IAsyncOperationWithProgress_abi(
+            query_interface::<Self, 0>,
+            add_ref::<Self, 0>,
+            release::<Self, 0>,
+            get_iids::<Self>,
+            get_runtime_class_name,
+            get_trust_level,
+            Self::SetProgress,
+            Self::Progress,
+            Self::SetCompleted,
+            Self::Completed,
+            Self::GetResults,
+            std::marker::PhantomData,
+            std::marker::PhantomData,
+        );
+
+    const INFO: IAsyncInfo_abi = IAsyncInfo_abi(
+        query_interface::<Self, 1>,
+        add_ref::<Self, 1>,
+        release::<Self, 1>,
+        get_iids::<Self>,
+        get_runtime_class_name,
+        get_trust_level,
+        Self::Id,
+        Self::Status,
+        Self::ErrorCode,
+        Self::Cancel,
+        Self::Close,
+    );
+
+    fn new(buffer: IBuffer) -> Self {
+        Self {
+            operation: &Self::OPERATION,
+            info: &Self::INFO,
+            count: RefCount::new(1),
+            buffer,
+            completed: Mutex::new(None),
+            progress: Mutex::new(None),
+        }
+    }
+
+    unsafe extern "system" fn SetProgress(this: RawPtr, handler: RawPtr) -> HRESULT {
+        let handler = &*(&handler as *const RawPtr
+            as *const Option<AsyncOperationProgressHandler<IBuffer, u32>>);
+        *object::<Self, 0>(this).progress.lock().unwrap() = handler.clone();
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn Progress(this: RawPtr, result: *mut RawPtr) -> HRESULT {
+        let handler = object::<Self, 0>(this).progress.lock().unwrap().clone();
+        *result = std::mem::transmute(handler);
+        HRESULT(0)
+    }
+
+    // The operation has already completed, so the handler is called as soon as it's set.
+    unsafe extern "system" fn SetCompleted(this: RawPtr, handler: RawPtr) -> HRESULT {
+        let handler = &*(&handler as *const RawPtr
+            as *const Option<AsyncOperationWithProgressCompletedHandler<IBuffer, u32>>);
+
+        let handler = match handler {
+            Some(handler) => handler,
+            None => return E_POINTER,
+        };
+
+        {
+            let mut completed = object::<Self, 0>(this).completed.lock().unwrap();
+
+            if completed.is_some() {
+                return E_ILLEGAL_DELEGATE_ASSIGNMENT;
+            }
+
+            *completed = Some(handler.clone());
+        }
+
+        let operation = &*(&this as *const RawPtr as *const ReadOperation);
+
+        match handler.Invoke(operation, AsyncStatus::Completed) {
+            Ok(()) => HRESULT(0),
+            Err(error) => error.into(),
+        }
+    }
+
+    unsafe extern "system" fn Completed(this: RawPtr, result: *mut RawPtr) -> HRESULT {
+        let handler = object::<Self, 0>(this).completed.lock().unwrap().clone();
+        *result = std::mem::transmute(handler);
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn GetResults(this: RawPtr, result: *mut RawPtr) -> HRESULT {
+        *result = std::mem::transmute(object::<Self, 0>(this).buffer.clone());
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn Id(_: RawPtr, result: *mut u32) -> HRESULT {
+        *result = 1;
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn Status(_: RawPtr, result: *mut AsyncStatus) -> HRESULT {
+        *result = AsyncStatus::Completed;
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn ErrorCode(_: RawPtr, result: *mut HRESULT) -> HRESULT {
+        *result = HRESULT(0);
+        HRESULT(0)
+    }
+
+    // Cancelling or closing a completed operation has no effect.
+    unsafe extern "system" fn Cancel(_: RawPtr) -> HRESULT {
+        HRESULT(0)
+    }
+
+    unsafe extern "system" fn Close(_: RawPtr) -> HRESULT {
+        HRESULT(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_stream_reader() -> Result<()> {
+        let stream: IInputStream = input_stream(&b"hello world"[..]).cast()?;
+        let mut reader = InputStreamReader::with_capacity(4, &stream)?;
+
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        assert_eq!(text, "hello world");
+
+        // Closing the stream drops the reader, after which reads fail.
+        stream.Close()?;
+        assert_eq!(
+            Error::from(reader.read(&mut [0; 4]).unwrap_err()).code(),
+            RO_E_CLOSED
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_options() -> Result<()> {
+        // A reader returning a byte at a time.
+        struct Trickle(std::vec::IntoIter<u8>);
+
+        impl Read for Trickle {
+            fn read(&mut self, bytes: &mut [u8]) -> std::io::Result<usize> {
+                match self.0.next() {
+                    Some(byte) => {
+                        bytes[0] = byte;
+                        Ok(1)
+                    }
+                    None => Ok(0),
+                }
+            }
+        }
+
+        let stream: IInputStream = input_stream(Trickle(vec![1, 2, 3].into_iter())).cast()?;
+        let buffer: IBuffer = Buffer::Create(8)?.cast()?;
+
+        let result = stream
+            .ReadAsync(&buffer, 2, InputStreamOptions::Partial)?
+            .get()?;
+        assert_eq!(result.Length()?, 1);
+
+        // Without `Partial` the read waits for every byte requested or the end of the reader.
+        let result = stream
+            .ReadAsync(&buffer, 8, InputStreamOptions::None)?
+            .get()?;
+        assert_eq!(result.Length()?, 2);
+
+        let operation = stream.ReadAsync(&buffer, 8, InputStreamOptions::None)?;
+        assert_eq!(operation.Status()?, AsyncStatus::Completed);
+        assert_eq!(operation.GetResults()?.Length()?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn completed_handler() -> Result<()> {
+        let stream: IInputStream = input_stream(&b"hi"[..]).cast()?;
+        let buffer: IBuffer = Buffer::Create(8)?.cast()?;
+        let operation = stream.ReadAsync(&buffer, 8, InputStreamOptions::None)?;
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+
+        operation.SetCompleted(
+            AsyncOperationWithProgressCompletedHandler::<IBuffer, u32>::new(
+                move |operation, status| {
+                    let length = operation.as_ref().unwrap().GetResults()?.Length()?;
+                    sender.send((status, length)).unwrap();
+                    Ok(())
+                },
+            ),
+        )?;
+
+        assert_eq!(receiver.try_recv().unwrap(), (AsyncStatus::Completed, 2));
+
+        // The completed handler may only be set once.
+        assert_eq!(
+            operation
+                .SetCompleted(
+                    AsyncOperationWithProgressCompletedHandler::<IBuffer, u32>::new(|_, _| Ok(()))
+                )
+                .unwrap_err()
+                .code(),
+            E_ILLEGAL_DELEGATE_ASSIGNMENT
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,366 @@
+use crate::*;
+use bindings::Windows::Win32::{
+    Storage::FileSystem::{FILE_GENERIC_READ, READ_USN_JOURNAL_DATA_V0, USN_JOURNAL_DATA_V0},
+    System::SystemServices::{FSCTL_QUERY_USN_JOURNAL, FSCTL_READ_USN_JOURNAL},
+};
+use std::time::SystemTime;
+
+// The size of the buffer each read of the journal fills with records.
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// The change journal of an NTFS or ReFS volume, which records each change made to the files and
+/// directories on the volume, identified by an update sequence number (USN).
+///
+/// Opening a volume's journal requires administrator rights. The journal must already have been
+/// created, as it is on system volumes.
+///
+/// ```ignore
+/// let journal = windows::UsnJournal::open("C:")?;
+///
+/// for record in journal.records(journal.first_usn()) {
+///     let record = record?;
+///     println!("{} {:x}", record.name, record.reason);
+/// }
+/// ```
+pub struct UsnJournal {
+    volume: Device,
+    id: u64,
+    first_usn: i64,
+    next_usn: i64,
+}
+
+impl UsnJournal {
+    /// Opens the change journal of the volume with the given drive, such as `C:`.
+    pub fn open(drive: &str) -> Result<Self> {
+        let path = format!(r"\\.\{}", drive.trim_end_matches('\\'));
+        let volume = Device::open_with_access(path, FILE_GENERIC_READ.0)?;
+
+        let data: USN_JOURNAL_DATA_V0 =
+            unsafe { volume.control(ControlCode(FSCTL_QUERY_USN_JOURNAL), &())? };
+
+        Ok(Self {
+            volume,
+            id: data.UsnJournalID,
+            first_usn: data.FirstUsn,
+            next_usn: data.NextUsn,
+        })
+    }
+
+    /// Returns the journal's identifier, which changes if the journal is deleted and created
+    /// again, invalidating any USN saved from it.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the USN of the oldest record still held by the journal when it was opened.
+    pub fn first_usn(&self) -> i64 {
+        self.first_usn
+    }
+
+    /// Returns the USN that the next change to the volume was to be given when the journal was
+    /// opened, from which only later changes are read.
+    pub fn next_usn(&self) -> i64 {
+        self.next_usn
+    }
+
+    /// Returns the records from the given USN until the end of the journal. Reading from a USN
+    /// that has since been removed from the journal fails.
+    pub fn records(&self, start: i64) -> UsnRecords<'_> {
+        UsnRecords {
+            journal: self,
+            start,
+            buffer: vec![0; BUFFER_SIZE / 8],
+            offset: 0,
+            len: 0,
+            done: false,
+        }
+    }
+}
+
+/// An iterator over the records of a [`UsnJournal`].
+pub struct UsnRecords<'a> {
+    journal: &'a UsnJournal,
+    // The USN from which the next read of the journal starts.
+    start: i64,
+    // The records are read into 8-byte aligned memory, as the file system requires.
+    buffer: Vec<u64>,
+    offset: usize,
+    len: usize,
+    done: bool,
+}
+
+impl<'a> UsnRecords<'a> {
+    /// Returns the USN from which reading may later continue after the records returned so far,
+    /// such as to save and resume from on the next run.
+    pub fn next_usn(&self) -> i64 {
+        let mut offset = self.offset;
+
+        while offset + 8 <= self.len {
+            let remaining = &self.bytes()[offset..self.len];
+
+            if let Some(usn) = record_usn(remaining) {
+                return usn;
+            }
+
+            match read_u32(remaining, 0) as usize {
+                0 => break,
+                len => offset += len,
+            }
+        }
+
+        self.start
+    }
+
+    fn bytes(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.buffer.as_ptr() as *const u8, BUFFER_SIZE) }
+    }
+
+    fn read(&mut self) -> Result<()> {
+        let read = READ_USN_JOURNAL_DATA_V0 {
+            StartUsn: self.start,
+            ReasonMask: u32::MAX,
+            UsnJournalID: self.journal.id,
+            ..Default::default()
+        };
+
+        let len = unsafe {
+            self.journal.volume.io(
+                FSCTL_READ_USN_JOURNAL,
+                &read as *const _ as _,
+                std::mem::size_of::<READ_USN_JOURNAL_DATA_V0>() as u32,
+                self.buffer.as_mut_ptr() as _,
+                BUFFER_SIZE as u32,
+            )?
+        } as usize;
+
+        // The records follow the USN from which the next read should start.
+        if len >= 8 {
+            self.start = read_i64(self.bytes(), 0);
+            self.offset = 8;
+            self.len = len;
+        }
+
+        if len <= 8 {
+            self.done = true;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Iterator for UsnRecords<'a> {
+    type Item = Result<UsnRecord>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.offset < self.len {
+                let remaining = &self.bytes()[self.offset..self.len];
+                let record_len = if remaining.len() >= 8 {
+                    read_u32(remaining, 0) as usize
+                } else {
+                    0
+                };
+
+                // Records are never empty, so this would only be caused by a malformed buffer.
+                if record_len == 0 || record_len > remaining.len() {
+                    self.offset = self.len;
+                    continue;
+                }
+
+                let record = parse_record(&remaining[..record_len]);
+                self.offset += record_len;
+
+                // Records of unknown versions are skipped.
+                if let Some(record) = record {
+                    return Some(Ok(record));
+                }
+
+                continue;
+            }
+
+            if self.done {
+                return None;
+            }
+
+            if let Err(error) = self.read() {
+                self.done = true;
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+/// A change to a file or directory recorded by a [`UsnJournal`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct UsnRecord {
+    pub usn: i64,
+    /// The file system's identifier for the file, which is 64 bits wide on NTFS and 128 bits wide
+    /// on ReFS.
+    pub file_id: u128,
+    /// The file system's identifier for the directory containing the file.
+    pub parent_id: u128,
+    pub timestamp: SystemTime,
+    /// The reasons for the change, a combination of the `USN_REASON_*` flags.
+    pub reason: u32,
+    /// Information about the source of the change, a combination of the `USN_SOURCE_*` flags.
+    pub source_info: u32,
+    pub attributes: FileAttributes,
+    /// The name of the file, without the path of the directory containing it.
+    pub name: String,
+}
+
+// Returns the length of the fixed part of a record of the given major version.
+fn record_header_len(version: u16) -> usize {
+    if version == 2 {
+        60
+    } else {
+        76
+    }
+}
+
+fn record_usn(record: &[u8]) -> Option<i64> {
+    if record.len() < 8 {
+        return None;
+    }
+
+    match read_u16(record, 4) {
+        2 if record.len() >= 32 => Some(read_i64(record, 24)),
+        3 if record.len() >= 48 => Some(read_i64(record, 40)),
+        _ => None,
+    }
+}
+
+// Parses a `USN_RECORD_V2` or `USN_RECORD_V3`, which differ only in the width of the file
+// identifiers.
+fn parse_record(record: &[u8]) -> Option<UsnRecord> {
+    if record.len() < 8 {
+        return None;
+    }
+
+    let version = read_u16(record, 4);
+
+    if (version != 2 && version != 3) || record.len() < record_header_len(version) {
+        return None;
+    }
+
+    let (file_id, parent_id, rest) = if version == 2 {
+        (
+            read_u64(record, 8) as u128,
+            read_u64(record, 16) as u128,
+            24,
+        )
+    } else {
+        (read_u128(record, 8), read_u128(record, 24), 40)
+    };
+
+    let name_len = read_u16(record, rest + 32) as usize;
+    let name_offset = read_u16(record, rest + 34) as usize;
+    let name = record.get(name_offset..name_offset + name_len)?;
+    let name: Vec<u16> = name
+        .chunks_exact(2)
+        .map(|c| u16::from_le_bytes([c[0], c[1]]))
+        .collect();
+
+    Some(UsnRecord {
+        usn: read_i64(record, rest),
+        file_id,
+        parent_id,
+        timestamp: from_file_ticks(read_i64(record, rest + 8) as u64),
+        reason: read_u32(record, rest + 16),
+        source_info: read_u32(record, rest + 20),
+        attributes: FileAttributes(read_u32(record, rest + 28)),
+        name: String::from_utf16_lossy(&name),
+    })
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    let mut value = [0; 2];
+    value.copy_from_slice(&bytes[offset..offset + 2]);
+    u16::from_le_bytes(value)
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    let mut value = [0; 4];
+    value.copy_from_slice(&bytes[offset..offset + 4]);
+    u32::from_le_bytes(value)
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    let mut value = [0; 8];
+    value.copy_from_slice(&bytes[offset..offset + 8]);
+    u64::from_le_bytes(value)
+}
+
+fn read_i64(bytes: &[u8], offset: usize) -> i64 {
+    read_u64(bytes, offset) as i64
+}
+
+fn read_u128(bytes: &[u8], offset: usize) -> u128 {
+    let mut value = [0; 16];
+    value.copy_from_slice(&bytes[offset..offset + 16]);
+    u128::from_le_bytes(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(version: u16, name: &str) -> Vec<u8> {
+        let header = record_header_len(version);
+        let name: Vec<u8> = name
+            .encode_utf16()
+            .flat_map(|c| c.to_le_bytes().to_vec())
+            .collect();
+        let len = (header + name.len() + 7) & !7;
+
+        let mut record = Vec::new();
+        record.extend_from_slice(&(len as u32).to_le_bytes());
+        record.extend_from_slice(&version.to_le_bytes());
+        record.extend_from_slice(&0u16.to_le_bytes());
+
+        if version == 2 {
+            record.extend_from_slice(&1u64.to_le_bytes());
+            record.extend_from_slice(&2u64.to_le_bytes());
+        } else {
+            record.extend_from_slice(&(1u128 << 64).to_le_bytes());
+            record.extend_from_slice(&2u128.to_le_bytes());
+        }
+
+        record.extend_from_slice(&1234i64.to_le_bytes()); // Usn
+        record.extend_from_slice(&116_444_736_000_000_000i64.to_le_bytes()); // TimeStamp
+        record.extend_from_slice(&0x100u32.to_le_bytes()); // Reason
+        record.extend_from_slice(&0u32.to_le_bytes()); // SourceInfo
+        record.extend_from_slice(&0u32.to_le_bytes()); // SecurityId
+        record.extend_from_slice(&0x20u32.to_le_bytes()); // FileAttributes
+        record.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        record.extend_from_slice(&(header as u16).to_le_bytes());
+        record.extend_from_slice(&name);
+        record.resize(len, 0);
+        record
+    }
+
+    #[test]
+    fn parse() {
+        let v2 = parse_record(&record(2, "file.txt")).unwrap();
+        assert_eq!(v2.usn, 1234);
+        assert_eq!(v2.file_id, 1);
+        assert_eq!(v2.parent_id, 2);
+        assert_eq!(v2.timestamp, std::time::UNIX_EPOCH);
+        assert_eq!(v2.reason, 0x100);
+        assert_eq!(v2.attributes, FileAttributes::ARCHIVE);
+        assert_eq!(v2.name, "file.txt");
+        assert_eq!(record_usn(&record(2, "file.txt")), Some(1234));
+
+        let v3 = parse_record(&record(3, "directory")).unwrap();
+        assert_eq!(v3.file_id, 1 << 64);
+        assert_eq!(v3.parent_id, 2);
+        assert_eq!(v3.usn, 1234);
+        assert_eq!(v3.name, "directory");
+        assert_eq!(record_usn(&record(3, "directory")), Some(1234));
+
+        let mut v4 = record(2, "range");
+        v4[4] = 4;
+        assert_eq!(parse_record(&v4), None);
+        assert_eq!(parse_record(&v4[..6]), None);
+    }
+}
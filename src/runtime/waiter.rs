@@ -2,17 +2,34 @@ use crate::*;
 
 use bindings::{
     Windows::Win32::Foundation::{CloseHandle, HANDLE, PSTR},
+    Windows::Win32::System::Com::{
+        CoGetApartmentType, CoWaitForMultipleHandles, APTTYPE, APTTYPEQUALIFIER,
+        APTTYPEQUALIFIER_APPLICATION_STA, APTTYPE_MAINSTA, APTTYPE_STA, COWAIT_DEFAULT,
+        COWAIT_DISPATCH_CALLS,
+    },
     Windows::Win32::System::Threading::{CreateEventA, SetEvent, WaitForSingleObject},
 };
 
+const INFINITE: u32 = 0xFFFF_FFFF;
+
 /// A simple blocking waiter used by the generated bindings and should not be used directly.
+///
+/// How the waiter blocks depends on the apartment of the calling thread:
+///
+/// * In the MTA, or on a thread that hasn't initialized COM, the thread simply blocks until the
+///   waiter is signaled.
+/// * In a classic STA the thread enters the COM modal loop, so incoming COM calls and window
+///   messages continue to be dispatched while waiting.
+/// * In an application STA, such as the UI thread of a packaged app, only the calls needed to
+///   complete marshaled calls are dispatched. Blocking a UI thread is still discouraged and
+///   `.await` should be preferred there.
 pub struct Waiter(HANDLE);
 pub struct WaiterSignaler(HANDLE);
 
 impl Waiter {
     pub fn new() -> (Waiter, WaiterSignaler) {
         unsafe {
-            let handle = CreateEventA(std::ptr::null_mut(), true, false, PSTR::NULL);
+            let handle = CreateEventA(core::ptr::null_mut(), true, false, PSTR::NULL);
             (Waiter(handle), WaiterSignaler(handle))
         }
     }
@@ -32,7 +49,31 @@ impl WaiterSignaler {
 impl Drop for Waiter {
     fn drop(&mut self) {
         unsafe {
-            WaitForSingleObject(self.0, 0xFFFFFFFF);
+            let mut kind = APTTYPE::default();
+            let mut qualifier = APTTYPEQUALIFIER::default();
+
+            let flags = match CoGetApartmentType(&mut kind, &mut qualifier) {
+                Ok(()) if qualifier == APTTYPEQUALIFIER_APPLICATION_STA => {
+                    Some(COWAIT_DISPATCH_CALLS)
+                }
+                Ok(()) if kind == APTTYPE_STA || kind == APTTYPE_MAINSTA => Some(COWAIT_DEFAULT),
+                _ => None,
+            };
+
+            // The completion may require the calling STA to dispatch calls, so it cannot simply
+            // block. If the wait fails the handle must still be signaled before it is closed.
+            let waited = match flags {
+                Some(flags) => {
+                    let mut handle = self.0;
+                    CoWaitForMultipleHandles(flags.0 as _, INFINITE, 1, &mut handle).is_ok()
+                }
+                None => false,
+            };
+
+            if !waited {
+                WaitForSingleObject(self.0, INFINITE);
+            }
+
             CloseHandle(self.0);
         }
     }
@@ -1,8 +1,22 @@
 use crate::*;
 use bindings::Windows::Win32::System::WinRT::{IWeakReference, IWeakReferenceSource};
-use std::marker::PhantomData;
+use core::marker::PhantomData;
 
-/// `Weak` holds a non-owning reference to an object.
+/// `Weak` holds a non-owning reference to an object, as created by the `downgrade` method of a
+/// class or interface, so that the object may be referred to without keeping it alive. This is
+/// useful for event handlers that would otherwise create a reference cycle with the object
+/// raising the event.
+///
+/// ```ignore
+/// let weak = button.downgrade()?;
+///
+/// button.Click(RoutedEventHandler::new(move |_, _| {
+///     if let Some(button) = weak.upgrade() {
+///         button.SetContent(...)?;
+///     }
+///     Ok(())
+/// }))?;
+/// ```
 #[derive(Clone, PartialEq, Eq, Default, Debug)]
 pub struct Weak<I: Interface>(Option<IWeakReference>, PhantomData<I>);
 
@@ -23,7 +37,12 @@ impl<I: Interface> Weak<I> {
     }
 
     pub(crate) fn downgrade(source: &IWeakReferenceSource) -> Result<Self> {
-        let reference = unsafe { source.GetWeakReference().ok() };
-        Ok(Self(reference, PhantomData))
+        let reference = unsafe { source.GetWeakReference()? };
+        Ok(Self(Some(reference), PhantomData))
     }
 }
+
+// Weak references are agile so a `Weak` may be shared across threads as long as the strong
+// references it upgrades to may be.
+unsafe impl<I: Interface + Send> Send for Weak<I> {}
+unsafe impl<I: Interface + Sync> Sync for Weak<I> {}
@@ -13,7 +13,7 @@ use bindings::Windows::Win32::{
         IWeakReference, IWeakReferenceSource, IWeakReferenceSource_abi, IWeakReference_abi,
     },
 };
-use std::sync::atomic::{AtomicIsize, Ordering};
+use core::sync::atomic::{AtomicIsize, Ordering};
 
 /// A thread-safe reference count for use with COM weak reference implementations.
 #[repr(transparent)]
@@ -86,7 +86,7 @@ impl WeakRefCount {
 
     pub unsafe fn query(&self, iid: &::windows::Guid, object: RawPtr) -> RawPtr {
         if iid != &IWeakReferenceSource::IID {
-            return std::ptr::null_mut();
+            return core::ptr::null_mut();
         }
 
         let count_or_pointer = self.0.load(Ordering::Relaxed);
@@ -97,7 +97,7 @@ impl WeakRefCount {
 
         let tear_off = TearOff::new(object, count_or_pointer as _);
         let encoding: usize =
-            ((tear_off.abi() as usize) >> 1) | (1 << (std::mem::size_of::<usize>() * 8 - 1));
+            ((tear_off.abi() as usize) >> 1) | (1 << (core::mem::size_of::<usize>() * 8 - 1));
 
         loop {
             if self
@@ -110,7 +110,7 @@ impl WeakRefCount {
                 )
                 .is_ok()
             {
-                let result: RawPtr = std::mem::transmute(tear_off);
+                let result: RawPtr = core::mem::transmute(tear_off);
                 TearOff::from_strong_ptr(result).strong_count.add_ref();
                 return result;
             }
@@ -142,7 +142,7 @@ struct TearOff {
 
 impl TearOff {
     unsafe fn new(object: RawPtr, strong_count: u32) -> IWeakReferenceSource {
-        std::mem::transmute(Box::new(TearOff {
+        core::mem::transmute(Box::new(TearOff {
             strong_vtable: &Self::STRONG_VTABLE,
             weak_vtable: &Self::WEAK_VTABLE,
             object,
@@ -154,7 +154,7 @@ impl TearOff {
     unsafe fn from_encoding(encoding: isize) -> RawPtr {
         let tear_off = TearOff::decode(encoding);
         tear_off.strong_count.add_ref();
-        std::mem::transmute(tear_off)
+        core::mem::transmute(tear_off)
     }
 
     const STRONG_VTABLE: IWeakReferenceSource_abi = IWeakReferenceSource_abi(
@@ -180,7 +180,7 @@ impl TearOff {
     }
 
     unsafe fn decode<'a>(value: isize) -> &'a mut Self {
-        std::mem::transmute(value << 1)
+        core::mem::transmute(value << 1)
     }
 
     unsafe fn query_interface(&self, iid: *const Guid, interface: *mut RawPtr) -> HRESULT {
@@ -222,7 +222,7 @@ impl TearOff {
             if iid == &IWeakReference::IID || iid == &IUnknown::IID || iid == &IAgileObject::IID {
                 ptr
             } else {
-                std::ptr::null_mut()
+                core::ptr::null_mut()
             };
 
         // TODO: implement IMarshal
@@ -294,7 +294,7 @@ impl TearOff {
 
         loop {
             if count == 0 {
-                *interface = std::ptr::null_mut();
+                *interface = core::ptr::null_mut();
                 return HRESULT(0);
             }
 
@@ -0,0 +1,117 @@
+use crate::*;
+
+/// A short string converted to UTF-16 in a buffer held by a [`StringParam`], so that string
+/// parameters are passed without allocating. Longer strings are instead allocated on the heap.
+/// `WideBuffer` is used by the generated bindings and should not generally be used directly.
+#[doc(hidden)]
+pub struct WideBuffer {
+    // The "fast pass" header referring to the buffer, which is only written once the buffer has
+    // reached its final location.
+    header: HStringReference,
+    reference: bool,
+    len: usize,
+    buffer: [u16; WideBuffer::CAPACITY + 1],
+}
+
+impl WideBuffer {
+    /// The number of UTF-16 code units that fit in the buffer.
+    pub const CAPACITY: usize = 64;
+
+    /// Converts the string to a null-terminated buffer of UTF-16, returning `None` if it may not
+    /// fit. The buffer's ABI value is a pointer to the null-terminated string.
+    pub fn new(value: &str) -> Option<Self> {
+        // A string never has more UTF-16 code units than UTF-8 bytes.
+        if value.len() > Self::CAPACITY {
+            return None;
+        }
+
+        let mut buffer = Self {
+            header: HStringReference::new(core::ptr::null(), 0),
+            reference: false,
+            len: 0,
+            buffer: [0; Self::CAPACITY + 1],
+        };
+
+        for (wide, unit) in buffer.buffer.iter_mut().zip(value.encode_utf16()) {
+            *wide = unit;
+            buffer.len += 1;
+        }
+
+        Some(buffer)
+    }
+
+    /// Converts the string as [`WideBuffer::new`] does, but the buffer's ABI value is an `HSTRING`
+    /// referring to the buffer, as created by `WindowsCreateStringReference`. The callee copies the
+    /// string if it needs to keep it.
+    pub fn reference(value: &str) -> Option<Self> {
+        let mut buffer = Self::new(value)?;
+        buffer.reference = true;
+        Some(buffer)
+    }
+
+    /// Returns the string as 16-bit wide characters (wchars), excluding the null terminator.
+    pub fn as_wide(&self) -> &[u16] {
+        &self.buffer[..self.len]
+    }
+
+    /// Forms the buffer's ABI value. The buffer mustn't move once the ABI value has been formed,
+    /// which [`ParamAbi::abi`] ensures by borrowing the parameter for the duration of the call.
+    pub fn abi(&mut self) -> RawPtr {
+        if !self.reference {
+            return self.buffer.as_mut_ptr() as _;
+        }
+
+        // An empty `HSTRING` is represented by a null pointer.
+        if self.len == 0 {
+            return core::ptr::null_mut();
+        }
+
+        self.header = HStringReference::new(self.buffer.as_ptr(), self.len as u32);
+        &mut self.header as *mut _ as _
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capacity() {
+        assert_eq!(
+            WideBuffer::new("hello").unwrap().as_wide(),
+            HSTRING::from("hello").as_wide()
+        );
+        assert!(WideBuffer::new(&"a".repeat(WideBuffer::CAPACITY)).is_some());
+        assert!(WideBuffer::new(&"a".repeat(WideBuffer::CAPACITY + 1)).is_none());
+
+        // Strings are measured in UTF-8 bytes so a non-ASCII string may be allocated although it
+        // would fit.
+        assert!(WideBuffer::new(&"é".repeat(WideBuffer::CAPACITY / 2 + 1)).is_none());
+    }
+
+    #[test]
+    fn reference() {
+        let mut param = IntoParam::<HSTRING>::into_param("hello");
+        let abi = param.abi();
+        let value: &HSTRING = unsafe { &*(&abi as *const RawPtr as *const HSTRING) };
+        assert_eq!(*value, "hello");
+
+        // Copying the string copies it out of the buffer.
+        let copy = value.clone();
+        drop(param);
+        assert_eq!(copy, "hello");
+
+        let mut param = IntoParam::<HSTRING>::into_param("");
+        assert!(param.abi().is_null());
+    }
+
+    #[test]
+    fn pointer() {
+        let mut buffer = WideBuffer::new("hi").unwrap();
+        let ptr = buffer.abi() as *const u16;
+        assert_eq!(
+            unsafe { core::slice::from_raw_parts(ptr, 3) },
+            [0x68, 0x69, 0]
+        );
+    }
+}
@@ -0,0 +1,158 @@
+/// A null-terminated UTF-16 string with static lifetime, as created by the [`w`](crate::w) macro.
+///
+/// The generated bindings accept a `WideStr` for `PWSTR` parameters without allocating.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct WideStr(&'static [u16]);
+
+impl WideStr {
+    /// # Safety
+    /// `value` must end with a null character. Use the [`w`](crate::w) macro instead.
+    #[doc(hidden)]
+    pub const unsafe fn from_static(value: &'static [u16]) -> Self {
+        Self(value)
+    }
+
+    /// Returns a pointer to the null-terminated string.
+    pub fn as_ptr(&self) -> *const u16 {
+        self.0.as_ptr()
+    }
+
+    /// Get the string as 16-bit wide characters (wchars), excluding the null terminator.
+    pub fn as_wide(&self) -> &'static [u16] {
+        &self.0[..self.0.len() - 1]
+    }
+
+    /// Returns the length of the string, excluding the null terminator.
+    pub fn len(&self) -> usize {
+        self.0.len() - 1
+    }
+
+    /// Returns `true` if the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl core::fmt::Display for WideStr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        use core::fmt::Write;
+        for c in core::char::decode_utf16(self.as_wide().iter().cloned()) {
+            f.write_char(c.map_err(|_| core::fmt::Error)?)?
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for WideStr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+// Decodes the UTF-8 character at `pos`, returning the code point and the position of the next
+// character. The input comes from a string literal so it is known to be valid UTF-8.
+#[doc(hidden)]
+pub const fn decode_utf8_char(bytes: &[u8], mut pos: usize) -> Option<(u32, usize)> {
+    if bytes.len() == pos {
+        return None;
+    }
+
+    let first = bytes[pos] as u32;
+    pos += 1;
+
+    let (mut code_point, continuation) = if first < 0x80 {
+        (first, 0)
+    } else if first & 0xe0 == 0xc0 {
+        (first & 0x1f, 1)
+    } else if first & 0xf0 == 0xe0 {
+        (first & 0x0f, 2)
+    } else {
+        (first & 0x07, 3)
+    };
+
+    let mut index = 0;
+
+    while index < continuation {
+        code_point = (code_point << 6) | (bytes[pos] as u32 & 0x3f);
+        pos += 1;
+        index += 1;
+    }
+
+    Some((code_point, pos))
+}
+
+/// Returns the number of UTF-16 code units needed to encode the UTF-8 `bytes`.
+#[doc(hidden)]
+pub const fn utf16_len(bytes: &[u8]) -> usize {
+    let mut pos = 0;
+    let mut len = 0;
+
+    while let Some((code_point, next)) = decode_utf8_char(bytes, pos) {
+        pos = next;
+        len += if code_point >= 0x10000 { 2 } else { 1 };
+    }
+
+    len
+}
+
+/// Encodes the UTF-8 `bytes` as UTF-16. `N` must be greater than [`utf16_len`] so that the
+/// result is null-terminated.
+#[doc(hidden)]
+pub const fn encode_utf16<const N: usize>(bytes: &[u8]) -> [u16; N] {
+    let mut buffer = [0; N];
+    let mut pos = 0;
+    let mut index = 0;
+
+    while let Some((mut code_point, next)) = decode_utf8_char(bytes, pos) {
+        pos = next;
+
+        if code_point <= 0xffff {
+            buffer[index] = code_point as u16;
+            index += 1;
+        } else {
+            code_point -= 0x10000;
+            buffer[index] = 0xd800 + (code_point >> 10) as u16;
+            buffer[index + 1] = 0xdc00 + (code_point & 0x3ff) as u16;
+            index += 2;
+        }
+    }
+
+    buffer
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode() {
+        fn check(value: &str) {
+            const LEN: usize = 16;
+            let expected: Vec<u16> = value.encode_utf16().collect();
+            assert_eq!(utf16_len(value.as_bytes()), expected.len());
+
+            let encoded: [u16; LEN] = encode_utf16(value.as_bytes());
+            assert_eq!(&encoded[..expected.len()], expected.as_slice());
+            assert!(encoded[expected.len()..].iter().all(|c| *c == 0));
+        }
+
+        check("");
+        check("hello");
+        check("ümlaut");
+        check("€ sign");
+        check("𝄞 clef");
+    }
+
+    #[test]
+    fn macro_literal() {
+        let value = crate::w!("𝄞mu");
+        assert_eq!(value.len(), 4);
+        assert_eq!(value.as_wide(), &[0xD834, 0xDD1E, 0x006d, 0x0075]);
+        assert_eq!(unsafe { *value.as_ptr().add(4) }, 0);
+        assert_eq!(value.to_string(), "𝄞mu");
+
+        let empty = crate::w!("");
+        assert!(empty.is_empty());
+        assert_eq!(unsafe { *empty.as_ptr() }, 0);
+    }
+}
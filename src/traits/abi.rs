@@ -18,7 +18,7 @@ pub unsafe trait Abi: Sized {
     fn abi(&self) -> Self::Abi {
         // It is always safe to interpret an `Abi` type's binary representation (without moving
         // the value) as the memory layout must be identical.
-        unsafe { std::mem::transmute_copy(self) }
+        unsafe { core::mem::transmute_copy(self) }
     }
 
     /// Returns a pointer for setting the object's value via an ABI call.
@@ -31,7 +31,7 @@ pub unsafe trait Abi: Sized {
     /// Casts the ABI representation to a Rust object by taking ownership of the bits.
     // Note: This default implementation is correct for all but interfaces.
     unsafe fn from_abi(abi: Self::Abi) -> Result<Self> {
-        Ok(std::mem::transmute_copy(&abi))
+        Ok(core::mem::transmute_copy(&abi))
     }
 
     fn drop_param(_: &mut Param<Self>) {}
@@ -45,12 +45,12 @@ unsafe impl<T: Interface> Abi for T {
     }
 
     unsafe fn from_abi(abi: Self::Abi) -> Result<Self> {
-        let abi: RawPtr = std::mem::transmute_copy(&abi);
+        let abi: RawPtr = core::mem::transmute_copy(&abi);
 
         if abi.is_null() {
             Err(Error::fast_error(E_POINTER))
         } else {
-            Ok(std::mem::transmute_copy(&abi))
+            Ok(core::mem::transmute_copy(&abi))
         }
     }
 }
@@ -17,23 +17,31 @@ pub unsafe trait Interface: Sized + Abi {
     /// Returns the vtable for an assumed interface. The name comes from [`Box::assume_init()`] as
     /// it assumes the vtable is implemented by the current interface's vtable (e.g. a parent interface).
     unsafe fn assume_vtable<T: Interface>(&self) -> &T::Vtable {
-        let this: RawPtr = std::mem::transmute_copy(self);
+        let this: RawPtr = core::mem::transmute_copy(self);
         &(*(*(this as *mut *mut _) as *mut _))
     }
 
     unsafe fn query(&self, iid: *const Guid, interface: *mut RawPtr) -> HRESULT {
-        (self.assume_vtable::<IUnknown>().0)(std::mem::transmute_copy(self), iid, interface)
+        (self.assume_vtable::<IUnknown>().0)(core::mem::transmute_copy(self), iid, interface)
     }
 
     /// Attempts to cast the current interface to another interface using `QueryInterface`.
     /// The name `cast` is preferred to `query` because there is a WinRT method named query but not one
     /// named cast.
+    ///
+    /// This is available on every interface and class as well as [`IUnknown`] and [`IInspectable`],
+    /// so any two interfaces implemented by the same object may be converted with a single call.
+    /// A failed cast returns an error with the `E_NOINTERFACE` code.
+    ///
+    /// ```ignore
+    /// let stringable: IStringable = uri.cast()?;
+    /// ```
     fn cast<T: Interface>(&self) -> Result<T> {
         unsafe {
             let mut result = None;
 
             (self.assume_vtable::<IUnknown>().0)(
-                std::mem::transmute_copy(self),
+                core::mem::transmute_copy(self),
                 &T::IID,
                 &mut result as *mut _ as _,
             )
@@ -41,6 +49,47 @@ pub unsafe trait Interface: Sized + Abi {
         }
     }
 
+    /// Attempts to cast the current interface to another interface or class, returning `None`
+    /// if the object doesn't implement it. A class is queried for by its default interface, so
+    /// this downcasts an [`IInspectable`], such as an event's sender or a value of a property
+    /// set, to the class it is expected to be.
+    ///
+    /// ```ignore
+    /// if let Some(file) = item.try_cast::<StorageFile>() {
+    ///     println!("{}", file.Path()?);
+    /// }
+    /// ```
+    fn try_cast<T: Interface>(&self) -> Option<T> {
+        self.cast().ok()
+    }
+
+    /// Returns the raw interface pointer without affecting the reference count, like
+    /// [`std::sync::Arc::as_ptr`]. The pointer is only valid while `self` is alive.
+    fn as_raw(&self) -> RawPtr {
+        unsafe { core::mem::transmute_copy(self) }
+    }
+
+    /// Consumes the interface and returns the raw interface pointer without releasing it, like
+    /// [`Box::into_raw`]. The caller becomes responsible for the reference, which may be passed to
+    /// code that adopts it or turned back into an interface with [`Interface::from_raw`].
+    fn into_raw(self) -> RawPtr {
+        let raw = self.as_raw();
+        core::mem::forget(self);
+        raw
+    }
+
+    /// Takes ownership of a raw interface pointer without calling `AddRef`, like
+    /// [`Box::from_raw`]. The reference is released when the returned interface is dropped.
+    ///
+    /// # Safety
+    /// `raw` must be a non-null pointer to this interface, such as one returned by
+    /// [`Interface::into_raw`], and the caller must own the reference being adopted. To borrow a
+    /// pointer that the caller does not own, wrap the result in [`core::mem::ManuallyDrop`].
+    unsafe fn from_raw(raw: RawPtr) -> Self {
+        debug_assert!(!raw.is_null());
+        core::mem::transmute_copy(&raw)
+    }
+
     /// Attempts to create a [`Weak`] reference to this object.
     fn downgrade(&self) -> Result<Weak<Self>> {
         self.cast::<IWeakReferenceSource>()
@@ -1,22 +1,37 @@
 use super::*;
 
 pub trait IntoParam<'a, T: Abi> {
-    fn into_param(self) -> Param<'a, T>;
+    /// The parameter held for the duration of the call, usually a [`Param`].
+    type Param: ParamAbi<T>;
+
+    fn into_param(self) -> Self::Param;
+}
+
+/// Forms the ABI value of a parameter converted by [`IntoParam`]. The parameter must outlive the
+/// call as the ABI value may refer to it.
+pub trait ParamAbi<T: Abi> {
+    fn abi(&mut self) -> T::Abi;
 }
 
-impl<'a, T: Abi> IntoParam<'a, T> for T {
+impl<'a, T: Abi + 'a> IntoParam<'a, T> for T {
+    type Param = Param<'a, T>;
+
     fn into_param(self) -> Param<'a, T> {
         Param::Owned(self)
     }
 }
 
 impl<'a, T: Abi> IntoParam<'a, T> for &'a T {
+    type Param = Param<'a, T>;
+
     fn into_param(self) -> Param<'a, T> {
         Param::Borrowed(self)
     }
 }
 
-impl<'a, T: Abi> IntoParam<'a, T> for Option<T> {
+impl<'a, T: Abi + 'a> IntoParam<'a, T> for Option<T> {
+    type Param = Param<'a, T>;
+
     fn into_param(self) -> Param<'a, T> {
         match self {
             Some(value) => Param::Owned(value),
@@ -26,6 +41,8 @@ impl<'a, T: Abi> IntoParam<'a, T> for Option<T> {
 }
 
 impl<'a, T: Abi> IntoParam<'a, T> for &'a Option<T> {
+    type Param = Param<'a, T>;
+
     fn into_param(self) -> Param<'a, T> {
         match self {
             Some(value) => Param::Borrowed(value),
@@ -34,14 +51,34 @@ impl<'a, T: Abi> IntoParam<'a, T> for &'a Option<T> {
     }
 }
 
+// Short strings are converted into a buffer held by a `StringParam`, which is passed as a "fast
+// pass" string referring to it, so that the call doesn't allocate.
 impl<'a> IntoParam<'a, HSTRING> for &'a str {
-    fn into_param(self) -> Param<'a, HSTRING> {
-        Param::Owned(self.into())
+    type Param = StringParam<'a, HSTRING>;
+
+    fn into_param(self) -> StringParam<'a, HSTRING> {
+        match WideBuffer::reference(self) {
+            Some(buffer) => StringParam::Buffer(buffer),
+            None => StringParam::Param(Param::Owned(self.into())),
+        }
     }
 }
 
 impl<'a> IntoParam<'a, HSTRING> for String {
-    fn into_param(self) -> Param<'a, HSTRING> {
-        Param::Owned(self.into())
+    type Param = StringParam<'a, HSTRING>;
+
+    fn into_param(self) -> StringParam<'a, HSTRING> {
+        match WideBuffer::reference(&self) {
+            Some(buffer) => StringParam::Buffer(buffer),
+            None => StringParam::Param(Param::Owned(self.into())),
+        }
+    }
+}
+
+impl<'a> IntoParam<'a, HSTRING> for &'a String {
+    type Param = StringParam<'a, HSTRING>;
+
+    fn into_param(self) -> StringParam<'a, HSTRING> {
+        IntoParam::<'a, HSTRING>::into_param(self.as_str())
     }
 }
@@ -0,0 +1,7 @@
+fn main() {
+    windows::build! {
+        api_stats = true,
+        Windows::Win32::Foundation::CloseHandle,
+        Windows::Win32::System::Threading::{CreateEventW, SetEvent},
+    };
+}
@@ -0,0 +1,29 @@
+use test_api_stats::Windows::Win32::{
+    Foundation::{CloseHandle, PWSTR},
+    System::Threading::{CreateEventW, SetEvent},
+};
+
+#[test]
+fn test() -> windows::Result<()> {
+    unsafe {
+        let event = CreateEventW(std::ptr::null_mut(), true, false, PWSTR::NULL);
+        assert!(event.0 != 0);
+
+        SetEvent(event).ok()?;
+        SetEvent(event).ok()?;
+        CloseHandle(event).ok()?;
+    }
+
+    let usage = windows::api_usage();
+    assert_eq!(usage.len(), 3);
+    assert_eq!(usage[0], ("Windows.Win32.Foundation.CloseHandle", 1));
+    assert_eq!(usage[1], ("Windows.Win32.System.Threading.CreateEventW", 1));
+    assert_eq!(usage[2], ("Windows.Win32.System.Threading.SetEvent", 2));
+
+    let mut report = Vec::new();
+    windows::write_api_usage(&mut report).unwrap();
+    let report = String::from_utf8(report).unwrap();
+    assert!(report.starts_with("Windows.Win32.Foundation.CloseHandle 1\n"));
+
+    Ok(())
+}
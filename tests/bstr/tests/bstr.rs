@@ -34,3 +34,15 @@ fn clone() {
     assert_eq!(a.as_wide(), wide);
     assert_eq!(a, "hello");
 }
+
+#[test]
+fn display() {
+    let a: BSTR = "hello".into();
+    assert_eq!(a.to_string(), "hello");
+    assert!("hello" == a);
+    assert!(String::from("hello") == a);
+    assert!(a == *"hello");
+
+    let a = BSTR::from_wide(&[0x68, 0xD800, 0x69]);
+    assert_eq!(a.to_string(), "h\u{FFFD}i");
+}
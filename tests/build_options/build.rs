@@ -0,0 +1,7 @@
+fn main() {
+    windows::build! {
+        rustfmt = false,
+        experimental = true,
+        Windows::Win32::Foundation::{CloseHandle, HANDLE},
+    };
+}
@@ -0,0 +1,14 @@
+use test_build_options::Windows::Win32::Foundation::{CloseHandle, HANDLE};
+
+#[test]
+fn unformatted() {
+    let path = std::path::Path::new(env!("OUT_DIR")).join("windows.rs");
+    let code = std::fs::read_to_string(path).unwrap();
+
+    // rustfmt would have indented the contents of the generated modules.
+    assert!(!code.contains("\n    "));
+
+    unsafe {
+        assert!(!CloseHandle(HANDLE(0)).as_bool());
+    }
+}
@@ -0,0 +1,6 @@
+fn main() {
+    windows::build! {
+        codegen_version = 1,
+        Windows::Foundation::{IReferenceArray, PropertyValue, Uri},
+    };
+}
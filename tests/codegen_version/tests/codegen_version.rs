@@ -0,0 +1,13 @@
+use test_codegen_version::Windows::Foundation::{IReferenceArray, PropertyValue};
+use windows::Interface;
+
+#[test]
+fn received_array() -> windows::Result<()> {
+    let value = PropertyValue::CreateInt32Array(&[1, 2, 3])?.cast::<IReferenceArray<i32>>()?;
+
+    // Version 1 returns received arrays as `Array<T>` rather than `Vec<T>`.
+    let array: windows::Array<i32> = value.Value()?;
+    assert_eq!(&array[..], [1, 2, 3]);
+
+    Ok(())
+}
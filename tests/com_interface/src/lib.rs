@@ -0,0 +1,26 @@
+use windows::{Guid, IUnknown, HRESULT};
+
+windows::com_interface! {
+    #[uuid("0000010c-0000-0000-C000-000000000046")]
+    pub interface IPersist: IUnknown {
+        fn GetClassID(&self, clsid: *mut Guid) -> HRESULT;
+    }
+}
+
+windows::com_interface! {
+    #[uuid("0000010b-0000-0000-C000-000000000046")]
+    pub interface IPersistFile: IPersist {
+        fn IsDirty(&self) -> HRESULT;
+        fn Load(&self, file_name: *const u16, mode: u32) -> HRESULT;
+        fn Save(&self, file_name: *const u16, remember: i32) -> HRESULT;
+        fn SaveCompleted(&self, file_name: *const u16) -> HRESULT;
+        fn GetCurFile(&self, file_name: *mut *mut u16) -> HRESULT;
+    }
+}
+
+pub const CLSID_SHELL_LINK: Guid = Guid::from_values(
+    0x0002_1401,
+    0x0000,
+    0x0000,
+    [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+);
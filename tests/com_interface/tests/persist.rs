@@ -0,0 +1,25 @@
+use test_com_interface::*;
+use windows::*;
+
+#[test]
+fn persist() -> Result<()> {
+    initialize_sta()?;
+
+    let file: IPersistFile = create_instance(&CLSID_SHELL_LINK)?;
+
+    unsafe {
+        // A new shell link has not been changed so it is not dirty.
+        assert_eq!(file.IsDirty(), HRESULT(1));
+
+        // The base interface's methods may be called directly.
+        let mut clsid = Guid::zeroed();
+        file.GetClassID(&mut clsid).ok()?;
+        assert_eq!(clsid, CLSID_SHELL_LINK);
+    }
+
+    let persist: IPersist = file.cast()?;
+    assert_eq!(IUnknown::from(&persist), IUnknown::from(&file));
+    assert_eq!(IPersist::from(&file).cast::<IPersistFile>()?, file);
+
+    Ok(())
+}
@@ -0,0 +1,7 @@
+fn main() {
+    windows::build! {
+        contracts = ["UniversalApiContract 5"],
+        contract_features = true,
+        Windows::UI::Xaml::Controls::TextBlock,
+    };
+}
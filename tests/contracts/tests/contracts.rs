@@ -0,0 +1,18 @@
+use test_contracts::Windows::UI::Xaml::{Controls::TextBlock, DependencyObject};
+
+#[test]
+fn test() {
+    // Methods available to the targeted contract version are never gated.
+    let _ = TextBlock::Text;
+    let _ = |text: &TextBlock| text.SetText("");
+
+    // Methods introduced by later versions are available when the version's feature is enabled.
+    let _ = TextBlock::KeyTipTarget;
+    let _ = |text: &TextBlock, target: &DependencyObject| text.SetKeyTipTarget(target);
+}
+
+#[test]
+fn present() {
+    assert!(TextBlock::is_present());
+    assert!(TextBlock::is_key_tip_target_present());
+}
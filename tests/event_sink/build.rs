@@ -0,0 +1,9 @@
+fn main() {
+    windows::build! {
+        Windows::Win32::Foundation::{BOOL, BSTR, PWSTR},
+        Windows::Win32::System::Com::{IFont, OleCreateFontIndirect, FONTDESC},
+        Windows::Win32::System::OleAutomation::{
+            IDispatch, ITypeLib, LoadRegTypeLib, DISPPARAMS, VARIANT,
+        },
+    };
+}
@@ -0,0 +1,69 @@
+use std::sync::{Arc, Mutex};
+use test_event_sink::Windows::Win32::{
+    Foundation::{BOOL, PWSTR},
+    System::Com::{IFont, OleCreateFontIndirect, FONTDESC},
+    System::OleAutomation::LoadRegTypeLib,
+};
+use windows::*;
+
+// The OLE Automation type library, which is registered on every system.
+const STDOLE: Guid = Guid::from_values(
+    0x0002_0430,
+    0x0000,
+    0x0000,
+    [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+);
+
+// The `FontEvents` event dispatch interface raised by OLE font objects.
+const FONT_EVENTS: Guid = Guid::from_values(
+    0x4EF6_100A,
+    0xAF88,
+    0x11D0,
+    [0x98, 0x46, 0x00, 0xC0, 0x4F, 0xC2, 0x99, 0x93],
+);
+
+fn create_font() -> Result<IFont> {
+    let mut name: Vec<u16> = "Arial".encode_utf16().chain(std::iter::once(0)).collect();
+
+    let mut desc = FONTDESC {
+        cbSizeofstruct: std::mem::size_of::<FONTDESC>() as _,
+        lpstrName: PWSTR(name.as_mut_ptr()),
+        sWeight: 400,
+        ..Default::default()
+    };
+
+    unsafe { OleCreateFontIndirect(&mut desc) }
+}
+
+#[test]
+fn connection_point() -> Result<()> {
+    initialize_mta()?;
+    let font = create_font()?;
+    let changed = Arc::new(Mutex::new(Vec::new()));
+    let handler_changed = changed.clone();
+
+    let type_info = unsafe { LoadRegTypeLib(&STDOLE, 2, 0, 0)?.GetTypeInfoOfGuid(&FONT_EVENTS)? };
+
+    let sink = EventSink::new(&type_info)?
+        .on("FontChanged", move |args| {
+            handler_changed.lock().unwrap().push(args.string(0)?);
+            Ok(())
+        })?
+        .into_unknown();
+
+    let connection = EventConnection::with_iid(&font, &FONT_EVENTS, &sink)?;
+    assert_ne!(connection.cookie(), 0);
+
+    unsafe { font.put_Bold(BOOL(1))? };
+    assert_eq!(*changed.lock().unwrap(), ["Bold"]);
+
+    // The sink is disconnected once the connection is dropped.
+    drop(connection);
+    unsafe { font.put_Italic(BOOL(1))? };
+    assert_eq!(*changed.lock().unwrap(), ["Bold"]);
+
+    // The source must have a connection point for the outgoing interface.
+    assert!(EventConnection::with_iid(&font, &Guid::zeroed(), &sink).is_err());
+
+    Ok(())
+}
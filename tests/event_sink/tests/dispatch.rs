@@ -0,0 +1,66 @@
+use test_event_sink::Windows::Win32::{
+    Foundation::PWSTR,
+    System::Com::{IFont, OleCreateFontIndirect, FONTDESC},
+};
+use windows::*;
+
+fn create_font() -> Result<IFont> {
+    let mut name: Vec<u16> = "Arial".encode_utf16().chain(std::iter::once(0)).collect();
+
+    let mut desc = FONTDESC {
+        cbSizeofstruct: std::mem::size_of::<FONTDESC>() as _,
+        lpstrName: PWSTR(name.as_mut_ptr()),
+        sWeight: 400,
+        ..Default::default()
+    };
+
+    unsafe { OleCreateFontIndirect(&mut desc) }
+}
+
+#[test]
+fn dictionary() -> Result<()> {
+    initialize_mta()?;
+    let dictionary = Dispatch::create("Scripting.Dictionary")?;
+
+    assert!(dictionary.call("Add", &["a".into(), 1.into()])?.is_empty());
+    dictionary.call("Add", &["b".into(), "two".into()])?;
+    assert_eq!(dictionary.get("Count")?.i32()?, 2);
+
+    assert_eq!(dictionary.call("Item", &["a".into()])?.i32()?, 1);
+    assert_eq!(dictionary.call("Item", &["b".into()])?.string()?, "two");
+    assert!(!dictionary.call("Exists", &["c".into()])?.bool()?);
+
+    // Adding an existing key raises an exception.
+    assert!(dictionary.call("Add", &["a".into(), 3.into()]).is_err());
+    assert!(dictionary.id("Missing").is_err());
+
+    dictionary.call("RemoveAll", &[])?;
+    assert_eq!(dictionary.get("Count")?.i32()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn properties() -> Result<()> {
+    initialize_mta()?;
+    let font = Dispatch::new(&create_font()?)?;
+
+    assert_eq!(font.get("Name")?.string()?, "Arial");
+    assert!(!font.get("Bold")?.bool()?);
+
+    font.put("Bold", true)?;
+    font.put("Name", "Courier New")?;
+    assert!(font.get("Bold")?.bool()?);
+    assert_eq!(font.get("Name")?.string()?, "Courier New");
+
+    let copy = font.get("Name")?.try_clone()?;
+    assert_eq!(copy.string()?, "Courier New");
+
+    // Objects are passed as arguments and returned as `Dispatch` values.
+    let dictionary = Dispatch::create("Scripting.Dictionary")?;
+    dictionary.call("Add", &["font".into(), (&font).into()])?;
+    let item = dictionary.call("Item", &["font".into()])?.dispatch()?;
+    assert_eq!(item, font);
+
+    Ok(())
+}
@@ -0,0 +1,124 @@
+use std::sync::{Arc, Mutex};
+use test_event_sink::Windows::Win32::{
+    Foundation::BSTR,
+    System::OleAutomation::{IDispatch, ITypeInfo, LoadRegTypeLib, DISPPARAMS, VARIANT},
+};
+use windows::*;
+
+// The OLE Automation type library, which is registered on every system.
+const STDOLE: Guid = Guid::from_values(
+    0x0002_0430,
+    0x0000,
+    0x0000,
+    [0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46],
+);
+
+// The `FontEvents` event dispatch interface raised by OLE font objects.
+const FONT_EVENTS: Guid = Guid::from_values(
+    0x4EF6_100A,
+    0xAF88,
+    0x11D0,
+    [0x98, 0x46, 0x00, 0xC0, 0x4F, 0xC2, 0x99, 0x93],
+);
+
+const FONT_CHANGED: i32 = 9;
+const DISPATCH_METHOD: u16 = 1;
+const VT_BSTR: u16 = 8;
+const VT_BOOL: u16 = 11;
+const VT_BYREF: u16 = 0x4000;
+
+fn font_events() -> Result<ITypeInfo> {
+    unsafe { LoadRegTypeLib(&STDOLE, 2, 0, 0)?.GetTypeInfoOfGuid(&FONT_EVENTS) }
+}
+
+fn invoke(dispatch: &IDispatch, id: i32, arg: &mut VARIANT) -> Result<()> {
+    let mut params = DISPPARAMS {
+        rgvarg: arg,
+        rgdispidNamedArgs: std::ptr::null_mut(),
+        cArgs: 1,
+        cNamedArgs: 0,
+    };
+
+    unsafe {
+        dispatch.Invoke(
+            id,
+            &Guid::zeroed(),
+            0,
+            DISPATCH_METHOD,
+            &mut params,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    }
+}
+
+#[test]
+fn by_name() -> Result<()> {
+    initialize_mta()?;
+    let changed = Arc::new(Mutex::new(Vec::new()));
+    let handler_changed = changed.clone();
+
+    let sink = EventSink::new(&font_events()?)?.on("FontChanged", move |args| {
+        assert_eq!(args.len(), 1);
+        handler_changed.lock().unwrap().push(args.string(0)?);
+
+        // The argument cannot be converted to a number.
+        assert!(args.i32(0).is_err());
+        assert!(args.string(1).is_err());
+        Ok(())
+    })?;
+
+    assert_eq!(sink.iid(), FONT_EVENTS);
+    assert!(EventSink::new(&font_events()?)?
+        .on("Missing", |_| Ok(()))
+        .is_err());
+
+    let sink = sink.into_unknown();
+    let dispatch: IDispatch = sink.cast()?;
+
+    let mut events = None;
+    unsafe { sink.query(&FONT_EVENTS, &mut events as *mut _ as _).ok()? };
+    let _: IUnknown = events.unwrap();
+
+    let name = BSTR::from("Size");
+    let mut arg = VARIANT::default();
+
+    unsafe {
+        arg.Anonymous.Anonymous.vt = VT_BSTR;
+        arg.Anonymous.Anonymous.Anonymous.bstrVal = std::mem::transmute_copy(&name);
+    }
+
+    invoke(&dispatch, FONT_CHANGED, &mut arg)?;
+
+    // Events without a handler are ignored.
+    invoke(&dispatch, FONT_CHANGED + 1, &mut arg)?;
+
+    assert_eq!(*changed.lock().unwrap(), ["Size"]);
+    Ok(())
+}
+
+#[test]
+fn by_reference() -> Result<()> {
+    initialize_mta()?;
+
+    let dispatch: IDispatch = EventSink::new(&font_events()?)?
+        .on_id(FONT_CHANGED, |args| {
+            assert!(!args.bool(0)?);
+            args.set_bool(0, true)
+        })
+        .into_unknown()
+        .cast()?;
+
+    let mut cancel: i16 = 0;
+    let mut arg = VARIANT::default();
+
+    unsafe {
+        arg.Anonymous.Anonymous.vt = VT_BYREF | VT_BOOL;
+        arg.Anonymous.Anonymous.Anonymous.pboolVal = &mut cancel;
+    }
+
+    invoke(&dispatch, FONT_CHANGED, &mut arg)?;
+    assert_eq!(cancel, -1);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use test_glam::Windows::Foundation::Numerics::*;
+
+#[test]
+fn vectors() {
+    let value = Vector2::new(1.0, 2.0);
+    let converted: glam::Vec2 = value.clone().into();
+    assert_eq!(converted, glam::Vec2::new(1.0, 2.0));
+    assert_eq!(Vector2::from(converted), value);
+
+    let value = Vector3::new(1.0, 2.0, 3.0);
+    let converted: glam::Vec3 = value.clone().into();
+    assert_eq!(converted, glam::Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(Vector3::from(converted), value);
+
+    let value = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let converted: glam::Vec4 = value.clone().into();
+    assert_eq!(converted, glam::Vec4::new(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Vector4::from(converted), value);
+}
+
+#[test]
+fn quaternion() {
+    let value = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let other = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    let converted: glam::Quat = value.clone().into();
+    assert_eq!(converted, glam::Quat::from_xyzw(1.0, 2.0, 3.0, 4.0));
+    assert_eq!(Quaternion::from(converted), value);
+
+    // Both use the Hamilton product.
+    let product: glam::Quat = (value.clone() * other.clone()).into();
+    assert_eq!(product, converted * glam::Quat::from(other));
+}
+
+#[test]
+fn matrices() {
+    // Translations are stored in the same elements so that transforms carry over.
+    let value = Matrix3x2::translation(10.0, 20.0);
+    let converted: glam::Affine2 = value.clone().into();
+    assert_eq!(
+        converted.transform_point2(glam::Vec2::new(1.0, 2.0)),
+        glam::Vec2::new(11.0, 22.0)
+    );
+    assert_eq!(Matrix3x2::from(converted), value);
+
+    let value = Matrix4x4 {
+        M11: 1.0,
+        M22: 1.0,
+        M33: 1.0,
+        M41: 10.0,
+        M42: 20.0,
+        M43: 30.0,
+        M44: 1.0,
+        ..Default::default()
+    };
+
+    let converted: glam::Mat4 = value.clone().into();
+    assert_eq!(
+        converted,
+        glam::Mat4::from_translation(glam::Vec3::new(10.0, 20.0, 30.0))
+    );
+    assert_eq!(Matrix4x4::from(converted), value);
+}
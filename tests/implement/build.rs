@@ -1,6 +1,10 @@
 fn main() {
     windows::build! {
         Windows::Foundation::{IClosable, IStringable},
-        Windows::UI::Xaml::{Application, Controls::Button},
+        Windows::UI::Xaml::{
+            Application, Controls::Button, IApplicationOverrides,
+            Data::{INotifyPropertyChanged, PropertyChangedEventArgs, PropertyChangedEventHandler},
+        },
+        Windows::Win32::System::{Com::IMarshal, WinRT::IWeakReferenceSource},
     };
 }
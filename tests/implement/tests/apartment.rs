@@ -0,0 +1,62 @@
+use std::sync::Arc;
+use test_implement::*;
+use windows::*;
+use Windows::Foundation::IStringable;
+
+#[implement(Windows::Foundation::IStringable)]
+struct Agile();
+
+#[allow(non_snake_case)]
+impl Agile {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Agile".into())
+    }
+}
+
+#[implement(agile = false, Windows::Foundation::IStringable)]
+struct ThreadBound();
+
+#[allow(non_snake_case)]
+impl ThreadBound {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("ThreadBound".into())
+    }
+}
+
+#[test]
+fn agile() -> Result<()> {
+    let object: IStringable = Agile().into();
+    let checked = Arc::new(ApartmentChecked::new(object));
+
+    let other = checked.clone();
+    let value = std::thread::spawn(move || other.ToString().unwrap())
+        .join()
+        .unwrap();
+
+    assert_eq!(value, "Agile");
+    assert_eq!(checked.ToString()?, "Agile");
+    Ok(())
+}
+
+#[test]
+fn thread_bound() -> Result<()> {
+    let object: IStringable = ThreadBound().into();
+    let checked = Arc::new(ApartmentChecked::new(object));
+    assert_eq!(checked.ToString()?, "ThreadBound");
+
+    let other = checked.clone();
+    let result = std::thread::spawn(move || {
+        assert!(!other.is_valid());
+        assert_eq!(other.try_get().unwrap_err().code(), HRESULT(0x8001010E));
+        other.ToString()
+    })
+    .join();
+
+    let message = result.unwrap_err();
+    let message = message.downcast_ref::<String>().unwrap();
+    assert!(message.contains("IStringable"));
+
+    assert!(checked.is_valid());
+    assert_eq!(checked.ToString()?, "ThreadBound");
+    Ok(())
+}
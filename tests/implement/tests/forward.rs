@@ -0,0 +1,68 @@
+use test_implement::*;
+use windows::*;
+use Windows::Foundation::{IClosable, IStringable};
+
+#[implement(Windows::Foundation::{IStringable, IClosable})]
+struct Inner();
+
+#[allow(non_snake_case)]
+impl Inner {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Inner".into())
+    }
+
+    fn Close(&self) -> Result<()> {
+        Err(Error::fast_error(HRESULT(0x8000_000E))) // E_ILLEGAL_METHOD_CALL
+    }
+}
+
+#[implement(forward = inner, override ToString, Windows::Foundation::{IStringable, IClosable})]
+struct Wrapper {
+    inner: IInspectable,
+}
+
+#[allow(non_snake_case)]
+impl Wrapper {
+    fn ToString(&self) -> Result<HSTRING> {
+        let inner = self.inner.cast::<IStringable>()?.ToString()?;
+        Ok(format!("Wrapped {}", inner).into())
+    }
+}
+
+#[implement(forward = 0, Windows::Foundation::IClosable)]
+struct Forwarder(IStringable);
+
+#[test]
+fn forward() -> Result<()> {
+    let wrapper: IStringable = Wrapper {
+        inner: Inner().into(),
+    }
+    .into();
+
+    assert_eq!(wrapper.ToString()?, "Wrapped Inner");
+
+    let error = wrapper.cast::<IClosable>()?.Close().unwrap_err();
+    assert_eq!(error.code(), HRESULT(0x8000_000E));
+
+    Ok(())
+}
+
+#[test]
+fn forward_missing_interface() -> Result<()> {
+    #[implement(Windows::Foundation::IStringable)]
+    struct Stringable();
+
+    #[allow(non_snake_case)]
+    impl Stringable {
+        fn ToString(&self) -> Result<HSTRING> {
+            Ok("Stringable".into())
+        }
+    }
+
+    // The wrapped object doesn't implement `IClosable` so the call fails rather than the query.
+    let closable: IClosable = Forwarder(Stringable().into()).into();
+    let error = closable.Close().unwrap_err();
+    assert_eq!(error.code(), HRESULT(0x8000_4002)); // E_NOINTERFACE
+
+    Ok(())
+}
@@ -18,6 +18,20 @@ fn test_implement() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_forward_overrides() -> Result<()> {
+    use Windows::UI::Xaml::IApplicationOverrides;
+
+    // Overrides that aren't implemented by the struct are forwarded to the base class, which
+    // only exists once the object is created by one of the class's constructors.
+    let app: IInspectable = AppWithOverrides {}.into();
+    let overrides: IApplicationOverrides = app.cast()?;
+    overrides.OnLaunched(None)?;
+    overrides.OnActivated(None)?;
+
+    Ok(())
+}
+
 #[implement(
     extend Windows::UI::Xaml::Application,
     override OnLaunched OnBackgroundActivated,
@@ -0,0 +1,33 @@
+use test_implement::*;
+use windows::*;
+use Windows::Foundation::IStringable;
+
+#[implement(Windows::Foundation::IStringable)]
+struct Tracked();
+
+#[allow(non_snake_case)]
+impl Tracked {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Tracked".into())
+    }
+}
+
+#[test]
+fn object_tracker() -> Result<()> {
+    let tracker = ObjectTracker::start();
+    let is_tracked = |object: &LiveObject| object.type_name.ends_with("::Tracked");
+
+    let object: IStringable = Tracked().into();
+    let identity: IUnknown = object.cast()?;
+
+    let live: Vec<LiveObject> = tracker.live().into_iter().filter(is_tracked).collect();
+    assert_eq!(live.len(), 1);
+    assert_eq!(live[0].address, identity.abi() as usize);
+    assert_eq!(live[0].thread, std::thread::current().id());
+
+    drop(identity);
+    drop(object);
+    assert!(!tracker.live().iter().any(is_tracked));
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use test_implement::*;
+use windows::*;
+use Windows::Foundation::{IClosable, IStringable};
+use Windows::Win32::System::{Com::IMarshal, WinRT::IWeakReferenceSource};
+
+#[implement(Windows::Foundation::{IStringable, IClosable})]
+struct Plain();
+
+#[allow(non_snake_case)]
+impl Plain {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Plain".into())
+    }
+
+    fn Close(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[implement(
+    agile = false,
+    marshal = free_threaded,
+    Windows::Foundation::IClosable,
+    Windows::Foundation::IStringable,
+)]
+struct FreeThreaded();
+
+#[allow(non_snake_case)]
+impl FreeThreaded {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("FreeThreaded".into())
+    }
+
+    fn Close(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[implement(class = "Test.Widget", Windows::Foundation::IStringable)]
+struct Widget();
+
+#[allow(non_snake_case)]
+impl Widget {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Widget".into())
+    }
+}
+
+#[implement(ref_count = "single_threaded", Windows::Foundation::IStringable)]
+struct SingleThreaded();
+
+#[allow(non_snake_case)]
+impl SingleThreaded {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("SingleThreaded".into())
+    }
+}
+
+#[implement(
+    class = "Test.Gadget",
+    ref_count = "single_threaded",
+    Windows::Foundation::IStringable
+)]
+struct Gadget();
+
+#[allow(non_snake_case)]
+impl Gadget {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Gadget".into())
+    }
+}
+
+#[test]
+fn plain() -> Result<()> {
+    let object: IInspectable = Plain().into();
+
+    assert!(object.cast::<IAgileObject>().is_ok());
+    assert!(object.cast::<IMarshal>().is_err());
+    assert_eq!(object.iids()?, [IStringable::IID, IClosable::IID]);
+    assert_eq!(object.runtime_class_name()?, "");
+
+    Ok(())
+}
+
+#[test]
+fn free_threaded() -> Result<()> {
+    let object: IInspectable = FreeThreaded().into();
+
+    assert!(object.cast::<IAgileObject>().is_err());
+    assert_eq!(object.iids()?, [IClosable::IID, IStringable::IID]);
+
+    // The marshaler is aggregated so it shares the object's identity.
+    let marshal = object.cast::<IMarshal>()?;
+    assert_eq!(marshal.cast::<IUnknown>()?, object.cast::<IUnknown>()?);
+    assert_eq!(marshal.cast::<IStringable>()?.ToString()?, "FreeThreaded");
+
+    Ok(())
+}
+
+#[test]
+fn class() -> Result<()> {
+    let object: IInspectable = Widget().into();
+
+    assert_eq!(object.runtime_class_name()?, "Test.Widget");
+    assert_eq!(object.cast::<IStringable>()?.ToString()?, "Widget");
+
+    Ok(())
+}
+
+#[test]
+fn single_threaded() -> Result<()> {
+    let object: IInspectable = SingleThreaded().into();
+
+    assert!(object.cast::<IAgileObject>().is_err());
+    assert!(object.cast::<IWeakReferenceSource>().is_err());
+
+    let stringable = object.cast::<IStringable>()?;
+    drop(object);
+    assert_eq!(stringable.ToString()?, "SingleThreaded");
+
+    Ok(())
+}
+
+#[test]
+fn combined() -> Result<()> {
+    let object: IInspectable = Gadget().into();
+
+    assert_eq!(object.runtime_class_name()?, "Test.Gadget");
+    assert!(object.cast::<IAgileObject>().is_err());
+    assert!(object.cast::<IWeakReferenceSource>().is_err());
+    assert_eq!(object.cast::<IStringable>()?.ToString()?, "Gadget");
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use test_implement::*;
+use windows::*;
+use Windows::Foundation::EventRegistrationToken;
+use Windows::UI::Xaml::Data::{INotifyPropertyChanged, PropertyChangedEventHandler};
+
+#[implement(Windows::UI::Xaml::Data::INotifyPropertyChanged)]
+struct Person {
+    changed: PropertyChangedEvent,
+}
+
+#[allow(non_snake_case)]
+impl Person {
+    fn PropertyChanged(
+        &self,
+        handler: &Option<PropertyChangedEventHandler>,
+    ) -> Result<EventRegistrationToken> {
+        Ok(EventRegistrationToken {
+            Value: self.changed.add(handler)?,
+        })
+    }
+
+    fn RemovePropertyChanged(&self, token: &EventRegistrationToken) -> Result<()> {
+        self.changed.remove(token.Value);
+        Ok(())
+    }
+}
+
+#[test]
+fn property_changed() -> Result<()> {
+    let person = Person {
+        changed: PropertyChangedEvent::new(),
+    };
+
+    // Notifying without handlers doesn't need XAML to be initialized.
+    person.changed.notify(None, "Name")?;
+    assert!(person.changed.is_empty());
+
+    let names = Rc::new(RefCell::new(Vec::new()));
+    let handler_names = names.clone();
+
+    let handler = PropertyChangedEventHandler::new(move |_, args| {
+        let name = args.as_ref().unwrap().PropertyName()?;
+        handler_names.borrow_mut().push(name.to_string());
+        Ok(())
+    });
+
+    let token = person.changed.add(&Some(handler.clone()))?;
+    assert!(!person.changed.is_empty());
+    person.changed.remove(token);
+    assert!(person.changed.is_empty());
+
+    let person: INotifyPropertyChanged = person.into();
+    let token = person.PropertyChanged(handler)?;
+    person.RemovePropertyChanged(token)?;
+
+    assert!(names.borrow().is_empty());
+    Ok(())
+}
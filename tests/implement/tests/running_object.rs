@@ -0,0 +1,34 @@
+use test_implement::*;
+use windows::*;
+use Windows::Foundation::IStringable;
+
+#[implement(Windows::Foundation::IStringable)]
+struct Stringable();
+
+#[allow(non_snake_case)]
+impl Stringable {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("Running".into())
+    }
+}
+
+#[test]
+fn running_object_table() -> Result<()> {
+    initialize_mta()?;
+
+    let name = format!("test_implement.{}", std::process::id());
+    let object: IStringable = Stringable().into();
+    let registration = RunningObject::register(&object, &name)?;
+
+    assert_eq!(registration.display_name()?, format!("!{}", name));
+    assert!(running_objects()?.contains(&format!("!{}", name)));
+
+    let found: IStringable = running_object(&name)?;
+    assert_eq!(found.ToString()?, "Running");
+
+    // The object is revoked once the registration is dropped.
+    drop(registration);
+    assert!(running_object::<IStringable>(&name).is_err());
+
+    Ok(())
+}
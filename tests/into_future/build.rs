@@ -0,0 +1,12 @@
+fn main() {
+    windows::build! {
+        into_future = true,
+        Windows::Foundation::{
+            AsyncOperationCompletedHandler, AsyncStatus, IAsyncInfo, IAsyncOperation,
+        },
+        Windows::Storage::Streams::{
+            DataReader, DataReaderLoadOperation, DataWriter, DataWriterStoreOperation,
+            InMemoryRandomAccessStream,
+        },
+    };
+}
@@ -0,0 +1,83 @@
+use std::future::IntoFuture;
+use std::time::Duration;
+use test_into_future::Windows::Storage::Streams::*;
+
+fn write(stream: &InMemoryRandomAccessStream) -> windows::Result<DataWriter> {
+    let writer = DataWriter::CreateDataWriter(stream)?;
+    writer.WriteByte(1)?;
+    writer.WriteByte(2)?;
+    writer.WriteByte(3)?;
+    Ok(writer)
+}
+
+fn read(reader: &DataReader) -> windows::Result<[u8; 3]> {
+    let mut bytes = [0; 3];
+    reader.ReadBytes(&mut bytes)?;
+    Ok(bytes)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn spawn() -> windows::Result<()> {
+    // The future is `Send` so the task may be spawned on any of the runtime's threads.
+    tokio::spawn(async {
+        let stream = &InMemoryRandomAccessStream::new()?;
+        write(stream)?.StoreAsync()?.await?;
+
+        stream.Seek(0)?;
+        let reader = DataReader::CreateDataReader(stream)?;
+        assert_eq!(reader.LoadAsync(3)?.await?, 3);
+        assert_eq!(read(&reader)?, [1, 2, 3]);
+        Ok(())
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn select() -> windows::Result<()> {
+    let stream = &InMemoryRandomAccessStream::new()?;
+    write(stream)?.StoreAsync()?.await?;
+
+    stream.Seek(0)?;
+    let reader = DataReader::CreateDataReader(stream)?;
+
+    tokio::select! {
+        loaded = reader.LoadAsync(3)?.into_future() => assert_eq!(loaded?, 3),
+        _ = tokio::time::sleep(Duration::from_secs(10)) => panic!("timed out"),
+    }
+
+    assert_eq!(read(&reader)?, [1, 2, 3]);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn join() -> windows::Result<()> {
+    let first = &InMemoryRandomAccessStream::new()?;
+    let second = &InMemoryRandomAccessStream::new()?;
+
+    let (first_stored, second_stored) = tokio::join!(
+        write(first)?.StoreAsync()?.into_future(),
+        write(second)?.StoreAsync()?.into_future()
+    );
+
+    assert_eq!(first_stored?, 3);
+    assert_eq!(second_stored?, 3);
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn blocking() -> windows::Result<()> {
+    let bytes = windows::spawn_blocking(|| {
+        let stream = &InMemoryRandomAccessStream::new()?;
+        write(stream)?.StoreAsync()?.get()?;
+
+        stream.Seek(0)?;
+        let reader = DataReader::CreateDataReader(stream)?;
+        reader.LoadAsync(3)?.get()?;
+        read(&reader)
+    })
+    .await?;
+
+    assert_eq!(bytes, [1, 2, 3]);
+    Ok(())
+}
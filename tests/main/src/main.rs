@@ -0,0 +1,12 @@
+use windows::{Error, Result, HRESULT};
+
+#[windows::main]
+fn main() -> Result<()> {
+    match std::env::args().nth(1) {
+        Some(code) => Err(Error::new(
+            HRESULT(code.parse().unwrap()),
+            "Failed on request",
+        )),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,25 @@
+use std::process::Command;
+
+#[test]
+fn success() {
+    let output = Command::new(env!("CARGO_BIN_EXE_test_main"))
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn failure() {
+    let output = Command::new(env!("CARGO_BIN_EXE_test_main"))
+        .arg("2147942402")
+        .output()
+        .unwrap();
+
+    assert_eq!(output.status.code(), Some(0x8007_0002_u32 as i32));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap().trim_end(),
+        "Error 0x80070002: Failed on request"
+    );
+}
@@ -0,0 +1,8 @@
+fn main() {
+    windows::build! {
+        Windows::Foundation::Numerics::{
+            Matrix3x2, Matrix4x4, Quaternion, Vector2, Vector3, Vector4,
+        },
+        Windows::Foundation::{Point, Size},
+    };
+}
@@ -0,0 +1,65 @@
+use test_mint::Windows::Foundation::Numerics::*;
+use test_mint::Windows::Foundation::{Point, Size};
+
+#[test]
+fn vectors() {
+    let value = Vector2::new(1.0, 2.0);
+    let converted: mint::Vector2<f32> = value.clone().into();
+    assert_eq!(converted, mint::Vector2 { x: 1.0, y: 2.0 });
+    assert_eq!(Vector2::from(converted), value);
+
+    let value = Vector3::new(1.0, 2.0, 3.0);
+    let converted: mint::Vector3<f32> = value.clone().into();
+    assert_eq!(converted, mint::Vector3::from([1.0, 2.0, 3.0]));
+    assert_eq!(Vector3::from(converted), value);
+
+    let value = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let converted: mint::Vector4<f32> = value.clone().into();
+    assert_eq!(converted, mint::Vector4::from([1.0, 2.0, 3.0, 4.0]));
+    assert_eq!(Vector4::from(converted), value);
+}
+
+#[test]
+fn quaternion() {
+    let value = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let converted: mint::Quaternion<f32> = value.clone().into();
+    assert_eq!(converted.v, mint::Vector3::from([1.0, 2.0, 3.0]));
+    assert_eq!(converted.s, 4.0);
+    assert_eq!(Quaternion::from(converted), value);
+}
+
+#[test]
+fn matrices() {
+    let value = Matrix3x2::translation(10.0, 20.0);
+    let converted: mint::RowMatrix3x2<f32> = value.clone().into();
+    assert_eq!(converted.z, mint::Vector2 { x: 10.0, y: 20.0 });
+    assert_eq!(Matrix3x2::from(converted), value);
+
+    let value = Matrix4x4 {
+        M11: 1.0,
+        M22: 1.0,
+        M33: 1.0,
+        M41: 10.0,
+        M42: 20.0,
+        M43: 30.0,
+        M44: 1.0,
+        ..Default::default()
+    };
+
+    let converted: mint::RowMatrix4<f32> = value.clone().into();
+    assert_eq!(converted.w, mint::Vector4::from([10.0, 20.0, 30.0, 1.0]));
+    assert_eq!(Matrix4x4::from(converted), value);
+}
+
+#[test]
+fn geometry() {
+    let value = Point::new(1.0, 2.0);
+    let converted: mint::Point2<f32> = value.clone().into();
+    assert_eq!(converted, mint::Point2 { x: 1.0, y: 2.0 });
+    assert_eq!(Point::from(converted), value);
+
+    let value = Size::new(3.0, 4.0);
+    let converted: mint::Vector2<f32> = value.clone().into();
+    assert_eq!(converted, mint::Vector2 { x: 3.0, y: 4.0 });
+    assert_eq!(Size::from(converted), value);
+}
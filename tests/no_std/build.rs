@@ -0,0 +1,7 @@
+fn main() {
+    windows::build! {
+        std_feature = true,
+        Windows::Foundation::{DateTime, Uri},
+        Windows::Win32::Foundation::{CloseHandle, PWSTR},
+    };
+}
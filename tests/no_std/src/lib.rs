@@ -0,0 +1,3 @@
+#![no_std]
+
+windows::include_bindings!();
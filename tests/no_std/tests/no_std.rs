@@ -0,0 +1,11 @@
+use test_no_std::Windows::Foundation::Uri;
+use windows::*;
+
+// The bindings compile within a `#![no_std]` crate, which can't name `std` itself.
+#[test]
+fn no_std() -> Result<()> {
+    let uri = Uri::CreateUri("http://kennykerr.ca")?;
+    assert_eq!(uri.Domain()?, "kennykerr.ca");
+
+    Ok(())
+}
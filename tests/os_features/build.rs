@@ -0,0 +1,7 @@
+fn main() {
+    windows::build! {
+        os_features = true,
+        Windows::Win32::Foundation::HANDLE,
+        Windows::Win32::System::Memory::{MapViewOfFile3, VirtualAlloc},
+    };
+}
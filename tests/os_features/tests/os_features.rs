@@ -0,0 +1,27 @@
+use test_os_features::Windows::Win32::{
+    Foundation::HANDLE,
+    System::Memory::{MapViewOfFile3, VirtualAlloc},
+};
+
+#[test]
+fn test() {
+    unsafe {
+        // Functions available on the first release of Windows 10 are never gated.
+        let _ = VirtualAlloc;
+
+        // Functions requiring later builds are available when the build's feature is enabled.
+        let view = MapViewOfFile3(
+            HANDLE::default(),
+            HANDLE::default(),
+            std::ptr::null_mut(),
+            0,
+            0,
+            Default::default(),
+            0,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        assert!(view.is_null());
+    }
+}
@@ -0,0 +1,5 @@
+fn main() {
+    windows::build! {
+        Windows::Win32::System::SystemServices::DEVPKEY_NAME,
+    };
+}
@@ -0,0 +1,10 @@
+#[test]
+fn process() -> windows::Result<()> {
+    windows::set_app_user_model_id("Microsoft.Windows.Test")?;
+    assert_eq!(
+        windows::app_user_model_id().as_deref(),
+        Some("Microsoft.Windows.Test")
+    );
+
+    Ok(())
+}
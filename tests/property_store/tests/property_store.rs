@@ -0,0 +1,53 @@
+use test_property_store::Windows::Win32::System::SystemServices::DEVPKEY_NAME;
+use windows::{Guid, PropVariant, PropertyKey};
+
+#[test]
+fn keys() -> windows::Result<()> {
+    let key = PropertyKey::new(DEVPKEY_NAME.fmtid, DEVPKEY_NAME.pid);
+    assert_eq!(
+        key.fmtid,
+        Guid::from("B725F130-47EF-101A-A5F1-02608C9EEBAC")
+    );
+    assert_eq!(key.pid, 10);
+
+    assert_eq!(key.name()?, "System.ItemNameDisplay");
+    assert_eq!(PropertyKey::from_name("System.ItemNameDisplay")?, key);
+    assert!(PropertyKey::from_name("System.NotAProperty").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn values() -> windows::Result<()> {
+    let value = PropVariant::new();
+    assert!(value.is_empty());
+
+    let value = PropVariant::from_value(&"hello".to_string())?;
+    assert_eq!(value.value::<String>()?, "hello");
+
+    let value = PropVariant::from_value(&123u32)?;
+    assert_eq!(value.value::<u32>()?, 123);
+    assert_eq!(value.value::<i64>()?, 123);
+    assert_eq!(value.value::<String>()?, "123");
+
+    let value = PropVariant::from_value(&-1.5f64)?;
+    assert_eq!(value.value::<f64>()?, -1.5);
+
+    let value = PropVariant::from_value(&true)?;
+    assert!(value.value::<bool>()?);
+    assert!(!PropVariant::from_value(&false)?.value::<bool>()?);
+
+    let guid = Guid::from("B725F130-47EF-101A-A5F1-02608C9EEBAC");
+    let value = PropVariant::from_value(&guid)?;
+    assert_eq!(value.value::<Guid>()?, guid);
+
+    let strings = vec!["one".to_string(), "two".to_string()];
+    let value = PropVariant::from_value(&strings)?;
+    assert_eq!(value.clone().value::<Vec<String>>()?, strings);
+
+    assert!(PropVariant::from_value(&"text".to_string())?
+        .value::<u32>()
+        .is_err());
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+fn main() {
+    windows::build! {
+        Windows::Win32::Foundation::BSTR,
+        Windows::Win32::System::OleAutomation::{SafeArrayCopy, SafeArrayGetDim},
+    };
+}
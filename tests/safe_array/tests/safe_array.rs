@@ -0,0 +1,53 @@
+use std::convert::TryFrom;
+use test_safe_array::Windows::Win32::{
+    Foundation::BSTR,
+    System::OleAutomation::{SafeArrayCopy, SafeArrayGetDim},
+};
+use windows::SafeArray;
+
+#[test]
+fn empty() {
+    let array = SafeArray::<i32>::new();
+    assert!(array.is_empty());
+    assert_eq!(array.len(), 0);
+    assert_eq!(array.to_vec().unwrap(), Vec::<i32>::new());
+}
+
+#[test]
+fn primitives() -> windows::Result<()> {
+    let array = SafeArray::from_slice(&[1, 2, 3])?;
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.to_vec()?, [1, 2, 3]);
+    assert_eq!(format!("{:?}", array), "[1, 2, 3]");
+
+    let array = SafeArray::try_from(vec![1.5f64, 2.5])?;
+    assert_eq!(Vec::try_from(&array)?, [1.5, 2.5]);
+
+    Ok(())
+}
+
+#[test]
+fn strings() -> windows::Result<()> {
+    let array = SafeArray::from_slice(&[BSTR::from("hello"), BSTR::from("world")])?;
+    assert_eq!(array.to_vec()?, ["hello", "world"]);
+    Ok(())
+}
+
+#[test]
+fn params() -> windows::Result<()> {
+    let array = SafeArray::from_slice(&[1u8, 2, 3])?;
+
+    unsafe {
+        assert_eq!(SafeArrayGetDim(&array), 1);
+
+        let mut copy = SafeArray::<u8>::new();
+        SafeArrayCopy(&array, &mut copy)?;
+        assert_eq!(copy.to_vec()?, [1, 2, 3]);
+
+        let mut mismatch = SafeArray::<i32>::new();
+        SafeArrayCopy(&array, &mut mismatch)?;
+        assert!(mismatch.to_vec().is_err());
+    }
+
+    Ok(())
+}
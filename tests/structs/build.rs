@@ -8,5 +8,9 @@ fn main() {
         // in generated struct Debug implementations.
         // https://github.com/microsoft/windows-rs/issues/439
         Windows::Win32::UI::WindowsAndMessaging::CWPSTRUCT,
+
+        // Tests for packed structs, some of which are only packed on x86.
+        Windows::Win32::Devices::DeviceAndDriverInstallation::SP_DEVINFO_DATA,
+        Windows::Win32::UI::Shell::{NOTIFYICONDATAW, SHFILEINFOW, SHITEMID},
     };
 }
@@ -0,0 +1,47 @@
+use std::mem::{align_of, size_of};
+use test_structs::Windows::Win32::{
+    Devices::DeviceAndDriverInstallation::SP_DEVINFO_DATA,
+    UI::Shell::{NOTIFYICONDATAW, SHFILEINFOW, SHITEMID},
+};
+
+#[test]
+fn packed() {
+    assert_eq!(size_of::<SHITEMID>(), 3);
+    assert_eq!(align_of::<SHITEMID>(), 1);
+
+    let id = SHITEMID::default();
+    assert_eq!({ id.cb }, 0);
+}
+
+#[cfg(target_arch = "x86")]
+#[test]
+fn arch_packed() {
+    assert_eq!(size_of::<SHFILEINFOW>(), 692);
+    assert_eq!(align_of::<SHFILEINFOW>(), 1);
+    assert_eq!(size_of::<SP_DEVINFO_DATA>(), 28);
+    assert_eq!(align_of::<SP_DEVINFO_DATA>(), 1);
+    assert_eq!(size_of::<NOTIFYICONDATAW>(), 956);
+    assert_eq!(align_of::<NOTIFYICONDATAW>(), 1);
+}
+
+#[cfg(not(target_arch = "x86"))]
+#[test]
+fn arch_packed() {
+    assert_eq!(size_of::<SHFILEINFOW>(), 696);
+    assert_eq!(align_of::<SHFILEINFOW>(), 8);
+    assert_eq!(size_of::<SP_DEVINFO_DATA>(), 32);
+    assert_eq!(align_of::<SP_DEVINFO_DATA>(), 8);
+    assert_eq!(size_of::<NOTIFYICONDATAW>(), 976);
+    assert_eq!(align_of::<NOTIFYICONDATAW>(), 8);
+}
+
+#[test]
+fn arch_packed_default() {
+    let data = SP_DEVINFO_DATA {
+        cbSize: size_of::<SP_DEVINFO_DATA>() as u32,
+        ..Default::default()
+    };
+
+    assert_eq!({ data.cbSize } as usize, size_of::<SP_DEVINFO_DATA>());
+    assert_eq!({ data.DevInst }, 0);
+}
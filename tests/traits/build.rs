@@ -0,0 +1,7 @@
+fn main() {
+    windows::build! {
+        traits = true,
+        Windows::Foundation::{IStringable, Uri},
+        Windows::Foundation::Collections::IVectorView,
+    };
+}
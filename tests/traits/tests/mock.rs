@@ -0,0 +1,56 @@
+use test_traits::Windows::Foundation::Collections::IVectorViewTrait;
+use test_traits::Windows::Foundation::{IStringable, IStringableTrait, Uri};
+use windows::*;
+
+// Code written against the companion trait rather than the interface itself.
+fn describe<T: IStringableTrait>(value: &T) -> Result<String> {
+    Ok(format!("<{}>", value.ToString()?))
+}
+
+fn last<T: IVectorViewTrait<HSTRING>>(vector: &T) -> Result<HSTRING> {
+    vector.GetAt(vector.Size()? - 1)
+}
+
+struct MockStringable;
+
+impl IStringableTrait for MockStringable {
+    fn ToString(&self) -> Result<HSTRING> {
+        Ok("mock".into())
+    }
+}
+
+struct MockVector(Vec<&'static str>);
+
+impl IVectorViewTrait<HSTRING> for MockVector {
+    fn GetAt(&self, index: u32) -> Result<HSTRING> {
+        self.0
+            .get(index as usize)
+            .map(|value| HSTRING::from(*value))
+            .ok_or_else(|| Error::fast_error(HRESULT(0x8000_000B)))
+    }
+    fn Size(&self) -> Result<u32> {
+        Ok(self.0.len() as u32)
+    }
+    fn IndexOf<'a>(&self, _: impl IntoParam<'a, HSTRING>, _: &mut u32) -> Result<bool> {
+        unimplemented!()
+    }
+    fn GetMany(&self, _: u32, _: &mut [HSTRING]) -> Result<u32> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn real() -> Result<()> {
+    let uri = Uri::CreateUri("http://kennykerr.ca/")?;
+    let stringable: IStringable = uri.cast()?;
+    assert_eq!(describe(&stringable)?, "<http://kennykerr.ca/>");
+    Ok(())
+}
+
+#[test]
+fn mock() -> Result<()> {
+    assert_eq!(describe(&MockStringable)?, "<mock>");
+    assert_eq!(last(&MockVector(vec!["a", "b"]))?, "b");
+    assert!(MockVector(vec![]).GetAt(0).is_err());
+    Ok(())
+}
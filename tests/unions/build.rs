@@ -3,5 +3,6 @@ fn main() {
         Windows::Win32::Graphics::Direct3D12::D3D12_INDIRECT_ARGUMENT_DESC,
         Windows::Win32::System::SystemServices::OVERLAPPED,
         Windows::Win32::UI::ColorSystem::WhitePoint,
+        Windows::Win32::UI::KeyboardAndMouseInput::INPUT,
     };
 }
@@ -0,0 +1,29 @@
+use test_unions::Windows::Win32::UI::KeyboardAndMouseInput::{
+    INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYEVENTF_KEYUP,
+};
+
+#[test]
+fn test() {
+    let mut input = INPUT {
+        r#type: INPUT_KEYBOARD,
+        Anonymous: INPUT_0 {
+            ki: KEYBDINPUT {
+                wVk: 0x41,
+                ..Default::default()
+            },
+        },
+    };
+
+    assert_eq!(input.r#type, INPUT_KEYBOARD);
+
+    unsafe {
+        assert_eq!(input.Anonymous.ki.wVk, 0x41);
+        assert_eq!(input.Anonymous.ki.dwFlags, Default::default());
+    }
+
+    input.Anonymous.ki.dwFlags = KEYEVENTF_KEYUP;
+
+    unsafe {
+        assert_eq!(input.Anonymous.ki.dwFlags, KEYEVENTF_KEYUP);
+    }
+}
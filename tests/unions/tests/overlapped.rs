@@ -65,3 +65,42 @@ fn test_arch() {
 fn test_arch() {
     assert_eq!(std::mem::size_of::<OVERLAPPED>(), 20);
 }
+
+#[test]
+fn default() {
+    let o = OVERLAPPED::default();
+    assert_eq!(o.Internal, 0);
+    assert_eq!(o.InternalHigh, 0);
+    assert_eq!(o.hEvent, HANDLE(0));
+
+    unsafe {
+        assert_eq!(o.Anonymous.Pointer, std::ptr::null_mut());
+        assert_eq!(o.Anonymous.Anonymous.Offset, 0);
+        assert_eq!(o.Anonymous.Anonymous.OffsetHigh, 0);
+    }
+
+    let mut union = OVERLAPPED_0::default();
+    union.Anonymous.Offset = 1;
+    let copy = union;
+    union.Anonymous.Offset = 2;
+
+    unsafe {
+        assert_eq!(copy.Anonymous.Offset, 1);
+        assert_eq!(union.Anonymous.Offset, 2);
+    }
+
+    assert_eq!(format!("{:?}", copy), "OVERLAPPED_0");
+}
+
+#[test]
+fn nested_debug() {
+    let nested = OVERLAPPED_0_0 {
+        Offset: 1,
+        OffsetHigh: 2,
+    };
+
+    assert_eq!(
+        format!("{:?}", nested),
+        "OVERLAPPED_0_0 { Offset: 1, OffsetHigh: 2 }"
+    );
+}
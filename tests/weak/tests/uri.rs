@@ -14,6 +14,18 @@ fn test_success() -> Result<()> {
     Ok(())
 }
 
+// Weak references to agile classes may be upgraded on other threads.
+#[test]
+fn test_thread() -> Result<()> {
+    let strong = Uri::CreateUri("http://kennykerr.ca")?;
+    let weak = Uri::downgrade(&strong)?;
+
+    let upgraded = std::thread::spawn(move || weak.upgrade()).join().unwrap();
+    assert_eq!(upgraded.unwrap(), strong);
+
+    Ok(())
+}
+
 // The Uri class factory does not support weak references, so it is used to test an unsuccessful downgrade.
 #[test]
 fn test_failure() -> Result<()> {
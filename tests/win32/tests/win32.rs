@@ -216,6 +216,10 @@ fn com_inheritance() {
                 .code()
                 == DXGI_ERROR_INVALID_CALL
         );
+
+        // Converting to a base interface doesn't need a cast.
+        let base: IDXGIFactory1 = (&factory).into();
+        assert!(base.IsCurrent().as_bool());
     }
 }
 
@@ -262,6 +266,9 @@ fn interface() -> windows::Result<()> {
 
         let domain = uri.GetDomain()?;
         assert!(domain == "kennykerr.ca");
+
+        let uri = CreateUri(windows::w!("http://kennykerr.ca"), Default::default(), 0)?;
+        assert!(uri.GetPort()? == 80);
     }
     Ok(())
 }
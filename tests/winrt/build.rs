@@ -24,13 +24,15 @@ fn main() {
         // This tests that it is escaped.
         Windows::Globalization::ICurrencyIdentifiersStatics,
 
+        Windows::Storage::Pickers::FileOpenPicker,
         Windows::Storage::Streams::{
-            DataReader, DataReaderLoadOperation, DataWriter, DataWriterStoreOperation,
-            InMemoryRandomAccessStream, RandomAccessStreamReference,
+            ByteOrder, DataReader, DataReaderLoadOperation, DataWriter, DataWriterStoreOperation,
+            InMemoryRandomAccessStream, RandomAccessStreamReference, UnicodeEncoding,
         },
 
         Windows::Win32::Foundation::{E_NOINTERFACE, E_POINTER},
         Windows::Win32::System::WinRT::CreateDispatcherQueueController,
+        Windows::Win32::UI::WindowsAndMessaging::GetDesktopWindow,
         Windows::AI::MachineLearning::*,
         Windows::UI::Composition::{
             CompositionColorBrush, Compositor, SpriteVisual, Visual, VisualCollection,
@@ -1,4 +1,5 @@
 use test_winrt::Windows::Foundation::Uri;
+use test_winrt::Windows::Storage::Streams::{DataReader, DataWriter};
 use windows::Array;
 
 #[test]
@@ -18,3 +19,34 @@ fn array() {
     assert!(a[0].as_ref().unwrap().Domain().unwrap() == "kennykerr.ca");
     assert!(a[1].as_ref().unwrap().Domain().unwrap() == "microsoft.com");
 }
+
+#[test]
+fn into_vec() {
+    let mut a = Array::<Uri>::with_len(2);
+    a[1] = Uri::CreateUri("http://kennykerr.ca").ok();
+
+    let v = a.into_vec();
+    assert_eq!(v.len(), 2);
+    assert!(v[0].is_none());
+    assert!(v[1].as_ref().unwrap().Domain().unwrap() == "kennykerr.ca");
+}
+
+#[test]
+fn slices() -> windows::Result<()> {
+    let writer = DataWriter::new()?;
+    let bytes: Vec<u8> = (0..=255).collect();
+    writer.WriteBytes(&bytes)?;
+    writer.WriteBytes(&[])?;
+
+    let reader = DataReader::FromBuffer(writer.DetachBuffer()?)?;
+    let mut first = vec![0; 16];
+    let mut rest = vec![0; 240];
+    reader.ReadBytes(&mut first)?;
+    reader.ReadBytes(&mut rest[..])?;
+    reader.ReadBytes(&mut [])?;
+
+    assert_eq!(first, &bytes[..16]);
+    assert_eq!(rest, &bytes[16..]);
+
+    Ok(())
+}
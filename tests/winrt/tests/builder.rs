@@ -0,0 +1,21 @@
+use test_winrt::Windows::Storage::Streams::*;
+
+#[test]
+fn with_properties() -> windows::Result<()> {
+    let writer = DataWriter::new()?
+        .with_byte_order(ByteOrder::BigEndian)?
+        .with_unicode_encoding(UnicodeEncoding::Utf16LE)?;
+
+    assert_eq!(writer.ByteOrder()?, ByteOrder::BigEndian);
+    assert_eq!(writer.UnicodeEncoding()?, UnicodeEncoding::Utf16LE);
+
+    writer.WriteUInt16(0x0102)?;
+    let buffer = writer.DetachBuffer()?;
+    let reader = DataReader::FromBuffer(&buffer)?;
+
+    let mut bytes: [u8; 2] = [0; 2];
+    reader.ReadBytes(&mut bytes)?;
+    assert_eq!(bytes, [1, 2]);
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+use test_winrt::TestComponent::*;
+use windows::*;
+
+async fn cancel_operation() -> Result<()> {
+    let tests = TestRunner::MakeTests()?;
+    let token = CancellationToken::new()?;
+
+    let operation = tests.Async3(TestRunner::CreateAsyncAction(200)?, false, 123)?;
+    token.link(&operation)?;
+
+    let waiter = token.clone();
+    let cancelled = async move {
+        waiter.cancelled().await;
+        waiter.is_cancelled()
+    };
+
+    token.cancel();
+    assert!(cancelled.await);
+
+    let error = operation.await.unwrap_err();
+    assert_eq!(error.code(), token.check().unwrap_err().code());
+
+    Ok(())
+}
+
+#[test]
+fn cancellation() -> Result<()> {
+    futures::executor::block_on(cancel_operation())
+}
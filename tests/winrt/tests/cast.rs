@@ -24,3 +24,47 @@ fn try_into() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn downcast() -> Result<()> {
+    use test_winrt::Windows::Foundation::Collections::PropertySet;
+    use windows::IInspectable;
+
+    let object = IInspectable::from(Uri::CreateUri("http://kennykerr.ca")?);
+    assert_eq!(object.runtime_class_name()?, "Windows.Foundation.Uri");
+
+    let uri = object.try_cast::<Uri>().unwrap();
+    assert!(uri.Domain()? == "kennykerr.ca");
+    assert!(object.try_cast::<PropertySet>().is_none());
+    assert!(object.try_cast::<IClosable>().is_none());
+
+    Ok(())
+}
+
+#[test]
+fn unrelated() -> Result<()> {
+    use test_winrt::Windows::Foundation::Collections::*;
+    use test_winrt::Windows::Win32::Foundation::E_NOINTERFACE;
+    use windows::{IInspectable, IUnknown, HSTRING};
+
+    let set = PropertySet::new()?;
+    set.Insert(
+        "key",
+        IInspectable::from(Uri::CreateUri("http://kennykerr.ca")?),
+    )?;
+
+    // Every wrapper, including IUnknown, IInspectable, and generic interfaces, supports cast.
+    let unknown: IUnknown = set.cast()?;
+    let inspectable: IInspectable = unknown.cast()?;
+    let map: IMap<HSTRING, IInspectable> = inspectable.cast()?;
+    let iterable: IIterable<IKeyValuePair<HSTRING, IInspectable>> = map.cast()?;
+    assert_eq!(iterable.First()?.Current()?.Key()?, "key");
+
+    let set: PropertySet = iterable.cast()?;
+    assert_eq!(set.Size()?, 1);
+
+    // Failed casts report E_NOINTERFACE.
+    assert_eq!(set.cast::<IClosable>().unwrap_err().code(), E_NOINTERFACE);
+
+    Ok(())
+}
@@ -0,0 +1,259 @@
+//! Conformance tests exercising the ABI in both directions against `TestComponent`, which is
+//! built with C++/WinRT. Each case passes values from Rust to C++ and has C++ call back into Rust
+//! with delegates implemented in Rust, so that the projections are checked to agree on how
+//! values, strings, arrays, async results, and errors cross the boundary.
+//!
+//! New cases are added by extending the tables below rather than writing each test by hand.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use test_winrt::TestComponent::*;
+use test_winrt::Windows::Foundation::{EventHandler, TypedEventHandler};
+use windows::*;
+
+// Copies the elements of a slice into a new WinRT array.
+fn copy<T: RuntimeType>(values: &[T::DefaultType]) -> Array<T>
+where
+    T::DefaultType: Clone,
+{
+    let mut array = Array::with_len(values.len());
+    array.clone_from_slice(values);
+    array
+}
+
+// Generates a test for each primitive type that round trips every value in its table through
+// the component as an input, output, and return value both individually and as arrays, and then
+// has the component call back into Rust with its own values through both kinds of delegate.
+macro_rules! primitives {
+    ($($name:ident: $ty:ty => $param:ident, $param_call:ident($param_handler:ident), $array:ident, $array_call:ident($array_handler:ident) = [$($value:expr),* $(,)?];)*) => {
+        $(
+            #[test]
+            fn $name() -> Result<()> {
+                let tests = TestRunner::MakeTests()?;
+                let values: &[$ty] = &[$($value),*];
+
+                for value in values {
+                    let mut out = <$ty>::default();
+                    let result = tests.$param(*value, &mut out)?;
+                    assert_eq!(out, *value);
+                    assert_eq!(result, *value);
+                }
+
+                let mut filled = vec![<$ty>::default(); values.len()];
+                let mut received = Array::new();
+                let returned = tests.$array(values, &mut filled, &mut received)?;
+                assert_eq!(filled, values);
+                assert_eq!(&received[..], values);
+                assert_eq!(&returned[..], values);
+
+                let calls = Rc::new(Cell::new(0));
+                let counter = calls.clone();
+
+                tests.$param_call($param_handler::new(move |a, b| {
+                    counter.set(counter.get() + 1);
+                    *b = a;
+                    Ok(a)
+                }))?;
+
+                let counter = calls.clone();
+
+                tests.$array_call($array_handler::new(move |a, b, c| {
+                    counter.set(counter.get() + 1);
+                    b.copy_from_slice(a);
+                    *c = copy(a);
+                    Ok(copy(a))
+                }))?;
+
+                assert_eq!(calls.get(), 2);
+                Ok(())
+            }
+        )*
+    };
+}
+
+primitives! {
+    bool_values: bool => Param1, Param1Call(Param1Handler), Array1, Array1Call(Array1Handler) = [true, false];
+    u8_values: u8 => Param2, Param2Call(Param2Handler), Array2, Array2Call(Array2Handler) = [0, 1, 0x7f, 0x80, u8::MAX];
+    u16_values: u16 => Param3, Param3Call(Param3Handler), Array3, Array3Call(Array3Handler) = [0, 1, 0x8000, u16::MAX];
+    u32_values: u32 => Param4, Param4Call(Param4Handler), Array4, Array4Call(Array4Handler) = [0, 1, 0x8000_0000, u32::MAX];
+    u64_values: u64 => Param5, Param5Call(Param5Handler), Array5, Array5Call(Array5Handler) = [0, 1, 0x8000_0000_0000_0000, u64::MAX];
+    i16_values: i16 => Param6, Param6Call(Param6Handler), Array6, Array6Call(Array6Handler) = [0, -1, i16::MIN, i16::MAX];
+    i32_values: i32 => Param7, Param7Call(Param7Handler), Array7, Array7Call(Array7Handler) = [0, -1, i32::MIN, i32::MAX];
+    i64_values: i64 => Param8, Param8Call(Param8Handler), Array8, Array8Call(Array8Handler) = [0, -1, i64::MIN, i64::MAX];
+    f32_values: f32 => Param9, Param9Call(Param9Handler), Array9, Array9Call(Array9Handler) = [0.0, -1.5, f32::MIN_POSITIVE, f32::MAX, f32::INFINITY, f32::NEG_INFINITY];
+    f64_values: f64 => Param10, Param10Call(Param10Handler), Array10, Array10Call(Array10Handler) = [0.0, -1.5, f64::MIN_POSITIVE, f64::MAX, f64::INFINITY, f64::NEG_INFINITY];
+    // WinRT characters are UTF-16 code units so a lone surrogate must pass through unchanged.
+    char_values: u16 => Param11, Param11Call(Param11Handler), Array11, Array11Call(Array11Handler) = [0, 'a' as u16, 0xd800, 0xdfff, u16::MAX];
+}
+
+// Strings that projections have historically disagreed on, such as embedded nulls, which an
+// `HSTRING` may contain since its length is stored separately, and characters outside the basic
+// multilingual plane, which are encoded as surrogate pairs.
+fn strings() -> Vec<HSTRING> {
+    vec![
+        HSTRING::new(),
+        "a".into(),
+        "embedded\0null".into(),
+        "\0".into(),
+        "trailing null\0".into(),
+        "\u{1F600} \u{10FFFF}".into(),
+        "e\u{301}".into(),
+        "x".repeat(0x10000).as_str().into(),
+    ]
+}
+
+#[test]
+fn string_values() -> Result<()> {
+    let tests = TestRunner::MakeTests()?;
+    let values = strings();
+
+    for value in &values {
+        let mut out = HSTRING::new();
+        let result = tests.Param12(value, &mut out)?;
+        assert_eq!(out.len(), value.len());
+        assert_eq!(out, *value);
+        assert_eq!(result, *value);
+    }
+
+    let mut filled = vec![HSTRING::new(); values.len()];
+    let mut received = Array::new();
+    let returned = tests.Array12(&values, &mut filled, &mut received)?;
+    assert_eq!(filled, values);
+    assert_eq!(&received[..], &values[..]);
+    assert_eq!(&returned[..], &values[..]);
+
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+
+    tests.Param12Call(Param12Handler::new(move |a, b| {
+        counter.set(counter.get() + 1);
+        *b = a.clone();
+        Ok(a.clone())
+    }))?;
+
+    let counter = calls.clone();
+
+    tests.Array12Call(Array12Handler::new(move |a, b, c| {
+        counter.set(counter.get() + 1);
+        b.clone_from_slice(a);
+        *c = copy(a);
+        Ok(copy(a))
+    }))?;
+
+    assert_eq!(calls.get(), 2);
+    Ok(())
+}
+
+#[test]
+fn empty_arrays() -> Result<()> {
+    let tests = TestRunner::MakeTests()?;
+
+    let mut received = Array::new();
+    let returned = tests.Array7(&[], &mut [], &mut received)?;
+    assert!(received.is_empty());
+    assert!(returned.is_empty());
+
+    let mut received = Array::new();
+    let returned = tests.Array12(&[], &mut [], &mut received)?;
+    assert!(received.is_empty());
+    assert!(returned.is_empty());
+
+    Ok(())
+}
+
+// An error returned by a Rust delegate must reach the Rust caller with its code and message
+// intact after passing through C++, which rethrows it as an exception and then returns it.
+#[test]
+fn delegate_errors() -> Result<()> {
+    let tests = TestRunner::MakeTests()?;
+    let code = HRESULT(0x8000_FFFF);
+
+    let error = tests
+        .Param7Call(Param7Handler::new(move |_, _| {
+            Err(Error::new(code, "raised by Rust"))
+        }))
+        .unwrap_err();
+
+    assert_eq!(error.code(), code);
+    assert_eq!(error.message(), "raised by Rust");
+
+    let error = tests
+        .Param12Call(Param12Handler::new(move |_, _| {
+            Err(Error::new(code, "raised by Rust"))
+        }))
+        .unwrap_err();
+
+    assert_eq!(error.code(), code);
+    assert_eq!(error.message(), "raised by Rust");
+
+    Ok(())
+}
+
+async fn async_values() -> Result<()> {
+    let tests = TestRunner::MakeTests()?;
+
+    for value in &[0, -1, i32::MIN, i32::MAX] {
+        let result = tests
+            .Async3(TestRunner::CreateAsyncAction(0)?, false, *value)?
+            .await?;
+        assert_eq!(result, *value);
+
+        let result = tests
+            .Async4(TestRunner::CreateAsyncAction(0)?, false, *value, 0)?
+            .await?;
+        assert_eq!(result, *value);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn async_results() -> Result<()> {
+    futures::executor::block_on(async_values())
+}
+
+#[test]
+fn events() -> Result<()> {
+    let tests = TestRunner::MakeTests()?;
+    let values = [0, -1, i32::MIN, i32::MAX];
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let sink = received.clone();
+
+    let token = tests.Event1(EventHandler::<i32>::new(move |_, value| {
+        sink.borrow_mut().push(*value);
+        Ok(())
+    }))?;
+
+    for value in &values {
+        tests.Event1Call(*value)?;
+    }
+
+    tests.RemoveEvent1(token)?;
+    tests.Event1Call(1)?;
+    assert_eq!(*received.borrow(), values);
+
+    let received = Rc::new(RefCell::new(Vec::new()));
+    let sink = received.clone();
+    let source = tests.clone();
+
+    let token = tests.Event2(TypedEventHandler::<ITests, i32>::new(
+        move |sender, value| {
+            // The sender must have the same COM identity as the object raising the event.
+            let sender: IUnknown = sender.as_ref().unwrap().cast()?;
+            assert_eq!(sender, source.cast()?);
+            sink.borrow_mut().push(*value);
+            Ok(())
+        },
+    ))?;
+
+    for value in &values {
+        tests.Event2Call(*value)?;
+    }
+
+    tests.RemoveEvent2(token)?;
+    tests.Event2Call(1)?;
+    assert_eq!(*received.borrow(), values);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use std::convert::TryFrom;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use test_winrt::Windows::Foundation::{DateTime, IPropertyValue, PropertyValue, TimeSpan};
+use windows::Interface;
+
+#[test]
+fn conversion() -> windows::Result<()> {
+    let epoch: DateTime = UNIX_EPOCH.into();
+    assert_eq!(epoch.UniversalTime, 116_444_736_000_000_000);
+    assert_eq!(SystemTime::try_from(epoch)?, UNIX_EPOCH);
+
+    let time = UNIX_EPOCH + Duration::new(1_600_000_000, 123_456_700);
+    assert_eq!(SystemTime::try_from(DateTime::from(time))?, time);
+
+    let first = DateTime { UniversalTime: 0 };
+    assert_eq!(
+        UNIX_EPOCH
+            .duration_since(SystemTime::try_from(first)?)
+            .unwrap(),
+        Duration::from_secs(11_644_473_600)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn system_time_param() -> windows::Result<()> {
+    let time = UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+    let object = PropertyValue::CreateDateTime(time)?;
+    let pv: IPropertyValue = object.cast()?;
+    assert_eq!(SystemTime::try_from(pv.GetDateTime()?)?, time);
+
+    Ok(())
+}
+
+#[test]
+fn time_span_range() {
+    let negative = TimeSpan { Duration: -1 };
+    assert_eq!(Duration::from(negative), Duration::from_secs(0));
+
+    let max = TimeSpan { Duration: i64::MAX };
+    assert_eq!(TimeSpan::from(Duration::from(max)), max);
+    assert_eq!(TimeSpan::from(Duration::from_secs(u64::MAX)), max);
+}
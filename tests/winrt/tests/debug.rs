@@ -7,7 +7,14 @@ fn debugging() -> windows::Result<()> {
     let url = Uri::CreateUri("http://kennykerr.ca")?;
 
     assert!(format!("{:?}", doc).ends_with(" Windows.Data.Xml.Dom.XmlDocument)"));
-    assert!(&format!("{:?}", url).ends_with(" http://kennykerr.ca/)"));
+    assert!(&format!("{:?}", url).ends_with(" Windows.Foundation.Uri \"http://kennykerr.ca/\")"));
+
+    // Classes without `IStringable` are displayed by their runtime class name.
+    assert_eq!(doc.to_string(), "Windows.Data.Xml.Dom.XmlDocument");
+    assert_eq!(url.to_string(), "http://kennykerr.ca/");
+
+    let object: windows::IInspectable = url.into();
+    assert_eq!(object.to_string(), "http://kennykerr.ca/");
 
     Ok(())
 }
@@ -0,0 +1,56 @@
+use std::sync::mpsc::channel;
+use test_winrt::Windows::Storage::Streams::{DataWriter, InMemoryRandomAccessStream};
+use windows::{Dispatcher, DispatcherThread, IInspectable};
+
+#[test]
+fn spawn() -> windows::Result<()> {
+    assert!(Dispatcher::current()?.is_none());
+
+    let thread = DispatcherThread::dedicated()?;
+    let dispatcher = thread.dispatcher()?;
+    assert!(!dispatcher.has_thread_access()?);
+
+    let (sender, receiver) = channel();
+    let inner = dispatcher.clone();
+
+    dispatcher.spawn(async move {
+        let stream = InMemoryRandomAccessStream::new().unwrap();
+        let writer = DataWriter::CreateDataWriter(&stream).unwrap();
+        writer.WriteByte(1).unwrap();
+
+        // The operation completes on another thread, but the future resumes on the queue's.
+        let stored = writer.StoreAsync().unwrap().await.unwrap();
+        sender
+            .send((stored, inner.has_thread_access().unwrap()))
+            .unwrap();
+    })?;
+
+    assert_eq!(receiver.recv().unwrap(), (1, true));
+    Ok(())
+}
+
+#[test]
+fn spawn_local() -> windows::Result<()> {
+    let thread = DispatcherThread::dedicated()?;
+    let dispatcher = thread.dispatcher()?;
+
+    // Futures that aren't `Send` may only be spawned from the queue's own thread.
+    assert!(dispatcher.spawn_local(async {}).is_err());
+
+    let (sender, receiver) = channel();
+    let inner = dispatcher.clone();
+
+    let queue: IInspectable = dispatcher.queue()?;
+
+    windows::spawn_on(&queue, async move {
+        let value = std::rc::Rc::new(7);
+
+        // The sender is dropped, failing the test, if the future can't be spawned.
+        let _ = inner.spawn_local(async move {
+            sender.send(*value).unwrap();
+        });
+    })?;
+
+    assert_eq!(receiver.recv().unwrap(), 7);
+    Ok(())
+}
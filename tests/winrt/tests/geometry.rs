@@ -0,0 +1,46 @@
+use test_winrt::Windows::Foundation::{Point, Rect, Size};
+
+#[test]
+fn point() {
+    let a = Point::new(1.0, 2.0);
+    let b = Point::new(3.0, 5.0);
+    assert_eq!(&a + &b, Point::new(4.0, 7.0));
+    assert_eq!(b.clone() - a.clone(), Point::new(2.0, 3.0));
+    assert_eq!(a.clone() * 2.0, Point::new(2.0, 4.0));
+    assert_eq!(&b / 2.0, Point::new(1.5, 2.5));
+}
+
+#[test]
+fn size() {
+    let a = Size::new(1.0, 2.0);
+    let b = Size::new(3.0, 5.0);
+    assert_eq!(&a + &b, Size::new(4.0, 7.0));
+    assert_eq!(b - a.clone(), Size::new(2.0, 3.0));
+    assert_eq!(a * 3.0, Size::new(3.0, 6.0));
+}
+
+#[test]
+fn rect() {
+    let rect = Rect::from_point_size(&Point::new(10.0, 20.0), &Size::new(30.0, 40.0));
+    assert_eq!(rect, Rect::new(10.0, 20.0, 30.0, 40.0));
+    assert_eq!(rect.point(), Point::new(10.0, 20.0));
+    assert_eq!(rect.size(), Size::new(30.0, 40.0));
+    assert_eq!((rect.right(), rect.bottom()), (40.0, 60.0));
+    assert!(!rect.is_empty());
+    assert!(Rect::new(0.0, 0.0, 0.0, 10.0).is_empty());
+
+    assert!(rect.contains(&Point::new(10.0, 20.0)));
+    assert!(rect.contains(&Point::new(39.0, 59.0)));
+    assert!(!rect.contains(&Point::new(40.0, 20.0)));
+
+    let moved = rect.offset(&Point::new(-10.0, 5.0));
+    assert_eq!(moved, Rect::new(0.0, 25.0, 30.0, 40.0));
+}
+
+#[test]
+fn const_constructors() {
+    const ORIGIN: Point = Point::new(0.0, 0.0);
+    const BOUNDS: Rect = Rect::from_point_size(&ORIGIN, &Size::new(30.0, 40.0));
+
+    assert_eq!(BOUNDS, Rect::new(0.0, 0.0, 30.0, 40.0));
+}
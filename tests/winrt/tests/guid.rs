@@ -25,3 +25,18 @@ fn guid_from_string() {
 
     assert!(a == b);
 }
+
+#[test]
+fn guid_const() {
+    const CLSID: Guid = Guid::from_values(
+        0xCFF52E04,
+        0xCCA6,
+        0x4614,
+        [0xA1, 0x7E, 0x75, 0x49, 0x10, 0xC8, 0x4A, 0x99],
+    );
+
+    match Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99") {
+        CLSID => {}
+        _ => panic!("the constant should match"),
+    }
+}
@@ -0,0 +1,27 @@
+use test_winrt::Windows::Foundation::Uri;
+use test_winrt::Windows::Storage::Pickers::FileOpenPicker;
+use test_winrt::Windows::Win32::Foundation::E_NOINTERFACE;
+use test_winrt::Windows::Win32::UI::WindowsAndMessaging::GetDesktopWindow;
+use windows::{initialize_with_window, requires_window};
+
+#[test]
+fn picker() -> windows::Result<()> {
+    let picker = FileOpenPicker::new()?;
+    assert!(requires_window(&picker));
+
+    let window = unsafe { GetDesktopWindow() };
+    initialize_with_window(&picker, window.0)?;
+
+    Ok(())
+}
+
+#[test]
+fn unsupported() -> windows::Result<()> {
+    let uri = Uri::CreateUri("http://kennykerr.ca")?;
+    assert!(!requires_window(&uri));
+
+    let error = initialize_with_window(&uri, 0).unwrap_err();
+    assert_eq!(error.code(), E_NOINTERFACE);
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
+use test_winrt::Windows::Data::Xml::Dom::{XmlDocument, XmlElement};
 use test_winrt::Windows::Foundation::{PropertyValue, Uri};
-use windows::Interface;
+use windows::{IInspectable, Interface};
 
 #[test]
 fn into() -> windows::Result<()> {
@@ -17,3 +18,33 @@ fn into() -> windows::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn optional() -> windows::Result<()> {
+    let address = "http://kennykerr.ca".to_string();
+    let uri: Option<IInspectable> = Some(Uri::CreateUri(&address)?.into());
+
+    let object = PropertyValue::CreateInspectable(&uri)?; // optional reference
+
+    let uri = object.cast::<Uri>()?;
+    assert!(uri.Domain()? == "kennykerr.ca");
+
+    let object = PropertyValue::CreateInspectable(Some(IInspectable::from(uri)))?; // optional value
+
+    let uri = object.cast::<Uri>()?;
+    assert!(uri.Domain()? == "kennykerr.ca");
+
+    Ok(())
+}
+
+#[test]
+fn optional_class() -> windows::Result<()> {
+    let document = XmlDocument::new()?;
+    let element: Option<XmlElement> = Some(document.CreateElement("root")?);
+
+    document.AppendChild(&element)?; // optional class reference as interface
+
+    assert!(document.DocumentElement()?.TagName()? == "root");
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use test_winrt::Windows::Foundation::{IMemoryBufferReference, MemoryBuffer};
+use windows::MemoryBufferBytes;
+
+#[test]
+fn bytes() -> windows::Result<()> {
+    let buffer = MemoryBuffer::Create(16)?;
+
+    let mut bytes = MemoryBufferBytes::from_buffer(&buffer)?;
+    assert_eq!(bytes.len(), 16);
+    bytes.copy_from_slice(&[7; 16]);
+    drop(bytes);
+
+    let reference = buffer.CreateReference()?;
+    let bytes = MemoryBufferBytes::new(&reference)?;
+    assert_eq!(&bytes[..], &[7; 16]);
+    bytes.close()?;
+
+    Ok(())
+}
+
+#[test]
+fn reference() -> windows::Result<()> {
+    let buffer = MemoryBuffer::Create(4)?;
+    let reference: IMemoryBufferReference = buffer.CreateReference()?;
+
+    let mut bytes = MemoryBufferBytes::new(&reference)?;
+    bytes[0] = 1;
+    assert_eq!(&bytes[..], &[1, 0, 0, 0]);
+
+    Ok(())
+}
@@ -593,3 +593,49 @@ fn matrix4x4_mul() {
 
     test_with_scalar!(value1, value2, *, expected);
 }
+
+#[test]
+fn quaternion_add() {
+    let value1 = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let value2 = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    let expected = Quaternion::new(6.0, 8.0, 10.0, 12.0);
+
+    test_with_same_type!(value1, value2, +, expected);
+}
+
+#[test]
+fn quaternion_sub() {
+    let value1 = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let value2 = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    let expected = Quaternion::new(-4.0, -4.0, -4.0, -4.0);
+
+    test_with_same_type!(value1, value2, -, expected);
+}
+
+#[test]
+fn quaternion_mul() {
+    let value1 = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+    let value2 = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+    let expected = Quaternion::new(24.0, 48.0, 48.0, -6.0);
+
+    test_with_same_type!(value1, value2, *, expected);
+
+    let value2 = 2.0;
+    let expected = Quaternion::new(2.0, 4.0, 6.0, 8.0);
+
+    test_with_scalar!(value1, value2, *, expected);
+
+    // i * j = k
+    let i = Quaternion::new(1.0, 0.0, 0.0, 0.0);
+    let j = Quaternion::new(0.0, 1.0, 0.0, 0.0);
+    assert_eq!(i * j, Quaternion::new(0.0, 0.0, 1.0, 0.0));
+}
+
+#[test]
+fn quaternion_inverse() {
+    let value = Quaternion::new(1.0, 1.0, 1.0, 1.0);
+    assert_eq!(value.length(), 2.0);
+    assert_eq!(value.inverse(), Quaternion::new(-0.25, -0.25, -0.25, 0.25));
+    assert_eq!(value * value.inverse(), Quaternion::identity());
+    assert_eq!(-value, Quaternion::new(-1.0, -1.0, -1.0, -1.0));
+}
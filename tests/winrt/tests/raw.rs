@@ -0,0 +1,23 @@
+use test_winrt::Windows::Foundation::{IStringable, Uri};
+use windows::Interface;
+
+#[test]
+fn raw() -> windows::Result<()> {
+    let uri = Uri::CreateUri("http://kennykerr.ca")?;
+    let raw = uri.as_raw();
+    assert!(!raw.is_null());
+
+    // Passing ownership out and back in again leaves the reference count unchanged.
+    let copy = uri.clone().into_raw();
+    assert_eq!(copy, raw);
+    let copy = unsafe { Uri::from_raw(copy) };
+    assert_eq!(copy.ToString()?, "http://kennykerr.ca/");
+    drop(copy);
+
+    let stringable: IStringable = uri.cast()?;
+    let stringable = unsafe { IStringable::from_raw(stringable.into_raw()) };
+    assert_eq!(stringable.ToString()?, "http://kennykerr.ca/");
+
+    assert_eq!(uri.Domain()?, "kennykerr.ca");
+    Ok(())
+}
@@ -98,3 +98,10 @@ fn send_sync_err() {
 
     wait.join().unwrap();
 }
+
+// Interfaces implemented only by agile classes are also marked Send and Sync.
+#[test]
+fn send_sync_exclusive() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<IUriRuntimeClass>();
+}
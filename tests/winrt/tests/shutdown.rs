@@ -0,0 +1,41 @@
+use std::sync::{Arc, Mutex};
+use test_winrt::Windows::Foundation::Uri;
+
+// The steps share a single test since `shutdown` affects the whole process.
+#[test]
+fn shutdown() -> windows::Result<()> {
+    windows::initialize_mta()?;
+
+    // Callbacks run in reverse order and only once.
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    for index in 0..3 {
+        let order = order.clone();
+        windows::on_shutdown(move || order.lock().unwrap().push(index));
+    }
+
+    windows::shutdown();
+    assert_eq!(*order.lock().unwrap(), [2, 1, 0]);
+    windows::shutdown();
+    assert_eq!(order.lock().unwrap().len(), 3);
+
+    // The factory cached by the first call is released and cached again by the next.
+    let uri = Uri::CreateUri("http://kennykerr.ca")?;
+    windows::shutdown();
+    assert_eq!(uri.Domain()?, "kennykerr.ca");
+    let uri = Uri::CreateUri("http://kennykerr.ca")?;
+
+    // Releasing an object after shutting down panics once the check is enabled.
+    windows::set_late_release_check(true);
+    windows::shutdown();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || drop(uri)));
+    assert!(result.is_err());
+
+    // Initializing COM again clears the check.
+    windows::initialize_mta()?;
+    drop(Uri::CreateUri("http://kennykerr.ca")?);
+    windows::set_late_release_check(false);
+
+    windows::uninitialize();
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use std::io::{Read, Seek, SeekFrom, Write};
+use test_winrt::Windows::Storage::Streams::{DataReader, IInputStream, InMemoryRandomAccessStream};
+use windows::{Interface, OutputStreamWriter, RandomAccessStreamIo};
+
+#[test]
+fn random_access() -> windows::Result<()> {
+    let stream = InMemoryRandomAccessStream::new()?;
+    let mut io = RandomAccessStreamIo::with_capacity(4, &stream)?;
+
+    io.write_all(b"hello world")?;
+    io.flush()?;
+    assert_eq!(stream.Size()?, 11);
+
+    assert_eq!(io.seek(SeekFrom::Start(6))?, 6);
+    let mut text = String::new();
+    io.read_to_string(&mut text)?;
+    assert_eq!(text, "world");
+
+    assert_eq!(io.seek(SeekFrom::End(-5))?, 6);
+    assert_eq!(io.seek(SeekFrom::Current(-6))?, 0);
+    assert!(io.seek(SeekFrom::Current(-1)).is_err());
+
+    let mut bytes = [0; 5];
+    io.read_exact(&mut bytes)?;
+    assert_eq!(&bytes, b"hello");
+
+    Ok(())
+}
+
+#[test]
+fn output_stream() -> windows::Result<()> {
+    let stream = InMemoryRandomAccessStream::new()?;
+    let mut writer = OutputStreamWriter::new(&stream.GetOutputStreamAt(0)?)?;
+    writer.write_all(&[1, 2, 3])?;
+    writer.flush()?;
+
+    let reader = DataReader::CreateDataReader(stream.GetInputStreamAt(0)?)?;
+    assert_eq!(reader.LoadAsync(3)?.get()?, 3);
+    assert_eq!(reader.ReadByte()?, 1);
+
+    Ok(())
+}
+
+#[test]
+fn input_stream() -> windows::Result<()> {
+    let stream: IInputStream = windows::input_stream(&[1, 2, 3, 4][..]).cast()?;
+
+    let reader = DataReader::CreateDataReader(&stream)?;
+    assert_eq!(reader.LoadAsync(4)?.get()?, 4);
+    assert_eq!(reader.ReadByte()?, 1);
+    assert_eq!(reader.ReadByte()?, 2);
+
+    // The end of the reader is the end of the stream.
+    assert_eq!(reader.LoadAsync(4)?.get()?, 0);
+
+    Ok(())
+}
@@ -22,6 +22,10 @@ fn uri() -> windows::Result<()> {
     let stringable: IStringable = uri.into();
     assert!(stringable.ToString()? == uri.ToString()?);
 
+    // Classes implementing IStringable may be displayed.
+    assert_eq!(uri.to_string(), "http://kennykerr.ca/");
+    assert_eq!(format!("<{}>", uri), "<http://kennykerr.ca/>");
+
     Ok(())
 }
 
@@ -57,3 +61,11 @@ fn interface_conversion() -> windows::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn literal() -> windows::Result<()> {
+    let uri = Uri::CreateUri(windows::h!("http://kennykerr.ca"))?;
+    assert!(uri.Domain()? == "kennykerr.ca");
+
+    Ok(())
+}
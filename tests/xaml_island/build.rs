@@ -0,0 +1,9 @@
+fn main() {
+    windows::build! {
+        Windows::UI::Xaml::Controls::TextBlock,
+        Windows::UI::Xaml::Hosting::DesktopWindowXamlSource,
+        Windows::Win32::UI::WindowsAndMessaging::{
+            CreateWindowExW, DestroyWindow, WINDOW_STYLE,
+        },
+    };
+}
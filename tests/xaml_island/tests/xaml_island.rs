@@ -0,0 +1,48 @@
+use test_xaml_island::Windows::Win32::Foundation::{HINSTANCE, HWND};
+use test_xaml_island::Windows::Win32::UI::WindowsAndMessaging::*;
+use test_xaml_island::Windows::UI::Xaml::{Controls::TextBlock, Hosting::XamlIsland, UIElement};
+use windows::*;
+
+#[test]
+fn xaml_island() -> Result<()> {
+    initialize_sta()?;
+
+    let parent = unsafe {
+        CreateWindowExW(
+            WINDOW_EX_STYLE(0),
+            "STATIC",
+            "XAML island",
+            WS_OVERLAPPEDWINDOW,
+            0,
+            0,
+            640,
+            480,
+            HWND::default(),
+            HMENU::default(),
+            HINSTANCE::default(),
+            std::ptr::null_mut(),
+        )
+    };
+
+    assert!(!parent.is_null());
+
+    {
+        let island = XamlIsland::new(parent)?;
+        assert!(!island.window().is_null());
+
+        let text = TextBlock::new()?;
+        text.SetText("Hello")?;
+        island.set_content(&text)?;
+        island.set_bounds(0, 0, 320, 240)?;
+
+        let element: UIElement = text.into();
+        assert_eq!(island.source().Content()?, element);
+        assert!(!island.pre_translate_message(&MSG::default())?);
+    }
+
+    unsafe {
+        DestroyWindow(parent);
+    }
+
+    Ok(())
+}